@@ -308,13 +308,7 @@ fn draw_second_tab(frame: &mut Frame, app: &mut App, area: Rect) {
                 resolution: MapResolution::High,
             });
             ctx.layer();
-            ctx.draw(&Rectangle {
-                x: 0.0,
-                y: 30.0,
-                width: 10.0,
-                height: 10.0,
-                color: Color::Yellow,
-            });
+            ctx.draw(&Rectangle::new(0.0, 30.0, 10.0, 10.0, Color::Yellow));
             ctx.draw(&Circle {
                 x: app.servers[2].coords.1,
                 y: app.servers[2].coords.0,
@@ -323,13 +317,13 @@ fn draw_second_tab(frame: &mut Frame, app: &mut App, area: Rect) {
             });
             for (i, s1) in app.servers.iter().enumerate() {
                 for s2 in &app.servers[i + 1..] {
-                    ctx.draw(&canvas::Line {
-                        x1: s1.coords.1,
-                        y1: s1.coords.0,
-                        y2: s2.coords.0,
-                        x2: s2.coords.1,
-                        color: Color::Yellow,
-                    });
+                    ctx.draw(&canvas::Line::new(
+                        s1.coords.1,
+                        s1.coords.0,
+                        s2.coords.1,
+                        s2.coords.0,
+                        Color::Yellow,
+                    ));
                 }
             }
             for server in &app.servers {
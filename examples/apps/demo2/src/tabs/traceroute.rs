@@ -136,10 +136,12 @@ fn render_map(selected_row: usize, area: Rect, buf: &mut Buffer) {
                 context.draw(&Points {
                     color: theme.source,
                     coords: &[path.0.location], // sydney
+                    colors: &[],
                 });
                 context.draw(&Points {
                     color: theme.destination,
                     coords: &[path.1.location], // perth
+                    colors: &[],
                 });
             }
         })
@@ -109,6 +109,7 @@ use crate::{
     layout::{Position, Size},
 };
 
+pub mod osc;
 mod test;
 pub use self::test::TestBackend;
 
@@ -165,6 +166,26 @@ pub trait Backend {
         Ok(())
     }
 
+    /// Ring the terminal bell.
+    ///
+    /// Depending on the configuration of the terminal, this plays a sound, flashes the screen or
+    /// marks the window as urgent.
+    ///
+    /// This method is optional and may not be implemented by all backends.
+    fn bell(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Send a desktop notification with the given title and body.
+    ///
+    /// Backends that write escape sequences use the OSC 9 or OSC 777 sequence, which are supported
+    /// by many terminal emulators. Terminals that don't support them ignore the notification.
+    ///
+    /// This method is optional and may not be implemented by all backends.
+    fn notify(&mut self, _title: &str, _body: &str) -> io::Result<()> {
+        Ok(())
+    }
+
     /// Hide the cursor on the terminal screen.
     ///
     ///
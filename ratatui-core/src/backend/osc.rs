@@ -0,0 +1,152 @@
+//! Operating System Command (OSC) escape sequences shared by the backends.
+//!
+//! The types of this module implement [`Display`](fmt::Display), so that backends can write them
+//! with their own writer. Control characters are removed from the text they contain so that it
+//! can't end the sequence early.
+
+use std::fmt::{self, Write};
+
+/// Displays the [OSC 8] escape sequence that starts a hyperlink to the given URL, or that ends the
+/// current hyperlink when the URL is `None`.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::backend::osc::Osc8Hyperlink;
+///
+/// let start = Osc8Hyperlink::new(Some("https://ratatui.rs")).to_string();
+/// assert_eq!(start, "\x1b]8;;https://ratatui.rs\x1b\\");
+/// let end = Osc8Hyperlink::new(None).to_string();
+/// assert_eq!(end, "\x1b]8;;\x1b\\");
+/// ```
+///
+/// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Osc8Hyperlink<'a> {
+    url: Option<&'a str>,
+}
+
+impl<'a> Osc8Hyperlink<'a> {
+    /// Creates the sequence starting a hyperlink to the given URL, or ending the current hyperlink
+    /// when the URL is `None`.
+    pub const fn new(url: Option<&'a str>) -> Self {
+        Self { url }
+    }
+}
+
+impl fmt::Display for Osc8Hyperlink<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\x1b]8;;")?;
+        self.url
+            .unwrap_or_default()
+            .chars()
+            .filter(|c| !c.is_control())
+            .try_for_each(|c| f.write_char(c))?;
+        f.write_str("\x1b\\")
+    }
+}
+
+/// Displays the escape sequence of a desktop notification with the given title and body.
+///
+/// OSC 777 is used on terminals that only support it (rxvt and foot, detected from the `TERM`
+/// environment variable), and OSC 9 otherwise. As OSC 9 has no title, the title is prepended to
+/// the body.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::backend::osc::OscNotification;
+///
+/// let sequence = OscNotification::new("Done", "All tests passed").to_string();
+/// assert!(sequence.ends_with("All tests passed\x1b\\"));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct OscNotification<'a> {
+    title: &'a str,
+    body: &'a str,
+    osc777: bool,
+}
+
+impl<'a> OscNotification<'a> {
+    /// Creates the notification sequence for the terminal given by the `TERM` environment variable.
+    pub fn new(title: &'a str, body: &'a str) -> Self {
+        let osc777 = std::env::var("TERM")
+            .is_ok_and(|term| term.starts_with("rxvt") || term.starts_with("foot"));
+        Self {
+            title,
+            body,
+            osc777,
+        }
+    }
+}
+
+impl fmt::Display for OscNotification<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut title = self.title.chars().filter(|c| !c.is_control());
+        let mut body = self.body.chars().filter(|c| !c.is_control());
+        if self.osc777 {
+            f.write_str("\x1b]777;notify;")?;
+            // the title can't contain the separator of the fields
+            title
+                .map(|c| if c == ';' { ',' } else { c })
+                .try_for_each(|c| f.write_char(c))?;
+            f.write_char(';')?;
+        } else {
+            f.write_str("\x1b]9;")?;
+            if !self.title.is_empty() {
+                title.try_for_each(|c| f.write_char(c))?;
+                f.write_str(": ")?;
+            }
+        }
+        body.try_for_each(|c| f.write_char(c))?;
+        f.write_str("\x1b\\")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::osc9(
+        false,
+        "Done",
+        "All tests passed",
+        "\x1b]9;Done: All tests passed\x1b\\"
+    )]
+    #[case::osc9_without_title(false, "", "Done", "\x1b]9;Done\x1b\\")]
+    #[case::osc777(
+        true,
+        "Done",
+        "All tests passed",
+        "\x1b]777;notify;Done;All tests passed\x1b\\"
+    )]
+    #[case::osc777_separator(true, "a;b", "c;d", "\x1b]777;notify;a,b;c;d\x1b\\")]
+    #[case::control_characters(false, "\x1bDone", "ok\x07\n", "\x1b]9;Done: ok\x1b\\")]
+    fn osc_notification(
+        #[case] osc777: bool,
+        #[case] title: &str,
+        #[case] body: &str,
+        #[case] expected: &str,
+    ) {
+        let notification = OscNotification {
+            title,
+            body,
+            osc777,
+        };
+        assert_eq!(notification.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case::start(Some("https://ratatui.rs"), "\x1b]8;;https://ratatui.rs\x1b\\")]
+    #[case::end(None, "\x1b]8;;\x1b\\")]
+    #[case::control_characters(
+        Some("https://ratatui.rs\x1b\\\x1b]0;title\x07"),
+        "\x1b]8;;https://ratatui.rs\\]0;title\x1b\\"
+    )]
+    fn osc8_hyperlink(#[case] url: Option<&str>, #[case] expected: &str) {
+        assert_eq!(Osc8Hyperlink::new(url).to_string(), expected);
+    }
+}
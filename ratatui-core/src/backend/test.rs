@@ -6,12 +6,11 @@ use std::{
     io, iter,
 };
 
-use unicode_width::UnicodeWidthStr;
-
 use crate::{
     backend::{Backend, ClearType, WindowSize},
     buffer::{Buffer, Cell},
     layout::{Position, Rect, Size},
+    text::DisplayWidth,
 };
 
 /// A [`Backend`] implementation used for integration testing that renders to an memory buffer.
@@ -58,7 +57,7 @@ fn buffer_view(buffer: &Buffer) -> String {
             } else {
                 overwritten.push((x, c.symbol()));
             }
-            skip = std::cmp::max(skip, c.symbol().width()).saturating_sub(1);
+            skip = std::cmp::max(skip, c.symbol().display_width()).saturating_sub(1);
         }
         view.push('"');
         if !overwritten.is_empty() {
@@ -4,6 +4,8 @@
 mod assert;
 mod buffer;
 mod cell;
+mod sprite;
 
 pub use buffer::Buffer;
 pub use cell::Cell;
+pub use sprite::Sprite;
@@ -1,16 +1,15 @@
 use std::{
     fmt,
-    ops::{Index, IndexMut},
+    ops::{Index, IndexMut, Range},
 };
 
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
 
 use crate::{
     buffer::Cell,
     layout::{Position, Rect},
     style::Style,
-    text::{Line, Span},
+    text::{DisplayWidth, Line, Span},
 };
 
 /// A buffer that maps to the desired content of the terminal after the draw call
@@ -346,7 +345,7 @@ impl Buffer {
         let mut remaining_width = self.area.right().saturating_sub(x).min(max_width);
         let graphemes = UnicodeSegmentation::graphemes(string.as_ref(), true)
             .filter(|symbol| !symbol.contains(|char: char| char.is_control()))
-            .map(|symbol| (symbol, symbol.width() as u16))
+            .map(|symbol| (symbol, symbol.display_width() as u16))
             .filter(|(_symbol, width)| *width > 0)
             .map_while(|(symbol, width)| {
                 remaining_width = remaining_width.checked_sub(width)?;
@@ -381,6 +380,7 @@ impl Buffer {
                 remaining_width as usize,
                 line.style.patch(span.style),
             );
+            self.set_hyperlink(x..pos.0, y, span.hyperlink.as_deref());
             let w = pos.0.saturating_sub(x);
             x = pos.0;
             remaining_width = remaining_width.saturating_sub(w);
@@ -390,7 +390,19 @@ impl Buffer {
 
     /// Print a span, starting at the position (x, y)
     pub fn set_span(&mut self, x: u16, y: u16, span: &Span<'_>, max_width: u16) -> (u16, u16) {
-        self.set_stringn(x, y, &span.content, max_width as usize, span.style)
+        let pos = self.set_stringn(x, y, &span.content, max_width as usize, span.style);
+        self.set_hyperlink(x..pos.0, y, span.hyperlink.as_deref());
+        pos
+    }
+
+    /// Marks the cells in the given range of columns of row `y` as part of a hyperlink
+    fn set_hyperlink(&mut self, columns: Range<u16>, y: u16, url: Option<&str>) {
+        if url.is_none() {
+            return;
+        }
+        for x in columns {
+            self[(x, y)].set_hyperlink(url);
+        }
     }
 
     /// Set the style of all cells in the given area.
@@ -457,6 +469,58 @@ impl Buffer {
         self.area = area;
     }
 
+    /// Returns the content of the buffer as plain text, without any styles.
+    ///
+    /// Each row of the buffer becomes a line of the returned string, and lines are separated by a
+    /// line feed. Every line has the same display width as the buffer: the cells hidden by a wide
+    /// symbol are skipped, and empty or zero-width symbols are replaced by a space. This is useful
+    /// to log the state of the UI (e.g. in bug reports) or to write simple assertions about what
+    /// was rendered.
+    ///
+    /// When `preserve_borders` is `false`, the box-drawing characters (U+2500 to U+257F) are
+    /// replaced by their closest ASCII equivalent (`-`, `|`, `+`, `/`, `\` or `X`), so that the
+    /// output can be displayed where Unicode is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::buffer::Buffer;
+    ///
+    /// let buffer = Buffer::with_lines(["┌──┐", "│コ│", "└──┘"]);
+    /// assert_eq!(buffer.to_plain_text(true), "┌──┐\n│コ│\n└──┘");
+    /// assert_eq!(buffer.to_plain_text(false), "+--+\n|コ|\n+--+");
+    /// ```
+    pub fn to_plain_text(&self, preserve_borders: bool) -> String {
+        let mut text = String::with_capacity(self.content.len() + self.area.height as usize);
+        for (y, line) in self
+            .content
+            .chunks(self.area.width.max(1) as usize)
+            .enumerate()
+        {
+            if y > 0 {
+                text.push('\n');
+            }
+            let mut skip: usize = 0;
+            for cell in line {
+                let symbol = cell.symbol();
+                let width = symbol.display_width();
+                if skip > 0 {
+                    skip -= 1;
+                    continue;
+                }
+                skip = width.saturating_sub(1);
+                match symbol.chars().next() {
+                    _ if width == 0 => text.push(' '),
+                    Some(c) if !preserve_borders && symbol.len() == c.len_utf8() => {
+                        text.push(ascii_box_drawing(c).unwrap_or(c));
+                    }
+                    _ => text.push_str(symbol),
+                }
+            }
+        }
+        text
+    }
+
     /// Builds a minimal sequence of coordinates and Cells necessary to update the UI from
     /// self to other.
     ///
@@ -501,15 +565,35 @@ impl Buffer {
                 updates.push((x, y, &next_buffer[i]));
             }
 
-            to_skip = current.symbol().width().saturating_sub(1);
+            to_skip = current.symbol().display_width().saturating_sub(1);
 
-            let affected_width = std::cmp::max(current.symbol().width(), previous.symbol().width());
+            let affected_width = std::cmp::max(
+                current.symbol().display_width(),
+                previous.symbol().display_width(),
+            );
             invalidated = std::cmp::max(affected_width, invalidated).saturating_sub(1);
         }
         updates
     }
 }
 
+/// Returns the closest ASCII equivalent of a box-drawing character.
+const fn ascii_box_drawing(c: char) -> Option<char> {
+    match c {
+        '─' | '━' | '┄' | '┅' | '┈' | '┉' | '╌' | '╍' | '═' | '╴' | '╶' | '╸' | '╺' | '╼' | '╾' => {
+            Some('-')
+        }
+        '│' | '┃' | '┆' | '┇' | '┊' | '┋' | '╎' | '╏' | '║' | '╵' | '╷' | '╹' | '╻' | '╽' | '╿' => {
+            Some('|')
+        }
+        '╱' => Some('/'),
+        '╲' => Some('\\'),
+        '╳' => Some('X'),
+        '\u{2500}'..='\u{257F}' => Some('+'),
+        _ => None,
+    }
+}
+
 impl<P: Into<Position>> Index<P> for Buffer {
     type Output = Cell;
 
@@ -600,7 +684,7 @@ impl fmt::Debug for Buffer {
                 } else {
                     overwritten.push((x, c.symbol()));
                 }
-                skip = std::cmp::max(skip, c.symbol().width()).saturating_sub(1);
+                skip = std::cmp::max(skip, c.symbol().display_width()).saturating_sub(1);
                 #[cfg(feature = "underline-color")]
                 {
                     let style = (c.fg, c.bg, c.underline_color, c.modifier);
@@ -650,6 +734,7 @@ mod tests {
 
     use itertools::Itertools;
     use rstest::{fixture, rstest};
+    use unicode_width::UnicodeWidthStr;
 
     use super::*;
     use crate::style::{Color, Modifier, Stylize};
@@ -1121,6 +1206,28 @@ mod tests {
         assert_eq!(diff, [(0, 0, &Cell::new("4"))],);
     }
 
+    #[rstest]
+    #[case::preserve_borders(true, "┌─┬─┐\n│a│b│\n╞═╪═╡\n└─┴─┘")]
+    #[case::ascii_borders(false, "+-+-+\n|a|b|\n+-+-+\n+-+-+")]
+    fn to_plain_text_borders(#[case] preserve_borders: bool, #[case] expected: &str) {
+        let buffer = Buffer::with_lines(["┌─┬─┐", "│a│b│", "╞═╪═╡", "└─┴─┘"]);
+        assert_eq!(buffer.to_plain_text(preserve_borders), expected);
+    }
+
+    #[test]
+    fn to_plain_text_strips_styles() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 2));
+        buffer.set_string(1, 0, "abc", Style::new().red().bold());
+        assert_eq!(buffer.to_plain_text(true), " abc \n     ");
+    }
+
+    #[test]
+    fn to_plain_text_wide_symbols() {
+        let mut buffer = Buffer::with_lines(["コン a", "xyz   "]);
+        buffer[(5, 1)].set_symbol("");
+        assert_eq!(buffer.to_plain_text(true), "コン a\nxyz   ");
+    }
+
     #[rstest]
     #[case(Rect::new(0, 0, 2, 2), Rect::new(0, 2, 2, 2), ["11", "11", "22", "22"])]
     #[case(Rect::new(2, 2, 2, 2), Rect::new(0, 0, 2, 2), ["22  ", "22  ", "  11", "  11"])]
@@ -31,6 +31,12 @@ pub struct Cell {
 
     /// Whether the cell should be skipped when copying (diffing) the buffer to the screen.
     pub skip: bool,
+
+    /// The URL of the hyperlink that the cell is part of.
+    ///
+    /// Backends that support it emit this as an OSC 8 escape sequence, which makes the cell
+    /// clickable in terminals that support hyperlinks.
+    hyperlink: Option<CompactString>,
 }
 
 impl Cell {
@@ -52,6 +58,7 @@ impl Cell {
             underline_color: Color::Reset,
             modifier: Modifier::empty(),
             skip: false,
+            hyperlink: None,
         }
     }
 
@@ -62,8 +69,12 @@ impl Cell {
     }
 
     /// Sets the symbol of the cell.
+    ///
+    /// This also removes any hyperlink from the cell, as the hyperlink belongs to the previous
+    /// content.
     pub fn set_symbol(&mut self, symbol: &str) -> &mut Self {
         self.symbol = CompactString::new(symbol);
+        self.hyperlink = None;
         self
     }
 
@@ -76,9 +87,28 @@ impl Cell {
     }
 
     /// Sets the symbol of the cell to a single character.
+    ///
+    /// This also removes any hyperlink from the cell, as the hyperlink belongs to the previous
+    /// content.
     pub fn set_char(&mut self, ch: char) -> &mut Self {
         let mut buf = [0; 4];
         self.symbol = CompactString::new(ch.encode_utf8(&mut buf));
+        self.hyperlink = None;
+        self
+    }
+
+    /// Gets the URL of the hyperlink of the cell, if any.
+    #[must_use]
+    pub fn hyperlink(&self) -> Option<&str> {
+        self.hyperlink.as_deref()
+    }
+
+    /// Sets the URL of the hyperlink of the cell.
+    ///
+    /// Passing `None` removes the hyperlink. Note that setting the symbol of the cell also removes
+    /// the hyperlink, so this must be called after [`Cell::set_symbol`].
+    pub fn set_hyperlink(&mut self, url: Option<&str>) -> &mut Self {
+        self.hyperlink = url.map(CompactString::new);
         self
     }
 
@@ -148,6 +178,7 @@ impl Cell {
         }
         self.modifier = Modifier::empty();
         self.skip = false;
+        self.hyperlink = None;
     }
 }
 
@@ -182,6 +213,7 @@ mod tests {
                 underline_color: Color::Reset,
                 modifier: Modifier::empty(),
                 skip: false,
+                hyperlink: None,
             }
         );
     }
@@ -216,6 +248,27 @@ mod tests {
         assert_eq!(cell.symbol(), "あ");
     }
 
+    #[test]
+    fn set_hyperlink() {
+        let mut cell = Cell::EMPTY;
+        cell.set_symbol("a")
+            .set_hyperlink(Some("https://ratatui.rs"));
+        assert_eq!(cell.hyperlink(), Some("https://ratatui.rs"));
+        cell.set_hyperlink(None);
+        assert_eq!(cell.hyperlink(), None);
+    }
+
+    #[test]
+    fn set_symbol_removes_hyperlink() {
+        let mut cell = Cell::EMPTY;
+        cell.set_hyperlink(Some("https://ratatui.rs"));
+        cell.set_symbol("a");
+        assert_eq!(cell.hyperlink(), None);
+        cell.set_hyperlink(Some("https://ratatui.rs"));
+        cell.set_char('b');
+        assert_eq!(cell.hyperlink(), None);
+    }
+
     #[test]
     fn set_fg() {
         let mut cell = Cell::EMPTY;
@@ -252,11 +305,13 @@ mod tests {
         cell.set_fg(Color::Red);
         cell.set_bg(Color::Blue);
         cell.set_skip(true);
+        cell.set_hyperlink(Some("https://ratatui.rs"));
         cell.reset();
         assert_eq!(cell.symbol(), " ");
         assert_eq!(cell.fg, Color::Reset);
         assert_eq!(cell.bg, Color::Reset);
         assert!(!cell.skip);
+        assert_eq!(cell.hyperlink(), None);
     }
 
     #[test]
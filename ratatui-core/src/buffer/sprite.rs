@@ -0,0 +1,222 @@
+use crate::{
+    buffer::{Buffer, Cell},
+    layout::{Position, Rect, Size},
+    style::Style,
+    text::{DisplayWidth, Line},
+    widgets::Widget,
+};
+
+/// A rectangular grid of styled cells with transparency that can be copied into a [`Buffer`]
+///
+/// Sprites are intended for games and other applications that draw many small, pre-built pieces
+/// of content (characters, items, map tiles, etc.) each frame. Blitting a sprite copies its cells
+/// directly into the buffer without going through any text layout, which makes it much cheaper
+/// than rendering the equivalent [`Text`].
+///
+/// Each cell of a sprite is either opaque (`Some(cell)`), in which case it replaces the cell of
+/// the buffer it is drawn onto, or transparent (`None`), in which case the buffer cell is left
+/// untouched.
+///
+/// A sprite can be drawn with [`Buffer::blit_sprite`], or rendered as a [`Widget`], in which case
+/// it is drawn at the top left corner of the area and clipped to it.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_core::{
+///     buffer::{Buffer, Sprite},
+///     layout::{Position, Rect},
+/// };
+///
+/// let sprite = Sprite::from_lines([" o ", "/|\\", "/ \\"], ' ');
+/// let mut buf = Buffer::with_lines(["#####"; 3]);
+/// buf.blit_sprite(&sprite, Position::new(1, 0));
+/// assert_eq!(buf, Buffer::with_lines(["##o##", "#/|\\#", "#/#\\#"]));
+/// ```
+///
+/// [`Text`]: crate::text::Text
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sprite {
+    size: Size,
+    cells: Vec<Option<Cell>>,
+}
+
+impl Sprite {
+    /// Creates a new fully transparent sprite of the given size
+    pub fn new<S: Into<Size>>(size: S) -> Self {
+        let size = size.into();
+        Self {
+            size,
+            cells: vec![None; size.width as usize * size.height as usize],
+        }
+    }
+
+    /// Creates a sprite from lines of (styled) text
+    ///
+    /// Each grapheme of the lines becomes a cell of the sprite, and graphemes equal to
+    /// `transparent` become transparent cells. The width of the sprite is the width of the widest
+    /// line, and shorter lines are padded with transparent cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::{buffer::Sprite, layout::Size, style::Stylize, text::Line};
+    ///
+    /// let sprite = Sprite::from_lines([Line::from("@".yellow()), Line::from(".")], '.');
+    /// assert_eq!(sprite.size(), Size::new(1, 2));
+    /// assert!(sprite.cell(0, 1).is_none());
+    /// ```
+    pub fn from_lines<'a, Iter>(lines: Iter, transparent: char) -> Self
+    where
+        Iter: IntoIterator,
+        Iter::Item: Into<Line<'a>>,
+    {
+        let lines = lines.into_iter().map(Into::into).collect::<Vec<Line>>();
+        let width = lines.iter().map(Line::width).max().unwrap_or_default() as u16;
+        let mut sprite = Self::new(Size::new(width, lines.len() as u16));
+        let mut transparent_buf = [0; 4];
+        let transparent = &*transparent.encode_utf8(&mut transparent_buf);
+        for (y, line) in lines.iter().enumerate() {
+            let mut x = 0;
+            for grapheme in line.styled_graphemes(Style::default()) {
+                let grapheme_width = grapheme.symbol.display_width() as u16;
+                if grapheme_width == 0 {
+                    continue;
+                }
+                if grapheme.symbol != transparent {
+                    let mut cell = Cell::EMPTY;
+                    cell.set_symbol(grapheme.symbol).set_style(grapheme.style);
+                    sprite.set_cell(x, y as u16, Some(cell));
+                    // reset the cells hidden by a wide grapheme like Buffer::set_stringn does
+                    for offset in 1..grapheme_width {
+                        sprite.set_cell(x + offset, y as u16, Some(Cell::EMPTY));
+                    }
+                }
+                x += grapheme_width;
+            }
+        }
+        sprite
+    }
+
+    /// Returns the size of the sprite
+    pub const fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the cell at the given coordinates
+    ///
+    /// Returns `None` if the cell is transparent or outside of the sprite.
+    pub fn cell(&self, x: u16, y: u16) -> Option<&Cell> {
+        self.index_of(x, y)
+            .and_then(|index| self.cells[index].as_ref())
+    }
+
+    /// Sets the cell at the given coordinates
+    ///
+    /// Passing `None` makes the cell transparent. Coordinates outside of the sprite are ignored.
+    pub fn set_cell(&mut self, x: u16, y: u16, cell: Option<Cell>) {
+        if let Some(index) = self.index_of(x, y) {
+            self.cells[index] = cell;
+        }
+    }
+
+    fn index_of(&self, x: u16, y: u16) -> Option<usize> {
+        (x < self.size.width && y < self.size.height)
+            .then(|| y as usize * self.size.width as usize + x as usize)
+    }
+}
+
+impl Buffer {
+    /// Copies the opaque cells of a [`Sprite`] into the buffer
+    ///
+    /// The top left corner of the sprite is placed at `position`. Transparent cells of the sprite
+    /// leave the buffer untouched, and cells that fall outside of the buffer area are clipped.
+    pub fn blit_sprite<P: Into<Position>>(&mut self, sprite: &Sprite, position: P) {
+        self.blit_sprite_clipped(sprite, position.into(), self.area);
+    }
+
+    /// Copies the opaque cells of a [`Sprite`] that fall within `clip` into the buffer
+    pub(crate) fn blit_sprite_clipped(&mut self, sprite: &Sprite, position: Position, clip: Rect) {
+        let sprite_area = Rect::from((position, sprite.size));
+        let area = self.area.intersection(clip).intersection(sprite_area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                if let Some(cell) = sprite.cell(x - position.x, y - position.y) {
+                    self[(x, y)].clone_from(cell);
+                }
+            }
+        }
+    }
+}
+
+impl Widget for Sprite {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Sprite {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.blit_sprite_clipped(self, area.as_position(), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::style::{Color, Stylize};
+
+    #[test]
+    fn new_is_transparent() {
+        let sprite = Sprite::new((2, 3));
+        assert_eq!(sprite.size(), Size::new(2, 3));
+        assert!(sprite.cell(0, 0).is_none());
+        assert!(sprite.cell(1, 2).is_none());
+    }
+
+    #[test]
+    fn from_lines() {
+        let sprite = Sprite::from_lines([Line::from("a.".red()), Line::from("b")], '.');
+        assert_eq!(sprite.size(), Size::new(2, 2));
+        assert_eq!(sprite.cell(0, 0).map(Cell::symbol), Some("a"));
+        assert_eq!(sprite.cell(0, 0).map(|cell| cell.fg), Some(Color::Red));
+        assert_eq!(sprite.cell(1, 0), None);
+        assert_eq!(sprite.cell(0, 1).map(Cell::symbol), Some("b"));
+        assert_eq!(sprite.cell(1, 1), None);
+    }
+
+    #[test]
+    fn from_lines_wide_graphemes() {
+        let sprite = Sprite::from_lines(["コ."], '.');
+        assert_eq!(sprite.size(), Size::new(3, 1));
+        assert_eq!(sprite.cell(0, 0).map(Cell::symbol), Some("コ"));
+        assert_eq!(sprite.cell(1, 0), Some(&Cell::EMPTY));
+        assert_eq!(sprite.cell(2, 0), None);
+    }
+
+    #[test]
+    fn set_cell_out_of_bounds() {
+        let mut sprite = Sprite::new((1, 1));
+        sprite.set_cell(1, 0, Some(Cell::new("x")));
+        assert_eq!(sprite, Sprite::new((1, 1)));
+    }
+
+    #[test]
+    fn blit_clipped_to_buffer() {
+        let sprite = Sprite::from_lines(["ab", "cd"], ' ');
+        let mut buf = Buffer::with_lines(["...", "..."]);
+        buf.blit_sprite(&sprite, (2, 1));
+        assert_eq!(buf, Buffer::with_lines(["...", "..a"]));
+    }
+
+    #[test]
+    fn render_clipped_to_area() {
+        let sprite = Sprite::from_lines(["ab", "c "], ' ');
+        let mut buf = Buffer::with_lines(["....", "...."]);
+        sprite.render(Rect::new(1, 0, 1, 2), &mut buf);
+        assert_eq!(buf, Buffer::with_lines([".a..", ".c.."]));
+    }
+}
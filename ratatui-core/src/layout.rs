@@ -4,6 +4,7 @@
 mod alignment;
 mod constraint;
 mod direction;
+mod field_grid;
 mod flex;
 mod layout;
 mod margin;
@@ -11,9 +12,10 @@ mod position;
 mod rect;
 mod size;
 
-pub use alignment::Alignment;
+pub use alignment::{Alignment, VerticalAlignment};
 pub use constraint::Constraint;
 pub use direction::Direction;
+pub use field_grid::{FieldArea, FieldGrid};
 pub use flex::Flex;
 pub use layout::{Layout, Spacing};
 pub use margin::Margin;
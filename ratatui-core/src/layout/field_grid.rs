@@ -0,0 +1,289 @@
+use crate::{
+    layout::{Alignment, Rect},
+    text::DisplayWidth,
+};
+
+/// A two column layout for forms, with a label column and an input column
+///
+/// The label column is as wide as the widest label (measured in terminal cells, so wide
+/// characters count twice), and the inputs are aligned in a second column that takes the rest of
+/// the width. Each field takes one row (see [`FieldGrid::field_height`]), and the fields are
+/// separated by [`FieldGrid::row_spacing`] empty rows.
+///
+/// When the area is too narrow to fit the label column and an input of at least
+/// [`FieldGrid::min_input_width`] cells next to each other, the grid switches to a one column
+/// layout where each label is placed on its own row above its input.
+///
+/// The label areas returned by [`FieldGrid::split`] are as wide as their label and aligned in the
+/// label column according to [`FieldGrid::label_alignment`], so the labels can be rendered without
+/// any further alignment.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_core::layout::{Alignment, FieldGrid, Rect};
+///
+/// let grid = FieldGrid::new(["Name", "Email"]).label_alignment(Alignment::Right);
+///
+/// let fields = grid.split(Rect::new(0, 0, 30, 5));
+/// assert_eq!(fields[0].label, Rect::new(1, 0, 4, 1));
+/// assert_eq!(fields[0].input, Rect::new(6, 0, 24, 1));
+/// assert_eq!(fields[1].label, Rect::new(0, 1, 5, 1));
+/// assert_eq!(fields[1].input, Rect::new(6, 1, 24, 1));
+///
+/// // on narrow areas, the labels are placed above the inputs
+/// let fields = grid.split(Rect::new(0, 0, 12, 5));
+/// assert_eq!(fields[0].label, Rect::new(0, 0, 4, 1));
+/// assert_eq!(fields[0].input, Rect::new(0, 1, 12, 1));
+/// assert_eq!(fields[1].label, Rect::new(0, 2, 5, 1));
+/// assert_eq!(fields[1].input, Rect::new(0, 3, 12, 1));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldGrid {
+    label_widths: Vec<u16>,
+    label_alignment: Alignment,
+    column_spacing: u16,
+    row_spacing: u16,
+    field_height: u16,
+    min_input_width: u16,
+}
+
+/// The areas of a field of a [`FieldGrid`]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldArea {
+    /// The area of the label, which is as wide as the label
+    pub label: Rect,
+    /// The area of the input
+    pub input: Rect,
+}
+
+impl FieldGrid {
+    /// Creates a new field grid with one field for each label
+    ///
+    /// The labels are only used to measure their width. By default, the labels are left aligned,
+    /// the columns are separated by one cell, each field is one row high without spacing between
+    /// the fields, and the grid switches to one column when an input would be narrower than 10
+    /// cells.
+    pub fn new<I>(labels: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let label_widths = labels
+            .into_iter()
+            .map(|label| u16::try_from(label.as_ref().display_width()).unwrap_or(u16::MAX))
+            .collect();
+        Self {
+            label_widths,
+            label_alignment: Alignment::Left,
+            column_spacing: 1,
+            row_spacing: 0,
+            field_height: 1,
+            min_input_width: 10,
+        }
+    }
+
+    /// Sets the alignment of the labels in the label column
+    ///
+    /// The alignment is not used in the one column layout, where the labels are always left
+    /// aligned above their input.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn label_alignment(mut self, alignment: Alignment) -> Self {
+        self.label_alignment = alignment;
+        self
+    }
+
+    /// Sets the number of cells between the label column and the input column
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn column_spacing(mut self, spacing: u16) -> Self {
+        self.column_spacing = spacing;
+        self
+    }
+
+    /// Sets the number of empty rows between two fields
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn row_spacing(mut self, spacing: u16) -> Self {
+        self.row_spacing = spacing;
+        self
+    }
+
+    /// Sets the height of the inputs
+    ///
+    /// Labels are always one row high. This is useful for multi-line inputs such as text areas.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn field_height(mut self, height: u16) -> Self {
+        self.field_height = height;
+        self
+    }
+
+    /// Sets the minimum width of the inputs in the two column layout
+    ///
+    /// When the area is narrower than the label column, the column spacing and this width, the
+    /// grid switches to the one column layout.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn min_input_width(mut self, width: u16) -> Self {
+        self.min_input_width = width;
+        self
+    }
+
+    /// Returns the width of the label column, which is the width of the widest label
+    pub fn label_width(&self) -> u16 {
+        self.label_widths.iter().copied().max().unwrap_or_default()
+    }
+
+    /// Returns whether the grid uses the one column layout for the given width
+    pub fn is_single_column(&self, width: u16) -> bool {
+        let two_column_width = u32::from(self.label_width())
+            + u32::from(self.column_spacing)
+            + u32::from(self.min_input_width);
+        u32::from(width) < two_column_width
+    }
+
+    /// Returns the height needed to display all the fields for the given width
+    pub fn height(&self, width: u16) -> u16 {
+        let count = self.label_widths.len() as u16;
+        let field_height = if self.is_single_column(width) {
+            self.field_height.saturating_add(1)
+        } else {
+            self.field_height.max(1)
+        };
+        field_height
+            .saturating_mul(count)
+            .saturating_add(self.row_spacing.saturating_mul(count.saturating_sub(1)))
+    }
+
+    /// Splits the area into the label and input areas of each field
+    ///
+    /// The fields are laid out from the top of the area. Fields that don't fit in the area are
+    /// clipped, so their areas may be empty.
+    pub fn split(&self, area: Rect) -> Vec<FieldArea> {
+        let single_column = self.is_single_column(area.width);
+        let label_column = self.label_width().min(area.width);
+        let input_x = area
+            .x
+            .saturating_add(label_column)
+            .saturating_add(self.column_spacing)
+            .min(area.right());
+        let mut y = area.y;
+        self.label_widths
+            .iter()
+            .map(|&label_width| {
+                let label_width = label_width.min(area.width);
+                let field = if single_column {
+                    let label = Rect::new(area.x, y, label_width, 1);
+                    let input =
+                        Rect::new(area.x, y.saturating_add(1), area.width, self.field_height);
+                    y = y.saturating_add(self.field_height).saturating_add(1);
+                    FieldArea { label, input }
+                } else {
+                    let offset = match self.label_alignment {
+                        Alignment::Left => 0,
+                        Alignment::Center => (label_column - label_width) / 2,
+                        Alignment::Right => label_column - label_width,
+                    };
+                    let label = Rect::new(area.x + offset, y, label_width, 1);
+                    let input_width = area.right() - input_x;
+                    let input = Rect::new(input_x, y, input_width, self.field_height);
+                    y = y.saturating_add(self.field_height.max(1));
+                    FieldArea { label, input }
+                };
+                y = y.saturating_add(self.row_spacing);
+                FieldArea {
+                    label: field.label.intersection(area),
+                    input: field.input.intersection(area),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn label_width_is_unicode_aware() {
+        let grid = FieldGrid::new(["Name", "名前は"]);
+        assert_eq!(grid.label_width(), 6);
+        assert_eq!(FieldGrid::new(Vec::<&str>::new()).label_width(), 0);
+    }
+
+    #[rstest]
+    #[case::left(Alignment::Left, [0, 0])]
+    #[case::center(Alignment::Center, [0, 1])]
+    #[case::right(Alignment::Right, [0, 2])]
+    fn label_alignment(#[case] alignment: Alignment, #[case] expected: [u16; 2]) {
+        let grid = FieldGrid::new(["Email", "Age"]).label_alignment(alignment);
+        let fields = grid.split(Rect::new(0, 0, 20, 2));
+        assert_eq!(fields[0].label.x, expected[0]);
+        assert_eq!(fields[1].label.x, expected[1]);
+        assert_eq!(fields[1].label.width, 3);
+    }
+
+    #[test]
+    fn two_columns() {
+        let grid = FieldGrid::new(["Name", "Email"])
+            .column_spacing(2)
+            .row_spacing(1)
+            .field_height(2);
+        assert!(!grid.is_single_column(17));
+        assert_eq!(grid.height(17), 5);
+        assert_eq!(
+            grid.split(Rect::new(1, 1, 17, 10)),
+            [
+                FieldArea {
+                    label: Rect::new(1, 1, 4, 1),
+                    input: Rect::new(8, 1, 10, 2),
+                },
+                FieldArea {
+                    label: Rect::new(1, 4, 5, 1),
+                    input: Rect::new(8, 4, 10, 2),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn single_column() {
+        let grid = FieldGrid::new(["Name", "Email"]).row_spacing(1);
+        assert!(grid.is_single_column(15));
+        assert_eq!(grid.height(15), 5);
+        assert_eq!(
+            grid.split(Rect::new(0, 0, 15, 10)),
+            [
+                FieldArea {
+                    label: Rect::new(0, 0, 4, 1),
+                    input: Rect::new(0, 1, 15, 1),
+                },
+                FieldArea {
+                    label: Rect::new(0, 3, 5, 1),
+                    input: Rect::new(0, 4, 15, 1),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn fields_are_clipped() {
+        let grid = FieldGrid::new(["A", "B", "C"]);
+        let fields = grid.split(Rect::new(0, 0, 20, 2));
+        assert_eq!(fields[1].input, Rect::new(2, 1, 18, 1));
+        assert!(fields[2].label.is_empty());
+        assert!(fields[2].input.is_empty());
+    }
+}
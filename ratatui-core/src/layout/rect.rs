@@ -134,6 +134,26 @@ impl Rect {
         }
     }
 
+    /// Returns a new `Rect` around the current one, with the given margin on each side.
+    ///
+    /// This is the opposite of [`Rect::inner`]. The returned `Rect` is clamped to the bounds of
+    /// `u16`, so it may grow less than the margin on the sides that are close to these bounds.
+    /// This is useful to make small hit targets (e.g. buttons or scrollbar thumbs) easier to hit
+    /// with imprecise input such as touch screens.
+    #[must_use = "method returns the modified value"]
+    pub const fn outer(self, margin: Margin) -> Self {
+        let x = self.x.saturating_sub(margin.horizontal);
+        let y = self.y.saturating_sub(margin.vertical);
+        let right = self.right().saturating_add(margin.horizontal);
+        let bottom = self.bottom().saturating_add(margin.vertical);
+        Self {
+            x,
+            y,
+            width: right - x,
+            height: bottom - y,
+        }
+    }
+
     /// Moves the `Rect` without modifying its size.
     ///
     /// Moves the `Rect` according to the given offset without modifying its [`width`](Rect::width)
@@ -465,6 +485,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn outer() {
+        assert_eq!(
+            Rect::new(5, 5, 3, 2).outer(Margin::new(1, 2)),
+            Rect::new(4, 3, 5, 6)
+        );
+        assert_eq!(
+            Rect::new(1, 0, 3, 2).outer(Margin::new(2, 2)),
+            Rect::new(0, 0, 6, 4)
+        );
+        assert_eq!(
+            Rect::new(u16::MAX - 2, 0, 2, 1).outer(Margin::new(2, 0)),
+            Rect::new(u16::MAX - 4, 0, 4, 1)
+        );
+    }
+
     #[test]
     #[allow(deprecated)]
     fn offset() {
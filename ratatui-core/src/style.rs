@@ -209,6 +209,9 @@ impl fmt::Debug for Modifier {
 /// );
 /// ```
 ///
+/// A style doesn't hold a hyperlink, as it would no longer be `Copy`. Hyperlinks are set on spans
+/// instead, see [`Span::hyperlink`].
+///
 /// The default implementation returns a `Style` that does not modify anything. If you wish to
 /// reset all properties until that point use [`Style::reset`].
 ///
@@ -241,6 +244,8 @@ impl fmt::Debug for Modifier {
 ///     buffer[(0, 0)].style(),
 /// );
 /// ```
+///
+/// [`Span::hyperlink`]: crate::text::Span::hyperlink
 #[derive(Default, Clone, Copy, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Style {
@@ -135,6 +135,116 @@ impl Color {
         let b = u as u8;
         Self::Rgb(r, g, b)
     }
+
+    /// Returns the RGB components of the color, or `None` for [`Color::Reset`]
+    ///
+    /// The named and indexed colors are converted with the default palette of xterm. Note that
+    /// terminals often use their own palette for the 16 named colors, so the result is only an
+    /// approximation of how the color is displayed for those colors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Rgb(1, 2, 3).to_rgb(), Some((1, 2, 3)));
+    /// assert_eq!(Color::Red.to_rgb(), Some((205, 0, 0)));
+    /// assert_eq!(Color::Indexed(196).to_rgb(), Some((255, 0, 0)));
+    /// assert_eq!(Color::Reset.to_rgb(), None);
+    /// ```
+    pub const fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        let rgb = match self {
+            Self::Reset => return None,
+            Self::Rgb(r, g, b) => (r, g, b),
+            Self::Black => (0, 0, 0),
+            Self::Red => (205, 0, 0),
+            Self::Green => (0, 205, 0),
+            Self::Yellow => (205, 205, 0),
+            Self::Blue => (0, 0, 238),
+            Self::Magenta => (205, 0, 205),
+            Self::Cyan => (0, 205, 205),
+            Self::Gray => (229, 229, 229),
+            Self::DarkGray => (127, 127, 127),
+            Self::LightRed => (255, 0, 0),
+            Self::LightGreen => (0, 255, 0),
+            Self::LightYellow => (255, 255, 0),
+            Self::LightBlue => (92, 92, 255),
+            Self::LightMagenta => (255, 0, 255),
+            Self::LightCyan => (0, 255, 255),
+            Self::White => (255, 255, 255),
+            Self::Indexed(index) => return indexed_to_rgb(index),
+        };
+        Some(rgb)
+    }
+
+    /// Interpolates linearly between this color and another one
+    ///
+    /// `t` is the position between the two colors, from `0.0` (this color) to `1.0` (the other
+    /// color), and is clamped to that range. The result is an RGB color (see [`Color::to_rgb`]),
+    /// unless one of the colors is [`Color::Reset`], in which case the closest of the two colors
+    /// is returned as is.
+    ///
+    /// This is useful to render gradients, or to color values on a scale.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// let start = Color::Rgb(0, 0, 0);
+    /// let end = Color::Rgb(255, 100, 0);
+    /// assert_eq!(start.lerp(end, 0.0), start);
+    /// assert_eq!(start.lerp(end, 0.5), Color::Rgb(128, 50, 0));
+    /// assert_eq!(start.lerp(end, 2.0), end);
+    /// ```
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let (Some((r1, g1, b1)), Some((r2, g2, b2))) = (self.to_rgb(), other.to_rgb()) else {
+            return if t < 0.5 { self } else { other };
+        };
+        let mix = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+        Self::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+    }
+}
+
+/// Converts an 8-bit color index to RGB components with the default palette of xterm
+const fn indexed_to_rgb(index: u8) -> Option<(u8, u8, u8)> {
+    const NAMED: [Color; 16] = [
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::Gray,
+        Color::DarkGray,
+        Color::LightRed,
+        Color::LightGreen,
+        Color::LightYellow,
+        Color::LightBlue,
+        Color::LightMagenta,
+        Color::LightCyan,
+        Color::White,
+    ];
+    // the 6x6x6 color cube uses these intensities for each component
+    const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    match index {
+        0..=15 => NAMED[index as usize].to_rgb(),
+        16..=231 => {
+            let index = index - 16;
+            Some((
+                CUBE[(index / 36) as usize],
+                CUBE[(index / 6 % 6) as usize],
+                CUBE[(index % 6) as usize],
+            ))
+        }
+        232..=255 => {
+            let level = 8 + (index - 232) * 10;
+            Some((level, level, level))
+        }
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -734,4 +844,34 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn to_rgb() {
+        assert_eq!(Color::Reset.to_rgb(), None);
+        assert_eq!(Color::Rgb(10, 20, 30).to_rgb(), Some((10, 20, 30)));
+        assert_eq!(Color::White.to_rgb(), Some((255, 255, 255)));
+        assert_eq!(Color::Indexed(1).to_rgb(), Color::Red.to_rgb());
+        assert_eq!(Color::Indexed(16).to_rgb(), Some((0, 0, 0)));
+        assert_eq!(Color::Indexed(110).to_rgb(), Some((135, 175, 215)));
+        assert_eq!(Color::Indexed(231).to_rgb(), Some((255, 255, 255)));
+        assert_eq!(Color::Indexed(232).to_rgb(), Some((8, 8, 8)));
+        assert_eq!(Color::Indexed(255).to_rgb(), Some((238, 238, 238)));
+    }
+
+    #[test]
+    fn lerp() {
+        let start = Color::Rgb(0, 100, 200);
+        let end = Color::Rgb(100, 100, 0);
+        assert_eq!(start.lerp(end, 0.0), start);
+        assert_eq!(start.lerp(end, 0.25), Color::Rgb(25, 100, 150));
+        assert_eq!(start.lerp(end, 1.0), end);
+        assert_eq!(start.lerp(end, -1.0), start);
+        assert_eq!(start.lerp(end, f64::NAN), start);
+        assert_eq!(
+            Color::Black.lerp(Color::White, 0.5),
+            Color::Rgb(128, 128, 128)
+        );
+        assert_eq!(Color::Reset.lerp(end, 0.4), Color::Reset);
+        assert_eq!(Color::Reset.lerp(end, 0.6), end);
+    }
 }
@@ -4,6 +4,7 @@ use strum::{Display, EnumString};
 
 pub mod border;
 pub mod line;
+pub mod merge;
 
 pub mod block {
     pub const FULL: &str = "█";
@@ -131,6 +132,59 @@ pub mod braille {
     ];
 }
 
+pub mod octant {
+    pub const BLANK: u8 = 0;
+    pub const DOTS: [[u8; 2]; 4] = [
+        [0b0000_0001, 0b0000_0010],
+        [0b0000_0100, 0b0000_1000],
+        [0b0001_0000, 0b0010_0000],
+        [0b0100_0000, 0b1000_0000],
+    ];
+
+    /// The octant characters indexed by the dots that are set
+    ///
+    /// Most of the characters are from the Unicode 16 [Symbols for Legacy Computing Supplement]
+    /// block. The patterns that already exist in other blocks (e.g. the half blocks `▀` and `▌`, the
+    /// quadrants `▚` and the one quarter blocks `▂`) use those characters instead.
+    ///
+    /// [Symbols for Legacy Computing Supplement]: https://www.unicode.org/charts/PDF/U1CC00.pdf
+    #[rustfmt::skip]
+    pub const OCTANTS: [char; 256] = [
+        ' ', '\u{1CEA8}', '\u{1CEAB}', '\u{1FB82}', '\u{1CD00}', '\u{2598}', '\u{1CD01}', '\u{1CD02}',
+        '\u{1CD03}', '\u{1CD04}', '\u{259D}', '\u{1CD05}', '\u{1CD06}', '\u{1CD07}', '\u{1CD08}', '\u{2580}',
+        '\u{1CD09}', '\u{1CD0A}', '\u{1CD0B}', '\u{1CD0C}', '\u{1FBE6}', '\u{1CD0D}', '\u{1CD0E}', '\u{1CD0F}',
+        '\u{1CD10}', '\u{1CD11}', '\u{1CD12}', '\u{1CD13}', '\u{1CD14}', '\u{1CD15}', '\u{1CD16}', '\u{1CD17}',
+        '\u{1CD18}', '\u{1CD19}', '\u{1CD1A}', '\u{1CD1B}', '\u{1CD1C}', '\u{1CD1D}', '\u{1CD1E}', '\u{1CD1F}',
+        '\u{1FBE7}', '\u{1CD20}', '\u{1CD21}', '\u{1CD22}', '\u{1CD23}', '\u{1CD24}', '\u{1CD25}', '\u{1CD26}',
+        '\u{1CD27}', '\u{1CD28}', '\u{1CD29}', '\u{1CD2A}', '\u{1CD2B}', '\u{1CD2C}', '\u{1CD2D}', '\u{1CD2E}',
+        '\u{1CD2F}', '\u{1CD30}', '\u{1CD31}', '\u{1CD32}', '\u{1CD33}', '\u{1CD34}', '\u{1CD35}', '\u{1FB85}',
+        '\u{1CEA3}', '\u{1CD36}', '\u{1CD37}', '\u{1CD38}', '\u{1CD39}', '\u{1CD3A}', '\u{1CD3B}', '\u{1CD3C}',
+        '\u{1CD3D}', '\u{1CD3E}', '\u{1CD3F}', '\u{1CD40}', '\u{1CD41}', '\u{1CD42}', '\u{1CD43}', '\u{1CD44}',
+        '\u{2596}', '\u{1CD45}', '\u{1CD46}', '\u{1CD47}', '\u{1CD48}', '\u{258C}', '\u{1CD49}', '\u{1CD4A}',
+        '\u{1CD4B}', '\u{1CD4C}', '\u{259E}', '\u{1CD4D}', '\u{1CD4E}', '\u{1CD4F}', '\u{1CD50}', '\u{259B}',
+        '\u{1CD51}', '\u{1CD52}', '\u{1CD53}', '\u{1CD54}', '\u{1CD55}', '\u{1CD56}', '\u{1CD57}', '\u{1CD58}',
+        '\u{1CD59}', '\u{1CD5A}', '\u{1CD5B}', '\u{1CD5C}', '\u{1CD5D}', '\u{1CD5E}', '\u{1CD5F}', '\u{1CD60}',
+        '\u{1CD61}', '\u{1CD62}', '\u{1CD63}', '\u{1CD64}', '\u{1CD65}', '\u{1CD66}', '\u{1CD67}', '\u{1CD68}',
+        '\u{1CD69}', '\u{1CD6A}', '\u{1CD6B}', '\u{1CD6C}', '\u{1CD6D}', '\u{1CD6E}', '\u{1CD6F}', '\u{1CD70}',
+        '\u{1CEA0}', '\u{1CD71}', '\u{1CD72}', '\u{1CD73}', '\u{1CD74}', '\u{1CD75}', '\u{1CD76}', '\u{1CD77}',
+        '\u{1CD78}', '\u{1CD79}', '\u{1CD7A}', '\u{1CD7B}', '\u{1CD7C}', '\u{1CD7D}', '\u{1CD7E}', '\u{1CD7F}',
+        '\u{1CD80}', '\u{1CD81}', '\u{1CD82}', '\u{1CD83}', '\u{1CD84}', '\u{1CD85}', '\u{1CD86}', '\u{1CD87}',
+        '\u{1CD88}', '\u{1CD89}', '\u{1CD8A}', '\u{1CD8B}', '\u{1CD8C}', '\u{1CD8D}', '\u{1CD8E}', '\u{1CD8F}',
+        '\u{2597}', '\u{1CD90}', '\u{1CD91}', '\u{1CD92}', '\u{1CD93}', '\u{259A}', '\u{1CD94}', '\u{1CD95}',
+        '\u{1CD96}', '\u{1CD97}', '\u{2590}', '\u{1CD98}', '\u{1CD99}', '\u{1CD9A}', '\u{1CD9B}', '\u{259C}',
+        '\u{1CD9C}', '\u{1CD9D}', '\u{1CD9E}', '\u{1CD9F}', '\u{1CDA0}', '\u{1CDA1}', '\u{1CDA2}', '\u{1CDA3}',
+        '\u{1CDA4}', '\u{1CDA5}', '\u{1CDA6}', '\u{1CDA7}', '\u{1CDA8}', '\u{1CDA9}', '\u{1CDAA}', '\u{1CDAB}',
+        '\u{2582}', '\u{1CDAC}', '\u{1CDAD}', '\u{1CDAE}', '\u{1CDAF}', '\u{1CDB0}', '\u{1CDB1}', '\u{1CDB2}',
+        '\u{1CDB3}', '\u{1CDB4}', '\u{1CDB5}', '\u{1CDB6}', '\u{1CDB7}', '\u{1CDB8}', '\u{1CDB9}', '\u{1CDBA}',
+        '\u{1CDBB}', '\u{1CDBC}', '\u{1CDBD}', '\u{1CDBE}', '\u{1CDBF}', '\u{1CDC0}', '\u{1CDC1}', '\u{1CDC2}',
+        '\u{1CDC3}', '\u{1CDC4}', '\u{1CDC5}', '\u{1CDC6}', '\u{1CDC7}', '\u{1CDC8}', '\u{1CDC9}', '\u{1CDCA}',
+        '\u{1CDCB}', '\u{1CDCC}', '\u{1CDCD}', '\u{1CDCE}', '\u{1CDCF}', '\u{1CDD0}', '\u{1CDD1}', '\u{1CDD2}',
+        '\u{1CDD3}', '\u{1CDD4}', '\u{1CDD5}', '\u{1CDD6}', '\u{1CDD7}', '\u{1CDD8}', '\u{1CDD9}', '\u{1CDDA}',
+        '\u{2584}', '\u{1CDDB}', '\u{1CDDC}', '\u{1CDDD}', '\u{1CDDE}', '\u{2599}', '\u{1CDDF}', '\u{1CDE0}',
+        '\u{1CDE1}', '\u{1CDE2}', '\u{259F}', '\u{1CDE3}', '\u{2586}', '\u{1CDE4}', '\u{1CDE5}', '\u{2588}',
+    ];
+}
+
 /// Marker to use when plotting data points
 #[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum Marker {
@@ -154,6 +208,16 @@ pub enum Marker {
     /// a grid that is double the resolution of the terminal. Because each terminal cell is
     /// generally about twice as tall as it is wide, this allows for a square grid of pixels.
     HalfBlock,
+    /// Use the Unicode 16 octant characters (`𜴀`, `𜴁`, `𜷥`) to represent data points.
+    ///
+    /// This is a 2x4 grid of solid pixels like the [`Braille`](Marker::Braille) marker, which fill
+    /// the cell instead of being drawn as dots. Patterns that exist as older block characters (e.g.
+    /// `▀`, `▌` and `▚`) are drawn with those characters.
+    ///
+    /// Note: Support for this marker is limited to terminals and fonts that support Unicode 16. If
+    /// your terminal does not support this, you will see unicode replacement characters (`�`)
+    /// instead of octants.
+    Octant,
 }
 
 pub mod scrollbar {
@@ -225,6 +289,8 @@ mod tests {
         assert_eq!(Marker::Block.to_string(), "Block");
         assert_eq!(Marker::Bar.to_string(), "Bar");
         assert_eq!(Marker::Braille.to_string(), "Braille");
+        assert_eq!(Marker::HalfBlock.to_string(), "HalfBlock");
+        assert_eq!(Marker::Octant.to_string(), "Octant");
     }
 
     #[test]
@@ -233,6 +299,19 @@ mod tests {
         assert_eq!("Block".parse::<Marker>(), Ok(Marker::Block));
         assert_eq!("Bar".parse::<Marker>(), Ok(Marker::Bar));
         assert_eq!("Braille".parse::<Marker>(), Ok(Marker::Braille));
+        assert_eq!("HalfBlock".parse::<Marker>(), Ok(Marker::HalfBlock));
+        assert_eq!("Octant".parse::<Marker>(), Ok(Marker::Octant));
         assert_eq!("".parse::<Marker>(), Err(ParseError::VariantNotFound));
     }
+
+    #[test]
+    fn octants() {
+        assert_eq!(octant::OCTANTS[0], ' ');
+        assert_eq!(octant::OCTANTS[0b0000_0100], '\u{1CD00}');
+        assert_eq!(octant::OCTANTS[0b0000_1111], '▀');
+        assert_eq!(octant::OCTANTS[0b0101_0101], '▌');
+        assert_eq!(octant::OCTANTS[0b1010_0101], '▚');
+        assert_eq!(octant::OCTANTS[0b1111_1110], '\u{1CDE5}');
+        assert_eq!(octant::OCTANTS[0b1111_1111], '█');
+    }
 }
@@ -18,6 +18,88 @@ impl Default for Set {
     }
 }
 
+/// Builder methods to mix the symbols of different sets or to replace some of them.
+///
+/// This makes it possible to create sets with different corners (e.g. rounded top corners and
+/// square bottom corners) or sides without listing all the symbols of the set.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_core::symbols::border;
+///
+/// // rounded top corners and square bottom corners
+/// let set = border::PLAIN.with_top_corners_from(border::ROUNDED);
+/// assert_eq!((set.top_left, set.bottom_left), ("╭", "└"));
+///
+/// // a custom symbol in the bottom right corner
+/// let set = border::ROUNDED.with_bottom_right("┘");
+/// assert_eq!((set.bottom_left, set.bottom_right), ("╰", "┘"));
+/// ```
+impl Set {
+    /// Returns the set with the given top left corner symbol
+    #[must_use = "method returns the modified value"]
+    pub const fn with_top_left(mut self, symbol: &'static str) -> Self {
+        self.top_left = symbol;
+        self
+    }
+
+    /// Returns the set with the given top right corner symbol
+    #[must_use = "method returns the modified value"]
+    pub const fn with_top_right(mut self, symbol: &'static str) -> Self {
+        self.top_right = symbol;
+        self
+    }
+
+    /// Returns the set with the given bottom left corner symbol
+    #[must_use = "method returns the modified value"]
+    pub const fn with_bottom_left(mut self, symbol: &'static str) -> Self {
+        self.bottom_left = symbol;
+        self
+    }
+
+    /// Returns the set with the given bottom right corner symbol
+    #[must_use = "method returns the modified value"]
+    pub const fn with_bottom_right(mut self, symbol: &'static str) -> Self {
+        self.bottom_right = symbol;
+        self
+    }
+
+    /// Returns the set with the symbols of the four corners taken from another set
+    #[must_use = "method returns the modified value"]
+    pub const fn with_corners_from(self, other: Self) -> Self {
+        self.with_top_corners_from(other)
+            .with_bottom_corners_from(other)
+    }
+
+    /// Returns the set with the symbols of the top left and top right corners taken from another
+    /// set
+    #[must_use = "method returns the modified value"]
+    pub const fn with_top_corners_from(self, other: Self) -> Self {
+        self.with_top_left(other.top_left)
+            .with_top_right(other.top_right)
+    }
+
+    /// Returns the set with the symbols of the bottom left and bottom right corners taken from
+    /// another set
+    #[must_use = "method returns the modified value"]
+    pub const fn with_bottom_corners_from(self, other: Self) -> Self {
+        self.with_bottom_left(other.bottom_left)
+            .with_bottom_right(other.bottom_right)
+    }
+
+    /// Returns the set with the symbols of the four sides (but not the corners) taken from another
+    /// set
+    #[must_use = "method returns the modified value"]
+    pub const fn with_sides_from(mut self, other: Self) -> Self {
+        self.vertical_left = other.vertical_left;
+        self.vertical_right = other.vertical_right;
+        self.horizontal_top = other.horizontal_top;
+        self.horizontal_bottom = other.horizontal_bottom;
+        self
+    }
+}
+
 /// Border Set with a single line width
 ///
 /// ```text
@@ -506,4 +588,35 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn mixed_corners() {
+        assert_eq!(
+            render(PLAIN.with_top_corners_from(ROUNDED).with_bottom_right("╯")),
+            indoc!(
+                "░░░░░░
+                 ░╭──╮░
+                 ░│░░│░
+                 ░│░░│░
+                 ░└──╯░
+                 ░░░░░░"
+            )
+        );
+    }
+
+    #[test]
+    fn mixed_sides() {
+        assert_eq!(
+            render(DOUBLE.with_sides_from(PLAIN).with_corners_from(ROUNDED)),
+            indoc!(
+                "░░░░░░
+                 ░╭──╮░
+                 ░│░░│░
+                 ░│░░│░
+                 ░╰──╯░
+                 ░░░░░░"
+            )
+        );
+        assert_eq!(THICK.with_corners_from(THICK).with_sides_from(THICK), THICK);
+    }
 }
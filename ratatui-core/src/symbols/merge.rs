@@ -0,0 +1,222 @@
+//! Merging of box drawing symbols
+//!
+//! When the borders of two widgets overlap, e.g. two [`Block`]s that share an edge, the border
+//! rendered last replaces the symbols of the other one. [`merge`] combines the two symbols
+//! instead, so that the lines of both borders are connected with the matching joint (e.g. `┬`,
+//! `├` or `┼`).
+//!
+//! [`Block`]: https://docs.rs/ratatui/latest/ratatui/widgets/struct.Block.html
+
+/// The weight of a line of a box drawing symbol
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum Weight {
+    Light,
+    Heavy,
+    Double,
+}
+
+/// The lines of a box drawing symbol, going up, right, down and left from its center
+type Lines = [Option<Weight>; 4];
+
+const L: Option<Weight> = Some(Weight::Light);
+const H: Option<Weight> = Some(Weight::Heavy);
+const D: Option<Weight> = Some(Weight::Double);
+
+/// The box drawing symbols and their lines
+///
+/// The rounded corners are at the end of the table, so that the square corners are used when
+/// looking up a symbol from its lines.
+const SYMBOLS: [(&str, Lines); 113] = [
+    ("─", [None, L, None, L]),
+    ("━", [None, H, None, H]),
+    ("│", [L, None, L, None]),
+    ("┃", [H, None, H, None]),
+    ("┌", [None, L, L, None]),
+    ("┍", [None, H, L, None]),
+    ("┎", [None, L, H, None]),
+    ("┏", [None, H, H, None]),
+    ("┐", [None, None, L, L]),
+    ("┑", [None, None, L, H]),
+    ("┒", [None, None, H, L]),
+    ("┓", [None, None, H, H]),
+    ("└", [L, L, None, None]),
+    ("┕", [L, H, None, None]),
+    ("┖", [H, L, None, None]),
+    ("┗", [H, H, None, None]),
+    ("┘", [L, None, None, L]),
+    ("┙", [L, None, None, H]),
+    ("┚", [H, None, None, L]),
+    ("┛", [H, None, None, H]),
+    ("├", [L, L, L, None]),
+    ("┝", [L, H, L, None]),
+    ("┞", [H, L, L, None]),
+    ("┟", [L, L, H, None]),
+    ("┠", [H, L, H, None]),
+    ("┡", [H, H, L, None]),
+    ("┢", [L, H, H, None]),
+    ("┣", [H, H, H, None]),
+    ("┤", [L, None, L, L]),
+    ("┥", [L, None, L, H]),
+    ("┦", [H, None, L, L]),
+    ("┧", [L, None, H, L]),
+    ("┨", [H, None, H, L]),
+    ("┩", [H, None, L, H]),
+    ("┪", [L, None, H, H]),
+    ("┫", [H, None, H, H]),
+    ("┬", [None, L, L, L]),
+    ("┭", [None, L, L, H]),
+    ("┮", [None, H, L, L]),
+    ("┯", [None, H, L, H]),
+    ("┰", [None, L, H, L]),
+    ("┱", [None, L, H, H]),
+    ("┲", [None, H, H, L]),
+    ("┳", [None, H, H, H]),
+    ("┴", [L, L, None, L]),
+    ("┵", [L, L, None, H]),
+    ("┶", [L, H, None, L]),
+    ("┷", [L, H, None, H]),
+    ("┸", [H, L, None, L]),
+    ("┹", [H, L, None, H]),
+    ("┺", [H, H, None, L]),
+    ("┻", [H, H, None, H]),
+    ("┼", [L, L, L, L]),
+    ("┽", [L, L, L, H]),
+    ("┾", [L, H, L, L]),
+    ("┿", [L, H, L, H]),
+    ("╀", [H, L, L, L]),
+    ("╁", [L, L, H, L]),
+    ("╂", [H, L, H, L]),
+    ("╃", [H, L, L, H]),
+    ("╄", [H, H, L, L]),
+    ("╅", [L, L, H, H]),
+    ("╆", [L, H, H, L]),
+    ("╇", [H, H, L, H]),
+    ("╈", [L, H, H, H]),
+    ("╉", [H, L, H, H]),
+    ("╊", [H, H, H, L]),
+    ("╋", [H, H, H, H]),
+    ("═", [None, D, None, D]),
+    ("║", [D, None, D, None]),
+    ("╒", [None, D, L, None]),
+    ("╓", [None, L, D, None]),
+    ("╔", [None, D, D, None]),
+    ("╕", [None, None, L, D]),
+    ("╖", [None, None, D, L]),
+    ("╗", [None, None, D, D]),
+    ("╘", [L, D, None, None]),
+    ("╙", [D, L, None, None]),
+    ("╚", [D, D, None, None]),
+    ("╛", [L, None, None, D]),
+    ("╜", [D, None, None, L]),
+    ("╝", [D, None, None, D]),
+    ("╞", [L, D, L, None]),
+    ("╟", [D, L, D, None]),
+    ("╠", [D, D, D, None]),
+    ("╡", [L, None, L, D]),
+    ("╢", [D, None, D, L]),
+    ("╣", [D, None, D, D]),
+    ("╤", [None, D, L, D]),
+    ("╥", [None, L, D, L]),
+    ("╦", [None, D, D, D]),
+    ("╧", [L, D, None, D]),
+    ("╨", [D, L, None, L]),
+    ("╩", [D, D, None, D]),
+    ("╪", [L, D, L, D]),
+    ("╫", [D, L, D, L]),
+    ("╬", [D, D, D, D]),
+    ("╭", [None, L, L, None]),
+    ("╮", [None, None, L, L]),
+    ("╯", [L, None, None, L]),
+    ("╰", [L, L, None, None]),
+    ("╴", [None, None, None, L]),
+    ("╵", [L, None, None, None]),
+    ("╶", [None, L, None, None]),
+    ("╷", [None, None, L, None]),
+    ("╸", [None, None, None, H]),
+    ("╹", [H, None, None, None]),
+    ("╺", [None, H, None, None]),
+    ("╻", [None, None, H, None]),
+    ("╼", [None, H, None, L]),
+    ("╽", [L, None, H, None]),
+    ("╾", [None, L, None, H]),
+    ("╿", [H, None, L, None]),
+];
+
+/// Merges two box drawing symbols, as if `next` was drawn over `previous`
+///
+/// Returns the symbol with the lines of both symbols, or `None` if `next` should be used as is:
+/// when one of the symbols is not a box drawing line symbol, when `next` already contains the
+/// lines of `previous`, or when Unicode has no symbol for the combination of lines (e.g. heavy
+/// and double lines). If both symbols have a line in the same direction, the line of `next` is
+/// used.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::symbols::merge::merge;
+///
+/// assert_eq!(merge("─", "│"), Some("┼"));
+/// assert_eq!(merge("┐", "┌"), Some("┬"));
+/// assert_eq!(merge("└", "┏"), Some("┢"));
+/// assert_eq!(merge("│", "│"), None);
+/// assert_eq!(merge("a", "│"), None);
+/// ```
+pub fn merge(previous: &str, next: &str) -> Option<&'static str> {
+    let previous = lines(previous)?;
+    let next = lines(next)?;
+    let mut merged = next;
+    for (line, previous) in merged.iter_mut().zip(previous) {
+        *line = line.or(previous);
+    }
+    if merged == next {
+        return None;
+    }
+    SYMBOLS
+        .iter()
+        .find(|(_, lines)| *lines == merged)
+        .map(|(symbol, _)| *symbol)
+}
+
+/// Returns the lines of a box drawing symbol
+fn lines(symbol: &str) -> Option<Lines> {
+    SYMBOLS
+        .iter()
+        .find(|(s, _)| *s == symbol)
+        .map(|(_, lines)| *lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::cross("─", "│", Some("┼"))]
+    #[case::top_corners("┐", "┌", Some("┬"))]
+    #[case::bottom_corners("┘", "└", Some("┴"))]
+    #[case::stacked_corners("└", "┌", Some("├"))]
+    #[case::corner_on_side("│", "┌", Some("├"))]
+    #[case::rounded_corners("╮", "╭", Some("┬"))]
+    #[case::rounded_unchanged("╭", "╭", None)]
+    #[case::thick("┃", "━", Some("╋"))]
+    #[case::mixed_weights("┃", "─", Some("╂"))]
+    #[case::double("║", "╔", Some("╠"))]
+    #[case::mixed_double("│", "═", Some("╪"))]
+    #[case::next_wins("━", "┬", None)]
+    #[case::up_line("┃", "┬", Some("╀"))]
+    #[case::no_symbol("┃", "═", None)]
+    #[case::same("─", "─", None)]
+    #[case::not_a_line(" ", "│", None)]
+    #[case::dashed("┆", "─", None)]
+    fn merge(#[case] previous: &str, #[case] next: &str, #[case] expected: Option<&str>) {
+        assert_eq!(super::merge(previous, next), expected);
+    }
+
+    #[test]
+    fn symbols_are_unique() {
+        for (symbol, symbol_lines) in SYMBOLS {
+            assert_eq!(lines(symbol), Some(symbol_lines), "{symbol}");
+        }
+    }
+}
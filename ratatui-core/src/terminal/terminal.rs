@@ -467,6 +467,37 @@ where
         Ok(())
     }
 
+    /// Rings the terminal bell.
+    ///
+    /// This can be used to alert the user, e.g. when a long-running background task completes.
+    /// The effect depends on the configuration of the terminal (a sound, a visual flash, an
+    /// urgent window hint, or nothing at all).
+    pub fn bell(&mut self) -> io::Result<()> {
+        self.backend.bell()?;
+        self.backend.flush()
+    }
+
+    /// Sends a desktop notification with the given title and body.
+    ///
+    /// The notification is sent through the backend, using the OSC 9 or OSC 777 escape sequences
+    /// where the terminal supports them. Terminals that don't support notifications ignore it, so
+    /// consider combining it with [`Terminal::bell`] for important alerts.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use std::io::stdout;
+    /// # use ratatui::{backend::CrosstermBackend, Terminal};
+    /// # let backend = CrosstermBackend::new(stdout());
+    /// # let mut terminal = Terminal::new(backend)?;
+    /// terminal.notify("Build finished", "All 42 tests passed")?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn notify(&mut self, title: &str, body: &str) -> io::Result<()> {
+        self.backend.notify(title, body)?;
+        self.backend.flush()
+    }
+
     /// Clear the terminal and force a full redraw on the next draw call.
     pub fn clear(&mut self) -> io::Result<()> {
         match self.viewport {
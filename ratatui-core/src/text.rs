@@ -48,12 +48,27 @@
 //! ]);
 //! ```
 
+#[cfg(feature = "ansi")]
+mod ansi;
+#[cfg(feature = "unicode-bidi")]
+mod bidi;
+#[cfg(feature = "syntect")]
+mod highlight;
+#[cfg(feature = "markdown")]
+mod markdown;
+#[cfg(feature = "regex")]
+mod pattern;
+
+mod diff;
+
 mod grapheme;
 pub use grapheme::StyledGrapheme;
 
 mod line;
 pub use line::{Line, ToLine};
 
+mod markup;
+
 mod masked;
 pub use masked::Masked;
 
@@ -62,3 +77,6 @@ pub use span::{Span, ToSpan};
 
 mod text;
 pub use text::{Text, ToText};
+
+mod width;
+pub use width::{AmbiguousWidth, DisplayWidth};
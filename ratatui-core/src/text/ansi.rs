@@ -0,0 +1,290 @@
+//! Conversion of strings containing ANSI escape sequences into [`Text`].
+//!
+//! Only Select Graphic Rendition (SGR) sequences (`ESC [ ... m`) affect the resulting styles. All
+//! other control sequences (cursor movement, erasing, OSC sequences, etc.) are stripped from the
+//! output.
+use crate::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+};
+
+const ESC: u8 = 0x1b;
+const BEL: u8 = 0x07;
+
+impl<'a> Text<'a> {
+    /// Creates a [`Text`] from a string containing ANSI escape sequences.
+    ///
+    /// The SGR (Select Graphic Rendition) escape codes in the input are converted into the styles
+    /// of the resulting [`Span`]s. This makes it possible to display the colored output of
+    /// subprocesses such as `git`, `cargo` or `grep --color` without an external crate. Styles
+    /// carry over line breaks, just like they do in a terminal.
+    ///
+    /// The following codes are supported:
+    ///
+    /// - `0` resets the style
+    /// - `1`-`9` add the bold, dim, italic, underlined, slow blink, rapid blink, reversed, hidden
+    ///   and crossed out modifiers, and `21`-`29` remove them
+    /// - `30`-`37`, `90`-`97` set the foreground color and `39` resets it
+    /// - `40`-`47`, `100`-`107` set the background color and `49` resets it
+    /// - `38;5;n` / `48;5;n` set an indexed color and `38;2;r;g;b` / `48;2;r;g;b` set an RGB color
+    ///   (colors with a value above 255 are ignored)
+    /// - `58` / `59` set and reset the underline color (when the `underline-color` feature is
+    ///   enabled)
+    ///
+    /// Unknown codes and any other escape sequences are ignored. The content of the spans borrows
+    /// from the input, so no allocation is made for the text itself.
+    ///
+    /// This method is only available when the `ansi` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{
+    ///     style::Stylize,
+    ///     text::{Line, Span, Text},
+    /// };
+    ///
+    /// let text = Text::from_ansi("\x1b[1;31mError:\x1b[0m something went wrong");
+    /// assert_eq!(
+    ///     text,
+    ///     Text::from(Line::from(vec![
+    ///         Span::from("Error:").red().bold(),
+    ///         Span::from(" something went wrong"),
+    ///     ]))
+    /// );
+    /// ```
+    pub fn from_ansi(input: &'a str) -> Self {
+        let mut style = Style::default();
+        let mut lines: Vec<Line<'a>> = input
+            .lines()
+            .map(|line| parse_line(line, &mut style))
+            .collect();
+        if lines.is_empty() {
+            lines.push(Line::default());
+        }
+        Self::from(lines)
+    }
+}
+
+/// Parses a single line, updating `style` with the SGR sequences found in the line.
+fn parse_line<'a>(line: &'a str, style: &mut Style) -> Line<'a> {
+    let bytes = line.as_bytes();
+    let mut spans = Vec::new();
+    let mut start = 0;
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] != ESC {
+            index += 1;
+            continue;
+        }
+        if let Some(text) = line.get(start..index).filter(|text| !text.is_empty()) {
+            spans.push(Span::styled(text, *style));
+        }
+        index = match bytes.get(index + 1) {
+            Some(b'[') => {
+                // CSI: parameter and intermediate bytes followed by a final byte in 0x40..=0x7e
+                let params_start = index + 2;
+                let mut end = params_start;
+                while end < bytes.len() && !(0x40..=0x7e).contains(&bytes[end]) {
+                    end += 1;
+                }
+                let params = line.get(params_start..end);
+                if let Some(params) = params.filter(|_| bytes.get(end) == Some(&b'm')) {
+                    apply_sgr(params, style);
+                }
+                char_end(line, end)
+            }
+            Some(b']') => {
+                // OSC: terminated by BEL or ST (ESC \)
+                let mut end = index + 2;
+                loop {
+                    match bytes.get(end) {
+                        None => break end,
+                        Some(&BEL) => break end + 1,
+                        Some(&ESC) if bytes.get(end + 1) == Some(&b'\\') => break end + 2,
+                        Some(_) => end += 1,
+                    }
+                }
+            }
+            // nF escape sequences (e.g. `ESC ( B` to select a character set): intermediate bytes
+            // in 0x20..=0x2f followed by a final byte
+            Some(0x20..=0x2f) => {
+                let mut end = index + 2;
+                while bytes
+                    .get(end)
+                    .is_some_and(|byte| (0x20..=0x2f).contains(byte))
+                {
+                    end += 1;
+                }
+                char_end(line, end)
+            }
+            // skip the whole character after ESC, which may be longer than a byte
+            Some(_) => char_end(line, index + 1),
+            None => index + 1,
+        }
+        .min(bytes.len());
+        start = index;
+    }
+    if let Some(text) = line.get(start..).filter(|text| !text.is_empty()) {
+        spans.push(Span::styled(text, *style));
+    }
+    Line::from(spans)
+}
+
+/// Returns the index after the character that starts at `index`, which may be longer than a byte.
+fn char_end(line: &str, index: usize) -> usize {
+    let next = line.get(index..).and_then(|rest| rest.chars().next());
+    index + next.map_or(1, char::len_utf8)
+}
+
+/// Applies the semicolon separated SGR parameters to `style`.
+fn apply_sgr(params: &str, style: &mut Style) {
+    // an empty parameter list is equivalent to a reset
+    if params.is_empty() {
+        *style = Style::default();
+        return;
+    }
+    let mut codes = params
+        .split([';', ':'])
+        .map(|code| code.parse::<u16>().unwrap_or(0));
+    while let Some(code) = codes.next() {
+        match code {
+            0 => *style = Style::default(),
+            1 => style.add_modifier.insert(Modifier::BOLD),
+            2 => style.add_modifier.insert(Modifier::DIM),
+            3 => style.add_modifier.insert(Modifier::ITALIC),
+            4 => style.add_modifier.insert(Modifier::UNDERLINED),
+            5 => style.add_modifier.insert(Modifier::SLOW_BLINK),
+            6 => style.add_modifier.insert(Modifier::RAPID_BLINK),
+            7 => style.add_modifier.insert(Modifier::REVERSED),
+            8 => style.add_modifier.insert(Modifier::HIDDEN),
+            9 => style.add_modifier.insert(Modifier::CROSSED_OUT),
+            21 | 22 => style.add_modifier.remove(Modifier::BOLD | Modifier::DIM),
+            23 => style.add_modifier.remove(Modifier::ITALIC),
+            24 => style.add_modifier.remove(Modifier::UNDERLINED),
+            25 => style
+                .add_modifier
+                .remove(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK),
+            27 => style.add_modifier.remove(Modifier::REVERSED),
+            28 => style.add_modifier.remove(Modifier::HIDDEN),
+            29 => style.add_modifier.remove(Modifier::CROSSED_OUT),
+            30..=37 => style.fg = Some(base_color(code - 30)),
+            38 => style.fg = extended_color(&mut codes).or(style.fg),
+            39 => style.fg = None,
+            40..=47 => style.bg = Some(base_color(code - 40)),
+            48 => style.bg = extended_color(&mut codes).or(style.bg),
+            49 => style.bg = None,
+            #[cfg(feature = "underline-color")]
+            58 => style.underline_color = extended_color(&mut codes).or(style.underline_color),
+            #[cfg(feature = "underline-color")]
+            59 => style.underline_color = None,
+            90..=97 => style.fg = Some(bright_color(code - 90)),
+            100..=107 => style.bg = Some(bright_color(code - 100)),
+            _ => {}
+        }
+    }
+}
+
+/// Parses the arguments of an extended color (`5;n` or `2;r;g;b`).
+///
+/// All the arguments are consumed, but the color is ignored if one of them is above 255.
+fn extended_color(codes: &mut impl Iterator<Item = u16>) -> Option<Color> {
+    let component = |code: u16| u8::try_from(code).ok();
+    match codes.next()? {
+        5 => codes.next().and_then(component).map(Color::Indexed),
+        2 => {
+            let [r, g, b] = [codes.next()?, codes.next()?, codes.next()?].map(component);
+            Some(Color::Rgb(r?, g?, b?))
+        }
+        _ => None,
+    }
+}
+
+const fn base_color(index: u16) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        _ => Color::Gray,
+    }
+}
+
+const fn bright_color(index: u16) -> Color {
+    match index {
+        0 => Color::DarkGray,
+        1 => Color::LightRed,
+        2 => Color::LightGreen,
+        3 => Color::LightYellow,
+        4 => Color::LightBlue,
+        5 => Color::LightMagenta,
+        6 => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::Stylize;
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(Text::from_ansi("hello\nworld"), Text::raw("hello\nworld"));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Text::from_ansi(""), Text::from(Line::default()));
+    }
+
+    #[test]
+    fn style_carries_over_lines() {
+        let text = Text::from_ansi("\x1b[32mfoo\nbar\x1b[0m baz");
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from("foo".green()),
+                Line::from(vec!["bar".green(), " baz".into()]),
+            ])
+        );
+    }
+
+    #[rstest]
+    #[case::reset("\x1b[1mfoo\x1b[mbar", vec!["foo".bold(), "bar".into()])]
+    #[case::bold_off("\x1b[1;3mfoo\x1b[22mbar", vec!["foo".bold().italic(), "bar".italic()])]
+    #[case::bright("\x1b[91;104mfoo", vec!["foo".light_red().on_light_blue()])]
+    #[case::default_fg("\x1b[31;42mfoo\x1b[39mbar", vec!["foo".red().on_green(), "bar".on_green()])]
+    #[case::indexed("\x1b[38;5;208mfoo", vec!["foo".fg(Color::Indexed(208))])]
+    #[case::rgb("\x1b[48;2;1;2;3mfoo", vec!["foo".bg(Color::Rgb(1, 2, 3))])]
+    #[case::colon_separated("\x1b[38:5:1mfoo", vec!["foo".fg(Color::Indexed(1))])]
+    #[case::non_sgr("\x1b[2Kfoo\x1b[1Gbar", vec!["foo".into(), "bar".into()])]
+    #[case::osc("\x1b]0;title\x07foo", vec!["foo".into()])]
+    #[case::osc_st("\x1b]8;;https://ratatui.rs\x1b\\foo", vec!["foo".into()])]
+    #[case::truncated("foo\x1b[31", vec!["foo".into()])]
+    #[case::multibyte_after_esc("ab\x1bécd", vec!["ab".into(), "cd".into()])]
+    #[case::charset("\x1b(Bfoo\x1b)0bar", vec!["foo".into(), "bar".into()])]
+    #[case::intermediates("\x1b #8foo", vec!["foo".into()])]
+    #[case::truncated_intermediates("foo\x1b(", vec!["foo".into()])]
+    #[case::multibyte_after_intermediates("ab\x1b(écd", vec!["ab".into(), "cd".into()])]
+    #[case::rgb_above_255("\x1b[31;38;2;300;2;3;1mfoo", vec!["foo".red().bold()])]
+    #[case::indexed_above_255("\x1b[38;5;256;4mfoo", vec!["foo".underlined()])]
+    fn sgr(#[case] input: &str, #[case] expected: Vec<Span>) {
+        assert_eq!(Text::from_ansi(input), Text::from(Line::from(expected)));
+    }
+
+    #[test]
+    fn crlf() {
+        let text = Text::from_ansi("\x1b[31mfoo\r\nbar\r\n");
+        assert_eq!(
+            text,
+            Text::from(vec![Line::from("foo".red()), Line::from("bar".red())])
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! Reordering of bidirectional text from logical to visual order.
+use std::borrow::Cow;
+
+use unicode_bidi::BidiInfo;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::text::{Span, StyledGrapheme};
+
+impl StyledGrapheme<'_> {
+    /// Reorders graphemes from logical order (the order in which they are stored) to visual order
+    /// (the order in which they are displayed from left to right).
+    ///
+    /// This applies the [Unicode Bidirectional Algorithm] to the graphemes, so that right-to-left
+    /// text (e.g. Arabic or Hebrew) is displayed in the correct order, including when it is mixed
+    /// with left-to-right text. The graphemes themselves are not modified, and mirrored characters
+    /// (e.g. parentheses in right-to-left text) are not replaced.
+    ///
+    /// The graphemes are borrowed as is when they don't contain any right-to-left text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::{style::Style, text::StyledGrapheme};
+    ///
+    /// let graphemes = ["a", " ", "א", "ב"].map(|symbol| StyledGrapheme::new(symbol, Style::new()));
+    /// let visual = StyledGrapheme::visual_order(&graphemes);
+    /// let symbols = visual.iter().map(|grapheme| grapheme.symbol);
+    /// assert_eq!(symbols.collect::<String>(), "a בא");
+    /// ```
+    ///
+    /// [Unicode Bidirectional Algorithm]: https://unicode.org/reports/tr9/
+    pub fn visual_order(graphemes: &[Self]) -> Cow<'_, [Self]> {
+        let symbols = graphemes.iter().map(|grapheme| grapheme.symbol);
+        visual_indices(symbols).map_or(Cow::Borrowed(graphemes), |indices| {
+            Cow::Owned(
+                indices
+                    .into_iter()
+                    .map(|index| graphemes[index].clone())
+                    .collect(),
+            )
+        })
+    }
+}
+
+/// Returns the spans of a line in visual order, or `None` if the spans don't contain any
+/// right-to-left text.
+///
+/// The spans are split where the direction changes, and consecutive graphemes of the same source
+/// span are merged into a single span with the style and hyperlink of the source span.
+pub(crate) fn visual_spans(spans: &[Span<'_>]) -> Option<Vec<Span<'static>>> {
+    let graphemes = spans
+        .iter()
+        .enumerate()
+        .flat_map(|(index, span)| {
+            span.content
+                .graphemes(true)
+                .map(move |symbol| (index, symbol))
+        })
+        .collect::<Vec<_>>();
+    let indices = visual_indices(graphemes.iter().map(|(_, symbol)| *symbol))?;
+
+    let mut visual: Vec<Span<'static>> = Vec::new();
+    let mut last = None;
+    for (span_index, symbol) in indices.into_iter().map(|index| graphemes[index]) {
+        if last == Some(span_index) {
+            if let Some(span) = visual.last_mut() {
+                span.content.to_mut().push_str(symbol);
+            }
+            continue;
+        }
+        last = Some(span_index);
+        let span = &spans[span_index];
+        visual.push(Span {
+            content: symbol.to_string().into(),
+            style: span.style,
+            hyperlink: span.hyperlink.clone().map(|url| url.into_owned().into()),
+        });
+    }
+    Some(visual)
+}
+
+/// Returns the indices of the symbols in visual order, or `None` if the symbols don't contain any
+/// right-to-left text.
+fn visual_indices<'a, I>(symbols: I) -> Option<Vec<usize>>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut text = String::new();
+    let mut starts = Vec::new();
+    for symbol in symbols {
+        starts.push(text.len());
+        text.push_str(symbol);
+    }
+    let bidi = BidiInfo::new(&text, None);
+    if !bidi.has_rtl() {
+        return None;
+    }
+
+    let mut indices = Vec::with_capacity(starts.len());
+    for paragraph in &bidi.paragraphs {
+        let (levels, runs) = bidi.visual_runs(paragraph, paragraph.range.clone());
+        for run in runs {
+            let first = starts.partition_point(|&start| start < run.start);
+            let end = starts.partition_point(|&start| start < run.end);
+            if levels[run.start].is_rtl() {
+                indices.extend((first..end).rev());
+            } else {
+                indices.extend(first..end);
+            }
+        }
+    }
+    Some(indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{Style, Stylize},
+        text::Line,
+        widgets::Widget,
+    };
+
+    fn visual_string(text: &str) -> String {
+        let graphemes = text
+            .graphemes(true)
+            .map(|symbol| StyledGrapheme::new(symbol, Style::new()))
+            .collect::<Vec<_>>();
+        StyledGrapheme::visual_order(&graphemes)
+            .iter()
+            .map(|grapheme| grapheme.symbol)
+            .collect()
+    }
+
+    #[rstest]
+    #[case::ltr("hello", "hello")]
+    #[case::rtl("שלום", "םולש")]
+    #[case::rtl_in_ltr("say שלום now", "say םולש now")]
+    #[case::ltr_in_rtl("שלום abc עולם", "םלוע abc םולש")]
+    #[case::combining_marks("\u{5d0}\u{5b8}\u{5d1}", "\u{5d1}\u{5d0}\u{5b8}")]
+    fn visual_order(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(visual_string(text), expected);
+    }
+
+    #[test]
+    fn visual_order_borrows_ltr_text() {
+        let graphemes = [StyledGrapheme::new("a", Style::new())];
+        assert!(matches!(
+            StyledGrapheme::visual_order(&graphemes),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn visual_spans_keep_style() {
+        let spans = [Span::raw("ab "), "אב".red()];
+        assert_eq!(
+            visual_spans(&spans),
+            Some(vec![Span::raw("ab "), "בא".red()])
+        );
+        assert_eq!(visual_spans(&[Span::raw("abc")]), None);
+    }
+
+    #[test]
+    fn render_line() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        Line::from("abc אבג").render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["abc גבא "]));
+    }
+}
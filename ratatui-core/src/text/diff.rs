@@ -0,0 +1,175 @@
+//! Highlighting of the differences between two [`Line`]s.
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    style::Style,
+    text::{Line, Span},
+};
+
+impl Line<'_> {
+    /// Returns a copy of this line where the graphemes that differ from `previous` are highlighted.
+    ///
+    /// The graphemes of both lines are compared with a longest common subsequence diff, and the
+    /// graphemes of this line that are not part of the common subsequence (i.e. that were inserted
+    /// or replaced) are patched with `style`. This is useful for diff views, or to draw attention
+    /// to the parts of a log or status line that changed since the last update.
+    ///
+    /// Only the content of the lines is compared, so a grapheme whose style changed is not
+    /// highlighted. The style, alignment and hyperlinks of this line are preserved.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{
+    ///     style::{Style, Stylize},
+    ///     text::Line,
+    /// };
+    ///
+    /// let previous = Line::from("status: idle");
+    /// let line = Line::from("status: busy");
+    /// assert_eq!(
+    ///     line.highlight_changes(&previous, Style::new().on_red()),
+    ///     Line::from(vec!["status: ".into(), "busy".on_red()])
+    /// );
+    /// ```
+    ///
+    /// [`Color`]: crate::style::Color
+    pub fn highlight_changes<S: Into<Style>>(
+        &self,
+        previous: &Line<'_>,
+        style: S,
+    ) -> Line<'static> {
+        let style = style.into();
+        let old = graphemes(previous)
+            .map(|(_, symbol)| symbol)
+            .collect::<Vec<_>>();
+        let new = graphemes(self).collect::<Vec<_>>();
+        let unchanged = common_subsequence(&old, &new, |old, (_, new)| old == new);
+
+        let mut spans: Vec<Span<'static>> = Vec::new();
+        let mut last = None;
+        for ((span_index, symbol), unchanged) in new.into_iter().zip(unchanged) {
+            let span = &self.spans[span_index];
+            if last == Some((span_index, unchanged)) {
+                if let Some(last_span) = spans.last_mut() {
+                    last_span.content.to_mut().push_str(symbol);
+                }
+                continue;
+            }
+            last = Some((span_index, unchanged));
+            spans.push(Span {
+                content: symbol.to_string().into(),
+                style: if unchanged {
+                    span.style
+                } else {
+                    span.style.patch(style)
+                },
+                hyperlink: span.hyperlink.clone().map(|url| url.into_owned().into()),
+            });
+        }
+        Line {
+            style: self.style,
+            alignment: self.alignment,
+            spans,
+        }
+    }
+}
+
+/// Returns the graphemes of the line along with the index of the span that contains them.
+fn graphemes<'a>(line: &'a Line<'_>) -> impl Iterator<Item = (usize, &'a str)> {
+    line.spans.iter().enumerate().flat_map(|(index, span)| {
+        span.content
+            .graphemes(true)
+            .map(move |symbol| (index, symbol))
+    })
+}
+
+/// Returns for each item of `new` whether it is part of a longest common subsequence of `old` and
+/// `new`.
+fn common_subsequence<A, B, F>(old: &[A], new: &[B], eq: F) -> Vec<bool>
+where
+    F: Fn(&A, &B) -> bool,
+{
+    // lengths[i][j] is the length of the longest common subsequence of old[i..] and new[j..]
+    let width = new.len() + 1;
+    let mut lengths = vec![0_usize; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i * width + j] = if eq(&old[i], &new[j]) {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+    let mut in_subsequence = vec![false; new.len()];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if eq(&old[i], &new[j]) {
+            in_subsequence[j] = true;
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    in_subsequence
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::{Color, Stylize};
+
+    #[rstest]
+    #[case::unchanged("abc", "abc", vec!["abc".into()])]
+    #[case::replaced("abc", "axc", vec!["a".into(), "x".red(), "c".into()])]
+    #[case::inserted("ac", "abbc", vec!["a".into(), "bb".red(), "c".into()])]
+    #[case::deleted("abc", "ac", vec!["ac".into()])]
+    #[case::empty_previous("", "ab", vec!["ab".red()])]
+    #[case::empty("ab", "", vec![])]
+    #[case::graphemes("née", "nèe", vec!["n".into(), "è".red(), "e".into()])]
+    fn highlight_changes(
+        #[case] previous: &str,
+        #[case] line: &str,
+        #[case] expected: Vec<Span<'static>>,
+    ) {
+        let line = Line::from(line).highlight_changes(&Line::from(previous), Color::Red);
+        assert_eq!(line, Line::from(expected));
+    }
+
+    #[test]
+    fn preserves_spans_and_line_style() {
+        let previous = Line::from(vec!["12".green(), " items".into()]);
+        let line = Line::from(vec!["13".green(), " items".into()])
+            .bold()
+            .centered();
+        assert_eq!(
+            line.highlight_changes(&previous, Style::new().reversed()),
+            Line::from(vec!["1".green(), "3".green().reversed(), " items".into()])
+                .bold()
+                .centered()
+        );
+    }
+
+    #[test]
+    fn preserves_hyperlinks() {
+        let previous = Line::from("v1");
+        let line = Line::from(Span::raw("v2").hyperlink("https://ratatui.rs"));
+        assert_eq!(
+            line.highlight_changes(&previous, Color::Red),
+            Line::from(vec![
+                Span::raw("v").hyperlink("https://ratatui.rs"),
+                Span::raw("2").red().hyperlink("https://ratatui.rs"),
+            ])
+        );
+    }
+}
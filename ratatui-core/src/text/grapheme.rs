@@ -12,6 +12,10 @@ const ZWSP: &str = "\u{200b}";
 pub struct StyledGrapheme<'a> {
     pub symbol: &'a str,
     pub style: Style,
+    /// The URL of the hyperlink of the [`Span`] the grapheme comes from, if any
+    ///
+    /// [`Span`]: crate::text::Span
+    pub hyperlink: Option<&'a str>,
 }
 
 impl<'a> StyledGrapheme<'a> {
@@ -25,9 +29,17 @@ impl<'a> StyledGrapheme<'a> {
         Self {
             symbol,
             style: style.into(),
+            hyperlink: None,
         }
     }
 
+    /// Sets the URL of the hyperlink of the grapheme.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn hyperlink(mut self, url: Option<&'a str>) -> Self {
+        self.hyperlink = url;
+        self
+    }
+
     pub fn is_whitespace(&self) -> bool {
         let symbol = self.symbol;
         symbol == ZWSP || symbol.chars().all(char::is_whitespace) && symbol != NBSP
@@ -60,6 +72,12 @@ mod tests {
         assert_eq!(sg.style, style);
     }
 
+    #[test]
+    fn hyperlink() {
+        let sg = StyledGrapheme::new("a", Style::new()).hyperlink(Some("https://ratatui.rs"));
+        assert_eq!(sg.hyperlink, Some("https://ratatui.rs"));
+    }
+
     #[test]
     fn style() {
         let style = Style::new().yellow();
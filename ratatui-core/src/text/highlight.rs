@@ -0,0 +1,206 @@
+//! Conversion of [`syntect`] highlighting results into styled text.
+use syntect::highlighting::{self, FontStyle};
+
+use crate::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+impl From<highlighting::Color> for Color {
+    /// Converts a [`syntect`] color into a [`Color`].
+    ///
+    /// Colors are converted to [`Color::Rgb`], except for the colors that follow the convention
+    /// used by terminal themes (e.g. the `ansi` and `base16` themes of `bat`) to refer to the
+    /// terminal's own palette:
+    ///
+    /// - colors with an alpha of `0` are the terminal color whose index is stored in the red
+    ///   channel, where the first 8 indices are converted to the named colors and the others to
+    ///   [`Color::Indexed`]
+    /// - colors with an alpha of `1` are the default color of the terminal ([`Color::Reset`])
+    fn from(color: highlighting::Color) -> Self {
+        match color.a {
+            0 => match color.r {
+                0 => Self::Black,
+                1 => Self::Red,
+                2 => Self::Green,
+                3 => Self::Yellow,
+                4 => Self::Blue,
+                5 => Self::Magenta,
+                6 => Self::Cyan,
+                7 => Self::Gray,
+                index => Self::Indexed(index),
+            },
+            1 => Self::Reset,
+            _ => Self::Rgb(color.r, color.g, color.b),
+        }
+    }
+}
+
+impl From<FontStyle> for Modifier {
+    /// Converts a [`syntect`] font style into a [`Modifier`].
+    fn from(font_style: FontStyle) -> Self {
+        let mut modifier = Self::empty();
+        if font_style.contains(FontStyle::BOLD) {
+            modifier |= Self::BOLD;
+        }
+        if font_style.contains(FontStyle::ITALIC) {
+            modifier |= Self::ITALIC;
+        }
+        if font_style.contains(FontStyle::UNDERLINE) {
+            modifier |= Self::UNDERLINED;
+        }
+        modifier
+    }
+}
+
+impl From<highlighting::Style> for Style {
+    /// Converts a [`syntect`] style into a [`Style`].
+    ///
+    /// The foreground and background colors are converted with [`Color::from`], and the font
+    /// style with [`Modifier::from`]. Themes usually set the same background color for every
+    /// token, so clear the background (`style.bg = None`) to keep the background of the widget.
+    fn from(style: highlighting::Style) -> Self {
+        Self::new()
+            .fg(style.foreground.into())
+            .bg(style.background.into())
+            .add_modifier(style.font_style.into())
+    }
+}
+
+impl<'a> Span<'a> {
+    /// Creates a [`Span`] from a range of text highlighted by [`syntect`].
+    ///
+    /// The style is converted with [`Style::from`]. Line endings (`\n` or `\r\n`) at the end of the
+    /// text are removed, so that the output of [`syntect::util::LinesWithEndings`] can be used as
+    /// is.
+    ///
+    /// This method is only available when the `syntect` feature is enabled.
+    pub fn from_syntect(style: highlighting::Style, content: &'a str) -> Self {
+        let content = content.strip_suffix('\n').map_or(content, |content| {
+            content.strip_suffix('\r').unwrap_or(content)
+        });
+        Self::styled(content, Style::from(style))
+    }
+}
+
+impl<'a> Line<'a> {
+    /// Creates a [`Line`] from the ranges of a line highlighted by [`syntect`].
+    ///
+    /// Each range becomes a [`Span`] with the style of the range (see [`Span::from_syntect`]),
+    /// without its line ending. This accepts the output of
+    /// `HighlightLines::highlight_line` and any other iterator of styled ranges.
+    ///
+    /// This method is only available when the `syntect` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{
+    ///     style::{Color, Modifier, Style},
+    ///     text::{Line, Span},
+    /// };
+    /// use syntect::highlighting::{self, FontStyle};
+    ///
+    /// let keyword = highlighting::Style {
+    ///     foreground: highlighting::Color { r: 255, g: 0, b: 0, a: 255 },
+    ///     background: highlighting::Color { r: 0, g: 0, b: 0, a: 1 },
+    ///     font_style: FontStyle::BOLD,
+    /// };
+    /// let plain = highlighting::Style { font_style: FontStyle::empty(), ..keyword };
+    ///
+    /// let line = Line::from_syntect([(keyword, "fn"), (plain, " main() {}\n")]);
+    ///
+    /// let red = Style::new().fg(Color::Rgb(255, 0, 0)).bg(Color::Reset);
+    /// assert_eq!(
+    ///     line,
+    ///     Line::from(vec![
+    ///         Span::styled("fn", red.add_modifier(Modifier::BOLD)),
+    ///         Span::styled(" main() {}", red),
+    ///     ])
+    /// );
+    /// ```
+    pub fn from_syntect<I>(ranges: I) -> Self
+    where
+        I: IntoIterator<Item = (highlighting::Style, &'a str)>,
+    {
+        ranges
+            .into_iter()
+            .map(|(style, content)| Span::from_syntect(style, content))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::Stylize;
+
+    const fn color(r: u8, g: u8, b: u8, a: u8) -> highlighting::Color {
+        highlighting::Color { r, g, b, a }
+    }
+
+    fn style(foreground: highlighting::Color, font_style: FontStyle) -> highlighting::Style {
+        highlighting::Style {
+            foreground,
+            background: color(0, 0, 0, 1),
+            font_style,
+        }
+    }
+
+    #[rstest]
+    #[case::rgb(color(1, 2, 3, 255), Color::Rgb(1, 2, 3))]
+    #[case::named(color(4, 0, 0, 0), Color::Blue)]
+    #[case::indexed(color(42, 0, 0, 0), Color::Indexed(42))]
+    #[case::default(color(0, 0, 0, 1), Color::Reset)]
+    fn convert_color(#[case] color: highlighting::Color, #[case] expected: Color) {
+        assert_eq!(Color::from(color), expected);
+    }
+
+    #[test]
+    fn convert_font_style() {
+        assert_eq!(Modifier::from(FontStyle::empty()), Modifier::empty());
+        assert_eq!(
+            Modifier::from(FontStyle::BOLD | FontStyle::ITALIC | FontStyle::UNDERLINE),
+            Modifier::BOLD | Modifier::ITALIC | Modifier::UNDERLINED
+        );
+    }
+
+    #[test]
+    fn convert_style() {
+        let style = style(color(0, 255, 0, 255), FontStyle::ITALIC);
+        assert_eq!(
+            Style::from(style),
+            Style::new()
+                .fg(Color::Rgb(0, 255, 0))
+                .bg(Color::Reset)
+                .italic()
+        );
+    }
+
+    #[rstest]
+    #[case::no_line_ending("let", "let")]
+    #[case::lf("x;\n", "x;")]
+    #[case::crlf("x;\r\n", "x;")]
+    #[case::only_line_ending("\n", "")]
+    fn span_strips_line_ending(#[case] content: &str, #[case] expected: &str) {
+        let span = Span::from_syntect(style(color(0, 0, 0, 1), FontStyle::empty()), content);
+        assert_eq!(span.content, expected);
+    }
+
+    #[test]
+    fn line_from_syntect() {
+        let keyword = style(color(3, 0, 0, 0), FontStyle::BOLD);
+        let plain = style(color(0, 0, 0, 1), FontStyle::empty());
+        let line = Line::from_syntect([(keyword, "let"), (plain, " x = 1;\n")]);
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::styled("let", Style::new().yellow().bg(Color::Reset).bold()),
+                Span::styled(" x = 1;", Style::new().fg(Color::Reset).bg(Color::Reset)),
+            ])
+        );
+    }
+}
@@ -2,8 +2,6 @@
 #![warn(clippy::pedantic, clippy::nursery, clippy::arithmetic_side_effects)]
 use std::{borrow::Cow, fmt};
 
-use unicode_truncate::UnicodeTruncateStr;
-
 use crate::{
     buffer::Buffer,
     layout::{Alignment, Rect},
@@ -543,6 +541,26 @@ impl<'a> Line<'a> {
         self.patch_style(Style::reset())
     }
 
+    /// Converts the content of all the spans of this line to full-width characters.
+    ///
+    /// See [`Span::full_width`] for more details.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{style::Stylize, text::Line};
+    ///
+    /// let line = Line::from(vec!["Big".bold(), " title".into()]).full_width();
+    /// assert_eq!(line.width(), 18);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn full_width(mut self) -> Self {
+        self.spans = self.spans.into_iter().map(Span::full_width).collect();
+        self
+    }
+
     /// Returns an iterator over the spans of this line.
     pub fn iter(&self) -> std::slice::Iter<Span<'a>> {
         self.spans.iter()
@@ -716,6 +734,13 @@ impl Line<'_> {
 
         let alignment = self.alignment.or(parent_alignment);
 
+        #[cfg(feature = "unicode-bidi")]
+        let visual_spans = super::bidi::visual_spans(&self.spans);
+        #[cfg(feature = "unicode-bidi")]
+        let spans = visual_spans.as_deref().unwrap_or(&self.spans);
+        #[cfg(not(feature = "unicode-bidi"))]
+        let spans = &self.spans;
+
         let area_width = usize::from(area.width);
         let can_render_complete_line = line_width <= area_width;
         if can_render_complete_line {
@@ -726,7 +751,7 @@ impl Line<'_> {
             };
             let indent_width = u16::try_from(indent_width).unwrap_or(u16::MAX);
             let area = area.indent_x(indent_width);
-            render_spans(&self.spans, area, buf, 0);
+            render_spans(spans, area, buf, 0);
         } else {
             // There is not enough space to render the whole line. As the right side is truncated by
             // the area width, only truncate the left.
@@ -735,7 +760,7 @@ impl Line<'_> {
                 Some(Alignment::Right) => line_width.saturating_sub(area_width),
                 Some(Alignment::Left) | None => 0,
             };
-            render_spans(&self.spans, area, buf, skip_width);
+            render_spans(spans, area, buf, skip_width);
         };
     }
 }
@@ -784,17 +809,16 @@ fn spans_after_width<'a>(
             }
             // Span is only partially visible. As the end is truncated by the area width, only
             // truncate the start of the span.
-            let (content, actual_width) = span.content.unicode_truncate_start(available_width);
+            let (content, actual_width) =
+                super::width::truncate_start(&span.content, available_width);
 
             // When the first grapheme of the span was truncated, start rendering from a position
             // that takes that into account by indenting the start of the area
             let first_grapheme_offset = available_width.saturating_sub(actual_width);
             let first_grapheme_offset = u16::try_from(first_grapheme_offset).unwrap_or(u16::MAX);
-            (
-                Span::styled(content, span.style),
-                actual_width,
-                first_grapheme_offset,
-            )
+            let mut truncated = Span::styled(content, span.style);
+            truncated.hyperlink.clone_from(&span.hyperlink);
+            (truncated, actual_width, first_grapheme_offset)
         })
 }
 
@@ -965,6 +989,14 @@ mod tests {
         assert_eq!(Style::reset(), line.style);
     }
 
+    #[test]
+    fn full_width() {
+        let line = Line::from(vec!["ab".red(), "c".into()])
+            .centered()
+            .full_width();
+        assert_eq!(line, Line::from(vec!["ａｂ".red(), "ｃ".into()]).centered());
+    }
+
     #[test]
     fn stylize() {
         assert_eq!(Line::default().green().style, Color::Green.into());
@@ -0,0 +1,446 @@
+//! Conversion of Markdown strings into [`Text`].
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+
+use crate::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+};
+
+const HEADING_STYLE: Style = Style::new().add_modifier(Modifier::BOLD);
+const H1_STYLE: Style = HEADING_STYLE.add_modifier(Modifier::UNDERLINED);
+const CODE_STYLE: Style = Style::new().fg(Color::Yellow);
+const LINK_STYLE: Style = Style::new()
+    .fg(Color::Blue)
+    .add_modifier(Modifier::UNDERLINED);
+const QUOTE_MARKER_STYLE: Style = Style::new().fg(Color::DarkGray);
+const RULE_STYLE: Style = Style::new().fg(Color::DarkGray);
+
+impl Text<'static> {
+    /// Creates a [`Text`] from a Markdown string.
+    ///
+    /// The Markdown is parsed as [CommonMark] (with the strikethrough and task list extensions)
+    /// and converted into styled lines, so help screens and READMEs can be displayed in a
+    /// `Paragraph` without styling them by hand:
+    ///
+    /// - headings are bold (and underlined for level 1) and keep their `#` markers
+    /// - emphasis is italic, strong emphasis is bold and strikethrough is crossed out
+    /// - code spans and code blocks are yellow
+    /// - links are blue and underlined, and carry their URL as a [hyperlink](Span::hyperlink)
+    /// - list items are prefixed with `- ` or their number, and nested lists are indented
+    /// - block quotes are prefixed with `> `
+    /// - blocks (paragraphs, headings, lists, etc.) are separated by an empty line
+    ///
+    /// HTML is displayed as is. Images are displayed as their alternative text.
+    ///
+    /// This method is only available when the `markdown` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{
+    ///     style::Stylize,
+    ///     text::{Line, Text},
+    /// };
+    ///
+    /// let text = Text::from_markdown("# Help\n\nPress *q* to quit");
+    /// assert_eq!(
+    ///     text,
+    ///     Text::from(vec![
+    ///         Line::from(vec!["# ".bold().underlined(), "Help".bold().underlined()]),
+    ///         Line::default(),
+    ///         Line::from(vec!["Press ".into(), "q".italic(), " to quit".into()]),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    /// [CommonMark]: https://commonmark.org
+    pub fn from_markdown(input: &str) -> Self {
+        let options = Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+        let mut writer = MarkdownWriter::default();
+        for event in Parser::new_ext(input, options) {
+            writer.handle(event);
+        }
+        writer.flush_line();
+        Self::from(writer.lines)
+    }
+}
+
+/// Accumulates the lines of text while walking through the Markdown events.
+#[derive(Debug, Default)]
+struct MarkdownWriter {
+    lines: Vec<Line<'static>>,
+    /// The line that is currently being written to
+    line: Option<Line<'static>>,
+    /// Whether the current line contains content besides its prefix
+    line_has_content: bool,
+    /// Whether an empty line must be inserted before the next block
+    needs_blank_line: bool,
+    /// The stack of inline styles
+    styles: Vec<Style>,
+    /// The prefixes (quote markers and list indentation) of each line
+    prefixes: Vec<Span<'static>>,
+    /// The marker of a list item that replaces the last prefix on the first line of the item
+    item_marker: Option<Span<'static>>,
+    /// The next number of each (nested) list, or `None` for unordered lists
+    lists: Vec<Option<u64>>,
+    /// The URLs of the links that are currently open
+    links: Vec<String>,
+    in_code_block: bool,
+}
+
+impl MarkdownWriter {
+    fn handle(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) if self.in_code_block => {
+                for line in text.lines() {
+                    self.push_span(Span::styled(line.to_string(), CODE_STYLE));
+                    self.flush_line();
+                }
+            }
+            Event::Text(text) | Event::Html(text) | Event::InlineHtml(text) => {
+                let mut span = Span::styled(text.to_string(), self.style());
+                span.hyperlink = self.links.last().cloned().map(Into::into);
+                self.push_span(span);
+            }
+            Event::Code(code) => {
+                let style = self.style().patch(CODE_STYLE);
+                self.push_span(Span::styled(code.to_string(), style));
+            }
+            Event::SoftBreak => self.push_span(Span::styled(" ", self.style())),
+            Event::HardBreak => {
+                self.ensure_line();
+                self.flush_line();
+            }
+            Event::Rule => {
+                self.start_block();
+                self.push_span(Span::styled("---", RULE_STYLE));
+                self.end_block();
+            }
+            Event::TaskListMarker(checked) => {
+                let marker = if checked { "[x] " } else { "[ ] " };
+                self.push_span(Span::raw(marker));
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => self.start_block(),
+            Tag::Heading { level, .. } => {
+                self.start_block();
+                let style = if level == HeadingLevel::H1 {
+                    H1_STYLE
+                } else {
+                    HEADING_STYLE
+                };
+                self.styles.push(style);
+                let marker = format!("{} ", "#".repeat(level as usize));
+                self.push_span(Span::styled(marker, style));
+            }
+            Tag::BlockQuote { .. } => {
+                self.start_block();
+                self.prefixes.push(Span::styled("> ", QUOTE_MARKER_STYLE));
+            }
+            Tag::CodeBlock(_) => {
+                self.start_block();
+                self.in_code_block = true;
+            }
+            Tag::List(start) => {
+                if self.lists.is_empty() {
+                    self.start_block();
+                } else {
+                    self.flush_line();
+                }
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.start_block();
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => "- ".to_string(),
+                };
+                self.prefixes.push(Span::raw(" ".repeat(marker.len())));
+                self.item_marker = Some(Span::raw(marker));
+            }
+            Tag::Emphasis => self.push_style(Modifier::ITALIC.into()),
+            Tag::Strong => self.push_style(Modifier::BOLD.into()),
+            Tag::Strikethrough => self.push_style(Modifier::CROSSED_OUT.into()),
+            Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. } => {
+                self.push_style(LINK_STYLE);
+                self.links.push(dest_url.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => self.end_block(),
+            TagEnd::Heading { .. } => {
+                self.styles.pop();
+                self.end_block();
+            }
+            TagEnd::BlockQuote { .. } => {
+                self.flush_line();
+                self.prefixes.pop();
+                self.needs_blank_line = true;
+            }
+            TagEnd::CodeBlock => {
+                self.in_code_block = false;
+                self.end_block();
+            }
+            TagEnd::List { .. } => {
+                self.flush_line();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.needs_blank_line = true;
+                }
+            }
+            TagEnd::Item => {
+                self.flush_line();
+                self.prefixes.pop();
+                self.item_marker = None;
+            }
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => {
+                self.styles.pop();
+            }
+            TagEnd::Link | TagEnd::Image => {
+                self.styles.pop();
+                self.links.pop();
+            }
+            _ => {}
+        }
+    }
+
+    fn style(&self) -> Style {
+        self.styles.last().copied().unwrap_or_default()
+    }
+
+    fn push_style(&mut self, style: Style) {
+        self.styles.push(self.style().patch(style));
+    }
+
+    /// Starts a new block, inserting an empty line after the previous block if necessary.
+    ///
+    /// A line that only contains a list item marker is kept, so that the first block of a list
+    /// item is displayed next to its marker.
+    fn start_block(&mut self) {
+        if self.line.is_some() && !self.line_has_content {
+            return;
+        }
+        self.flush_line();
+        if self.needs_blank_line && !self.lines.is_empty() {
+            let prefix = self
+                .prefixes
+                .iter()
+                .map(|span| Span::styled(span.content.trim_end().to_string(), span.style));
+            self.lines.push(prefix.collect());
+        }
+        self.needs_blank_line = false;
+    }
+
+    fn end_block(&mut self) {
+        self.flush_line();
+        self.needs_blank_line = true;
+    }
+
+    fn ensure_line(&mut self) {
+        if self.line.is_some() {
+            return;
+        }
+        let mut prefixes = self.prefixes.clone();
+        if let Some(marker) = self.item_marker.take() {
+            prefixes.pop();
+            prefixes.push(marker);
+        }
+        self.line = Some(Line::from(prefixes));
+        self.line_has_content = false;
+    }
+
+    fn push_span(&mut self, span: Span<'static>) {
+        self.ensure_line();
+        if let Some(line) = self.line.as_mut() {
+            line.push_span(span);
+        }
+        self.line_has_content = true;
+    }
+
+    fn flush_line(&mut self) {
+        if let Some(line) = self.line.take() {
+            self.lines.push(line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::style::Stylize;
+
+    #[test]
+    fn empty() {
+        assert_eq!(Text::from_markdown(""), Text::default());
+    }
+
+    #[test]
+    fn inline_styles() {
+        let text = Text::from_markdown("a *b* **c** ~~d~~ `e`");
+        assert_eq!(
+            text,
+            Text::from(Line::from(vec![
+                "a ".into(),
+                "b".italic(),
+                " ".into(),
+                "c".bold(),
+                " ".into(),
+                "d".crossed_out(),
+                " ".into(),
+                "e".yellow(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn nested_inline_styles() {
+        let text = Text::from_markdown("***a*** **b `c`**");
+        assert_eq!(
+            text,
+            Text::from(Line::from(vec![
+                "a".bold().italic(),
+                " ".into(),
+                "b ".bold(),
+                "c".bold().yellow(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn headings() {
+        let text = Text::from_markdown("# One\n## Two");
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from(vec!["# ".bold().underlined(), "One".bold().underlined()]),
+                Line::default(),
+                Line::from(vec!["## ".bold(), "Two".bold()]),
+            ])
+        );
+    }
+
+    #[test]
+    fn paragraphs_and_breaks() {
+        let text = Text::from_markdown("a\nb  \nc\n\nd");
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from(vec!["a".into(), " ".into(), "b".into()]),
+                Line::from("c"),
+                Line::default(),
+                Line::from("d"),
+            ])
+        );
+    }
+
+    #[test]
+    fn lists() {
+        let text = Text::from_markdown(indoc! {"
+            - a
+              - b
+            - c
+
+            3. d
+            4. e
+        "});
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from(vec!["- ".into(), "a".into()]),
+                Line::from(vec!["  ".into(), "- ".into(), "b".into()]),
+                Line::from(vec!["- ".into(), "c".into()]),
+                Line::default(),
+                Line::from(vec!["3. ".into(), "d".into()]),
+                Line::from(vec!["4. ".into(), "e".into()]),
+            ])
+        );
+    }
+
+    #[test]
+    fn task_list() {
+        let text = Text::from_markdown("- [x] done\n- [ ] todo");
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from(vec!["- ".into(), "[x] ".into(), "done".into()]),
+                Line::from(vec!["- ".into(), "[ ] ".into(), "todo".into()]),
+            ])
+        );
+    }
+
+    #[test]
+    fn block_quote() {
+        let text = Text::from_markdown("> a\n>\n> b\n\nc");
+        let marker = Span::styled("> ", QUOTE_MARKER_STYLE);
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from(vec![marker.clone(), "a".into()]),
+                Line::from(Span::styled(">", QUOTE_MARKER_STYLE)),
+                Line::from(vec![marker, "b".into()]),
+                Line::default(),
+                Line::from("c"),
+            ])
+        );
+    }
+
+    #[test]
+    fn code_block() {
+        let text = Text::from_markdown("```rust\nfn main() {}\nlet a = 1;\n```\ntext");
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from("fn main() {}".yellow()),
+                Line::from("let a = 1;".yellow()),
+                Line::default(),
+                Line::from("text"),
+            ])
+        );
+    }
+
+    #[test]
+    fn link() {
+        let text = Text::from_markdown("[ratatui](https://ratatui.rs)");
+        assert_eq!(
+            text,
+            Text::from(Line::from(
+                "ratatui"
+                    .blue()
+                    .underlined()
+                    .hyperlink("https://ratatui.rs")
+            ))
+        );
+    }
+
+    #[test]
+    fn rule() {
+        let text = Text::from_markdown("a\n\n---\n\nb");
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from("a"),
+                Line::default(),
+                Line::from("---".dark_gray()),
+                Line::default(),
+                Line::from("b"),
+            ])
+        );
+    }
+}
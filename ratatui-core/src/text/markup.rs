@@ -0,0 +1,217 @@
+//! Conversion of strings with a lightweight inline markup into styled [`Line`]s.
+use std::str::FromStr;
+
+use crate::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+impl<'a> Line<'a> {
+    /// Creates a [`Line`] from a string with inline style tags.
+    ///
+    /// This is a lightweight alternative to chains of [`Span::styled`] for status bars and
+    /// messages. The markup is made of the following tags:
+    ///
+    /// - `<style words>` starts a styled section, where the words are separated by spaces
+    /// - `</>` ends the last styled section that was started
+    /// - `<<` is a literal `<`
+    ///
+    /// The words of a tag are:
+    ///
+    /// - a color, which sets the foreground color (e.g. `red`, `light-blue`, `#ff8000` or `42`,
+    ///   see [`Color::from_str`] for all the supported formats)
+    /// - `on_` followed by a color, which sets the background color (e.g. `on_dark-gray`)
+    /// - a modifier: `bold`, `dim`, `italic`, `underlined`, `slow_blink`, `rapid_blink`,
+    ///   `reversed`, `hidden` or `crossed_out`
+    ///
+    /// Sections can be nested, in which case the style of the inner section is patched onto the
+    /// style of the outer one. Sections that are not ended extend to the end of the line, and
+    /// `</>` tags that don't end any section are ignored. A `<` that doesn't start a valid tag
+    /// (e.g. in `a < b`) is displayed as is.
+    ///
+    /// The content of the spans borrows from the input, so no allocation is made for the text
+    /// itself. See the [`markup!`](crate::markup!) macro to create a line from a format string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{style::Stylize, text::Line};
+    ///
+    /// let line = Line::from_markup("<red bold>error:</> file <italic>foo.rs</> not found");
+    /// assert_eq!(
+    ///     line,
+    ///     Line::from(vec![
+    ///         "error:".red().bold(),
+    ///         " file ".into(),
+    ///         "foo.rs".italic(),
+    ///         " not found".into(),
+    ///     ])
+    /// );
+    /// ```
+    pub fn from_markup(input: &'a str) -> Self {
+        let mut spans = Vec::new();
+        let mut styles: Vec<Style> = Vec::new();
+        // start of the text that is not part of a span yet
+        let mut start = 0;
+        // position from where to look for the next tag
+        let mut position = 0;
+        // `<` and `>` are ASCII, so the offsets next to them are always on character boundaries
+        while let Some(offset) = input.get(position..).and_then(|rest| rest.find('<')) {
+            let tag_start = position + offset;
+            let style = styles.last().copied().unwrap_or_default();
+            let text = input.get(start..tag_start).unwrap_or_default();
+            let rest = input.get(tag_start + 1..).unwrap_or_default();
+            if rest.starts_with('<') {
+                let text = input.get(start..=tag_start).unwrap_or_default();
+                push_span(&mut spans, text, style);
+                start = tag_start + 2;
+                position = start;
+                continue;
+            }
+            let Some((tag, _)) = rest.split_once('>') else {
+                break;
+            };
+            if tag == "/" {
+                push_span(&mut spans, text, style);
+                styles.pop();
+            } else if let Some(patch) = parse_tag(tag) {
+                push_span(&mut spans, text, style);
+                styles.push(style.patch(patch));
+            } else {
+                position = tag_start + 1;
+                continue;
+            }
+            start = tag_start + tag.len() + 2;
+            position = start;
+        }
+        let style = styles.last().copied().unwrap_or_default();
+        push_span(&mut spans, input.get(start..).unwrap_or_default(), style);
+        Self::from(spans)
+    }
+}
+
+impl Line<'static> {
+    /// Implementation detail of the [`markup!`](crate::markup!) macro.
+    #[doc(hidden)]
+    pub fn __from_markup_string(input: &str) -> Self {
+        let line = Line::from_markup(input);
+        Self::from(
+            line.spans
+                .into_iter()
+                .map(|span| Span::styled(span.content.into_owned(), span.style))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+/// Creates a styled [`Line`] from a format string with inline style tags.
+///
+/// The arguments are formatted like with [`format!`], and the result is parsed with
+/// [`Line::from_markup`], which describes the supported tags. Note that the formatted arguments
+/// are parsed as markup too, so a `<` in an argument should be escaped as `<<` if it may be
+/// followed by a valid tag.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::{markup, style::Stylize, text::Line};
+///
+/// let file = "foo.rs";
+/// let line = markup!("<red bold>error:</> {file} not found");
+/// assert_eq!(
+///     line,
+///     Line::from(vec!["error:".red().bold(), " foo.rs not found".into()])
+/// );
+/// ```
+#[macro_export]
+macro_rules! markup {
+    ($($arg:tt)*) => {
+        $crate::text::Line::__from_markup_string(&::std::format!($($arg)*))
+    };
+}
+
+fn push_span<'a>(spans: &mut Vec<Span<'a>>, content: &'a str, style: Style) {
+    if !content.is_empty() {
+        spans.push(Span::styled(content, style));
+    }
+}
+
+/// Parses the words of a tag into a style, or returns `None` if the tag is not a valid style tag.
+fn parse_tag(tag: &str) -> Option<Style> {
+    let mut words = tag.split_whitespace().peekable();
+    words.peek()?;
+    words.try_fold(Style::new(), |style, word| {
+        if let Some(color) = word.strip_prefix("on_") {
+            return Color::from_str(color).ok().map(|color| style.bg(color));
+        }
+        let modifier = match word {
+            "bold" => Modifier::BOLD,
+            "dim" => Modifier::DIM,
+            "italic" => Modifier::ITALIC,
+            "underlined" => Modifier::UNDERLINED,
+            "slow_blink" => Modifier::SLOW_BLINK,
+            "rapid_blink" => Modifier::RAPID_BLINK,
+            "reversed" => Modifier::REVERSED,
+            "hidden" => Modifier::HIDDEN,
+            "crossed_out" => Modifier::CROSSED_OUT,
+            _ => return Color::from_str(word).ok().map(|color| style.fg(color)),
+        };
+        Some(style.add_modifier(modifier))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::Stylize;
+
+    #[rstest]
+    #[case::plain("hello", Line::from("hello"))]
+    #[case::empty("", Line::default())]
+    #[case::styled("<red>a</>b", Line::from(vec!["a".red(), "b".into()]))]
+    #[case::background("<on_dark-gray>a</>", Line::from("a".on_dark_gray()))]
+    #[case::hex_color("<#ff8000>a", Line::from("a".fg(Color::Rgb(255, 128, 0))))]
+    #[case::unclosed("a<bold>b", Line::from(vec!["a".into(), "b".bold()]))]
+    #[case::unmatched_close("a</>b", Line::from(vec!["a".into(), "b".into()]))]
+    #[case::escaped("a <<red> b", Line::from(vec!["a <".into(), "red> b".into()]))]
+    #[case::not_a_tag("1 < 2 and 3 > 1", Line::from("1 < 2 and 3 > 1"))]
+    #[case::unknown_word("<red bolder>a</>", Line::from(vec!["<red bolder>a".into()]))]
+    #[case::empty_tag("<>", Line::from("<>"))]
+    #[case::no_closing_bracket("a <red", Line::from("a <red"))]
+    fn from_markup(#[case] input: &str, #[case] expected: Line) {
+        assert_eq!(Line::from_markup(input), expected);
+    }
+
+    #[test]
+    fn nested() {
+        assert_eq!(
+            Line::from_markup("<blue>a<bold on_white>b</>c</>d"),
+            Line::from(vec![
+                "a".blue(),
+                "b".blue().bold().on_white(),
+                "c".blue(),
+                "d".into(),
+            ])
+        );
+    }
+
+    #[test]
+    fn modifiers() {
+        assert_eq!(
+            Line::from_markup("<dim italic underlined reversed crossed_out>a"),
+            Line::from("a".dim().italic().underlined().reversed().crossed_out())
+        );
+    }
+
+    #[test]
+    fn macro_formats_arguments() {
+        let count = 3;
+        assert_eq!(
+            markup!("<green>{count}</> files"),
+            Line::from(vec!["3".green(), " files".into()])
+        );
+    }
+}
@@ -0,0 +1,256 @@
+//! Styling of the parts of a [`Line`] or [`Text`] that match regular expressions.
+use std::ops::Range;
+
+use regex::Regex;
+
+use crate::{
+    style::Style,
+    text::{Line, Span, Text},
+};
+
+impl Line<'_> {
+    /// Returns a copy of this line where the parts that match the given regular expressions are
+    /// patched with the associated styles.
+    ///
+    /// The regular expressions are matched against the content of the whole line, so a match can
+    /// span several spans. The spans are split at the boundaries of the matches, and each part of
+    /// a match keeps the style and hyperlink of its span, patched with the style of the match.
+    /// When matches overlap, the styles are applied in the order of the patterns. This is useful
+    /// for search highlighting or to colorize the log levels of log lines.
+    ///
+    /// The style, alignment and hyperlinks of this line are preserved.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This method is only available when the `regex` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{
+    ///     style::{Color, Stylize},
+    ///     text::Line,
+    /// };
+    /// use regex::Regex;
+    ///
+    /// let error = Regex::new(r"\bERROR\b").unwrap();
+    /// let number = Regex::new(r"\d+").unwrap();
+    /// let line = Line::from(vec!["ERROR ".into(), "disk 1 is full".italic()]);
+    /// assert_eq!(
+    ///     line.highlight_matches([(&error, Color::Red), (&number, Color::Yellow)]),
+    ///     Line::from(vec![
+    ///         "ERROR".red(),
+    ///         " ".into(),
+    ///         "disk ".italic(),
+    ///         "1".italic().yellow(),
+    ///         " is full".italic(),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    /// [`Color`]: crate::style::Color
+    pub fn highlight_matches<'r, I, S>(&self, patterns: I) -> Line<'static>
+    where
+        I: IntoIterator<Item = (&'r Regex, S)>,
+        S: Into<Style>,
+    {
+        let patterns = patterns
+            .into_iter()
+            .map(|(regex, style)| (regex, style.into()))
+            .collect::<Vec<_>>();
+        highlight_line(self, &patterns)
+    }
+}
+
+impl Text<'_> {
+    /// Returns a copy of this text where the parts that match the given regular expressions are
+    /// patched with the associated styles.
+    ///
+    /// The regular expressions are matched against each line separately, see
+    /// [`Line::highlight_matches`] for more details. The style and alignment of this text are
+    /// preserved.
+    ///
+    /// This method is only available when the `regex` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{
+    ///     style::{Style, Stylize},
+    ///     text::{Line, Text},
+    /// };
+    /// use regex::Regex;
+    ///
+    /// let search = Regex::new("(?i)rat").unwrap();
+    /// let text = Text::from("Ratatui\nmeow");
+    /// assert_eq!(
+    ///     text.highlight_matches([(&search, Style::new().reversed())]),
+    ///     Text::from(vec![
+    ///         Line::from(vec!["Rat".reversed(), "atui".into()]),
+    ///         Line::from("meow"),
+    ///     ])
+    /// );
+    /// ```
+    pub fn highlight_matches<'r, I, S>(&self, patterns: I) -> Text<'static>
+    where
+        I: IntoIterator<Item = (&'r Regex, S)>,
+        S: Into<Style>,
+    {
+        let patterns = patterns
+            .into_iter()
+            .map(|(regex, style)| (regex, style.into()))
+            .collect::<Vec<_>>();
+        Text {
+            lines: self
+                .lines
+                .iter()
+                .map(|line| highlight_line(line, &patterns))
+                .collect(),
+            style: self.style,
+            alignment: self.alignment,
+        }
+    }
+}
+
+fn highlight_line(line: &Line<'_>, patterns: &[(&Regex, Style)]) -> Line<'static> {
+    let content = line
+        .spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect::<String>();
+    let matches = patterns
+        .iter()
+        .flat_map(|(regex, style)| {
+            regex
+                .find_iter(&content)
+                .filter(|found| !found.is_empty())
+                .map(|found| (found.range(), *style))
+        })
+        .collect::<Vec<(Range<usize>, Style)>>();
+
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut span_start = 0;
+    for span in &line.spans {
+        let span_end = span_start + span.content.len();
+        // split the span at the boundaries of the matches that start or end inside of it
+        let mut boundaries = vec![span_start, span_end];
+        for (range, _) in &matches {
+            for boundary in [range.start, range.end] {
+                if span_start < boundary && boundary < span_end {
+                    boundaries.push(boundary);
+                }
+            }
+        }
+        boundaries.sort_unstable();
+        boundaries.dedup();
+        let mut last: Option<Style> = None;
+        for segment in boundaries.windows(2) {
+            let (start, end) = (segment[0], segment[1]);
+            let style = matches
+                .iter()
+                .filter(|(range, _)| range.start <= start && end <= range.end)
+                .fold(span.style, |style, (_, patch)| style.patch(*patch));
+            // the boundaries are the ends of spans and matches, which are all on char boundaries
+            let text = content.get(start..end).unwrap_or_default();
+            if last == Some(style) {
+                if let Some(last_span) = spans.last_mut() {
+                    last_span.content.to_mut().push_str(text);
+                }
+                continue;
+            }
+            last = Some(style);
+            spans.push(Span {
+                content: text.to_string().into(),
+                style,
+                hyperlink: span.hyperlink.clone().map(|url| url.into_owned().into()),
+            });
+        }
+        span_start = span_end;
+    }
+    Line {
+        style: line.style,
+        alignment: line.alignment,
+        spans,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::style::{Color, Stylize};
+
+    #[test]
+    fn no_match() {
+        let regex = Regex::new("x").unwrap();
+        let line = Line::from(vec!["ab".red(), "cd".into()]).centered();
+        assert_eq!(line.highlight_matches([(&regex, Color::Blue)]), line);
+    }
+
+    #[test]
+    fn match_across_spans() {
+        let regex = Regex::new("bc").unwrap();
+        let line = Line::from(vec!["ab".red(), "cd".into()]);
+        assert_eq!(
+            line.highlight_matches([(&regex, Style::new().bold())]),
+            Line::from(vec!["a".red(), "b".red().bold(), "c".bold(), "d".into()])
+        );
+    }
+
+    #[test]
+    fn overlapping_matches() {
+        let word = Regex::new("hello").unwrap();
+        let letter = Regex::new("l+").unwrap();
+        let line = Line::from("hello world");
+        assert_eq!(
+            line.highlight_matches([
+                (&word, Style::new().red()),
+                (&letter, Style::new().blue().bold()),
+            ]),
+            Line::from(vec![
+                "he".red(),
+                "ll".blue().bold(),
+                "o".red(),
+                " wor".into(),
+                "l".blue().bold(),
+                "d".into(),
+            ])
+        );
+    }
+
+    #[test]
+    fn empty_matches_are_ignored() {
+        let regex = Regex::new("x*").unwrap();
+        let line = Line::from("abc");
+        assert_eq!(line.highlight_matches([(&regex, Color::Red)]), line);
+    }
+
+    #[test]
+    fn keeps_hyperlinks() {
+        let regex = Regex::new("rat").unwrap();
+        let line = Line::from(Span::raw("ratatui").hyperlink("https://ratatui.rs"));
+        assert_eq!(
+            line.highlight_matches([(&regex, Color::Red)]),
+            Line::from(vec![
+                Span::raw("rat").red().hyperlink("https://ratatui.rs"),
+                Span::raw("atui").hyperlink("https://ratatui.rs"),
+            ])
+        );
+    }
+
+    #[test]
+    fn text() {
+        let regex = Regex::new("b").unwrap();
+        let text = Text::from("ab\nba").yellow();
+        assert_eq!(
+            text.highlight_matches([(&regex, Color::Red)]),
+            Text::from(vec![
+                Line::from(vec!["a".into(), "b".red()]),
+                Line::from(vec!["b".red(), "a".into()]),
+            ])
+            .yellow()
+        );
+    }
+}
@@ -1,13 +1,12 @@
 use std::{borrow::Cow, fmt};
 
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
 
 use crate::{
     buffer::Buffer,
     layout::Rect,
     style::{Style, Styled},
-    text::{Line, StyledGrapheme},
+    text::{DisplayWidth, Line, StyledGrapheme},
     widgets::Widget,
 };
 
@@ -28,6 +27,7 @@ use crate::{
 ///
 /// - [`Span::content`] sets the content of the span.
 /// - [`Span::style`] sets the style of the span.
+/// - [`Span::hyperlink`] sets the URL of the hyperlink of the span.
 ///
 /// # Other Methods
 ///
@@ -102,6 +102,8 @@ pub struct Span<'a> {
     pub style: Style,
     /// The content of the span as a Clone-on-write string.
     pub content: Cow<'a, str>,
+    /// The URL of the hyperlink of the span, if any.
+    pub hyperlink: Option<Cow<'a, str>>,
 }
 
 impl fmt::Debug for Span<'_> {
@@ -114,6 +116,9 @@ impl fmt::Debug for Span<'_> {
         if self.style != Style::default() {
             self.style.fmt_stylize(f)?;
         }
+        if let Some(hyperlink) = &self.hyperlink {
+            write!(f, ".hyperlink({hyperlink:?})")?;
+        }
         Ok(())
     }
 }
@@ -136,6 +141,7 @@ impl<'a> Span<'a> {
         Self {
             content: content.into(),
             style: Style::default(),
+            hyperlink: None,
         }
     }
 
@@ -169,6 +175,7 @@ impl<'a> Span<'a> {
         Self {
             content: content.into(),
             style: style.into(),
+            hyperlink: None,
         }
     }
 
@@ -223,6 +230,42 @@ impl<'a> Span<'a> {
         self
     }
 
+    /// Sets the URL of the hyperlink of the span.
+    ///
+    /// When the span is rendered (directly, or as part of a [`Line`]), the cells it covers are
+    /// marked as part of the hyperlink. Backends that support it emit this as an [OSC 8] escape
+    /// sequence, which makes the text clickable in terminals such as iTerm2, `WezTerm`, kitty or
+    /// Windows Terminal. Terminals that do not support hyperlinks display the text as usual.
+    ///
+    /// The hyperlink is a property of the span rather than of its [`Style`]: a style is `Copy` and
+    /// can be built in `const` contexts, which an owned URL would prevent. So the hyperlink is not
+    /// applied by [`Line::styled`], [`Text::styled`] or [`Buffer::set_style`], and it is kept when
+    /// the style of the span is [patched](Span::patch_style) or [reset](Span::reset_style).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::{style::Stylize, text::Span};
+    ///
+    /// let span = Span::raw("ratatui.rs")
+    ///     .hyperlink("https://ratatui.rs")
+    ///     .blue()
+    ///     .underlined();
+    /// ```
+    ///
+    /// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+    /// [`Text::styled`]: crate::text::Text::styled
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hyperlink<T>(mut self, url: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.hyperlink = Some(url.into());
+        self
+    }
+
     /// Patches the style of the Span, adding modifiers from the given style.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -276,9 +319,35 @@ impl<'a> Span<'a> {
         self.patch_style(Style::reset())
     }
 
+    /// Converts the content of the span to full-width characters.
+    ///
+    /// Printable ASCII characters are replaced with their full-width forms (U+FF01 to U+FF5E) and
+    /// spaces with the ideographic space (U+3000), so each character takes up two cells. This
+    /// emulates double-width text for headers or accessibility-large text in terminals that do not
+    /// support the DEC double-width line attributes. Other characters are left unchanged.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui_core::text::Span;
+    ///
+    /// let span = Span::raw("Hi!").full_width();
+    /// assert_eq!(span.content, "Ｈｉ！");
+    /// assert_eq!(span.width(), 6);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn full_width(mut self) -> Self {
+        if self.content.chars().any(|c| to_full_width(c) != c) {
+            self.content = Cow::Owned(self.content.chars().map(to_full_width).collect());
+        }
+        self
+    }
+
     /// Returns the unicode width of the content held by this span.
     pub fn width(&self) -> usize {
-        self.content.width()
+        self.content.display_width()
     }
 
     /// Returns an iterator over the graphemes held by this span.
@@ -319,11 +388,12 @@ impl<'a> Span<'a> {
         base_style: S,
     ) -> impl Iterator<Item = StyledGrapheme<'a>> {
         let style = base_style.into().patch(self.style);
+        let hyperlink = self.hyperlink.as_deref();
         self.content
             .as_ref()
             .graphemes(true)
             .filter(|g| *g != "\n")
-            .map(move |g| StyledGrapheme { symbol: g, style })
+            .map(move |g| StyledGrapheme::new(g, style).hyperlink(hyperlink))
     }
 
     /// Converts this Span into a left-aligned [`Line`]
@@ -430,7 +500,7 @@ impl Widget for &Span<'_> {
         }
         let Rect { mut x, y, .. } = area;
         for (i, grapheme) in self.styled_graphemes(Style::default()).enumerate() {
-            let symbol_width = grapheme.symbol.width();
+            let symbol_width = grapheme.symbol.display_width();
             let next_x = x.saturating_add(symbol_width as u16);
             if next_x > area.right() {
                 break;
@@ -459,6 +529,10 @@ impl Widget for &Span<'_> {
                     .set_style(grapheme.style);
             }
 
+            if symbol_width > 0 && self.hyperlink.is_some() {
+                buf[(x, y)].set_hyperlink(self.hyperlink.as_deref());
+            }
+
             // multi-width graphemes must clear the cells of characters that are hidden by the
             // grapheme, otherwise the hidden characters will be re-rendered if the grapheme is
             // overwritten.
@@ -472,6 +546,18 @@ impl Widget for &Span<'_> {
     }
 }
 
+/// Returns the full-width form of a printable ASCII character, or the character itself.
+const fn to_full_width(c: char) -> char {
+    match c {
+        ' ' => '\u{3000}',
+        '!'..='~' => match char::from_u32(c as u32 - 0x21 + 0xFF01) {
+            Some(full_width) => full_width,
+            None => c,
+        },
+        _ => c,
+    }
+}
+
 /// A trait for converting a value to a [`Span`].
 ///
 /// This trait is automatically implemented for any type that implements the [`Display`] trait. As
@@ -680,6 +766,15 @@ mod tests {
         assert_eq!(line.alignment, Some(Alignment::Right));
     }
 
+    #[rstest]
+    #[case::ascii("Hello, World!", "Ｈｅｌｌｏ，\u{3000}Ｗｏｒｌｄ！")]
+    #[case::range_ends("!~", "！～")]
+    #[case::unchanged("コ\t", "コ\t")]
+    fn full_width(#[case] content: &str, #[case] expected: &str) {
+        let span = Span::styled(content, Style::new().red()).full_width();
+        assert_eq!(span, Span::styled(expected, Style::new().red()));
+    }
+
     mod widget {
         use rstest::rstest;
 
@@ -698,6 +793,24 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_full_width() {
+            let span = Span::raw("a b").full_width();
+            let mut buf = Buffer::empty(Rect::new(0, 0, 7, 1));
+            span.render(buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["ａ\u{3000}ｂ "]));
+        }
+
+        #[test]
+        fn render_hyperlink() {
+            let span = Span::raw("link").hyperlink("https://ratatui.rs");
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+            span.render(buf.area, &mut buf);
+            let links: Vec<_> = buf.content.iter().map(Cell::hyperlink).collect();
+            let url = Some("https://ratatui.rs");
+            assert_eq!(links, [url, url, url, url, None, None]);
+        }
+
         #[rstest]
         #[case::x(20, 0)]
         #[case::y(0, 20)]
@@ -0,0 +1,178 @@
+//! Measurement of the display width of text.
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Whether East Asian ambiguous width characters are wide, see [`AmbiguousWidth::set_global`]
+static AMBIGUOUS_WIDE: AtomicBool = AtomicBool::new(false);
+
+/// The width of East Asian ambiguous width characters
+///
+/// Some characters, such as Greek and Cyrillic letters, box drawing characters, or symbols like
+/// `±` and `§`, have an ambiguous width in the [Unicode East Asian Width] standard: they are
+/// displayed one cell wide by most terminals, but two cells wide by many terminals configured for
+/// Chinese, Japanese or Korean (CJK) locales. When the width that Ratatui computes doesn't match
+/// the width used by the terminal, lines are misaligned and the borders of blocks are shifted.
+///
+/// By default, ambiguous width characters are narrow. Call [`AmbiguousWidth::set_global`] with
+/// [`AmbiguousWidth::Wide`] at the start of the application to treat them as wide, e.g. based on
+/// a command line flag or the locale of the user. The setting applies to all the width
+/// computations of Ratatui ([`DisplayWidth`]), including setting strings and lines in a buffer,
+/// computing the width of spans and lines, wrapping and truncation.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::text::{AmbiguousWidth, DisplayWidth};
+///
+/// assert_eq!("±1".display_width(), 2);
+///
+/// AmbiguousWidth::Wide.set_global();
+/// assert_eq!("±1".display_width(), 3);
+/// # AmbiguousWidth::Narrow.set_global();
+/// ```
+///
+/// [Unicode East Asian Width]: https://www.unicode.org/reports/tr11/
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum AmbiguousWidth {
+    /// Ambiguous width characters are one cell wide
+    #[default]
+    Narrow,
+    /// Ambiguous width characters are two cells wide, as in CJK terminals
+    Wide,
+}
+
+impl AmbiguousWidth {
+    /// Returns the width of ambiguous width characters used by all the width computations
+    pub fn global() -> Self {
+        if AMBIGUOUS_WIDE.load(Ordering::Relaxed) {
+            Self::Wide
+        } else {
+            Self::Narrow
+        }
+    }
+
+    /// Sets the width of ambiguous width characters used by all the width computations
+    ///
+    /// This should be called before anything is rendered, as changing the width while a buffer
+    /// holds text that was laid out with the previous width produces inconsistent diffs. Call
+    /// [`Terminal::clear`] after changing it at runtime.
+    ///
+    /// [`Terminal::clear`]: crate::terminal::Terminal::clear
+    pub fn set_global(self) {
+        AMBIGUOUS_WIDE.store(self == Self::Wide, Ordering::Relaxed);
+    }
+
+    /// Returns the number of cells needed to display the string with this ambiguous width
+    pub fn str_width(self, content: &str) -> usize {
+        match self {
+            Self::Narrow => content.width(),
+            Self::Wide => content.width_cjk(),
+        }
+    }
+
+    /// Returns the number of cells needed to display the character with this ambiguous width
+    ///
+    /// Control characters have no width.
+    pub fn char_width(self, c: char) -> usize {
+        let width = match self {
+            Self::Narrow => c.width(),
+            Self::Wide => c.width_cjk(),
+        };
+        width.unwrap_or_default()
+    }
+}
+
+/// Computes the number of cells needed to display a string or a character
+///
+/// This is the width as computed by the [`unicode-width`] crate, where East Asian ambiguous width
+/// characters are narrow or wide according to [`AmbiguousWidth::global`].
+///
+/// [`unicode-width`]: https://docs.rs/unicode-width
+pub trait DisplayWidth {
+    /// Returns the number of cells needed to display the value
+    fn display_width(&self) -> usize;
+}
+
+impl DisplayWidth for str {
+    fn display_width(&self) -> usize {
+        AmbiguousWidth::global().str_width(self)
+    }
+}
+
+impl DisplayWidth for char {
+    fn display_width(&self) -> usize {
+        AmbiguousWidth::global().char_width(*self)
+    }
+}
+
+/// Truncates the start of a string so that it fits in the given width.
+///
+/// Returns the longest suffix of whole graphemes that fits in `max_width`, along with its width.
+pub(crate) fn truncate_start(content: &str, max_width: usize) -> (&str, usize) {
+    let (index, width) = content
+        .grapheme_indices(true)
+        .rev()
+        .scan(0, |width, (index, grapheme)| {
+            *width += grapheme.display_width();
+            Some((index, *width))
+        })
+        .take_while(|&(_, width)| width <= max_width)
+        .last()
+        .unwrap_or((content.len(), 0));
+    (content.get(index..).unwrap_or_default(), width)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::ascii("abc", 3)]
+    #[case::wide("コンピュ", 8)]
+    #[case::ambiguous("±§", 2)]
+    #[case::zero_width("a\u{200B}", 1)]
+    fn display_width(#[case] content: &str, #[case] expected: usize) {
+        assert_eq!(content.display_width(), expected);
+    }
+
+    #[rstest]
+    #[case::ascii("abc", 3)]
+    #[case::wide("コンピュ", 8)]
+    #[case::ambiguous("±§", 4)]
+    #[case::box_drawing("┌─┐", 6)]
+    fn wide_ambiguous_width(#[case] content: &str, #[case] expected: usize) {
+        assert_eq!(AmbiguousWidth::Wide.str_width(content), expected);
+    }
+
+    #[test]
+    fn char_width() {
+        assert_eq!(AmbiguousWidth::Narrow.char_width('±'), 1);
+        assert_eq!(AmbiguousWidth::Wide.char_width('±'), 2);
+        assert_eq!(AmbiguousWidth::Wide.char_width('\u{7}'), 0);
+    }
+
+    #[test]
+    fn char_display_width() {
+        assert_eq!('a'.display_width(), 1);
+        assert_eq!('コ'.display_width(), 2);
+        assert_eq!('\u{7}'.display_width(), 0);
+    }
+
+    #[rstest]
+    #[case::fits("abc", 5, ("abc", 3))]
+    #[case::ascii("abcdef", 3, ("def", 3))]
+    #[case::wide("コンピュ", 5, ("ピュ", 4))]
+    #[case::zero("abc", 0, ("", 0))]
+    fn truncate_start(
+        #[case] content: &str,
+        #[case] max_width: usize,
+        #[case] expected: (&str, usize),
+    ) {
+        assert_eq!(super::truncate_start(content, max_width), expected);
+    }
+}
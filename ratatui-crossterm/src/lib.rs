@@ -29,7 +29,10 @@ use crossterm::{
     terminal::{self, Clear},
 };
 use ratatui_core::{
-    backend::{Backend, ClearType, WindowSize},
+    backend::{
+        osc::{Osc8Hyperlink, OscNotification},
+        Backend, ClearType, WindowSize,
+    },
     buffer::Cell,
     layout::{Position, Size},
     style::{Color, Modifier, Style},
@@ -169,6 +172,7 @@ where
         #[cfg(feature = "underline-color")]
         let mut underline_color = Color::Reset;
         let mut modifier = Modifier::empty();
+        let mut hyperlink: Option<&str> = None;
         let mut last_pos: Option<Position> = None;
         for (x, y, cell) in content {
             // Move the cursor if the previous location was not (x - 1, y)
@@ -201,9 +205,16 @@ where
                 queue!(self.writer, SetUnderlineColor(color))?;
                 underline_color = cell.underline_color;
             }
+            if cell.hyperlink() != hyperlink {
+                queue!(self.writer, Print(Osc8Hyperlink::new(cell.hyperlink())))?;
+                hyperlink = cell.hyperlink();
+            }
 
             queue!(self.writer, Print(cell.symbol()))?;
         }
+        if hyperlink.is_some() {
+            queue!(self.writer, Print(Osc8Hyperlink::new(None)))?;
+        }
 
         #[cfg(feature = "underline-color")]
         return queue!(
@@ -265,6 +276,14 @@ where
         self.writer.flush()
     }
 
+    fn bell(&mut self) -> io::Result<()> {
+        execute!(self.writer, Print('\x07'))
+    }
+
+    fn notify(&mut self, title: &str, body: &str) -> io::Result<()> {
+        execute!(self.writer, Print(OscNotification::new(title, body)))
+    }
+
     fn size(&self) -> io::Result<Size> {
         let (width, height) = terminal::size()?;
         Ok(Size { width, height })
@@ -20,7 +20,10 @@ use std::{
 };
 
 use ratatui_core::{
-    backend::{Backend, ClearType, WindowSize},
+    backend::{
+        osc::{Osc8Hyperlink, OscNotification},
+        Backend, ClearType, WindowSize,
+    },
     buffer::Cell,
     layout::{Position, Size},
     style::{Color, Modifier, Style},
@@ -166,6 +169,16 @@ where
         self.writer.flush()
     }
 
+    fn bell(&mut self) -> io::Result<()> {
+        write!(self.writer, "\x07")?;
+        self.writer.flush()
+    }
+
+    fn notify(&mut self, title: &str, body: &str) -> io::Result<()> {
+        write!(self.writer, "{}", OscNotification::new(title, body))?;
+        self.writer.flush()
+    }
+
     fn hide_cursor(&mut self) -> io::Result<()> {
         write!(self.writer, "{}", termion::cursor::Hide)?;
         self.writer.flush()
@@ -197,6 +210,7 @@ where
         let mut fg = Color::Reset;
         let mut bg = Color::Reset;
         let mut modifier = Modifier::empty();
+        let mut hyperlink: Option<&str> = None;
         let mut last_pos: Option<Position> = None;
         for (x, y, cell) in content {
             // Move the cursor if the previous location was not (x - 1, y)
@@ -224,8 +238,15 @@ where
                 write!(string, "{}", Bg(cell.bg)).unwrap();
                 bg = cell.bg;
             }
+            if cell.hyperlink() != hyperlink {
+                write!(string, "{}", Osc8Hyperlink::new(cell.hyperlink())).unwrap();
+                hyperlink = cell.hyperlink();
+            }
             string.push_str(cell.symbol());
         }
+        if hyperlink.is_some() {
+            write!(string, "{}", Osc8Hyperlink::new(None)).unwrap();
+        }
         write!(
             self.writer,
             "{string}{}{}{}",
@@ -14,10 +14,10 @@
 #![cfg_attr(feature = "document-features", doc = "\n## Features")]
 #![cfg_attr(feature = "document-features", doc = document_features::document_features!())]
 
-use std::{error::Error, io};
+use std::{error::Error, io, sync::Arc};
 
 use ratatui_core::{
-    backend::{Backend, WindowSize},
+    backend::{osc::OscNotification, Backend, WindowSize},
     buffer::Cell,
     layout::{Position, Size},
     style::{Color, Modifier, Style},
@@ -27,6 +27,7 @@ use termwiz::{
     caps::Capabilities,
     cell::{AttributeChange, Blink, CellAttributes, Intensity, Underline},
     color::{AnsiColor, ColorAttribute, ColorSpec, LinearRgba, RgbColor, SrgbaTuple},
+    hyperlink::Hyperlink,
     surface::{Change, CursorVisibility, Position as TermwizPosition},
     terminal::{buffered::BufferedTerminal, ScreenSize, SystemTerminal, Terminal},
 };
@@ -117,6 +118,18 @@ impl TermwizBackend {
     pub fn buffered_terminal_mut(&mut self) -> &mut BufferedTerminal<SystemTerminal> {
         &mut self.buffered_terminal
     }
+
+    /// Writes a control sequence directly to the terminal.
+    ///
+    /// The sequence bypasses the surface of the buffered terminal, where it would be drawn as text.
+    /// It must not move the cursor, so that the surface stays in sync with the terminal.
+    fn write_sequence(&mut self, sequence: String) -> io::Result<()> {
+        let terminal = self.buffered_terminal.terminal();
+        terminal
+            .render(&[Change::Text(sequence)])
+            .map_err(io::Error::other)?;
+        terminal.flush().map_err(io::Error::other)
+    }
 }
 
 impl Backend for TermwizBackend {
@@ -185,6 +198,11 @@ impl Backend for TermwizBackend {
                     },
                 )));
 
+            self.buffered_terminal
+                .add_change(Change::Attribute(AttributeChange::Hyperlink(
+                    cell.hyperlink().map(|url| Arc::new(Hyperlink::new(url))),
+                )));
+
             self.buffered_terminal.add_change(cell.symbol());
         }
         Ok(())
@@ -251,6 +269,14 @@ impl Backend for TermwizBackend {
         })
     }
 
+    fn bell(&mut self) -> io::Result<()> {
+        self.write_sequence("\x07".to_string())
+    }
+
+    fn notify(&mut self, title: &str, body: &str) -> io::Result<()> {
+        self.write_sequence(OscNotification::new(title, body).to_string())
+    }
+
     fn flush(&mut self) -> io::Result<()> {
         self.buffered_terminal
             .flush()
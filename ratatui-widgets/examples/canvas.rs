@@ -73,13 +73,7 @@ pub fn render_canvas(frame: &mut Frame, area: Rect) {
             });
             ctx.layer();
             ctx.draw(&Line::new(0.0, 10.0, 10.0, 10.0, Color::Blue));
-            ctx.draw(&Rectangle {
-                x: 10.0,
-                y: 20.0,
-                width: 10.0,
-                height: 10.0,
-                color: Color::Green,
-            });
+            ctx.draw(&Rectangle::new(10.0, 20.0, 10.0, 10.0, Color::Green));
             ctx.draw(&Points {
                 coords: &[
                     (2.3522, 48.8566),    // Paris
@@ -88,6 +82,7 @@ pub fn render_canvas(frame: &mut Frame, area: Rect) {
                     (32.8597, 39.9334),   // Ankara
                 ],
                 color: Color::Red,
+                colors: &[],
             });
         });
 
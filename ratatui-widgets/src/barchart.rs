@@ -2,18 +2,24 @@
 
 use ratatui_core::{
     buffer::Buffer,
-    layout::{Direction, Rect},
-    style::{Style, Styled},
+    layout::{Direction, Position, Rect},
+    style::{Color, Style, Styled},
     symbols::{self},
-    text::Line,
-    widgets::Widget,
+    text::{DisplayWidth, Line},
+    widgets::{StatefulWidget, Widget},
 };
 
-pub use self::{bar::Bar, bar_group::BarGroup};
-use crate::block::{Block, BlockExt};
+use strum::{Display, EnumString};
+
+pub use self::{bar::Bar, bar_group::BarGroup, state::BarChartState};
+use crate::{
+    block::{Block, BlockExt},
+    callback::Callback,
+};
 
 mod bar;
 mod bar_group;
+mod state;
 
 /// A chart showing values as [bars](Bar).
 ///
@@ -104,6 +110,31 @@ pub struct BarChart<'a> {
     /// Value necessary for a bar to reach the maximum height (if no value is specified,
     /// the maximum value in the data is taken as reference)
     max: Option<u64>,
+    /// Value necessary for a bar to reach the maximum depth below the baseline (if no value is
+    /// specified, the minimum value in the data is taken as reference)
+    min: Option<i64>,
+    /// Value from which the bars start
+    baseline: i64,
+    /// Style of the bars below the baseline
+    negative_bar_style: Style,
+    /// Where the values of horizontal bars are printed
+    value_position: BarValuePosition,
+    /// Where the labels of the groups of horizontal bars are printed
+    group_label_position: GroupLabelPosition,
+    /// The function formatting the values printed on the bars
+    value_formatter: Option<ValueFormatter>,
+    /// Style of the bar selected in the [`BarChartState`]
+    highlight_style: Style,
+    /// How the bars are colored according to their value
+    value_color: Option<ValueColor>,
+    /// Whether the scale of the values is printed on the left of vertical bars
+    value_axis: bool,
+    /// Style of the scale of the values
+    axis_style: Style,
+    /// Number of gridlines drawn across vertical bars
+    gridlines: u16,
+    /// Style of the gridlines
+    gridline_style: Style,
     /// direction of the bars
     direction: Direction,
 }
@@ -113,6 +144,18 @@ impl Default for BarChart<'_> {
         Self {
             block: None,
             max: None,
+            min: None,
+            baseline: 0,
+            negative_bar_style: Style::default(),
+            value_position: BarValuePosition::default(),
+            group_label_position: GroupLabelPosition::default(),
+            value_formatter: None,
+            highlight_style: Style::default(),
+            value_color: None,
+            value_axis: false,
+            axis_style: Style::default(),
+            gridlines: 0,
+            gridline_style: Style::default(),
             data: Vec::new(),
             bar_style: Style::default(),
             bar_width: 1,
@@ -243,6 +286,65 @@ impl<'a> BarChart<'a> {
         self
     }
 
+    /// Set the value necessary for a [`Bar`] to reach the maximum depth below the baseline.
+    ///
+    /// If not set, the minimum value in the data is taken as reference. This has no effect when no
+    /// value is below the [baseline](BarChart::baseline) and the minimum is not below it either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::{Bar, BarChart};
+    ///
+    /// BarChart::new([Bar::signed(5), Bar::signed(-2)]).min(-5);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn min(mut self, min: i64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set the value from which the bars start.
+    ///
+    /// Bars with a value above the baseline grow up from it (or to the right for
+    /// [`Horizontal`](ratatui_core::layout::Direction::Horizontal) bars), and bars with a value
+    /// below it grow down (or to the left). The length of the bars is split between both sides in
+    /// proportion to the range of values above and below the baseline. Bars below the baseline
+    /// are drawn with whole cells only, using the
+    /// [negative bar style](BarChart::negative_bar_style).
+    ///
+    /// If not set, this defaults to `0`, so that [signed](Bar::signed) bars with a negative value
+    /// are drawn below the positive ones (e.g. a profit/loss chart).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{Bar, BarChart};
+    ///
+    /// // deltas from a target of 100
+    /// BarChart::new([Bar::new(120), Bar::new(80), Bar::new(100)]).baseline(100);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn baseline(mut self, baseline: i64) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Set the style of the bars below the [baseline](BarChart::baseline).
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style patches the [bar style](BarChart::bar_style) and is patched by the style of each
+    /// [`Bar`].
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn negative_bar_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.negative_bar_style = style.into();
+        self
+    }
+
     /// Set the default style of the bar.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -324,6 +426,33 @@ impl<'a> BarChart<'a> {
         self
     }
 
+    /// Set the function formatting the values printed on the bars.
+    ///
+    /// The function is given the value of each bar without a [text value](Bar::text_value) and
+    /// returns the text printed on the bar, so that values can be displayed as bytes, percentages
+    /// or durations instead of raw integers. The function is given the absolute value of the bars
+    /// with a negative [signed value](Bar::signed), and `-` is prepended to the text.
+    ///
+    /// If not set, the values are printed as integers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::BarChart;
+    ///
+    /// BarChart::default()
+    ///     .data(&[("cpu", 42), ("mem", 87)])
+    ///     .value_formatter(|value| format!("{value}%"));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn value_formatter<F>(mut self, formatter: F) -> Self
+    where
+        F: Fn(u64) -> String + Send + Sync + 'static,
+    {
+        self.value_formatter = Some(Callback::new(formatter));
+        self
+    }
+
     /// Set the default label style of the groups and bars.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -343,6 +472,155 @@ impl<'a> BarChart<'a> {
         self
     }
 
+    /// Color each bar according to its value, by interpolating between two colors.
+    ///
+    /// The bars with the lowest value get the `low` color, the bars with the highest value get the
+    /// `high` color, and the other bars get a color in between, interpolated in RGB (see
+    /// [`Color::lerp`]). The lowest and highest values are those of the scale of the chart (see
+    /// [`BarChart::max`], [`BarChart::min`] and [`BarChart::baseline`]). This allows heat-style
+    /// bar charts without computing the style of each bar.
+    ///
+    /// The color is set as the foreground color of the bars, on top of the
+    /// [bar style](BarChart::bar_style) and [negative bar style](BarChart::negative_bar_style),
+    /// and below the [style](Bar::style) of each bar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{style::Color, widgets::BarChart};
+    ///
+    /// BarChart::default()
+    ///     .data(&[("A", 1), ("B", 5), ("C", 10)])
+    ///     .bar_gradient(Color::Rgb(0, 128, 255), Color::Rgb(255, 0, 0));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bar_gradient(mut self, low: Color, high: Color) -> Self {
+        self.value_color = Some(ValueColor::Gradient(low, high));
+        self
+    }
+
+    /// Color each bar according to its value, with a function.
+    ///
+    /// The function is given the value of each bar (the absolute value for the bars with a
+    /// negative [signed value](Bar::signed)) and returns its color. Like with
+    /// [`BarChart::bar_gradient`], which this replaces, the color is set as the foreground color
+    /// of the bars below the [style](Bar::style) of each bar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{style::Color, widgets::BarChart};
+    ///
+    /// BarChart::default()
+    ///     .data(&[("cpu", 42), ("mem", 87)])
+    ///     .bar_color(|value| if value > 80 { Color::Red } else { Color::Green });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bar_color<F>(mut self, color: F) -> Self
+    where
+        F: Fn(u64) -> Color + Send + Sync + 'static,
+    {
+        self.value_color = Some(ValueColor::Function(Callback::new(color)));
+        self
+    }
+
+    /// Set the style of the selected bar.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style patches the style of the bar selected in the [`BarChartState`] when the chart is
+    /// rendered as a stateful widget.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::BarChart,
+    /// };
+    ///
+    /// BarChart::default()
+    ///     .data(&[("A", 1), ("B", 2)])
+    ///     .highlight_style(Style::new().yellow().bold());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Show the scale of the values on the left of the bars.
+    ///
+    /// The highest value of the chart (see [`BarChart::max`]) is printed at the top of the bars,
+    /// the value of each [gridline](BarChart::gridlines) is printed at its height, and the
+    /// [baseline](BarChart::baseline) is printed under the bars. The values are formatted with
+    /// the [value formatter](BarChart::value_formatter) of the chart.
+    ///
+    /// The scale is only shown for [`Vertical`](Direction::Vertical) bars.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::BarChart;
+    ///
+    /// BarChart::default()
+    ///     .data(&[("A", 20), ("B", 40)])
+    ///     .value_axis(true)
+    ///     .gridlines(2);
+    /// // Renders
+    /// // 40 ──█
+    /// //      █
+    /// // 20 █─█
+    /// //    █ █
+    /// //  0 A B
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn value_axis(mut self, visible: bool) -> Self {
+        self.value_axis = visible;
+        self
+    }
+
+    /// Set the style of the scale of the values.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn axis_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.axis_style = style.into();
+        self
+    }
+
+    /// Set the number of horizontal gridlines drawn across the bars.
+    ///
+    /// The gridlines split the values between the [baseline](BarChart::baseline) and the highest
+    /// value of the chart (see [`BarChart::max`]) in `count` equal parts, the last gridline being
+    /// at the top of the bars. They are drawn on the cells which are not covered by a bar.
+    ///
+    /// The gridlines are only drawn across [`Vertical`](Direction::Vertical) bars. Defaults to
+    /// `0`, i.e. no gridlines.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn gridlines(mut self, count: u16) -> Self {
+        self.gridlines = count;
+        self
+    }
+
+    /// Set the style of the gridlines.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn gridline_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.gridline_style = style.into();
+        self
+    }
+
     /// Set the gap between [`BarGroup`].
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn group_gap(mut self, gap: u16) -> Self {
@@ -388,6 +666,101 @@ impl<'a> BarChart<'a> {
         self.direction = direction;
         self
     }
+
+    /// Set where the values of [`Horizontal`](ratatui_core::layout::Direction::Horizontal) bars
+    /// are printed.
+    ///
+    /// [`BarValuePosition::Inside`] is the default.
+    ///
+    /// # Examples
+    ///
+    /// Values inside the bars
+    /// ```plain
+    /// foo 1███
+    /// bar 12██████
+    /// ```
+    ///
+    /// Values at the end of the bars
+    /// ```plain
+    /// foo ███1
+    /// bar ██████12
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn value_position(mut self, position: BarValuePosition) -> Self {
+        self.value_position = position;
+        self
+    }
+
+    /// Set where the labels of the [groups](BarGroup) of
+    /// [`Horizontal`](ratatui_core::layout::Direction::Horizontal) bars are printed.
+    ///
+    /// [`GroupLabelPosition::Below`] is the default. Vertical bars always have their group labels
+    /// below them.
+    ///
+    /// # Examples
+    ///
+    /// Group labels below the groups, in the [group gap](BarChart::group_gap)
+    /// ```plain
+    /// a █
+    /// b ██
+    /// Group1
+    /// c ███
+    /// Group2
+    /// ```
+    ///
+    /// Group labels on the left of the first bar of the groups
+    /// ```plain
+    /// Group1 a █
+    ///        b ██
+    ///
+    /// Group2 c ███
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn group_label_position(mut self, position: GroupLabelPosition) -> Self {
+        self.group_label_position = position;
+        self
+    }
+}
+
+/// A function formatting the values of the bars, see [`BarChart::value_formatter`]
+type ValueFormatter = Callback<u64, String>;
+
+/// How the bars are colored according to their value
+///
+/// See [`BarChart::bar_gradient`] and [`BarChart::bar_color`]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+enum ValueColor {
+    /// The color is interpolated between the colors of the lowest and highest values
+    Gradient(Color, Color),
+    /// The color is given by a function of the value
+    Function(Callback<u64, Color>),
+}
+
+/// Where the values of horizontal bars are printed
+///
+/// See [`BarChart::value_position`]
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum BarValuePosition {
+    /// The values are printed inside the bars, from their start. This is the default.
+    #[default]
+    Inside,
+    /// The values are printed right after the end of the bars, or inside them at their end when
+    /// there is not enough space after them.
+    End,
+}
+
+/// Where the labels of the groups of horizontal bars are printed
+///
+/// See [`BarChart::group_label_position`]
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GroupLabelPosition {
+    /// The labels are printed below the groups, in the [group gap](BarChart::group_gap). They are
+    /// not printed if there is no group gap. This is the default.
+    #[default]
+    Below,
+    /// The labels are printed in a column on the left of the bar labels, next to the first bar of
+    /// the groups.
+    Left,
 }
 
 #[derive(Clone, Copy)]
@@ -398,11 +771,24 @@ struct LabelInfo {
 }
 
 impl BarChart<'_> {
-    /// Returns the visible bars length in ticks. A cell contains 8 ticks.
+    /// Returns the visible bars length in ticks. A cell contains 8 ticks. The length of the bars
+    /// below the baseline is negative.
     /// `available_space` used to calculate how many bars can fit in the space
     /// `bar_max_length` is the maximal length a bar can take.
-    fn group_ticks(&self, available_space: u16, bar_max_length: u16) -> Vec<Vec<u64>> {
-        let max: u64 = self.maximum_data_value();
+    fn group_ticks(&self, available_space: u16, bar_max_length: u16) -> Vec<Vec<i64>> {
+        let [bottom, baseline, top] = self.value_range();
+        let below = self.cells_below_baseline(bar_max_length);
+        let above = bar_max_length - below;
+        let ticks = |bar: &Bar| {
+            let value = bar.signed_amount() - baseline;
+            let (cells, range) = if value < 0 {
+                (below, baseline - bottom)
+            } else {
+                (above, top - baseline)
+            };
+            let ticks = value * i128::from(cells) * 8 / range.max(1);
+            ticks.clamp(-i128::from(below) * 8, i128::from(above) * 8) as i64
+        };
         self.data
             .iter()
             .scan(available_space, |space, group| {
@@ -425,14 +811,7 @@ impl BarChart<'_> {
                     }
                 };
 
-                n_bars.map(|n| {
-                    group
-                        .bars
-                        .iter()
-                        .take(n as usize)
-                        .map(|bar| bar.value * u64::from(bar_max_length) * 8 / max)
-                        .collect()
-                })
+                n_bars.map(|n| group.bars.iter().take(n as usize).map(ticks).collect())
             })
             .collect()
     }
@@ -476,7 +855,23 @@ impl BarChart<'_> {
         }
     }
 
-    fn render_horizontal(&self, buf: &mut Buffer, area: Rect) {
+    #[allow(clippy::too_many_lines)]
+    fn render_horizontal(&self, buf: &mut Buffer, area: Rect, selected: Option<usize>) {
+        // get the longest group label, of all the groups so that the columns don't change with the
+        // visible groups
+        let group_label_size = match self.group_label_position {
+            GroupLabelPosition::Below => 0,
+            GroupLabelPosition::Left => self
+                .data
+                .iter()
+                .filter_map(|group| group.label.as_ref())
+                .map(Line::width)
+                .max()
+                .unwrap_or(0) as u16,
+        };
+        let group_label_size = group_label_size.min(area.width);
+        let group_label_x = area.x;
+
         // get the longest label
         let label_size = self
             .data
@@ -487,41 +882,61 @@ impl BarChart<'_> {
             .max()
             .unwrap_or(0) as u16;
 
-        let label_x = area.x;
+        let label_x = area.x + group_label_size + u16::from(group_label_size != 0);
+        let label_size = label_size.min(area.right().saturating_sub(label_x));
         let bars_area = {
             let margin = u16::from(label_size != 0);
+            let x = (label_x + label_size + margin).min(area.right());
             Rect {
-                x: area.x + label_size + margin,
-                width: area.width - label_size - margin,
+                x,
+                width: area.right() - x,
                 ..area
             }
         };
 
         let group_ticks = self.group_ticks(bars_area.height, bars_area.width);
+        let baseline_x = bars_area.left() + self.cells_below_baseline(bars_area.width);
+        let range = self.value_range();
 
         // print all visible bars, label and values
         let mut bar_y = bars_area.top();
+        let mut first_index = 0;
         for (ticks_vec, group) in group_ticks.into_iter().zip(self.data.iter()) {
-            for (ticks, bar) in ticks_vec.into_iter().zip(group.bars.iter()) {
-                let bar_length = (ticks / 8) as u16;
-                let bar_style = self.bar_style.patch(bar.style);
+            if group_label_size > 0 && !ticks_vec.is_empty() {
+                let label_rect = Rect {
+                    x: group_label_x,
+                    y: bar_y + (self.bar_width >> 1),
+                    width: group_label_size,
+                    height: 1,
+                };
+                group.render_label(buf, label_rect, self.label_style);
+            }
+            for (index, (ticks, bar)) in ticks_vec.into_iter().zip(group.bars.iter()).enumerate() {
+                let selected = selected == Some(first_index + index);
+                let (bar_style, negative_style) = self.bar_styles(bar, range, selected);
+                let bar_length = (ticks.unsigned_abs() / 8) as u16;
+                let (bar_x, style) = if ticks < 0 {
+                    (baseline_x - bar_length, negative_style)
+                } else {
+                    (baseline_x, bar_style)
+                };
 
                 for y in 0..self.bar_width {
                     let bar_y = bar_y + y;
-                    for x in 0..bars_area.width {
-                        let symbol = if x < bar_length {
-                            self.bar_set.full
+                    for x in bars_area.left()..bars_area.right() {
+                        let (symbol, style) = if (bar_x..bar_x + bar_length).contains(&x) {
+                            (self.bar_set.full, style)
                         } else {
-                            self.bar_set.empty
+                            (self.bar_set.empty, bar_style)
                         };
-                        buf[(bars_area.left() + x, bar_y)]
-                            .set_symbol(symbol)
-                            .set_style(bar_style);
+                        buf[(x, bar_y)].set_symbol(symbol).set_style(style);
                     }
                 }
 
                 let bar_value_area = Rect {
+                    x: bar_x,
                     y: bar_y + (self.bar_width >> 1),
+                    width: bars_area.right() - bar_x,
                     ..bars_area
                 };
 
@@ -530,13 +945,31 @@ impl BarChart<'_> {
                     buf.set_line(label_x, bar_value_area.top(), label, label_size);
                 }
 
-                bar.render_value_with_different_styles(
-                    buf,
-                    bar_value_area,
-                    bar_length as usize,
-                    self.value_style,
-                    self.bar_style,
-                );
+                match self.value_position {
+                    BarValuePosition::Inside => bar.render_value_with_different_styles(
+                        buf,
+                        bar_value_area,
+                        bar_length as usize,
+                        self.value_style,
+                        self.bar_style,
+                        self.value_formatter.as_ref(),
+                    ),
+                    BarValuePosition::End => {
+                        let filled_area = Rect {
+                            x: bar_x,
+                            width: bar_length,
+                            ..bar_value_area
+                        };
+                        self.render_value_at_end(
+                            buf,
+                            bars_area,
+                            filled_area,
+                            bar,
+                            ticks < 0,
+                            style,
+                        );
+                    }
+                }
 
                 bar_y += self.bar_gap + self.bar_width;
             }
@@ -545,36 +978,239 @@ impl BarChart<'_> {
             // check also if the group label is still inside the visible area
             let label_y = bar_y - self.bar_gap;
             if self.group_gap > 0 && label_y < bars_area.bottom() {
-                let label_rect = Rect {
-                    y: label_y,
-                    ..bars_area
-                };
-                group.render_label(buf, label_rect, self.label_style);
+                if self.group_label_position == GroupLabelPosition::Below {
+                    let label_rect = Rect {
+                        y: label_y,
+                        ..bars_area
+                    };
+                    group.render_label(buf, label_rect, self.label_style);
+                }
                 bar_y += self.group_gap;
             }
+            first_index += group.bars.len();
         }
     }
 
-    fn render_vertical(&self, buf: &mut Buffer, area: Rect) {
+    /// Returns the style of the bar, and the style of its filled cells if it is below the
+    /// baseline
+    fn bar_styles(&self, bar: &Bar, [bottom, _, top]: [i128; 3], selected: bool) -> (Style, Style) {
+        let color = self
+            .value_color
+            .as_ref()
+            .map(|value_color| match value_color {
+                ValueColor::Gradient(low, high) => {
+                    let t = (bar.signed_amount() - bottom) as f64 / (top - bottom).max(1) as f64;
+                    low.lerp(*high, t)
+                }
+                ValueColor::Function(color) => color.call(bar.value),
+            });
+        let color = color.map_or_else(Style::new, |color| Style::new().fg(color));
+        let highlight = if selected {
+            self.highlight_style
+        } else {
+            Style::new()
+        };
+        let style = self
+            .bar_style
+            .patch(color)
+            .patch(bar.style)
+            .patch(highlight);
+        let negative_style = self
+            .bar_style
+            .patch(self.negative_bar_style)
+            .patch(color)
+            .patch(bar.style)
+            .patch(highlight);
+        (style, negative_style)
+    }
+
+    /// Prints the value of a horizontal bar right after its end (before its start for the bars
+    /// below the baseline) with the style of the bar, or inside the bar at its end with the value
+    /// style if there is not enough space.
+    fn render_value_at_end(
+        &self,
+        buf: &mut Buffer,
+        bars_area: Rect,
+        filled_area: Rect,
+        bar: &Bar,
+        negative: bool,
+        bar_style: Style,
+    ) {
+        let text = bar.value_text(self.value_formatter.as_ref());
+        let width = text.display_width() as u16;
+        let value_style = self.value_style.patch(bar.value_style);
+        let (x, style) = if negative {
+            if filled_area.left() >= bars_area.left() + width {
+                (filled_area.left() - width, bar_style)
+            } else {
+                (filled_area.left(), value_style)
+            }
+        } else if filled_area.right() + width <= bars_area.right() {
+            (filled_area.right(), bar_style)
+        } else {
+            (
+                bars_area
+                    .right()
+                    .saturating_sub(width)
+                    .max(bars_area.left()),
+                value_style,
+            )
+        };
+        let max_width = bars_area.right() - x;
+        buf.set_stringn(x, filled_area.y, text, usize::from(max_width), style);
+    }
+
+    fn render_vertical(&self, buf: &mut Buffer, area: Rect, selected: Option<usize>) {
         let label_info = self.label_info(area.height - 1);
 
+        let bars_height = area.height - label_info.height;
+        let scale = self.scale(bars_height);
+        let axis_width = if self.value_axis {
+            scale
+                .iter()
+                .map(|(_, label)| label.display_width() as u16 + 1)
+                .max()
+                .unwrap_or_default()
+        } else {
+            0
+        };
+        let area = if axis_width > 0 && axis_width < area.width {
+            self.render_value_axis(buf, area, axis_width, bars_height, &scale);
+            Rect {
+                x: area.x + axis_width,
+                width: area.width - axis_width,
+                ..area
+            }
+        } else {
+            area
+        };
+
         let bars_area = Rect {
-            height: area.height - label_info.height,
+            height: bars_height,
             ..area
         };
 
         let group_ticks = self.group_ticks(bars_area.width, bars_area.height);
-        self.render_vertical_bars(bars_area, buf, &group_ticks);
+        self.render_vertical_bars(bars_area, buf, &group_ticks, selected);
+        self.render_gridlines(bars_area, buf, &scale);
         self.render_labels_and_values(area, buf, label_info, &group_ticks);
     }
 
-    fn render_vertical_bars(&self, area: Rect, buf: &mut Buffer, group_ticks: &[Vec<u64>]) {
+    /// Returns the values of the scale of vertical bars, with the number of rows above the
+    /// baseline at which each of them is
+    ///
+    /// The baseline is the first value, with `0` rows, and is followed by the values of the
+    /// gridlines, the last one being the highest value of the chart.
+    fn scale(&self, bars_height: u16) -> Vec<(u16, String)> {
+        let [_, baseline, top] = self.value_range();
+        let rows = bars_height - self.cells_below_baseline(bars_height);
+        let count = self.gridlines.max(1);
+        let mut scale = vec![(0, self.format_value(baseline))];
+        for k in 1..=count {
+            let (k, count) = (i128::from(k), i128::from(count));
+            // rounded to the nearest row
+            let row = ((i128::from(rows) * k * 2 + count) / (count * 2)) as u16;
+            if row > scale.last().map_or(0, |(row, _)| *row) {
+                let value = baseline + (top - baseline) * k / count;
+                scale.push((row, self.format_value(value)));
+            }
+        }
+        scale
+    }
+
+    /// Formats a value of the scale with the value formatter of the chart
+    fn format_value(&self, value: i128) -> String {
+        let amount = value.unsigned_abs() as u64;
+        let text = self
+            .value_formatter
+            .as_ref()
+            .map_or_else(|| amount.to_string(), |format| format.call(amount));
+        if value < 0 {
+            format!("-{text}")
+        } else {
+            text
+        }
+    }
+
+    /// Prints the values of the scale right aligned in the first `width - 1` columns of the area
+    fn render_value_axis(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        width: u16,
+        bars_height: u16,
+        scale: &[(u16, String)],
+    ) {
+        let baseline_y = area.top() + bars_height - self.cells_below_baseline(bars_height);
+        for (row, label) in scale {
+            let y = baseline_y - row;
+            if y >= area.bottom() {
+                continue;
+            }
+            let label_width = label.display_width() as u16;
+            let x = area.left() + width - 1 - label_width;
+            buf.set_stringn(x, y, label, label_width as usize, self.axis_style);
+        }
+    }
+
+    /// Draws the gridlines of the scale across the cells of the area which are not covered by a
+    /// bar
+    fn render_gridlines(&self, area: Rect, buf: &mut Buffer, scale: &[(u16, String)]) {
+        if self.gridlines == 0 {
+            return;
+        }
+        let baseline_y = area.bottom() - self.cells_below_baseline(area.height);
+        for (row, _) in scale.iter().skip(1) {
+            let y = baseline_y - row;
+            for x in area.left()..area.right() {
+                let cell = &mut buf[(x, y)];
+                if cell.symbol() == " " {
+                    cell.set_symbol(symbols::line::HORIZONTAL)
+                        .set_style(self.gridline_style);
+                }
+            }
+        }
+    }
+
+    fn render_vertical_bars(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        group_ticks: &[Vec<i64>],
+        selected: Option<usize>,
+    ) {
+        let below = self.cells_below_baseline(area.height);
+        let above_area = Rect {
+            height: area.height - below,
+            ..area
+        };
+        let range = self.value_range();
         // print all visible bars (without labels and values)
         let mut bar_x = area.left();
+        let mut first_index = 0;
         for (ticks_vec, group) in group_ticks.iter().zip(&self.data) {
-            for (ticks, bar) in ticks_vec.iter().zip(&group.bars) {
-                let mut ticks = *ticks;
-                for j in (0..area.height).rev() {
+            for (index, (ticks, bar)) in ticks_vec.iter().zip(&group.bars).enumerate() {
+                let selected = selected == Some(first_index + index);
+                let (bar_style, negative_style) = self.bar_styles(bar, range, selected);
+                // bars below the baseline are drawn with whole cells, rounded to the nearest one
+                let cells_below = if *ticks < 0 {
+                    (ticks.unsigned_abs() + 4) / 8
+                } else {
+                    0
+                };
+                for (j, y) in (above_area.bottom()..area.bottom()).enumerate() {
+                    let (symbol, style) = if (j as u64) < cells_below {
+                        (self.bar_set.full, negative_style)
+                    } else {
+                        (self.bar_set.empty, bar_style)
+                    };
+                    for x in 0..self.bar_width {
+                        buf[(bar_x + x, y)].set_symbol(symbol).set_style(style);
+                    }
+                }
+
+                let mut ticks = u64::try_from(*ticks).unwrap_or_default();
+                for j in (0..above_area.height).rev() {
                     let symbol = match ticks {
                         0 => self.bar_set.empty,
                         1 => self.bar_set.one_eighth,
@@ -587,10 +1223,8 @@ impl BarChart<'_> {
                         _ => self.bar_set.full,
                     };
 
-                    let bar_style = self.bar_style.patch(bar.style);
-
                     for x in 0..self.bar_width {
-                        buf[(bar_x + x, area.top() + j)]
+                        buf[(bar_x + x, above_area.top() + j)]
                             .set_symbol(symbol)
                             .set_style(bar_style);
                     }
@@ -600,20 +1234,37 @@ impl BarChart<'_> {
                 bar_x += self.bar_gap + self.bar_width;
             }
             bar_x += self.group_gap;
+            first_index += group.bars.len();
         }
     }
 
-    /// get the maximum data value. the returned value is always greater equal 1
-    fn maximum_data_value(&self) -> u64 {
-        self.max
-            .unwrap_or_else(|| {
-                self.data
-                    .iter()
-                    .map(|group| group.max().unwrap_or_default())
-                    .max()
-                    .unwrap_or_default()
-            })
-            .max(1)
+    /// Returns the lowest value, the baseline and the highest value of the bars. The lowest value
+    /// is less or equal to the baseline, which is less or equal to the highest value.
+    fn value_range(&self) -> [i128; 3] {
+        let baseline = i128::from(self.baseline);
+        let values = self
+            .data
+            .iter()
+            .flat_map(|group| &group.bars)
+            .map(Bar::signed_amount);
+        let top = self
+            .max
+            .map_or_else(|| values.clone().max().unwrap_or_default(), i128::from);
+        let bottom = self
+            .min
+            .map_or_else(|| values.min().unwrap_or_default(), i128::from);
+        [bottom.min(baseline), baseline, top.max(baseline)]
+    }
+
+    /// Returns how many cells of the `length` of the bars are below the baseline
+    fn cells_below_baseline(&self, length: u16) -> u16 {
+        let [bottom, baseline, top] = self.value_range();
+        if bottom == baseline {
+            return 0;
+        }
+        let (below, range) = (baseline - bottom, top - bottom);
+        // rounded to the nearest cell
+        ((i128::from(length) * below * 2 + range) / (range * 2)) as u16
     }
 
     fn render_labels_and_values(
@@ -621,11 +1272,13 @@ impl BarChart<'_> {
         area: Rect,
         buf: &mut Buffer,
         label_info: LabelInfo,
-        group_ticks: &[Vec<u64>],
+        group_ticks: &[Vec<i64>],
     ) {
         // print labels and values in one go
         let mut bar_x = area.left();
         let bar_y = area.bottom() - label_info.height - 1;
+        let below = self.cells_below_baseline(area.height - label_info.height);
+        let above = area.height - label_info.height - below;
         for (group, ticks_vec) in self.data.iter().zip(group_ticks) {
             if group.bars.is_empty() {
                 continue;
@@ -649,7 +1302,20 @@ impl BarChart<'_> {
                     bar.render_label(buf, self.bar_width, bar_x, bar_y + 1, self.label_style);
                 }
 
-                bar.render_value(buf, self.bar_width, bar_x, bar_y, self.value_style, *ticks);
+                // print the value in the cell of the bar next to the baseline
+                let value_y = if *ticks < 0 || above == 0 {
+                    bar_y + 1 - below
+                } else {
+                    bar_y - below
+                };
+                bar.render_value(
+                    buf,
+                    self.bar_width,
+                    Position::new(bar_x, value_y),
+                    self.value_style,
+                    ticks.unsigned_abs(),
+                    self.value_formatter.as_ref(),
+                );
 
                 bar_x += self.bar_gap + self.bar_width;
             }
@@ -666,18 +1332,43 @@ impl Widget for BarChart<'_> {
 
 impl Widget for &BarChart<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = BarChartState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+// `StatefulWidget` is only implemented for `&BarChart` so that calling `render` on an owned bar
+// chart stays unambiguous when both widget traits are in scope.
+impl StatefulWidget for &BarChart<'_> {
+    type State = BarChartState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         buf.set_style(area, self.style);
 
         self.block.as_ref().render(area, buf);
         let inner = self.block.inner_if_some(area);
 
+        // keep the selection on an existing bar
+        let bar_count = self
+            .data
+            .iter()
+            .map(|group| group.bars.len())
+            .sum::<usize>();
+        let last = bar_count.checked_sub(1);
+        state.select(
+            state
+                .selected
+                .zip(last)
+                .map(|(selected, last)| selected.min(last)),
+        );
+
         if inner.is_empty() || self.data.is_empty() || self.bar_width == 0 {
             return;
         }
 
         match self.direction {
-            Direction::Horizontal => self.render_horizontal(buf, inner),
-            Direction::Vertical => self.render_vertical(buf, inner),
+            Direction::Horizontal => self.render_horizontal(buf, inner, state.selected),
+            Direction::Vertical => self.render_vertical(buf, inner, state.selected),
         }
     }
 }
@@ -1406,6 +2097,207 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn negative_values() {
+        let chart = BarChart::new([Bar::signed(2), Bar::signed(-2)])
+            .bar_width(2)
+            .negative_bar_style(Style::new().red());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 4));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["██   ", "2█   ", "   -2", "   ██"]);
+        expected.set_style(Rect::new(3, 2, 2, 2), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn baseline() {
+        let chart = BarChart::new([Bar::new(120), Bar::new(80), Bar::new(100)]).baseline(100);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 2));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["█    ", "  █  "]));
+    }
+
+    #[test]
+    fn horizontal_negative_values() {
+        let chart = BarChart::horizontal([Bar::signed(2), Bar::signed(-2)])
+            .bar_gap(0)
+            .negative_bar_style(Style::new().red());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["  2█", "-2  "]);
+        expected.set_style(Rect::new(0, 1, 2, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn horizontal_grouped_bars() {
+        let chart = BarChart::default()
+            .direction(Direction::Horizontal)
+            .group_label_position(GroupLabelPosition::Left)
+            .value_position(BarValuePosition::End)
+            .bar_gap(0)
+            .group_gap(1)
+            .data(
+                BarGroup::default()
+                    .label("G1")
+                    .bars(&[Bar::with_label("a", 1), Bar::with_label("b", 2)]),
+            )
+            .data(
+                BarGroup::default()
+                    .label("G2")
+                    .bars(&[Bar::with_label("c", 4)]),
+            );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 4));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["G1 a █1   ", "   b ██2  ", "          ", "G2 c ████4"]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn horizontal_negative_value_at_end() {
+        let chart = BarChart::horizontal([Bar::signed(-1), Bar::signed(2)])
+            .value_position(BarValuePosition::End)
+            .min(-4)
+            .bar_gap(0);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([" -1█  ", "    █2"]));
+    }
+
+    #[test]
+    fn value_formatter() {
+        let chart = BarChart::default()
+            .data(&[("a", 50)])
+            .bar_width(3)
+            .value_formatter(|value| format!("{value}%"));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        assert_eq!(chart, chart.clone());
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["50%", " a "]));
+    }
+
+    #[test]
+    fn non_ascii_value_formatter() {
+        let chart = BarChart::default()
+            .data(&[("a", 5)])
+            .bar_width(3)
+            .value_formatter(|value| format!("{value}µs"));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["5µs", " a "]));
+
+        let chart = BarChart::horizontal(vec![Bar::new(2), Bar::new(4)])
+            .bar_style(Style::new().red())
+            .value_formatter(|value| format!("{value}µs"));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["2µs ", "    ", "4µs█"]);
+        expected.set_style(Rect::new(0, 0, 4, 1), Style::new().red());
+        expected.set_style(Rect::new(0, 2, 4, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn highlight_selected_bar() {
+        let chart = BarChart::default()
+            .data(&[("a", 1), ("b", 1)])
+            .data(&[("c", 1)])
+            .group_gap(1)
+            .highlight_style(Style::new().red());
+        let mut state = BarChartState::default().with_selected(Some(2));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines(["1 1  1", "a b  c"]);
+        expected.set_style(Rect::new(5, 0, 1, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn selection_is_clamped_to_bars() {
+        let chart = BarChart::default().data(&[("a", 1), ("b", 2)]);
+        let mut state = BarChartState::default();
+        state.select_last();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.selected(), Some(1));
+
+        let mut state = BarChartState::default().with_selected(Some(0));
+        StatefulWidget::render(&BarChart::default(), buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.selected(), None);
+    }
+
+    #[test]
+    fn bar_gradient() {
+        let chart = BarChart::default()
+            .data(&[("a", 0), ("b", 2), ("c", 4)])
+            .bar_gradient(Color::Rgb(0, 0, 0), Color::Rgb(200, 0, 0));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["    █", "  2 4", "a b c"]);
+        for (x, color) in [(0, 0), (2, 100), (4, 200)] {
+            expected.set_style(
+                Rect::new(x, 0, 1, 2),
+                Style::new().fg(Color::Rgb(color, 0, 0)),
+            );
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn bar_color() {
+        // the closure captures the threshold
+        let threshold = 1;
+        let chart = BarChart::default()
+            .data(&[("a", 1), ("b", 2)])
+            .bar_color(move |value| {
+                if value > threshold {
+                    Color::Red
+                } else {
+                    Color::Green
+                }
+            });
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["  █", "1 2", "a b"]);
+        expected.set_style(Rect::new(0, 0, 1, 2), Style::new().green());
+        expected.set_style(Rect::new(2, 0, 1, 2), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn value_axis_and_gridlines() {
+        let chart = BarChart::default()
+            .data(&[("a", 2), ("b", 4)])
+            .value_axis(true)
+            .gridlines(2);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 5));
+        chart.render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "4 ──█",
+            "    █",
+            "2 █─█",
+            "  2 4",
+            "0 a b",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn value_axis_without_gridlines() {
+        let chart = BarChart::default()
+            .data(&[("a", 5), ("b", 10)])
+            .value_axis(true)
+            .value_formatter(|value| format!("{value}%"))
+            .axis_style(Style::new().dark_gray());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["10%   █", "    █ █", " 0% a b"]);
+        expected.set_style(Rect::new(0, 0, 3, 1), Style::new().dark_gray());
+        expected.set_style(Rect::new(1, 2, 2, 1), Style::new().dark_gray());
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn test_barchart_new() {
         let bars = [Bar::with_label("Red", 1), Bar::with_label("Green", 2)];
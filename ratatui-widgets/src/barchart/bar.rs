@@ -1,11 +1,16 @@
+use std::borrow::Cow;
+
 use ratatui_core::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{Position, Rect},
     style::{Style, Styled},
-    text::Line,
+    text::{DisplayWidth, Line},
     widgets::Widget,
 };
-use unicode_width::UnicodeWidthStr;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::ValueFormatter;
 
 /// A bar to be shown by the [`BarChart`](super::BarChart) widget.
 ///
@@ -36,6 +41,8 @@ use unicode_width::UnicodeWidthStr;
 pub struct Bar<'a> {
     /// Value to display on the bar (computed when the data is passed to the widget)
     pub(super) value: u64,
+    /// Whether the value is negative, i.e. `value` is its absolute value
+    pub(super) negative: bool,
     /// optional label to be printed under the bar
     pub(super) label: Option<Line<'a>>,
     /// style for the bar
@@ -59,6 +66,7 @@ impl<'a> Bar<'a> {
     pub const fn new(value: u64) -> Self {
         Self {
             value,
+            negative: false,
             label: None,
             style: Style::new(),
             value_style: Style::new(),
@@ -66,6 +74,22 @@ impl<'a> Bar<'a> {
         }
     }
 
+    /// Creates a new `Bar` with the given signed value.
+    ///
+    /// Bars with a value below the [baseline](super::BarChart::baseline) of the chart (e.g. negative
+    /// values) are drawn below it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::Bar;
+    ///
+    /// let bar = Bar::signed(-42);
+    /// ```
+    pub const fn signed(value: i64) -> Self {
+        Self::new(0).signed_value(value)
+    }
+
     /// Creates a new `Bar` with the given `label` and value.
     ///
     /// a `label` can be a [`&str`], [`String`] or anything that can be converted into [`Line`].
@@ -80,6 +104,7 @@ impl<'a> Bar<'a> {
     pub fn with_label<T: Into<Line<'a>>>(label: T, value: u64) -> Self {
         Self {
             value,
+            negative: false,
             label: Some(label.into()),
             style: Style::new(),
             value_style: Style::new(),
@@ -98,9 +123,51 @@ impl<'a> Bar<'a> {
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn value(mut self, value: u64) -> Self {
         self.value = value;
+        self.negative = false;
+        self
+    }
+
+    /// Set the signed value of this bar.
+    ///
+    /// Bars with a value below the [baseline](super::BarChart::baseline) of the chart (e.g. negative
+    /// values) are drawn below it, with the
+    /// [negative bar style](super::BarChart::negative_bar_style).
+    ///
+    /// # See also
+    ///
+    /// - [`Bar::value`] to set an unsigned value.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn signed_value(mut self, value: i64) -> Self {
+        self.value = value.unsigned_abs();
+        self.negative = value < 0;
         self
     }
 
+    /// The value of this bar, with its sign
+    pub(super) fn signed_amount(&self) -> i128 {
+        let value = i128::from(self.value);
+        if self.negative {
+            -value
+        } else {
+            value
+        }
+    }
+
+    /// The text printed on the bar: the text value if set, or the value formatted with the given
+    /// formatter otherwise
+    pub(super) fn value_text(&self, formatter: Option<&ValueFormatter>) -> Cow<'_, str> {
+        if let Some(text) = &self.text_value {
+            return Cow::Borrowed(text);
+        }
+        let value =
+            formatter.map_or_else(|| self.value.to_string(), |format| format.call(self.value));
+        if self.negative {
+            Cow::Owned(format!("-{value}"))
+        } else {
+            Cow::Owned(value)
+        }
+    }
+
     /// Set the label of the bar.
     ///
     /// `label` can be a [`&str`], [`String`] or anything that can be converted into [`Line`].
@@ -205,25 +272,37 @@ impl<'a> Bar<'a> {
         bar_length: usize,
         default_value_style: Style,
         bar_style: Style,
+        formatter: Option<&ValueFormatter>,
     ) {
-        let value = self.value.to_string();
-        let text = self.text_value.as_ref().unwrap_or(&value);
+        let text = self.value_text(formatter);
 
         if !text.is_empty() {
             let style = default_value_style.patch(self.value_style);
             // Since the value may be longer than the bar itself, we need to use 2 different styles
             // while rendering. Render the first part with the default value style
-            buf.set_stringn(area.x, area.y, text, bar_length, style);
-            // render the second part with the bar_style
-            if text.len() > bar_length {
-                let (first, second) = text.split_at(bar_length);
-
+            buf.set_stringn(area.x, area.y, &text, bar_length, style);
+            // render the second part with the bar_style. The text is split on the first grapheme
+            // that doesn't fit in the bar, as the formatted value may contain non-ASCII characters
+            let mut first_width = 0;
+            let split = text
+                .grapheme_indices(true)
+                .find(|(_, grapheme)| {
+                    let width = grapheme.display_width();
+                    if first_width + width > bar_length {
+                        return true;
+                    }
+                    first_width += width;
+                    false
+                })
+                .map(|(index, _)| index);
+            if let Some(split) = split {
+                let (_, second) = text.split_at(split);
                 let style = bar_style.patch(self.style);
                 buf.set_stringn(
-                    area.x + first.len() as u16,
+                    area.x + first_width as u16,
                     area.y,
                     second,
-                    area.width as usize - first.len(),
+                    (area.width as usize).saturating_sub(first_width),
                     style,
                 );
             }
@@ -234,23 +313,22 @@ impl<'a> Bar<'a> {
         &self,
         buf: &mut Buffer,
         max_width: u16,
-        x: u16,
-        y: u16,
+        Position { x, y }: Position,
         default_value_style: Style,
         ticks: u64,
+        formatter: Option<&ValueFormatter>,
     ) {
         if self.value != 0 {
             const TICKS_PER_LINE: u64 = 8;
-            let value = self.value.to_string();
-            let value_label = self.text_value.as_ref().unwrap_or(&value);
-            let width = value_label.width() as u16;
+            let value_label = self.value_text(formatter);
+            let width = value_label.display_width() as u16;
             // if we have enough space or the ticks are greater equal than 1 cell (8)
             // then print the value
             if width < max_width || (width == max_width && ticks >= TICKS_PER_LINE) {
                 buf.set_string(
-                    x + (max_width.saturating_sub(value_label.len() as u16) >> 1),
+                    x + (max_width.saturating_sub(width) >> 1),
                     y,
-                    value_label,
+                    &value_label,
                     default_value_style.patch(self.value_style),
                 );
             }
@@ -303,6 +381,7 @@ mod tests {
     use ratatui_core::style::{Color, Modifier, Style, Stylize};
 
     use super::*;
+    use crate::callback::Callback;
 
     #[test]
     fn test_bar_new() {
@@ -318,6 +397,30 @@ mod tests {
         assert_eq!(bar.value, 42);
     }
 
+    #[test]
+    fn test_bar_signed() {
+        let bar = Bar::signed(-42);
+        assert_eq!(bar.value, 42);
+        assert!(bar.negative);
+        assert_eq!(bar.signed_amount(), -42);
+        assert_eq!(bar.value_text(None), "-42");
+
+        let bar = bar.value(42);
+        assert!(!bar.negative);
+        assert_eq!(bar.value_text(None), "42");
+    }
+
+    #[test]
+    fn test_bar_value_formatter() {
+        let formatter = Callback::new(|value| format!("{value}%"));
+        assert_eq!(Bar::new(42).value_text(Some(&formatter)), "42%");
+        assert_eq!(Bar::signed(-42).value_text(Some(&formatter)), "-42%");
+        assert_eq!(
+            Bar::new(42).text_value("x").value_text(Some(&formatter)),
+            "x"
+        );
+    }
+
     #[test]
     fn test_bar_stylized() {
         let bar = Bar::default().red().bold();
@@ -80,11 +80,6 @@ impl<'a> BarGroup<'a> {
         self
     }
 
-    /// The maximum bar value of this group
-    pub(super) fn max(&self) -> Option<u64> {
-        self.bars.iter().max_by_key(|v| v.value).map(|v| v.value)
-    }
-
     pub(super) fn render_label(&self, buf: &mut Buffer, area: Rect, default_label_style: Style) {
         if let Some(label) = &self.label {
             // align the label. Necessary to do it this way as we don't want to set the style
@@ -0,0 +1,155 @@
+/// State of the [`BarChart`] widget
+///
+/// This state can be used to select a bar, e.g. to drill down into the data of the selected bar.
+/// When the chart is rendered as a stateful widget, the selected bar is drawn with the
+/// [highlight style](super::BarChart::highlight_style) of the chart.
+///
+/// The bars are indexed in the order they are added to the chart, across all the
+/// [groups](super::BarGroup): the first bar of the second group follows the last bar of the first
+/// group.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Rect,
+///     style::{Style, Stylize},
+///     widgets::{BarChart, BarChartState},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let chart = BarChart::default()
+///     .data(&[("A", 1), ("B", 2), ("C", 3)])
+///     .highlight_style(Style::new().yellow());
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = BarChartState::default().with_selected(Some(1));
+///
+/// frame.render_stateful_widget(&chart, area, &mut state);
+/// # }
+/// ```
+///
+/// [`BarChart`]: super::BarChart
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BarChartState {
+    pub(crate) selected: Option<usize>,
+}
+
+impl BarChartState {
+    /// Sets the index of the selected bar
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::BarChartState;
+    ///
+    /// let state = BarChartState::default().with_selected(Some(1));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Index of the selected bar
+    ///
+    /// Returns `None` if no bar is selected
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Mutable reference to the index of the selected bar
+    ///
+    /// Returns `None` if no bar is selected
+    pub fn selected_mut(&mut self) -> &mut Option<usize> {
+        &mut self.selected
+    }
+
+    /// Sets the index of the selected bar
+    ///
+    /// Set to `None` if no bar is selected.
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+
+    /// Selects the next bar or the first one if no bar is selected
+    ///
+    /// Note: until the chart is rendered, the number of bars is not known, so the index is set to
+    /// `0` and will be corrected when the chart is rendered
+    pub fn select_next(&mut self) {
+        let next = self.selected.map_or(0, |i| i.saturating_add(1));
+        self.select(Some(next));
+    }
+
+    /// Selects the previous bar or the last one if no bar is selected
+    ///
+    /// Note: until the chart is rendered, the number of bars is not known, so the index is set to
+    /// `usize::MAX` and will be corrected when the chart is rendered
+    pub fn select_previous(&mut self) {
+        let previous = self.selected.map_or(usize::MAX, |i| i.saturating_sub(1));
+        self.select(Some(previous));
+    }
+
+    /// Selects the first bar
+    pub fn select_first(&mut self) {
+        self.select(Some(0));
+    }
+
+    /// Selects the last bar
+    ///
+    /// Note: until the chart is rendered, the number of bars is not known, so the index is set to
+    /// `usize::MAX` and will be corrected when the chart is rendered
+    pub fn select_last(&mut self) {
+        self.select(Some(usize::MAX));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn select() {
+        let mut state = BarChartState::default();
+        assert_eq!(state.selected(), None);
+
+        state.select(Some(2));
+        assert_eq!(state.selected(), Some(2));
+
+        *state.selected_mut() = None;
+        assert_eq!(state.selected(), None);
+    }
+
+    #[test]
+    fn state_navigation() {
+        let mut state = BarChartState::default();
+        state.select_next();
+        assert_eq!(state.selected, Some(0));
+
+        state.select_previous(); // should not go below 0
+        assert_eq!(state.selected, Some(0));
+
+        state.select_next();
+        assert_eq!(state.selected, Some(1));
+
+        state.select_last();
+        assert_eq!(state.selected, Some(usize::MAX));
+
+        state.select_next(); // should not overflow
+        assert_eq!(state.selected, Some(usize::MAX));
+
+        state.select_first();
+        assert_eq!(state.selected, Some(0));
+
+        state.select(None);
+        state.select_previous();
+        assert_eq!(state.selected, Some(usize::MAX));
+    }
+}
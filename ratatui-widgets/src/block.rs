@@ -5,23 +5,31 @@
 //! In its simplest form, a `Block` is a [border](Borders) around another widget. It can have a
 //! [title](Block::title) and [padding](Block::padding).
 
+use std::ops::Range;
+
 use itertools::Itertools;
 use ratatui_core::{
     buffer::Buffer,
     layout::{Alignment, Rect},
-    style::{Style, Styled},
-    symbols::border,
+    style::{Color, Style, Styled},
+    symbols::{border, merge},
     text::Line,
-    widgets::Widget,
+    widgets::{StatefulWidget, Widget},
 };
 
 pub use self::{
+    label::{BorderLabel, Edge, LabelAnchor},
     padding::Padding,
+    shadow::ShadowStyle,
+    state::BlockState,
     title::{Position, Title},
 };
 use crate::borders::{BorderType, Borders};
 
+mod label;
 mod padding;
+mod shadow;
+mod state;
 pub mod title;
 
 /// Base widget to be used to display a box border around all other built-in widgets.
@@ -54,19 +62,33 @@ pub mod title;
 ///
 /// - [`Block::borders`] Defines which borders to display.
 /// - [`Block::border_style`] Defines the style of the borders.
+/// - [`Block::border_gradient`] Colors the borders with a gradient.
 /// - [`Block::border_type`] Sets the symbols used to display the border (e.g. single line, double
 ///   line, thick or rounded borders).
+/// - [`Block::side_border_type`] and [`Block::side_border_set`] Set the symbols of the borders on
+///   some sides only.
+/// - [`Block::side_border_style`] Defines the style of the borders on some sides only.
+/// - [`Block::merge_borders`] Joins the borders with the borders of adjacent blocks.
 /// - [`Block::padding`] Defines the padding inside a [`Block`].
+/// - [`Block::shadow`] Adds a drop shadow to the block.
 /// - [`Block::style`] Sets the base style of the widget.
+/// - [`Block::content_overlay_style`] Sets the style applied over the content of the block.
 /// - [`Block::title`] Adds a title to the block.
 /// - [`Block::title_alignment`] Sets the default [`Alignment`] for all block titles.
 /// - [`Block::title_style`] Applies the style to all titles.
 /// - [`Block::title_top`] Adds a title to the top of the block.
 /// - [`Block::title_bottom`] Adds a title to the bottom of the block.
 /// - [`Block::title_position`] Adds a title to the block.
+/// - [`Block::title_slot`] Reserves room for a widget in the titles of the block.
+/// - [`Block::border_label`] Adds a label at an arbitrary position in a border.
 ///
 /// # Other Methods
 /// - [`Block::inner`] Compute the inner area of a block based on its border visibility rules.
+/// - [`Block::title_slot_areas`] and [`Block::render_title_widget`] Render widgets in title slots.
+/// - [`Block::inner_if_expanded`] Compute the inner area of a collapsible block (see
+///   [`BlockState`]).
+/// - [`Block::render_content_overlay`] Applies the content overlay style after the content is
+///   rendered.
 ///
 /// [`Style`]s are applied first to the entire block, then to the borders, and finally to the
 /// titles. If the block is used as a container for another widget, the inner widget can also be
@@ -114,6 +136,10 @@ pub mod title;
 pub struct Block<'a> {
     /// List of titles
     titles: Vec<(Option<Position>, Line<'a>)>,
+    /// The indices of the titles that are slots for widgets
+    title_slots: Vec<usize>,
+    /// Labels embedded in the borders
+    labels: Vec<BorderLabel<'a>>,
     /// The style to be patched to all titles of the block
     titles_style: Style,
     /// The default alignment of the titles that don't have one
@@ -127,10 +153,22 @@ pub struct Block<'a> {
     /// The symbols used to render the border. The default is plain lines but one can choose to
     /// have rounded or doubled lines instead or a custom set of symbols
     border_set: border::Set,
+    /// Symbols that replace the border set on the top, right, bottom and left sides
+    side_border_sets: [Option<border::Set>; 4],
+    /// Styles that are patched onto the border style on the top, right, bottom and left sides
+    side_border_styles: [Option<Style>; 4],
+    /// The start and end colors of a gradient applied to the borders
+    border_gradient: Option<(Color, Color)>,
     /// Widget style
     style: Style,
     /// Block padding
     padding: Padding,
+    /// The drop shadow of the block
+    shadow: Option<ShadowStyle>,
+    /// Style applied over the content of the block
+    content_overlay_style: Style,
+    /// Whether the borders are merged with the borders already in the buffer
+    merge_borders: bool,
 }
 
 impl<'a> Block<'a> {
@@ -138,14 +176,22 @@ impl<'a> Block<'a> {
     pub const fn new() -> Self {
         Self {
             titles: Vec::new(),
+            title_slots: Vec::new(),
+            labels: Vec::new(),
             titles_style: Style::new(),
             titles_alignment: Alignment::Left,
             titles_position: Position::Top,
             borders: Borders::NONE,
             border_style: Style::new(),
             border_set: BorderType::Plain.to_border_set(),
+            side_border_sets: [None; 4],
+            side_border_styles: [None; 4],
+            border_gradient: None,
             style: Style::new(),
             padding: Padding::ZERO,
+            shadow: None,
+            content_overlay_style: Style::new(),
+            merge_borders: false,
         }
     }
 
@@ -302,6 +348,79 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Reserves room for a widget in the titles of the block.
+    ///
+    /// The slot is laid out like a title of the given `width` at the given position and alignment,
+    /// so that the other titles are placed around it, and it is rendered as blank space. The
+    /// widget itself is rendered after the block with [`Block::render_title_widget`], or in the
+    /// area returned by [`Block::title_slot_areas`]. This makes it possible to display a small
+    /// widget (e.g. a spinner, [`Tabs`] or a [`LineGauge`]) in the border of the block.
+    ///
+    /// Slots are numbered from `0` in the order in which they are added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     buffer::Buffer,
+    ///     layout::{Alignment, Rect},
+    ///     widgets::{block::Position, Block, Tabs, Widget},
+    /// };
+    ///
+    /// let block = Block::bordered()
+    ///     .title("Files")
+    ///     .title_slot(13, Position::Top, Alignment::Right);
+    /// let tabs = Tabs::new(["All", "Recent"]).padding("", "").divider(" | ");
+    ///
+    /// let area = Rect::new(0, 0, 24, 3);
+    /// let mut buf = Buffer::empty(area);
+    /// Widget::render(&block, area, &mut buf);
+    /// block.render_title_widget(0, tabs, area, &mut buf);
+    /// assert_eq!(buf[(10, 0)].symbol(), "A");
+    /// ```
+    ///
+    /// [`Tabs`]: crate::tabs::Tabs
+    /// [`LineGauge`]: crate::gauge::LineGauge
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn title_slot(mut self, width: u16, position: Position, alignment: Alignment) -> Self {
+        let line = Line::from(" ".repeat(width as usize)).alignment(alignment);
+        self.title_slots.push(self.titles.len());
+        self.titles.push((Some(position), line));
+        self
+    }
+
+    /// Adds a label embedded in a border of the block.
+    ///
+    /// Unlike titles, labels can be placed on any edge of the block at a given offset, e.g. a
+    /// keybinding hint in the bottom right corner or a counter on the right edge. See
+    /// [`BorderLabel`] for more information.
+    ///
+    /// Labels are rendered after the titles, so they are displayed on top of them if they overlap.
+    /// Note that labels don't reserve any space: the top and bottom rows of the block are only
+    /// part of its border if the corresponding [`Borders`] are enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{
+    ///     block::{BorderLabel, Edge, LabelAnchor},
+    ///     Block,
+    /// };
+    ///
+    /// Block::bordered()
+    ///     .title("Files")
+    ///     .border_label(BorderLabel::new(Edge::Bottom, "q quit").anchor(LabelAnchor::End));
+    /// // Renders
+    /// // ┌Files─────┐
+    /// // │          │
+    /// // └────q quit┘
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn border_label(mut self, label: BorderLabel<'a>) -> Self {
+        self.labels.push(label);
+        self
+    }
+
     /// Applies the style to all titles.
     ///
     /// This style will be applied to all titles of the block. If a title has a style set, it will
@@ -396,6 +515,30 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Colors the borders with a gradient from `start` to `end`.
+    ///
+    /// The gradient goes diagonally from the top left corner of the block, which has the `start`
+    /// color, to its bottom right corner, which has the `end` color, so the color of each border
+    /// edge is interpolated along the edge and the corners are shared by adjacent edges. The
+    /// colors are interpolated in RGB (see [`Color::lerp`]).
+    ///
+    /// The gradient sets the foreground color of the borders on top of the
+    /// [`border_style`](Block::border_style), and titles that don't set a foreground color are
+    /// displayed with the color of the border below them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{style::Color, widgets::Block};
+    ///
+    /// Block::bordered().border_gradient(Color::Rgb(255, 0, 128), Color::Rgb(0, 128, 255));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn border_gradient(mut self, start: Color, end: Color) -> Self {
+        self.border_gradient = Some((start, end));
+        self
+    }
+
     /// Defines the style of the entire block.
     ///
     /// This is the most generic [`Style`] a block can receive, it will be merged with any other
@@ -440,6 +583,49 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Defines the style applied over the content of the block.
+    ///
+    /// Unlike [`Block::style`], which is applied before the content is rendered and can be
+    /// overridden by the widgets inside the block, this style is applied on top of everything
+    /// rendered in the [inner area](Block::inner) of the block. This makes it easy to display a
+    /// whole panel as disabled or unfocused, without restyling each of its child widgets.
+    ///
+    /// As the content is rendered after the block, the overlay is applied by calling
+    /// [`Block::render_content_overlay`] once the content is rendered.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::{Block, Paragraph},
+    ///     Frame,
+    /// };
+    ///
+    /// # fn render(frame: &mut Frame, focused: bool) {
+    /// let block = Block::bordered().title("Details");
+    /// let block = if focused {
+    ///     block
+    /// } else {
+    ///     block.content_overlay_style(Style::new().dark_gray().dim())
+    /// };
+    /// let area = frame.area();
+    /// frame.render_widget(&block, area);
+    /// frame.render_widget(Paragraph::new("content".green()), block.inner(area));
+    /// block.render_content_overlay(area, frame.buffer_mut());
+    /// # }
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn content_overlay_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.content_overlay_style = style.into();
+        self
+    }
+
     /// Defines which borders to display.
     ///
     /// [`Borders`] can also be styled with [`Block::border_style`] and [`Block::border_type`].
@@ -488,6 +674,9 @@ impl<'a> Block<'a> {
     ///
     /// Setting this overwrites any [`border_type`](Block::border_type) that was set.
     ///
+    /// The builder methods of [`border::Set`] can be used to mix the symbols of different sets,
+    /// e.g. to use different corners.
+    ///
     /// # Examples
     ///
     /// ```
@@ -498,12 +687,145 @@ impl<'a> Block<'a> {
     /// // ╔Block╗
     /// // ║     ║
     /// // ╚═════╝
+    ///
+    /// let set = symbols::border::PLAIN.with_top_corners_from(symbols::border::ROUNDED);
+    /// Block::bordered().border_set(set).title("Block");
+    /// // Renders
+    /// // ╭Block╮
+    /// // │     │
+    /// // └─────┘
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn border_set(mut self, border_set: border::Set) -> Self {
         self.border_set = border_set;
         self
     }
 
+    /// Sets the symbols used to display the borders on the given sides.
+    ///
+    /// This overrides the [`border_type`](Block::border_type) or [`border_set`](Block::border_set)
+    /// of the block for these sides only, e.g. to display a thick top border with plain sides.
+    /// The corners are displayed with the symbols of the top or bottom side they belong to.
+    ///
+    /// See [`Block::side_border_set`] to use a custom set of symbols.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::{Block, BorderType, Borders};
+    ///
+    /// Block::bordered()
+    ///     .side_border_type(Borders::TOP, BorderType::Thick)
+    ///     .title("Block");
+    /// // Renders
+    /// // ┏Block┓
+    /// // │     │
+    /// // └─────┘
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn side_border_type(self, sides: Borders, border_type: BorderType) -> Self {
+        self.side_border_set(sides, border_type.to_border_set())
+    }
+
+    /// Sets the symbols used to display the borders on the given sides as a
+    /// [`ratatui_core::symbols::border::Set`].
+    ///
+    /// This overrides the [`border_type`](Block::border_type) or [`border_set`](Block::border_set)
+    /// of the block for these sides only. Each side uses the symbols of the set that belong to it
+    /// (e.g. [`horizontal_top`](border::Set::horizontal_top) for the top side), and the corners
+    /// are displayed with the symbols of the top or bottom side they belong to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     symbols,
+    ///     widgets::{Block, Borders},
+    /// };
+    ///
+    /// Block::bordered().side_border_set(Borders::TOP | Borders::BOTTOM, symbols::border::DOUBLE);
+    /// // Renders
+    /// // ╔═════╗
+    /// // │     │
+    /// // ╚═════╝
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn side_border_set(mut self, sides: Borders, border_set: border::Set) -> Self {
+        let mut index = 0;
+        while index < SIDES.len() {
+            if sides.contains(SIDES[index]) {
+                self.side_border_sets[index] = Some(border_set);
+            }
+            index += 1;
+        }
+        self
+    }
+
+    /// Defines the style of the borders on the given sides.
+    ///
+    /// The style is patched onto the [`border_style`](Block::border_style) for these sides only,
+    /// e.g. to highlight the top border of a block. The corners are styled like the top or bottom
+    /// side they belong to.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::{Block, Borders},
+    /// };
+    ///
+    /// Block::bordered()
+    ///     .border_style(Style::new().dark_gray())
+    ///     .side_border_style(Borders::TOP, Style::new().yellow().bold());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn side_border_style<S: Into<Style>>(mut self, sides: Borders, style: S) -> Self {
+        let style = style.into();
+        for (index, side) in SIDES.into_iter().enumerate() {
+            if sides.contains(side) {
+                self.side_border_styles[index] = Some(style);
+            }
+        }
+        self
+    }
+
+    /// Joins the borders of the block with the borders already rendered in the buffer.
+    ///
+    /// By default, the borders of a block replace whatever is rendered below them. When the
+    /// borders are merged, the border symbols are combined with the box drawing symbols below
+    /// them, so that the borders of blocks that overlap by one cell are connected with the
+    /// matching joints (e.g. `┬`, `├` or `┼`) and multi-pane layouts look like a single frame. See
+    /// [`merge`](ratatui_core::symbols::merge::merge) for how the symbols are combined.
+    ///
+    /// The blocks should be rendered in areas that overlap by one cell, e.g. with a
+    /// [`Layout`](ratatui_core::layout::Layout) that has a
+    /// [`Spacing::Overlap`](ratatui_core::layout::Spacing::Overlap) of 1, and all of them but the
+    /// first one must merge their borders.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     buffer::Buffer,
+    ///     layout::Rect,
+    ///     widgets::{Block, Widget},
+    /// };
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+    /// let block = Block::bordered().merge_borders(true);
+    /// Widget::render(&block, Rect::new(0, 0, 4, 3), &mut buf);
+    /// Widget::render(&block, Rect::new(3, 0, 4, 3), &mut buf);
+    /// assert_eq!(buf, Buffer::with_lines(["┌──┬──┐", "│  │  │", "└──┴──┘"]));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn merge_borders(mut self, merge_borders: bool) -> Self {
+        self.merge_borders = merge_borders;
+        self
+    }
+
     /// Defines the padding inside a `Block`.
     ///
     /// See [`Padding`] for more information.
@@ -538,6 +860,33 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Adds a drop shadow to the block.
+    ///
+    /// The shadow is displayed in the column to the right of the block and in the row below it,
+    /// offset by one cell, so it is rendered outside of the area of the block. Make sure that there
+    /// is room for it, e.g. by reducing the area of a popup by one cell on these sides. The parts
+    /// of the shadow that are outside of the buffer are not rendered.
+    ///
+    /// See [`ShadowStyle`] for the available styles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{block::ShadowStyle, Block};
+    ///
+    /// Block::bordered().title("Popup").shadow(ShadowStyle::Shade);
+    /// // Renders
+    /// // ┌Popup┐
+    /// // │     │░
+    /// // └─────┘░
+    /// //  ░░░░░░░
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn shadow(mut self, shadow: ShadowStyle) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
     /// Compute the inner area of a block based on its border visibility rules.
     ///
     /// # Examples
@@ -594,6 +943,65 @@ impl<'a> Block<'a> {
         inner
     }
 
+    /// Returns the areas of the [title slots](Block::title_slot) of the block rendered in the
+    /// given area, in the order in which the slots were added.
+    ///
+    /// The area of a slot is empty if the block is too small to display it. Parts of a slot can be
+    /// hidden by other titles if the block is too small to display all the titles.
+    pub fn title_slot_areas(&self, area: Rect) -> Vec<Rect> {
+        self.title_slots
+            .iter()
+            .map(|&index| {
+                self.title_area(index, area)
+                    .unwrap_or_else(|| Rect::new(area.x, area.y, 0, 0))
+            })
+            .collect()
+    }
+
+    /// Returns the inner area of the block if it is expanded, or `None` if it is collapsed.
+    ///
+    /// See [`BlockState`] for more information about collapsible blocks.
+    pub fn inner_if_expanded(&self, area: Rect, state: &BlockState) -> Option<Rect> {
+        (!state.is_collapsed()).then(|| self.inner(area))
+    }
+
+    /// Renders a widget in a [title slot](Block::title_slot) of the block rendered in the given
+    /// area.
+    ///
+    /// This does nothing if there is no slot with this index, or if the block is too small to
+    /// display it. The block must be rendered before the widget, as it blanks the slot.
+    pub fn render_title_widget<W: Widget>(
+        &self,
+        slot: usize,
+        widget: W,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let Some(&index) = self.title_slots.get(slot) else {
+            return;
+        };
+        let Some(slot_area) = self.title_area(index, area) else {
+            return;
+        };
+        let slot_area = slot_area.intersection(buf.area);
+        if !slot_area.is_empty() {
+            widget.render(slot_area, buf);
+        }
+    }
+
+    /// Applies the [content overlay style](Block::content_overlay_style) of the block rendered in
+    /// the given area over its inner area.
+    ///
+    /// This must be called after the content of the block is rendered. It does nothing if the
+    /// block has no content overlay style.
+    pub fn render_content_overlay(&self, area: Rect, buf: &mut Buffer) {
+        if self.content_overlay_style == Style::new() {
+            return;
+        }
+        let inner = self.inner(area).intersection(buf.area);
+        buf.set_style(inner, self.content_overlay_style);
+    }
+
     fn has_title_at_position(&self, position: Position) -> bool {
         self.titles
             .iter()
@@ -609,17 +1017,69 @@ impl Widget for Block<'_> {
 
 impl Widget for &Block<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        if let Some(shadow) = self.shadow {
+            shadow.render(area, buf);
+        }
         let area = area.intersection(buf.area);
         if area.is_empty() {
             return;
         }
         buf.set_style(area, self.style);
+        let previous_symbols = self.previous_border_symbols(area, buf);
         self.render_borders(area, buf);
+        Block::merge_border_symbols(previous_symbols, buf);
         self.render_titles(area, buf);
     }
 }
 
+/// The sides of a block, in the order of the side border sets and styles
+const SIDES: [Borders; 4] = [Borders::TOP, Borders::RIGHT, Borders::BOTTOM, Borders::LEFT];
+
+/// Renders the block according to its [`BlockState`], i.e. only its title bar when it is collapsed.
+impl StatefulWidget for &Block<'_> {
+    type State = BlockState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if !state.is_collapsed() {
+            Widget::render(self, area, buf);
+            return;
+        }
+        let area = Rect {
+            height: area.height.min(1),
+            ..area
+        };
+        if let Some(shadow) = self.shadow {
+            shadow.render(area, buf);
+        }
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, self.style);
+        let previous_symbols = self.previous_border_symbols(area, buf);
+        self.render_left_side(area, buf);
+        self.render_top_side(area, buf);
+        self.render_right_side(area, buf);
+        self.render_top_right_corner(buf, area);
+        self.render_top_left_corner(buf, area);
+        self.render_border_gradient(area, buf);
+        Block::merge_border_symbols(previous_symbols, buf);
+        self.render_title_position(Position::Top, area, buf);
+    }
+}
+
 impl Block<'_> {
+    /// Returns the symbols and the style of the border on the given side
+    fn side_border(&self, side: Borders) -> (&border::Set, Style) {
+        let index = SIDES.iter().position(|&s| s == side).unwrap_or_default();
+        let set = self.side_border_sets[index]
+            .as_ref()
+            .unwrap_or(&self.border_set);
+        let style = self.side_border_styles[index]
+            .map_or(self.border_style, |style| self.border_style.patch(style));
+        (set, style)
+    }
+
     fn render_borders(&self, area: Rect, buf: &mut Buffer) {
         self.render_left_side(area, buf);
         self.render_top_side(area, buf);
@@ -630,107 +1090,240 @@ impl Block<'_> {
         self.render_top_right_corner(buf, area);
         self.render_bottom_left_corner(buf, area);
         self.render_top_left_corner(buf, area);
+
+        self.render_border_gradient(area, buf);
+    }
+
+    /// Returns the symbols below the borders of the block, if the borders are merged
+    fn previous_border_symbols(&self, area: Rect, buf: &Buffer) -> Vec<(u16, u16, String)> {
+        if !self.merge_borders {
+            return Vec::new();
+        }
+        area.positions()
+            .filter(|position| {
+                (self.borders.contains(Borders::LEFT) && position.x == area.left())
+                    || (self.borders.contains(Borders::RIGHT) && position.x == area.right() - 1)
+                    || (self.borders.contains(Borders::TOP) && position.y == area.top())
+                    || (self.borders.contains(Borders::BOTTOM) && position.y == area.bottom() - 1)
+            })
+            .map(|position| {
+                let symbol = buf[position].symbol().to_string();
+                (position.x, position.y, symbol)
+            })
+            .collect()
+    }
+
+    /// Merges the symbols of the borders with the symbols that were below them
+    fn merge_border_symbols(previous_symbols: Vec<(u16, u16, String)>, buf: &mut Buffer) {
+        for (x, y, previous) in previous_symbols {
+            let cell = &mut buf[(x, y)];
+            if let Some(symbol) = merge::merge(&previous, cell.symbol()) {
+                cell.set_symbol(symbol);
+            }
+        }
+    }
+
+    fn render_border_gradient(&self, area: Rect, buf: &mut Buffer) {
+        let Some((start, end)) = self.border_gradient else {
+            return;
+        };
+        let length = f64::from(area.width - 1) + f64::from(area.height - 1);
+        let mut paint = |x: u16, y: u16| {
+            let distance = f64::from(x - area.left()) + f64::from(y - area.top());
+            let t = if length > 0.0 { distance / length } else { 0.0 };
+            buf[(x, y)].set_fg(start.lerp(end, t));
+        };
+        if self.borders.contains(Borders::TOP) {
+            for x in area.left()..area.right() {
+                paint(x, area.top());
+            }
+        }
+        if self.borders.contains(Borders::BOTTOM) {
+            for x in area.left()..area.right() {
+                paint(x, area.bottom() - 1);
+            }
+        }
+        if self.borders.contains(Borders::LEFT) {
+            for y in area.top()..area.bottom() {
+                paint(area.left(), y);
+            }
+        }
+        if self.borders.contains(Borders::RIGHT) {
+            for y in area.top()..area.bottom() {
+                paint(area.right() - 1, y);
+            }
+        }
     }
 
     fn render_titles(&self, area: Rect, buf: &mut Buffer) {
         self.render_title_position(Position::Top, area, buf);
         self.render_title_position(Position::Bottom, area, buf);
+        self.render_labels(area, buf);
+    }
+
+    fn render_labels(&self, area: Rect, buf: &mut Buffer) {
+        for label in &self.labels {
+            let edge_area = match label.edge {
+                Edge::Top => self.titles_area(area, Position::Top),
+                Edge::Bottom => self.titles_area(area, Position::Bottom),
+                Edge::Left | Edge::Right => {
+                    let top = u16::from(self.borders.contains(Borders::TOP));
+                    let bottom = u16::from(self.borders.contains(Borders::BOTTOM));
+                    Rect {
+                        x: if label.edge == Edge::Left {
+                            area.left()
+                        } else {
+                            area.right() - 1
+                        },
+                        y: area.top() + top,
+                        width: 1,
+                        height: area.height.saturating_sub(top + bottom),
+                    }
+                }
+            };
+            label.render(edge_area, self.titles_style, buf);
+        }
     }
 
     fn render_title_position(&self, position: Position, area: Rect, buf: &mut Buffer) {
-        // NOTE: the order in which these functions are called defines the overlapping behavior
-        self.render_right_titles(position, area, buf);
-        self.render_center_titles(position, area, buf);
-        self.render_left_titles(position, area, buf);
+        // NOTE: the order in which the titles are rendered defines the overlapping behavior
+        let layout = self
+            .right_titles_layout(position, area)
+            .into_iter()
+            .chain(self.center_titles_layout(position, area))
+            .chain(self.left_titles_layout(position, area));
+        for (index, title_area) in layout {
+            buf.set_style(title_area, self.titles_style);
+            Widget::render(&self.titles[index].1, title_area, buf);
+        }
+    }
+
+    /// Returns the area of the title with the given index
+    fn title_area(&self, index: usize, area: Rect) -> Option<Rect> {
+        let (position, line) = self.titles.get(index)?;
+        let position = position.unwrap_or(self.titles_position);
+        let layout = match line.alignment.unwrap_or(self.titles_alignment) {
+            Alignment::Left => self.left_titles_layout(position, area),
+            Alignment::Center => self.center_titles_layout(position, area),
+            Alignment::Right => self.right_titles_layout(position, area),
+        };
+        layout
+            .into_iter()
+            .find(|&(title_index, _)| title_index == index)
+            .map(|(_, title_area)| title_area)
     }
 
     fn render_left_side(&self, area: Rect, buf: &mut Buffer) {
         if self.borders.contains(Borders::LEFT) {
-            for y in area.top()..area.bottom() {
+            let (set, style) = self.side_border(Borders::LEFT);
+            for y in self.vertical_side_rows(area) {
                 buf[(area.left(), y)]
-                    .set_symbol(self.border_set.vertical_left)
-                    .set_style(self.border_style);
+                    .set_symbol(set.vertical_left)
+                    .set_style(style);
             }
         }
     }
 
+    /// Returns the rows of the left and right sides, leaving out the corners which have the style
+    /// of the top and bottom sides
+    const fn vertical_side_rows(&self, area: Rect) -> Range<u16> {
+        let top = if self.borders.contains(Borders::TOP) {
+            area.top() + 1
+        } else {
+            area.top()
+        };
+        let bottom = if self.borders.contains(Borders::BOTTOM) {
+            area.bottom() - 1
+        } else {
+            area.bottom()
+        };
+        top..bottom
+    }
+
     fn render_top_side(&self, area: Rect, buf: &mut Buffer) {
         if self.borders.contains(Borders::TOP) {
+            let (set, style) = self.side_border(Borders::TOP);
             for x in area.left()..area.right() {
                 buf[(x, area.top())]
-                    .set_symbol(self.border_set.horizontal_top)
-                    .set_style(self.border_style);
+                    .set_symbol(set.horizontal_top)
+                    .set_style(style);
             }
         }
     }
 
     fn render_right_side(&self, area: Rect, buf: &mut Buffer) {
         if self.borders.contains(Borders::RIGHT) {
+            let (set, style) = self.side_border(Borders::RIGHT);
             let x = area.right() - 1;
-            for y in area.top()..area.bottom() {
-                buf[(x, y)]
-                    .set_symbol(self.border_set.vertical_right)
-                    .set_style(self.border_style);
+            for y in self.vertical_side_rows(area) {
+                buf[(x, y)].set_symbol(set.vertical_right).set_style(style);
             }
         }
     }
 
     fn render_bottom_side(&self, area: Rect, buf: &mut Buffer) {
         if self.borders.contains(Borders::BOTTOM) {
+            let (set, style) = self.side_border(Borders::BOTTOM);
             let y = area.bottom() - 1;
             for x in area.left()..area.right() {
                 buf[(x, y)]
-                    .set_symbol(self.border_set.horizontal_bottom)
-                    .set_style(self.border_style);
+                    .set_symbol(set.horizontal_bottom)
+                    .set_style(style);
             }
         }
     }
 
     fn render_bottom_right_corner(&self, buf: &mut Buffer, area: Rect) {
         if self.borders.contains(Borders::RIGHT | Borders::BOTTOM) {
+            let (set, style) = self.side_border(Borders::BOTTOM);
             buf[(area.right() - 1, area.bottom() - 1)]
-                .set_symbol(self.border_set.bottom_right)
-                .set_style(self.border_style);
+                .set_symbol(set.bottom_right)
+                .set_style(style);
         }
     }
 
     fn render_top_right_corner(&self, buf: &mut Buffer, area: Rect) {
         if self.borders.contains(Borders::RIGHT | Borders::TOP) {
+            let (set, style) = self.side_border(Borders::TOP);
             buf[(area.right() - 1, area.top())]
-                .set_symbol(self.border_set.top_right)
-                .set_style(self.border_style);
+                .set_symbol(set.top_right)
+                .set_style(style);
         }
     }
 
     fn render_bottom_left_corner(&self, buf: &mut Buffer, area: Rect) {
         if self.borders.contains(Borders::LEFT | Borders::BOTTOM) {
+            let (set, style) = self.side_border(Borders::BOTTOM);
             buf[(area.left(), area.bottom() - 1)]
-                .set_symbol(self.border_set.bottom_left)
-                .set_style(self.border_style);
+                .set_symbol(set.bottom_left)
+                .set_style(style);
         }
     }
 
     fn render_top_left_corner(&self, buf: &mut Buffer, area: Rect) {
         if self.borders.contains(Borders::LEFT | Borders::TOP) {
+            let (set, style) = self.side_border(Borders::TOP);
             buf[(area.left(), area.top())]
-                .set_symbol(self.border_set.top_left)
-                .set_style(self.border_style);
+                .set_symbol(set.top_left)
+                .set_style(style);
         }
     }
 
-    /// Render titles aligned to the right of the block
+    /// Lays out the titles aligned to the right of the block, returning the index and area of
+    /// each title
     ///
     /// Currently (due to the way lines are truncated), the right side of the leftmost title will
     /// be cut off if the block is too small to fit all titles. This is not ideal and should be
     /// the left side of that leftmost that is cut off. This is due to the line being truncated
     /// incorrectly. See <https://github.com/ratatui/ratatui/issues/932>
     #[allow(clippy::similar_names)]
-    fn render_right_titles(&self, position: Position, area: Rect, buf: &mut Buffer) {
+    fn right_titles_layout(&self, position: Position, area: Rect) -> Vec<(usize, Rect)> {
         let titles = self.filtered_titles(position, Alignment::Right);
         let mut titles_area = self.titles_area(area, position);
+        let mut layout = Vec::new();
 
-        // render titles in reverse order to align them to the right
-        for title in titles.rev() {
+        // lay out titles in reverse order to align them to the right
+        for (index, title) in titles.rev() {
             if titles_area.is_empty() {
                 break;
             }
@@ -743,8 +1336,7 @@ impl Block<'_> {
                 width: title_width.min(titles_area.width),
                 ..titles_area
             };
-            buf.set_style(title_area, self.titles_style);
-            title.render(title_area, buf);
+            layout.push((index, title_area));
 
             // bump the width of the titles area to the left
             titles_area.width = titles_area
@@ -752,53 +1344,49 @@ impl Block<'_> {
                 .saturating_sub(title_width)
                 .saturating_sub(1); // space between titles
         }
+        layout
     }
 
-    /// Render titles in the center of the block
+    /// Lays out the titles in the center of the block, returning the index and area of each title
     ///
     /// Currently this method aligns the titles to the left inside a centered area. This is not
     /// ideal and should be fixed in the future to align the titles to the center of the block and
     /// truncate both sides of the titles if the block is too small to fit all titles.
     #[allow(clippy::similar_names)]
-    fn render_center_titles(&self, position: Position, area: Rect, buf: &mut Buffer) {
+    fn center_titles_layout(&self, position: Position, area: Rect) -> Vec<(usize, Rect)> {
         let titles = self
             .filtered_titles(position, Alignment::Center)
             .collect_vec();
         let total_width = titles
             .iter()
-            .map(|title| title.width() as u16 + 1) // space between titles
+            .map(|(_, title)| title.width() as u16 + 1) // space between titles
             .sum::<u16>()
             .saturating_sub(1); // no space for the last title
 
         let titles_area = self.titles_area(area, position);
-        let mut titles_area = Rect {
+        let titles_area = Rect {
             x: titles_area.left() + (titles_area.width.saturating_sub(total_width) / 2),
             ..titles_area
         };
-        for title in titles {
-            if titles_area.is_empty() {
-                break;
-            }
-            let title_width = title.width() as u16;
-            let title_area = Rect {
-                width: title_width.min(titles_area.width),
-                ..titles_area
-            };
-            buf.set_style(title_area, self.titles_style);
-            title.render(title_area, buf);
+        Self::titles_layout_from_left(titles, titles_area)
+    }
 
-            // bump the titles area to the right and reduce its width
-            titles_area.x = titles_area.x.saturating_add(title_width + 1);
-            titles_area.width = titles_area.width.saturating_sub(title_width + 1);
-        }
+    /// Lays out the titles aligned to the left of the block, returning the index and area of each
+    /// title
+    fn left_titles_layout(&self, position: Position, area: Rect) -> Vec<(usize, Rect)> {
+        let titles = self.filtered_titles(position, Alignment::Left);
+        let titles_area = self.titles_area(area, position);
+        Self::titles_layout_from_left(titles, titles_area)
     }
 
-    /// Render titles aligned to the left of the block
+    /// Lays out titles from the left of the titles area, separated by a space
     #[allow(clippy::similar_names)]
-    fn render_left_titles(&self, position: Position, area: Rect, buf: &mut Buffer) {
-        let titles = self.filtered_titles(position, Alignment::Left);
-        let mut titles_area = self.titles_area(area, position);
-        for title in titles {
+    fn titles_layout_from_left<'t, 'l: 't>(
+        titles: impl IntoIterator<Item = (usize, &'t Line<'l>)>,
+        mut titles_area: Rect,
+    ) -> Vec<(usize, Rect)> {
+        let mut layout = Vec::new();
+        for (index, title) in titles {
             if titles_area.is_empty() {
                 break;
             }
@@ -807,26 +1395,29 @@ impl Block<'_> {
                 width: title_width.min(titles_area.width),
                 ..titles_area
             };
-            buf.set_style(title_area, self.titles_style);
-            title.render(title_area, buf);
+            layout.push((index, title_area));
 
             // bump the titles area to the right and reduce its width
             titles_area.x = titles_area.x.saturating_add(title_width + 1);
             titles_area.width = titles_area.width.saturating_sub(title_width + 1);
         }
+        layout
     }
 
-    /// An iterator over the titles that match the position and alignment
+    /// An iterator over the titles that match the position and alignment, with their index
     fn filtered_titles(
         &self,
         position: Position,
         alignment: Alignment,
-    ) -> impl DoubleEndedIterator<Item = &Line> {
+    ) -> impl DoubleEndedIterator<Item = (usize, &Line<'_>)> {
         self.titles
             .iter()
-            .filter(move |(pos, _)| pos.unwrap_or(self.titles_position) == position)
-            .filter(move |(_, line)| line.alignment.unwrap_or(self.titles_alignment) == alignment)
-            .map(|(_, line)| line)
+            .enumerate()
+            .filter(move |(_, (pos, _))| pos.unwrap_or(self.titles_position) == position)
+            .filter(move |(_, (_, line))| {
+                line.alignment.unwrap_or(self.titles_alignment) == alignment
+            })
+            .map(|(index, (_, line))| (index, line))
     }
 
     /// An area that is one line tall and spans the width of the block excluding the borders and
@@ -909,7 +1500,7 @@ impl Styled for Block<'_> {
 
 #[cfg(test)]
 mod tests {
-    use ratatui_core::style::{Color, Modifier, Stylize};
+    use ratatui_core::style::{Modifier, Stylize};
     use rstest::rstest;
     use strum::ParseError;
 
@@ -1146,14 +1737,22 @@ mod tests {
             Block::new(),
             Block {
                 titles: Vec::new(),
+                title_slots: Vec::new(),
+                labels: Vec::new(),
                 titles_style: Style::new(),
                 titles_alignment: Alignment::Left,
                 titles_position: Position::Top,
                 borders: Borders::NONE,
                 border_style: Style::new(),
                 border_set: BorderType::Plain.to_border_set(),
+                side_border_sets: [None; 4],
+                side_border_styles: [None; 4],
+                border_gradient: None,
                 style: Style::new(),
                 padding: Padding::ZERO,
+                shadow: None,
+                content_overlay_style: Style::new(),
+                merge_borders: false,
             }
         );
     }
@@ -1490,6 +2089,172 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn render_title_widget() {
+        let block = Block::bordered()
+            .title("L")
+            .title_slot(3, Position::Top, Alignment::Right)
+            .title_bottom(Line::from("C").centered())
+            .title_slot(2, Position::Bottom, Alignment::Center);
+        let area = Rect::new(0, 0, 10, 3);
+        assert_eq!(
+            block.title_slot_areas(area),
+            [Rect::new(6, 0, 3, 1), Rect::new(5, 2, 2, 1)]
+        );
+        let mut buffer = Buffer::empty(area);
+        Widget::render(&block, area, &mut buffer);
+        block.render_title_widget(0, Line::from("abc"), area, &mut buffer);
+        block.render_title_widget(1, Line::from("xyz"), area, &mut buffer);
+        block.render_title_widget(2, Line::from("nope"), area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["┌L────abc┐", "│        │", "└──C─xy──┘"])
+        );
+    }
+
+    #[test]
+    fn title_slot_areas_when_too_small() {
+        let block = Block::bordered().title_slot(3, Position::Top, Alignment::Left);
+        assert_eq!(
+            block.title_slot_areas(Rect::new(1, 1, 2, 2)),
+            [Rect::new(1, 1, 0, 0)]
+        );
+    }
+
+    #[test]
+    fn render_content_overlay() {
+        let block = Block::bordered().content_overlay_style(Style::new().dark_gray().dim());
+        let area = Rect::new(0, 0, 6, 3);
+        let mut buffer = Buffer::empty(area);
+        Widget::render(&block, area, &mut buffer);
+        Line::from("text".red().bold()).render(block.inner(area), &mut buffer);
+        block.render_content_overlay(area, &mut buffer);
+        let mut expected = Buffer::with_lines(["┌────┐", "│text│", "└────┘"]);
+        expected.set_style(Rect::new(1, 1, 4, 1), Style::new().dark_gray().dim());
+        expected.set_style(Rect::new(1, 1, 4, 1), Style::new().bold());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_merged_borders() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 5));
+        Block::bordered().render(Rect::new(0, 0, 7, 5), &mut buffer);
+        let block = Block::bordered().merge_borders(true);
+        Widget::render(&block, Rect::new(0, 0, 4, 3), &mut buffer);
+        Widget::render(&block, Rect::new(3, 0, 4, 3), &mut buffer);
+        Widget::render(&block, Rect::new(0, 2, 7, 3), &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["┌──┬──┐", "│  │  │", "├──┴──┤", "│     │", "└─────┘",])
+        );
+    }
+
+    #[test]
+    fn render_merged_borders_with_titles() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        Block::bordered().render(Rect::new(0, 0, 4, 3), &mut buffer);
+        Block::bordered()
+            .border_type(BorderType::Thick)
+            .title("T")
+            .merge_borders(true)
+            .render(Rect::new(3, 0, 4, 3), &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["┌──┲T━┓", "│  ┃  ┃", "└──┺━━┛"])
+        );
+    }
+
+    #[test]
+    fn render_border_labels() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 5));
+        Block::bordered()
+            .title("T")
+            .border_label(BorderLabel::new(Edge::Top, "x").anchor(LabelAnchor::End))
+            .border_label(
+                BorderLabel::new(Edge::Bottom, "q")
+                    .anchor(LabelAnchor::End)
+                    .offset(1),
+            )
+            .border_label(BorderLabel::new(Edge::Left, "ab").anchor(LabelAnchor::Center))
+            .border_label(BorderLabel::new(Edge::Right, "1/2").offset(1))
+            .render(buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["┌T────x┐", "a      │", "b      1", "│      /", "└────q─┘",])
+        );
+    }
+
+    #[test]
+    fn render_collapsed() {
+        let block = Block::bordered()
+            .title("Top")
+            .title_bottom("Bottom")
+            .borders(Borders::ALL);
+        let mut state = BlockState::new().with_collapsed(true);
+        let area = Rect::new(0, 0, 10, 3);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(&block, area, &mut buffer, &mut state);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["┌Top─────┐", "          ", "          "])
+        );
+        assert_eq!(block.inner_if_expanded(area, &state), None);
+
+        state.expand();
+        StatefulWidget::render(&block, area, &mut buffer, &mut state);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["┌Top─────┐", "│        │", "└Bottom──┘"])
+        );
+        assert_eq!(
+            block.inner_if_expanded(area, &state),
+            Some(Rect::new(1, 1, 8, 1))
+        );
+    }
+
+    #[test]
+    fn render_shadow() {
+        let mut buffer = Buffer::with_lines(["xxxxx", "xxxxx", "xxxxx", "xxxxx"]);
+        Block::bordered()
+            .shadow(ShadowStyle::Shade)
+            .render(Rect::new(0, 0, 4, 3), &mut buffer);
+        let mut expected = Buffer::with_lines(["┌──┐x", "│xx│░", "└──┘░", "x░░░░"]);
+        expected.set_style(Rect::new(4, 1, 1, 3), Color::DarkGray);
+        expected.set_style(Rect::new(1, 3, 4, 1), Color::DarkGray);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_side_borders() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Block::bordered()
+            .side_border_type(Borders::TOP, BorderType::Thick)
+            .side_border_set(Borders::RIGHT | Borders::BOTTOM, border::DOUBLE)
+            .side_border_style(Borders::LEFT, Color::Red)
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["┏━━━┓", "│   ║", "╚═══╝"]);
+        expected.set_style(Rect::new(0, 1, 1, 1), Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_border_gradient() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Block::bordered()
+            .border_gradient(Color::Rgb(0, 0, 0), Color::Rgb(0, 0, 120))
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["┌───┐", "│   │", "└───┘"]);
+        for y in 0..3_u16 {
+            for x in 0..5_u16 {
+                if x == 0 || x == 4 || y == 0 || y == 2 {
+                    let blue = (x + y) as u8 * 20;
+                    expected[(x, y)].set_fg(Color::Rgb(0, 0, blue));
+                }
+            }
+        }
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn render_custom_border_set() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
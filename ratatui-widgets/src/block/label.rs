@@ -0,0 +1,205 @@
+//! This module holds the [`BorderLabel`] element and its related configuration types.
+//! A border label is a piece of [`Block`](crate::block::Block) configuration.
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    text::{DisplayWidth, Line, StyledGrapheme},
+    widgets::Widget,
+};
+use strum::{Display, EnumString};
+
+/// A label embedded in a border of a [`Block`](crate::block::Block) at an arbitrary position.
+///
+/// Unlike titles, which are laid out next to each other at the top or bottom of the block, a label
+/// is placed at a given offset from the start, center or end of any edge (see [`LabelAnchor`]).
+/// This is useful for a keybinding hint in the bottom right corner, or a counter on the right edge.
+///
+/// Labels on the left and right edges are displayed vertically, one character per row from top to
+/// bottom, and characters wider than one cell are not displayed there. Labels are displayed in
+/// the area of the edge between the corners, and are truncated if they don't fit.
+///
+/// Labels are styled like titles: the [title style](crate::block::Block::title_style) of the
+/// block is applied first, then the style of the line.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::{
+///     block::{BorderLabel, Edge, LabelAnchor},
+///     Block,
+/// };
+///
+/// Block::bordered()
+///     .border_label(
+///         BorderLabel::new(Edge::Bottom, "q quit")
+///             .anchor(LabelAnchor::End)
+///             .offset(1),
+///     )
+///     .border_label(BorderLabel::new(Edge::Right, "3/9"));
+/// // Renders
+/// // ┌────────────┐
+/// // │            3
+/// // │            /
+/// // │            9
+/// // └─────q quit─┘
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct BorderLabel<'a> {
+    /// The content of the label
+    pub content: Line<'a>,
+    /// The edge of the block on which the label is displayed
+    pub edge: Edge,
+    /// Where the label is placed along the edge
+    pub anchor: LabelAnchor,
+    /// The number of cells between the anchor and the label
+    pub offset: u16,
+}
+
+/// An edge of a [`Block`](crate::block::Block).
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Edge {
+    /// The top edge
+    #[default]
+    Top,
+    /// The right edge
+    Right,
+    /// The bottom edge
+    Bottom,
+    /// The left edge
+    Left,
+}
+
+/// The position along an edge from which a [`BorderLabel`] is placed.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum LabelAnchor {
+    /// The label is placed after the offset from the start of the edge (the left of horizontal
+    /// edges and the top of vertical edges)
+    #[default]
+    Start,
+    /// The label is centered on the edge, then moved forward by the offset
+    Center,
+    /// The label is placed before the offset from the end of the edge (the right of horizontal
+    /// edges and the bottom of vertical edges)
+    End,
+}
+
+impl<'a> BorderLabel<'a> {
+    /// Creates a new label with the given content on the given edge
+    ///
+    /// The label is placed at the start of the edge by default.
+    pub fn new<T: Into<Line<'a>>>(edge: Edge, content: T) -> Self {
+        Self {
+            content: content.into(),
+            edge,
+            anchor: LabelAnchor::Start,
+            offset: 0,
+        }
+    }
+
+    /// Sets where the label is placed along the edge
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn anchor(mut self, anchor: LabelAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Sets the number of cells between the anchor and the label
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn offset(mut self, offset: u16) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Renders the label in the area of its edge, with the given base style
+    pub(crate) fn render(&self, edge_area: Rect, style: Style, buf: &mut Buffer) {
+        let vertical = matches!(self.edge, Edge::Left | Edge::Right);
+        let length = if vertical {
+            edge_area.height
+        } else {
+            edge_area.width
+        };
+        let width = if vertical {
+            self.vertical_graphemes(style).count() as u16
+        } else {
+            self.content.width() as u16
+        };
+        let start = match self.anchor {
+            LabelAnchor::Start => self.offset,
+            LabelAnchor::Center => (length.saturating_sub(width) / 2).saturating_add(self.offset),
+            LabelAnchor::End => length.saturating_sub(width).saturating_sub(self.offset),
+        }
+        .min(length);
+        let visible = width.min(length - start);
+        if visible == 0 {
+            return;
+        }
+        if vertical {
+            let graphemes = self.vertical_graphemes(style).take(visible as usize);
+            for (y, grapheme) in (edge_area.y + start..).zip(graphemes) {
+                buf[(edge_area.x, y)]
+                    .set_symbol(grapheme.symbol)
+                    .set_style(grapheme.style);
+            }
+        } else {
+            let area = Rect {
+                x: edge_area.x + start,
+                width: visible,
+                ..edge_area
+            };
+            buf.set_style(area, style);
+            Widget::render(&self.content, area, buf);
+        }
+    }
+
+    /// The graphemes of the label that are displayed on a vertical edge
+    fn vertical_graphemes(&self, style: Style) -> impl Iterator<Item = StyledGrapheme<'_>> {
+        self.content
+            .styled_graphemes(style)
+            .filter(|grapheme| grapheme.symbol.display_width() == 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::style::{Color, Stylize};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::start(LabelAnchor::Start, 0, "ab     ")]
+    #[case::start_offset(LabelAnchor::Start, 2, "  ab   ")]
+    #[case::center(LabelAnchor::Center, 0, "  ab   ")]
+    #[case::center_offset(LabelAnchor::Center, 1, "   ab  ")]
+    #[case::end(LabelAnchor::End, 0, "     ab")]
+    #[case::end_offset(LabelAnchor::End, 1, "    ab ")]
+    #[case::truncated(LabelAnchor::Start, 6, "      a")]
+    #[case::outside(LabelAnchor::Start, 7, "       ")]
+    fn render_horizontal(#[case] anchor: LabelAnchor, #[case] offset: u16, #[case] expected: &str) {
+        let label = BorderLabel::new(Edge::Top, "ab")
+            .anchor(anchor)
+            .offset(offset);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 1));
+        label.render(buf.area, Style::new(), &mut buf);
+        assert_eq!(buf, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn render_vertical() {
+        let label = BorderLabel::new(Edge::Right, Line::from("a界b".red()))
+            .anchor(LabelAnchor::End)
+            .offset(1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 4));
+        label.render(buf.area, Style::new().bold(), &mut buf);
+        let mut expected = Buffer::with_lines([" ", "a", "b", " "]);
+        expected.set_style(Rect::new(0, 1, 1, 2), Style::new().fg(Color::Red).bold());
+        assert_eq!(buf, expected);
+    }
+}
@@ -0,0 +1,106 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    symbols::shade,
+};
+
+/// Defines how the drop shadow of a [`Block`] is displayed.
+///
+/// See the [`shadow`] method of [`Block`] to add a shadow to a block.
+///
+/// The shadow covers the column to the right of the block and the row below it, offset by one cell
+/// so that the block looks like it is floating above the content behind it. This is mostly useful
+/// for popups.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::{block::ShadowStyle, Block};
+///
+/// Block::bordered().shadow(ShadowStyle::Dim);
+/// Block::bordered().shadow(ShadowStyle::Shade);
+/// ```
+///
+/// [`Block`]: crate::block::Block
+/// [`shadow`]: crate::block::Block::shadow
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ShadowStyle {
+    /// Keeps the content below the shadow and dims it
+    ///
+    /// ```plain
+    /// ┌────┐
+    /// │    │content
+    /// └────┘ontent
+    ///  content
+    /// ```
+    ///
+    /// The content in the shadow is displayed in dark gray with the [`Modifier::DIM`] modifier.
+    #[default]
+    Dim,
+    /// Replaces the content below the shadow with light shade characters
+    ///
+    /// ```plain
+    /// ┌────┐
+    /// │    │░
+    /// └────┘░
+    ///  ░░░░░░
+    /// ```
+    Shade,
+}
+
+impl ShadowStyle {
+    /// Renders the shadow of a block that covers the given area
+    pub(crate) fn render(self, area: Rect, buf: &mut Buffer) {
+        let right = Rect::new(area.right(), area.top().saturating_add(1), 1, area.height);
+        let bottom = Rect::new(area.left().saturating_add(1), area.bottom(), area.width, 1);
+        for shadow in [right, bottom] {
+            let shadow = shadow.intersection(buf.area);
+            if shadow.is_empty() {
+                continue;
+            }
+            for position in shadow.positions() {
+                let cell = &mut buf[position];
+                match self {
+                    Self::Dim => {
+                        cell.set_style(
+                            Style::new().fg(Color::DarkGray).add_modifier(Modifier::DIM),
+                        );
+                    }
+                    Self::Shade => {
+                        cell.set_symbol(shade::LIGHT).set_fg(Color::DarkGray);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn render_shade() {
+        let mut buf = Buffer::with_lines(["xxxx", "xxxx", "xxxx", "xxxx"]);
+        ShadowStyle::Shade.render(Rect::new(0, 0, 2, 2), &mut buf);
+        let mut expected = Buffer::with_lines(["xxxx", "xx░x", "x░░x", "xxxx"]);
+        expected.set_style(Rect::new(2, 1, 1, 2), Color::DarkGray);
+        expected.set_style(Rect::new(1, 2, 1, 1), Color::DarkGray);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_dim_is_clipped() {
+        let mut buf = Buffer::with_lines(["xxx", "xxx", "xxx"]);
+        ShadowStyle::Dim.render(Rect::new(1, 1, 2, 1), &mut buf);
+        let mut expected = Buffer::with_lines(["xxx", "xxx", "xxx"]);
+        expected.set_style(
+            Rect::new(2, 2, 1, 1),
+            Style::new().fg(Color::DarkGray).add_modifier(Modifier::DIM),
+        );
+        assert_eq!(buf, expected);
+    }
+}
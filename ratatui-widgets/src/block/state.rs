@@ -0,0 +1,96 @@
+/// State of a collapsible [`Block`]
+///
+/// When a block is rendered as a stateful widget, it is either expanded, in which case it is
+/// rendered as usual, or collapsed, in which case only its title bar (the top row with the top
+/// border and titles) is rendered. This is useful for accordion-style panels, where the user can
+/// collapse the panels they are not interested in.
+///
+/// Use [`Block::inner_if_expanded`] to get the area in which to render the content of the block,
+/// which is `None` when the block is collapsed.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     widgets::{block::BlockState, Block, StatefulWidget},
+/// };
+///
+/// // This should be stored outside of the render function in your application state.
+/// let mut state = BlockState::default();
+/// state.toggle();
+///
+/// let block = Block::bordered().title("▶ Details");
+/// let area = Rect::new(0, 0, 12, 4);
+/// let mut buf = Buffer::empty(area);
+/// StatefulWidget::render(&block, area, &mut buf, &mut state);
+/// assert_eq!(block.inner_if_expanded(area, &state), None);
+/// assert_eq!(
+///     buf,
+///     Buffer::with_lines(["┌▶ Details─┐", "", "", ""])
+/// );
+/// ```
+///
+/// [`Block`]: super::Block
+/// [`Block::inner_if_expanded`]: super::Block::inner_if_expanded
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockState {
+    collapsed: bool,
+}
+
+impl BlockState {
+    /// Creates a new expanded block state
+    pub const fn new() -> Self {
+        Self { collapsed: false }
+    }
+
+    /// Sets whether the block is collapsed
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    /// Returns whether the block is collapsed
+    pub const fn is_collapsed(&self) -> bool {
+        self.collapsed
+    }
+
+    /// Collapses the block
+    pub fn collapse(&mut self) {
+        self.collapsed = true;
+    }
+
+    /// Expands the block
+    pub fn expand(&mut self) {
+        self.collapsed = false;
+    }
+
+    /// Collapses the block if it is expanded, and expands it otherwise
+    pub fn toggle(&mut self) {
+        self.collapsed = !self.collapsed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle() {
+        let mut state = BlockState::new();
+        assert!(!state.is_collapsed());
+        state.toggle();
+        assert!(state.is_collapsed());
+        state.toggle();
+        assert!(!state.is_collapsed());
+        state.collapse();
+        assert_eq!(state, BlockState::new().with_collapsed(true));
+        state.expand();
+        assert_eq!(state, BlockState::default());
+    }
+}
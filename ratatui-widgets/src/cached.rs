@@ -0,0 +1,194 @@
+//! The [`Cached`] widget wrapper renders a widget only when its content changes.
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{StatefulWidget, Widget},
+};
+
+/// A wrapper that caches the rendered output of a widget
+///
+/// Some widgets are expensive to render but rarely change, e.g. a panel of syntax highlighted
+/// code or a large table of static data. `Cached` renders the wrapped widget into an offscreen
+/// [`Buffer`] stored in a [`CachedState`], and only renders it again when the user provided cache
+/// key or the area changes. Otherwise the cells of the cached buffer are copied into the
+/// destination buffer, which is much cheaper than rendering the widget.
+///
+/// The key can be any value that identifies the content of the widget, e.g. a version counter or
+/// a hash of the data that the widget displays. It is the responsibility of the caller to change
+/// the key when the output of the widget would change.
+///
+/// The widget is rendered into an empty buffer, so the cached output covers the entire area:
+/// cells that the widget does not draw are reset rather than left untouched.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::{
+///     widgets::{Cached, CachedState, Paragraph},
+///     Frame,
+/// };
+///
+/// struct App {
+///     source: String,
+///     version: u64,
+///     cache: CachedState<u64>,
+/// }
+///
+/// fn draw(frame: &mut Frame, app: &mut App) {
+///     let code = Paragraph::new(app.source.as_str());
+///     let area = frame.area();
+///     frame.render_stateful_widget(Cached::new(app.version, code), area, &mut app.cache);
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Cached<K, W> {
+    key: K,
+    widget: W,
+}
+
+/// State of the [`Cached`] widget wrapper
+///
+/// The state holds the rendered output of the wrapped widget and the key it was rendered with.
+/// It should be stored in the application state between frames.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CachedState<K> {
+    key: Option<K>,
+    buffer: Buffer,
+}
+
+impl<K, W> Cached<K, W> {
+    /// Creates a new cached widget that is identified by the given key
+    pub const fn new(key: K, widget: W) -> Self {
+        Self { key, widget }
+    }
+}
+
+impl<K> CachedState<K> {
+    /// Creates a new empty cache
+    pub const fn new() -> Self {
+        Self {
+            key: None,
+            buffer: Buffer {
+                area: Rect::ZERO,
+                content: Vec::new(),
+            },
+        }
+    }
+
+    /// Returns the key of the cached output, if any
+    pub const fn key(&self) -> Option<&K> {
+        self.key.as_ref()
+    }
+
+    /// Returns whether the cache holds the output of a widget with the given key and area
+    pub fn is_valid(&self, key: &K, area: Rect) -> bool
+    where
+        K: PartialEq,
+    {
+        self.key.as_ref() == Some(key) && self.buffer.area == area
+    }
+
+    /// Clears the cache, so that the next render renders the wrapped widget again
+    pub fn invalidate(&mut self) {
+        self.key = None;
+    }
+}
+
+impl<K> Default for CachedState<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: PartialEq, W: Widget> StatefulWidget for Cached<K, W> {
+    type State = CachedState<K>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if !state.is_valid(&self.key, area) {
+            state.buffer.resize(area);
+            state.buffer.reset();
+            self.widget.render(area, &mut state.buffer);
+            state.key = Some(self.key);
+        }
+        let area = area.intersection(buf.area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                buf[(x, y)].clone_from(&state.buffer[(x, y)]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use pretty_assertions::assert_eq;
+    use ratatui_core::style::Style;
+
+    use super::*;
+
+    /// A widget that counts how many times it was rendered
+    struct Counter<'a>(&'a Cell<usize>, &'a str);
+
+    impl Widget for Counter<'_> {
+        fn render(self, area: Rect, buf: &mut Buffer) {
+            self.0.set(self.0.get() + 1);
+            buf.set_string(area.x, area.y, self.1, Style::new());
+        }
+    }
+
+    #[test]
+    fn renders_only_when_key_changes() {
+        let count = Cell::new(0);
+        let mut state = CachedState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+
+        Cached::new(1, Counter(&count, "one")).render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["one  "]));
+        assert_eq!(count.get(), 1);
+        assert_eq!(state.key(), Some(&1));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Cached::new(1, Counter(&count, "two")).render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["one  "]));
+        assert_eq!(count.get(), 1);
+
+        Cached::new(2, Counter(&count, "two")).render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["two  "]));
+        assert_eq!(count.get(), 2);
+    }
+
+    #[test]
+    fn renders_when_area_changes() {
+        let count = Cell::new(0);
+        let mut state = CachedState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+
+        Cached::new((), Counter(&count, "abc")).render(Rect::new(0, 0, 5, 1), &mut buf, &mut state);
+        Cached::new((), Counter(&count, "abc")).render(Rect::new(1, 1, 4, 1), &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["abc  ", " abc "]));
+        assert_eq!(count.get(), 2);
+    }
+
+    #[test]
+    fn invalidate() {
+        let count = Cell::new(0);
+        let mut state = CachedState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+
+        Cached::new(0, Counter(&count, "abc")).render(buf.area, &mut buf, &mut state);
+        state.invalidate();
+        Cached::new(0, Counter(&count, "abc")).render(buf.area, &mut buf, &mut state);
+        assert_eq!(count.get(), 2);
+    }
+
+    #[test]
+    fn overwrites_destination() {
+        let count = Cell::new(0);
+        let mut state = CachedState::default();
+        let mut buf = Buffer::with_lines(["xxxx"]);
+        Cached::new(0, Counter(&count, "ab")).render(Rect::new(1, 0, 4, 1), &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["xab "]));
+    }
+}
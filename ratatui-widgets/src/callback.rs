@@ -0,0 +1,77 @@
+//! A shared closure stored in the configuration of the widgets.
+
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+/// A closure of one argument, shared between the clones of a widget
+///
+/// Widgets store the closures given to their builder methods (e.g. value formatters or color
+/// functions) in this wrapper, so that they stay cheap to clone and keep implementing the common
+/// traits. The closure must be [`Send`] and [`Sync`] so that the widgets are too.
+///
+/// Two callbacks are equal if they are clones of the same callback, i.e. if they share the same
+/// closure. Callbacks created from the same closure expression are not equal.
+pub(crate) struct Callback<A, R>(Arc<dyn Fn(A) -> R + Send + Sync>);
+
+impl<A, R> Callback<A, R> {
+    /// Creates a callback from a closure
+    pub(crate) fn new<F>(f: F) -> Self
+    where
+        F: Fn(A) -> R + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    /// Calls the closure with the given argument
+    pub(crate) fn call(&self, arg: A) -> R {
+        (self.0)(arg)
+    }
+
+    /// Returns the address of the shared closure, which identifies the callback
+    fn address(&self) -> *const () {
+        Arc::as_ptr(&self.0).cast::<()>()
+    }
+}
+
+impl<A, R> Clone for Callback<A, R> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<A, R> fmt::Debug for Callback<A, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Callback")
+    }
+}
+
+impl<A, R> PartialEq for Callback<A, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.address() == other.address()
+    }
+}
+
+impl<A, R> Eq for Callback<A, R> {}
+
+impl<A, R> Hash for Callback<A, R> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.address().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_are_equal() {
+        let unit = String::from("ms");
+        let callback = Callback::new(move |value: u64| format!("{value}{unit}"));
+        assert_eq!(callback.call(3), "3ms");
+        assert_eq!(callback, callback.clone());
+        assert_ne!(callback, Callback::new(|value: u64| format!("{value}ms")));
+    }
+}
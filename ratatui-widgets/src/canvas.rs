@@ -5,11 +5,17 @@
 //!
 //! The available shapes are:
 //!
+//! - [`Arc`]: A part of a circle or an ellipse, optionally joined to its center
+//! - [`Bezier`]: A quadratic or cubic Bézier curve
 //! - [`Circle`]: A basic circle
+//! - [`Ellipse`]: A basic ellipse
+//! - `Image`: An image from raw pixel data (requires the `canvas-image` feature)
 //! - [`Line`]: A line between two points
 //! - [`Map`]: A world map
 //! - [`Points`]: A scatter of points
+//! - [`Polygon`]: A polygon, optionally filled
 //! - [`Rectangle`]: A basic rectangle
+//! - [`Sprite`]: A grid of colored points, stamped as is
 //!
 //! You can also implement your own custom [`Shape`]s.
 
@@ -22,23 +28,39 @@ use ratatui_core::{
     style::{Color, Style},
     symbols::{self, Marker},
     text::Line as TextLine,
-    widgets::Widget,
+    widgets::{StatefulWidget, Widget},
 };
 
+#[cfg(feature = "canvas-image")]
+pub use self::image::{Image, PixelFormat};
 pub use self::{
+    arc::Arc,
+    bezier::Bezier,
     circle::Circle,
-    line::Line,
+    ellipse::Ellipse,
+    line::{Line, Stroke},
     map::{Map, MapResolution},
     points::Points,
+    polygon::Polygon,
     rectangle::Rectangle,
+    sprite::Sprite,
+    state::CanvasState,
 };
 use crate::block::{Block, BlockExt};
 
+mod arc;
+mod bezier;
 mod circle;
+mod ellipse;
+#[cfg(feature = "canvas-image")]
+mod image;
 mod line;
 mod map;
 mod points;
+mod polygon;
 mod rectangle;
+mod sprite;
+mod state;
 mod world;
 
 /// Something that can be drawn on a [`Canvas`].
@@ -63,9 +85,12 @@ pub struct Label<'a> {
 /// A single layer of the canvas.
 ///
 /// This allows the canvas to be drawn in multiple layers. This is useful if you want to draw
-/// multiple shapes on the canvas in specific order.
+/// multiple shapes on the canvas in specific order. Layers are drawn by increasing z-index, and
+/// in the order they were saved for the same z-index.
 #[derive(Debug)]
 struct Layer {
+    // The z-index of the layer, layers with a higher z-index are drawn on top
+    z_index: i32,
     // A string of characters representing the grid. This will be wrapped to the width of the grid
     // when rendering
     string: String,
@@ -144,7 +169,11 @@ impl Grid for BrailleGrid {
         let string = String::from_utf16(&self.utf16_code_points).unwrap();
         // the background color is always reset for braille patterns
         let colors = self.colors.iter().map(|c| (*c, Color::Reset)).collect();
-        Layer { string, colors }
+        Layer {
+            z_index: 0,
+            string,
+            colors,
+        }
     }
 
     fn reset(&mut self) {
@@ -165,6 +194,76 @@ impl Grid for BrailleGrid {
     }
 }
 
+/// The `OctantGrid` is a grid made up of cells each containing an octant character.
+///
+/// This makes it possible to draw shapes with a resolution of 2x4 pixels per cell, like the
+/// `BrailleGrid`, but with solid pixels that fill the cell instead of dots. Font support for the
+/// Unicode 16 octant characters is required to see the pixels.
+///
+/// This grid type only supports a single foreground color for each 2x4 pixels cell.
+#[derive(Debug)]
+struct OctantGrid {
+    /// Width of the grid in number of terminal columns
+    width: u16,
+    /// Height of the grid in number of terminal rows
+    height: u16,
+    /// The pixels that are set in each cell, which index into [`symbols::octant::OCTANTS`]
+    cells: Vec<u8>,
+    /// The foreground color of each cell
+    colors: Vec<Color>,
+}
+
+impl OctantGrid {
+    /// Create a new `OctantGrid` with the given width and height measured in terminal columns and
+    /// rows respectively.
+    fn new(width: u16, height: u16) -> Self {
+        let length = usize::from(width * height);
+        Self {
+            width,
+            height,
+            cells: vec![symbols::octant::BLANK; length],
+            colors: vec![Color::Reset; length],
+        }
+    }
+}
+
+impl Grid for OctantGrid {
+    fn resolution(&self) -> (f64, f64) {
+        (f64::from(self.width) * 2.0, f64::from(self.height) * 4.0)
+    }
+
+    fn save(&self) -> Layer {
+        let string = self
+            .cells
+            .iter()
+            .map(|&cell| symbols::octant::OCTANTS[usize::from(cell)])
+            .collect();
+        let colors = self.colors.iter().map(|c| (*c, Color::Reset)).collect();
+        Layer {
+            z_index: 0,
+            string,
+            colors,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.cells.fill(symbols::octant::BLANK);
+        self.colors.fill(Color::Reset);
+    }
+
+    fn paint(&mut self, x: usize, y: usize, color: Color) {
+        let index = y / 4 * self.width as usize + x / 2;
+        // using get_mut here because we are indexing the vector with usize values
+        // and we want to make sure we don't panic if the index is out of bounds
+        if let Some(c) = self.cells.get_mut(index) {
+            *c |= symbols::octant::DOTS[y % 4][x % 2];
+        }
+        if let Some(c) = self.colors.get_mut(index) {
+            *c = color;
+        }
+    }
+}
+
 /// The `CharGrid` is a grid made up of cells each containing a single character.
 ///
 /// This makes it possible to draw shapes with a resolution of 1x1 dots per cell. This is useful
@@ -205,6 +304,7 @@ impl Grid for CharGrid {
 
     fn save(&self) -> Layer {
         Layer {
+            z_index: 0,
             string: self.cells.iter().collect(),
             colors: self.colors.iter().map(|c| (*c, Color::Reset)).collect(),
         }
@@ -326,7 +426,11 @@ impl Grid for HalfBlockGrid {
             })
             .collect();
 
-        Layer { string, colors }
+        Layer {
+            z_index: 0,
+            string,
+            colors,
+        }
     }
 
     fn reset(&mut self) {
@@ -466,6 +570,7 @@ pub struct Context<'a> {
     y_bounds: [f64; 2],
     grid: Box<dyn Grid>,
     dirty: bool,
+    z_index: i32,
     layers: Vec<Layer>,
     labels: Vec<Label<'a>>,
 }
@@ -509,12 +614,14 @@ impl<'a> Context<'a> {
             Marker::Bar => Box::new(CharGrid::new(width, height, bar)),
             Marker::Braille => Box::new(BrailleGrid::new(width, height)),
             Marker::HalfBlock => Box::new(HalfBlockGrid::new(width, height)),
+            Marker::Octant => Box::new(OctantGrid::new(width, height)),
         };
         Self {
             x_bounds,
             y_bounds,
             grid,
             dirty: false,
+            z_index: 0,
             layers: Vec::new(),
             labels: Vec::new(),
         }
@@ -538,11 +645,70 @@ impl<'a> Context<'a> {
     /// This allows the canvas to be drawn in multiple layers. This is useful if you want to
     /// draw multiple shapes on the [`Canvas`] in specific order.
     pub fn layer(&mut self) {
-        self.layers.push(self.grid.save());
+        let mut layer = self.grid.save();
+        layer.z_index = self.z_index;
+        self.layers.push(layer);
         self.grid.reset();
         self.dirty = false;
     }
 
+    /// Start a new layer with the given z-index.
+    ///
+    /// The current layer is saved (see [`Context::layer`]) and the next shapes are drawn on a layer
+    /// with the given z-index. Layers are drawn on the [`Canvas`] by increasing z-index, whatever
+    /// the order in which they were drawn, and in the order they were saved for the same z-index.
+    /// The default z-index is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::Color,
+    ///     symbols,
+    ///     widgets::canvas::{Circle, Context, Rectangle},
+    /// };
+    ///
+    /// let mut ctx = Context::new(10, 5, [0.0, 10.0], [0.0, 10.0], symbols::Marker::Braille);
+    /// // the circle is drawn above the rectangle
+    /// ctx.z_index(1);
+    /// ctx.draw(&Circle::new(5.0, 5.0, 3.0, Color::Red));
+    /// ctx.z_index(0);
+    /// ctx.draw(&Rectangle::new(2.0, 2.0, 6.0, 6.0, Color::Blue));
+    /// ```
+    pub fn z_index(&mut self, z_index: i32) {
+        self.finish();
+        self.z_index = z_index;
+    }
+
+    /// Draw the given [`Shape`] on its own layer with the given z-index
+    ///
+    /// The shape is drawn on a new layer, and the next shapes are drawn on a new layer with the
+    /// current z-index. See [`Context::z_index`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::Color,
+    ///     symbols,
+    ///     widgets::canvas::{Context, Line, Rectangle},
+    /// };
+    ///
+    /// let mut ctx = Context::new(10, 5, [0.0, 10.0], [0.0, 10.0], symbols::Marker::Braille);
+    /// ctx.draw(&Rectangle::new(2.0, 2.0, 6.0, 6.0, Color::Blue));
+    /// // the line is drawn below the rectangle, which stays on the default z-index
+    /// ctx.draw_with_z_index(-1, &Line::new(0.0, 5.0, 10.0, 5.0, Color::Red));
+    /// ```
+    pub fn draw_with_z_index<S>(&mut self, z_index: i32, shape: &S)
+    where
+        S: Shape,
+    {
+        let current = self.z_index;
+        self.z_index(z_index);
+        self.draw(shape);
+        self.z_index(current);
+    }
+
     /// Print a [`Text`] on the [`Canvas`] at the given position.
     ///
     /// Note that the text is always printed on top of the canvas and is **not** affected by the
@@ -590,9 +756,13 @@ impl<'a> Context<'a> {
 /// The [`Context`] object provides a [`Context::draw`] method that can be used to draw shapes on
 /// the canvas. The [`Context::layer`] method can be used to save the current state of the canvas
 /// and start a new layer. This is useful if you want to draw multiple shapes on the canvas in
-/// specific order. The [`Context`] object also provides a [`Context::print`] method that can be
-/// used to print text on the canvas. Note that the text is always printed on top of the canvas and
-/// is not affected by the layers.
+/// specific order. The [`Context::z_index`] method can be used to order the layers regardless of
+/// the order in which they are drawn. The [`Context`] object also provides a [`Context::print`]
+/// method that can be used to print text on the canvas. Note that the text is always printed on top
+/// of the canvas and is not affected by the layers.
+///
+/// The canvas can also be rendered as a stateful widget with a [`CanvasState`], which stores the
+/// visible bounds so that the scene can be panned and zoomed, e.g. with the mouse.
 ///
 /// # Examples
 ///
@@ -615,20 +785,8 @@ impl<'a> Context<'a> {
 ///             color: Color::White,
 ///         });
 ///         ctx.layer();
-///         ctx.draw(&Line {
-///             x1: 0.0,
-///             y1: 10.0,
-///             x2: 10.0,
-///             y2: 10.0,
-///             color: Color::White,
-///         });
-///         ctx.draw(&Rectangle {
-///             x: 10.0,
-///             y: 20.0,
-///             width: 10.0,
-///             height: 10.0,
-///             color: Color::Red,
-///         });
+///         ctx.draw(&Line::new(0.0, 10.0, 10.0, 10.0, Color::White));
+///         ctx.draw(&Rectangle::new(10.0, 20.0, 10.0, 10.0, Color::Red));
 ///     });
 /// ```
 ///
@@ -729,7 +887,12 @@ where
     /// cell. This allows for more flexibility than the `BrailleGrid` which only supports a single
     /// foreground color for each 2x4 dots cell.
     ///
+    /// The [`Octant`] marker has the same resolution as [`Braille`], but draws solid pixels that
+    /// fill the cell instead of dots. It requires a font that supports the Unicode 16 octant
+    /// characters.
+    ///
     /// [`Braille`]: ratatui_core::symbols::Marker::Braille
+    /// [`Octant`]: ratatui_core::symbols::Marker::Octant
     /// [`HalfBlock`]: ratatui_core::symbols::Marker::HalfBlock
     /// [`Dot`]: ratatui_core::symbols::Marker::Dot
     /// [`Block`]: ratatui_core::symbols::Marker::Block
@@ -748,6 +911,10 @@ where
     ///     .paint(|ctx| {});
     ///
     /// Canvas::default()
+    ///     .marker(symbols::Marker::Octant)
+    ///     .paint(|ctx| {});
+    ///
+    /// Canvas::default()
     ///     .marker(symbols::Marker::Dot)
     ///     .paint(|ctx| {});
     ///
@@ -776,8 +943,24 @@ where
     F: Fn(&mut Context),
 {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = CanvasState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+// `StatefulWidget` is only implemented for `&Canvas` so that calling `render` on an owned canvas
+// stays unambiguous when both widget traits are in scope.
+impl<F> StatefulWidget for &Canvas<'_, F>
+where
+    F: Fn(&mut Context),
+{
+    type State = CanvasState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         self.block.as_ref().render(area, buf);
         let canvas_area = self.block.inner_if_some(area);
+        state.area = canvas_area;
+        let [x_bounds, y_bounds] = *state.bounds.get_or_insert([self.x_bounds, self.y_bounds]);
         if canvas_area.is_empty() {
             return;
         }
@@ -794,13 +977,14 @@ where
         let mut ctx = Context::new(
             canvas_area.width,
             canvas_area.height,
-            self.x_bounds,
-            self.y_bounds,
+            x_bounds,
+            y_bounds,
             self.marker,
         );
         // Paint to this context
         painter(&mut ctx);
         ctx.finish();
+        ctx.layers.sort_by_key(|layer| layer.z_index);
 
         // Retrieve painted points for each layer
         for layer in ctx.layers {
@@ -822,12 +1006,10 @@ where
         }
 
         // Finally draw the labels
-        let left = self.x_bounds[0];
-        let right = self.x_bounds[1];
-        let top = self.y_bounds[1];
-        let bottom = self.y_bounds[0];
-        let width = (self.x_bounds[1] - self.x_bounds[0]).abs();
-        let height = (self.y_bounds[1] - self.y_bounds[0]).abs();
+        let [left, right] = x_bounds;
+        let [bottom, top] = y_bounds;
+        let width = (right - left).abs();
+        let height = (top - bottom).abs();
         let resolution = {
             let width = f64::from(canvas_area.width - 1);
             let height = f64::from(canvas_area.height - 1);
@@ -857,20 +1039,8 @@ mod tests {
     fn test_marker(marker: Marker, expected: &str) {
         let area = Rect::new(0, 0, 5, 5);
         let mut buf = Buffer::filled(area, Cell::new("x"));
-        let horizontal_line = Line {
-            x1: 0.0,
-            y1: 0.0,
-            x2: 10.0,
-            y2: 0.0,
-            color: Color::Reset,
-        };
-        let vertical_line = Line {
-            x1: 0.0,
-            y1: 0.0,
-            x2: 0.0,
-            y2: 10.0,
-            color: Color::Reset,
-        };
+        let horizontal_line = Line::new(0.0, 0.0, 10.0, 0.0, Color::Reset);
+        let vertical_line = Line::new(0.0, 0.0, 0.0, 10.0, Color::Reset);
         Canvas::default()
             .marker(marker)
             .paint(|ctx| {
@@ -928,6 +1098,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_octant_marker() {
+        test_marker(
+            Marker::Octant,
+            indoc!(
+                "
+                ▌xxxx
+                ▌xxxx
+                ▌xxxx
+                ▌xxxx
+                𜷀▂▂▂▂"
+            ),
+        );
+    }
+
+    #[test]
+    fn z_index() {
+        let area = Rect::new(0, 0, 3, 3);
+        let mut buf = Buffer::empty(area);
+        let horizontal_line = Line::new(0.0, 1.0, 2.0, 1.0, Color::Red);
+        let vertical_line = Line::new(1.0, 0.0, 1.0, 2.0, Color::Blue);
+        Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 2.0])
+            .y_bounds([0.0, 2.0])
+            .paint(|ctx| {
+                ctx.draw_with_z_index(1, &horizontal_line);
+                ctx.draw(&vertical_line);
+            })
+            .render(area, &mut buf);
+        let mut expected = Buffer::with_lines([" █ ", "███", " █ "]);
+        expected.set_style(Rect::new(1, 0, 1, 3), Style::new().fg(Color::Blue));
+        expected.set_style(Rect::new(0, 1, 3, 1), Style::new().fg(Color::Red));
+        assert_eq!(buf, expected);
+    }
+
     #[test]
     fn test_dot_marker() {
         test_marker(
@@ -942,4 +1148,33 @@ mod tests {
             ),
         );
     }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn render_with_state() {
+        let area = Rect::new(0, 0, 5, 5);
+        let point = Points::new(&[(2.0, 2.0)], Color::Reset);
+        let canvas = Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 4.0])
+            .y_bounds([0.0, 4.0])
+            .paint(|ctx| ctx.draw(&point));
+        let mut state = CanvasState::default();
+        let mut buf = Buffer::empty(area);
+        StatefulWidget::render(&canvas, area, &mut buf, &mut state);
+        assert_eq!(state.x_bounds(), [0.0, 4.0]);
+        assert_eq!(state.y_bounds(), [0.0, 4.0]);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["     ", "     ", "  █  ", "     ", "     "])
+        );
+
+        state.pan(1, 1);
+        let mut buf = Buffer::empty(area);
+        StatefulWidget::render(&canvas, area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["     ", " █   ", "     ", "     ", "     "])
+        );
+    }
 }
@@ -0,0 +1,142 @@
+use ratatui_core::style::Color;
+
+use crate::canvas::{ellipse::draw_elliptic_arc, Line, Painter, Shape};
+
+/// A part of an ellipse between two angles, with a given color
+///
+/// The angles are in degrees, counterclockwise from the positive `x` axis, and the arc goes
+/// counterclockwise from `start_angle` to `end_angle`. When `sector` is set, the ends of the arc
+/// are also joined to the center, e.g. to draw pie-style indicators.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{style::Color, widgets::canvas::Arc};
+///
+/// // the upper half of a dial
+/// let dial = Arc::new(0.0, 0.0, 10.0, 0.0, 180.0, Color::White);
+/// // a quarter of a pie
+/// let sector = Arc::new(0.0, 0.0, 10.0, 0.0, 90.0, Color::Red).sector(true);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Arc {
+    /// `x` coordinate of the arc's center
+    pub x: f64,
+    /// `y` coordinate of the arc's center
+    pub y: f64,
+    /// Radius of the arc along the `x` axis
+    pub x_radius: f64,
+    /// Radius of the arc along the `y` axis
+    pub y_radius: f64,
+    /// Angle of the start of the arc, in degrees
+    pub start_angle: f64,
+    /// Angle of the end of the arc, in degrees
+    pub end_angle: f64,
+    /// Whether the ends of the arc are joined to the center
+    pub sector: bool,
+    /// Color of the arc
+    pub color: Color,
+}
+
+impl Arc {
+    /// Create a new arc of a circle with the given center, radius, angles in degrees, and color
+    pub const fn new(
+        x: f64,
+        y: f64,
+        radius: f64,
+        start_angle: f64,
+        end_angle: f64,
+        color: Color,
+    ) -> Self {
+        Self {
+            x,
+            y,
+            x_radius: radius,
+            y_radius: radius,
+            start_angle,
+            end_angle,
+            sector: false,
+            color,
+        }
+    }
+
+    /// Sets whether the ends of the arc are joined to the center, to draw a circle sector.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn sector(mut self, sector: bool) -> Self {
+        self.sector = sector;
+        self
+    }
+}
+
+impl Shape for Arc {
+    fn draw(&self, painter: &mut Painter) {
+        let start = self.start_angle.to_radians();
+        let mut end = self.end_angle.to_radians();
+        if end < start {
+            end += std::f64::consts::TAU;
+        }
+        let ends = draw_elliptic_arc(
+            painter,
+            (self.x, self.y),
+            (self.x_radius, self.y_radius),
+            (start, end),
+            self.color,
+        );
+        if self.sector {
+            for (x, y) in [ends.0, ends.1] {
+                Line::new(self.x, self.y, x, y, self.color).draw(painter);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{buffer::Buffer, layout::Rect, symbols::Marker, widgets::Widget};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::canvas::Canvas;
+
+    #[rstest]
+    #[case::arc(false)]
+    #[case::sector(true)]
+    fn quarter(#[case] sector: bool) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 10));
+        let arc = Arc::new(0.0, 0.0, 4.0, 0.0, 90.0, Color::Red).sector(sector);
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 9.0])
+            .y_bounds([0.0, 9.0])
+            .paint(|context| context.draw(&arc))
+            .render(buffer.area, &mut buffer);
+        // the ends of the arc
+        for position in [(4, 9), (0, 5)] {
+            assert_eq!(buffer[position].symbol(), "•", "{position:?}");
+        }
+        // the center and the radii to the ends
+        let symbol = if sector { "•" } else { " " };
+        for position in [(0, 9), (2, 9), (0, 7)] {
+            assert_eq!(buffer[position].symbol(), symbol, "{position:?}");
+        }
+        // the arc does not go past the axes
+        assert_eq!(buffer[(5, 9)].symbol(), " ");
+    }
+
+    #[test]
+    fn wraps_around() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 10));
+        let arc = Arc::new(4.0, 4.0, 4.0, 270.0, 90.0, Color::Red);
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 9.0])
+            .y_bounds([0.0, 9.0])
+            .paint(|context| context.draw(&arc))
+            .render(buffer.area, &mut buffer);
+        // the right half of the circle
+        for position in [(4, 9), (8, 5), (4, 1)] {
+            assert_eq!(buffer[position].symbol(), "•", "{position:?}");
+        }
+        assert_eq!(buffer[(0, 5)].symbol(), " ");
+    }
+}
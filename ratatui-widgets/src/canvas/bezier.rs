@@ -0,0 +1,174 @@
+use ratatui_core::style::Color;
+
+use crate::canvas::{Line, Painter, Shape};
+
+/// A cubic Bézier curve from `start` to `end`, bent towards two control points, with the given
+/// color
+///
+/// The curve starts in the direction of the first control point and ends coming from the
+/// direction of the second one. Quadratic curves, with a single control point, can be created
+/// with [`Bezier::quadratic`].
+///
+/// The curve is drawn as a sequence of short [`Line`]s, with enough of them to look smooth at the
+/// resolution of the canvas.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Bezier {
+    /// `(x, y)` of the starting point
+    pub start: (f64, f64),
+    /// `(x, y)` of the control point near the start
+    pub control1: (f64, f64),
+    /// `(x, y)` of the control point near the end
+    pub control2: (f64, f64),
+    /// `(x, y)` of the ending point
+    pub end: (f64, f64),
+    /// Color of the curve
+    pub color: Color,
+}
+
+impl Bezier {
+    /// Create a new cubic curve from `start` to `end` with two control points and the given color
+    pub const fn new(
+        start: (f64, f64),
+        control1: (f64, f64),
+        control2: (f64, f64),
+        end: (f64, f64),
+        color: Color,
+    ) -> Self {
+        Self {
+            start,
+            control1,
+            control2,
+            end,
+            color,
+        }
+    }
+
+    /// Create a new quadratic curve from `start` to `end` with a single control point and the
+    /// given color
+    ///
+    /// The curve is converted to the equivalent cubic curve.
+    pub fn quadratic(
+        start: (f64, f64),
+        control: (f64, f64),
+        end: (f64, f64),
+        color: Color,
+    ) -> Self {
+        let towards = |(x, y): (f64, f64)| {
+            (
+                (control.0 - x).mul_add(2.0 / 3.0, x),
+                (control.1 - y).mul_add(2.0 / 3.0, y),
+            )
+        };
+        Self::new(start, towards(start), towards(end), end, color)
+    }
+
+    /// Returns the point of the curve at `t`, between `0.0` (start) and `1.0` (end)
+    fn point(&self, t: f64) -> (f64, f64) {
+        let u = 1.0 - t;
+        let weights = [u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t];
+        let points = [self.start, self.control1, self.control2, self.end];
+        weights
+            .iter()
+            .zip(points)
+            .fold((0.0, 0.0), |(x, y), (weight, point)| {
+                (weight.mul_add(point.0, x), weight.mul_add(point.1, y))
+            })
+    }
+}
+
+/// The maximum number of lines used to draw a curve
+const MAX_STEPS: usize = 4096;
+
+impl Shape for Bezier {
+    fn draw(&self, painter: &mut Painter) {
+        // the curve is never longer than its control polygon, so drawing as many lines as the
+        // number of grid points along the polygon is enough to look smooth
+        let (x_bounds, y_bounds) = painter.bounds();
+        let scale = (
+            painter.resolution.0 / (x_bounds[1] - x_bounds[0]),
+            painter.resolution.1 / (y_bounds[1] - y_bounds[0]),
+        );
+        let points = [self.start, self.control1, self.control2, self.end];
+        let length: f64 = points
+            .windows(2)
+            .map(|pair| {
+                ((pair[1].0 - pair[0].0) * scale.0).hypot((pair[1].1 - pair[0].1) * scale.1)
+            })
+            .sum();
+        let steps = (length.ceil() as usize).clamp(1, MAX_STEPS);
+
+        let mut previous = self.start;
+        for step in 1..=steps {
+            let point = self.point(step as f64 / steps as f64);
+            Line::new(previous.0, previous.1, point.0, point.1, self.color).draw(painter);
+            previous = point;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{Style, Stylize},
+        symbols::Marker,
+        widgets::Widget,
+    };
+
+    use super::*;
+    use crate::canvas::Canvas;
+
+    fn render(bezier: &Bezier) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 10));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 10.0])
+            .paint(|context| context.draw(bezier))
+            .render(buffer.area, &mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn straight() {
+        let bezier = Bezier::quadratic((0.0, 0.0), (5.0, 0.0), (10.0, 0.0), Color::Red);
+        let mut expected = Buffer::with_lines([
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "          ",
+            "••••••••••",
+        ]);
+        expected.set_style(Rect::new(0, 9, 10, 1), Style::new().red());
+        assert_eq!(render(&bezier), expected);
+    }
+
+    #[test]
+    fn quadratic() {
+        let bezier = Bezier::quadratic((0.0, 0.0), (5.0, 10.0), (10.0, 0.0), Color::Red);
+        let buffer = render(&bezier);
+        // the ends and the top of the curve, halfway to the control point
+        for position in [(0, 9), (5, 5), (9, 9)] {
+            assert_eq!(buffer[position].symbol(), "•", "{position:?}");
+        }
+        for y in 0..5 {
+            for x in 0..10 {
+                assert_eq!(buffer[(x, y)].symbol(), " ", "{:?}", (x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn point() {
+        let bezier = Bezier::new((0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0), Color::Red);
+        assert_eq!(bezier.point(0.0), (0.0, 0.0));
+        assert_eq!(bezier.point(0.5), (2.0, 3.0));
+        assert_eq!(bezier.point(1.0), (4.0, 0.0));
+    }
+}
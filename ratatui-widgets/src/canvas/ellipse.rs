@@ -0,0 +1,110 @@
+use ratatui_core::style::Color;
+
+use crate::canvas::{Line, Painter, Shape};
+
+/// An ellipse with a given center and radii along the `x` and `y` axes, and with a given color
+///
+/// See [`Arc`](crate::canvas::Arc) to draw a part of an ellipse.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Ellipse {
+    /// `x` coordinate of the ellipse's center
+    pub x: f64,
+    /// `y` coordinate of the ellipse's center
+    pub y: f64,
+    /// Radius of the ellipse along the `x` axis
+    pub x_radius: f64,
+    /// Radius of the ellipse along the `y` axis
+    pub y_radius: f64,
+    /// Color of the ellipse
+    pub color: Color,
+}
+
+impl Ellipse {
+    /// Create a new ellipse with the given center, radii, and color
+    pub const fn new(x: f64, y: f64, x_radius: f64, y_radius: f64, color: Color) -> Self {
+        Self {
+            x,
+            y,
+            x_radius,
+            y_radius,
+            color,
+        }
+    }
+}
+
+impl Shape for Ellipse {
+    fn draw(&self, painter: &mut Painter) {
+        draw_elliptic_arc(
+            painter,
+            (self.x, self.y),
+            (self.x_radius, self.y_radius),
+            (0.0, std::f64::consts::TAU),
+            self.color,
+        );
+    }
+}
+
+/// The maximum number of lines used to draw an arc
+const MAX_STEPS: usize = 4096;
+
+/// Draws the part of an ellipse between the given angles, in radians, as a sequence of lines
+///
+/// Returns the ends of the arc.
+pub(super) fn draw_elliptic_arc(
+    painter: &mut Painter,
+    (x, y): (f64, f64),
+    (x_radius, y_radius): (f64, f64),
+    (start, end): (f64, f64),
+    color: Color,
+) -> ((f64, f64), (f64, f64)) {
+    let point = |angle: f64| {
+        (
+            x_radius.mul_add(angle.cos(), x),
+            y_radius.mul_add(angle.sin(), y),
+        )
+    };
+
+    // the arc is never longer than the same arc of a circle with the largest radius, so drawing
+    // as many lines as the number of grid points along that arc is enough to look smooth
+    let (x_bounds, y_bounds) = painter.bounds();
+    let radius = f64::max(
+        x_radius.abs() * painter.resolution.0 / (x_bounds[1] - x_bounds[0]),
+        y_radius.abs() * painter.resolution.1 / (y_bounds[1] - y_bounds[0]),
+    );
+    let steps = (((end - start).abs() * radius).ceil() as usize).clamp(1, MAX_STEPS);
+
+    let mut previous = point(start);
+    for step in 1..=steps {
+        let next = point((end - start).mul_add(step as f64 / steps as f64, start));
+        Line::new(previous.0, previous.1, next.0, next.1, color).draw(painter);
+        previous = next;
+    }
+    (point(start), point(end))
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{buffer::Buffer, layout::Rect, symbols::Marker, widgets::Widget};
+
+    use super::*;
+    use crate::canvas::Canvas;
+
+    #[test]
+    fn ellipse() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 10));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 9.0])
+            .y_bounds([0.0, 9.0])
+            .paint(|context| context.draw(&Ellipse::new(4.0, 4.0, 4.0, 2.0, Color::Red)))
+            .render(buffer.area, &mut buffer);
+        // the ends of the axes of the ellipse
+        for position in [(8, 5), (4, 3), (0, 5), (4, 7)] {
+            assert_eq!(buffer[position].symbol(), "•", "{position:?}");
+        }
+        // the center and the outside of the ellipse
+        for position in [(4, 5), (9, 5), (4, 2), (4, 8)] {
+            assert_eq!(buffer[position].symbol(), " ", "{position:?}");
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use ratatui_core::style::Color;
+
+use crate::canvas::{Painter, Shape};
+
+/// How the bytes of each pixel of an [`Image`] are laid out
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum PixelFormat {
+    /// Three bytes per pixel: red, green and blue. This is the default.
+    #[default]
+    Rgb,
+    /// Four bytes per pixel: red, green, blue and alpha.
+    Rgba,
+}
+
+impl PixelFormat {
+    /// Returns the number of bytes of each pixel
+    const fn bytes(self) -> usize {
+        match self {
+            Self::Rgb => 3,
+            Self::Rgba => 4,
+        }
+    }
+}
+
+/// An image drawn from raw pixel data, with the colors of its pixels
+///
+/// The pixels are given row by row, from the top left corner, in the given [`PixelFormat`]. The
+/// image is drawn in the area from `(x, y)` (its bottom left corner) of the given size, and is
+/// resampled to the grid of the canvas: each point of the grid gets the average color of the
+/// pixels it covers. Transparent points (with an average alpha below one half) are not painted.
+///
+/// The [`HalfBlock`] marker gives the best results, since it has a color for each point of the
+/// grid. The [`Braille`] marker has a higher resolution but a single color for each cell, which is
+/// the color of the last point painted in the cell. This is useful for logos and thumbnails in
+/// terminals without a graphics protocol.
+///
+/// [`HalfBlock`]: ratatui_core::symbols::Marker::HalfBlock
+/// [`Braille`]: ratatui_core::symbols::Marker::Braille
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::canvas::{Canvas, Image};
+///
+/// // a 2x2 image with red, green, blue and white pixels
+/// let pixels = [255, 0, 0, 0, 255, 0, 0, 0, 255, 255, 255, 255];
+/// Canvas::default()
+///     .x_bounds([0.0, 10.0])
+///     .y_bounds([0.0, 10.0])
+///     .paint(|ctx| ctx.draw(&Image::rgb(&pixels, 2, 2).area(0.0, 0.0, 10.0, 10.0)));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Image<'a> {
+    /// The `x` position of the image, from its bottom left corner
+    pub x: f64,
+    /// The `y` position of the image, from its bottom left corner
+    pub y: f64,
+    /// The width of the image in the coordinates of the canvas
+    pub width: f64,
+    /// The height of the image in the coordinates of the canvas
+    pub height: f64,
+    /// The bytes of the pixels, row by row
+    pub pixels: &'a [u8],
+    /// The number of pixels in each row
+    pub pixel_width: usize,
+    /// The number of rows of pixels
+    pub pixel_height: usize,
+    /// The layout of the bytes of each pixel
+    pub format: PixelFormat,
+}
+
+impl<'a> Image<'a> {
+    /// Create a new image from RGB pixels
+    ///
+    /// The image is drawn at `(0.0, 0.0)` with one unit per pixel, see [`Image::area`].
+    pub const fn rgb(pixels: &'a [u8], pixel_width: usize, pixel_height: usize) -> Self {
+        Self::new(pixels, pixel_width, pixel_height, PixelFormat::Rgb)
+    }
+
+    /// Create a new image from RGBA pixels
+    ///
+    /// The image is drawn at `(0.0, 0.0)` with one unit per pixel, see [`Image::area`].
+    pub const fn rgba(pixels: &'a [u8], pixel_width: usize, pixel_height: usize) -> Self {
+        Self::new(pixels, pixel_width, pixel_height, PixelFormat::Rgba)
+    }
+
+    const fn new(
+        pixels: &'a [u8],
+        pixel_width: usize,
+        pixel_height: usize,
+        format: PixelFormat,
+    ) -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            width: pixel_width as f64,
+            height: pixel_height as f64,
+            pixels,
+            pixel_width,
+            pixel_height,
+            format,
+        }
+    }
+
+    /// Sets the area where the image is drawn, from its bottom left corner.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn area(mut self, x: f64, y: f64, width: f64, height: f64) -> Self {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Returns the average color of the pixels in the given ranges of columns and rows, if it is
+    /// opaque enough to be painted
+    fn average(&self, pixel_columns: (usize, usize), pixel_rows: (usize, usize)) -> Option<Color> {
+        let bytes = self.format.bytes();
+        let mut sums = [0_u64; 4];
+        let mut count = 0;
+        for row in pixel_rows.0..pixel_rows.1 {
+            for column in pixel_columns.0..pixel_columns.1 {
+                let index = (row * self.pixel_width + column) * bytes;
+                let pixel = &self.pixels[index..index + bytes];
+                for (sum, &channel) in sums.iter_mut().zip(pixel) {
+                    *sum += u64::from(channel);
+                }
+                count += 1;
+            }
+        }
+        if count == 0 {
+            return None;
+        }
+        let [red, green, blue, alpha] = sums.map(|sum| (sum / count) as u8);
+        if self.format == PixelFormat::Rgba && alpha < 128 {
+            return None;
+        }
+        Some(Color::Rgb(red, green, blue))
+    }
+}
+
+/// Returns the range of pixels covered by a point of the grid, centered on `center` with the
+/// given size, with at least one pixel
+fn pixel_range(center: f64, size: f64, length: usize) -> (usize, usize) {
+    let start = ((center - size / 2.0).floor().max(0.0) as usize).min(length - 1);
+    let end = ((center + size / 2.0).ceil() as usize).clamp(start + 1, length);
+    (start, end)
+}
+
+impl Shape for Image<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        let (&[left, right], &[bottom, top]) = painter.bounds();
+        let (grid_width, grid_height) = painter.resolution;
+        if self.pixel_width == 0
+            || self.pixel_height == 0
+            || self.pixels.len() < self.pixel_width * self.pixel_height * self.format.bytes()
+            || self.width <= 0.0
+            || self.height <= 0.0
+            || right <= left
+            || top <= bottom
+        {
+            return;
+        }
+
+        // the size of a step of the grid, in pixels
+        let step = (
+            (right - left) / (grid_width - 1.0).max(1.0) * self.pixel_width as f64 / self.width,
+            (top - bottom) / (grid_height - 1.0).max(1.0) * self.pixel_height as f64 / self.height,
+        );
+        for row in 0..grid_height as usize {
+            let y = top - row as f64 * (top - bottom) / (grid_height - 1.0).max(1.0);
+            if y < self.y || y > self.y + self.height {
+                continue;
+            }
+            let v = (self.y + self.height - y) / self.height * self.pixel_height as f64;
+            let pixel_rows = pixel_range(v, step.1, self.pixel_height);
+            for column in 0..grid_width as usize {
+                let x = left + column as f64 * (right - left) / (grid_width - 1.0).max(1.0);
+                if x < self.x || x > self.x + self.width {
+                    continue;
+                }
+                let u = (x - self.x) / self.width * self.pixel_width as f64;
+                let pixel_columns = pixel_range(u, step.0, self.pixel_width);
+                if let Some(color) = self.average(pixel_columns, pixel_rows) {
+                    painter.paint(column, row, color);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        buffer::Buffer, layout::Rect, style::Style, symbols::Marker, widgets::Widget,
+    };
+
+    use super::*;
+    use crate::canvas::Canvas;
+
+    fn render(image: &Image) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        Canvas::default()
+            .marker(Marker::HalfBlock)
+            .x_bounds([0.0, 2.0])
+            .y_bounds([0.0, 2.0])
+            .paint(|context| context.draw(image))
+            .render(buffer.area, &mut buffer);
+        buffer
+    }
+
+    const RED: [u8; 3] = [255, 0, 0];
+    const GREEN: [u8; 3] = [0, 255, 0];
+    const BLUE: [u8; 3] = [0, 0, 255];
+    const WHITE: [u8; 3] = [255, 255, 255];
+
+    #[test]
+    fn rgb() {
+        let pixels = [RED, GREEN, BLUE, WHITE].concat();
+        let image = Image::rgb(&pixels, 2, 2);
+        let mut expected = Buffer::with_lines(["▀▀"]);
+        expected.set_style(
+            Rect::new(0, 0, 1, 1),
+            Style::new()
+                .fg(Color::Rgb(255, 0, 0))
+                .bg(Color::Rgb(0, 0, 255)),
+        );
+        expected.set_style(
+            Rect::new(1, 0, 1, 1),
+            Style::new()
+                .fg(Color::Rgb(0, 255, 0))
+                .bg(Color::Rgb(255, 255, 255)),
+        );
+        assert_eq!(render(&image), expected);
+    }
+
+    #[test]
+    fn downsampled() {
+        // a 4x4 image with a red left half and a blue right half
+        let row = [RED, RED, BLUE, BLUE].concat();
+        let pixels = [row.as_slice(); 4].concat();
+        let image = Image::rgb(&pixels, 4, 4).area(0.0, 0.0, 2.0, 2.0);
+        let mut expected = Buffer::with_lines(["██"]);
+        expected.set_style(
+            Rect::new(0, 0, 1, 1),
+            Style::new()
+                .fg(Color::Rgb(255, 0, 0))
+                .bg(Color::Rgb(255, 0, 0)),
+        );
+        expected.set_style(
+            Rect::new(1, 0, 1, 1),
+            Style::new()
+                .fg(Color::Rgb(0, 0, 255))
+                .bg(Color::Rgb(0, 0, 255)),
+        );
+        assert_eq!(render(&image), expected);
+    }
+
+    #[test]
+    fn transparent() {
+        let transparent = [0, 0, 0, 0];
+        let red = [255, 0, 0, 255];
+        let pixels = [transparent, transparent, red, red].concat();
+        let image = Image::rgba(&pixels, 2, 2);
+        let mut expected = Buffer::with_lines(["▄▄"]);
+        expected.set_style(
+            Rect::new(0, 0, 2, 1),
+            Style::new().fg(Color::Rgb(255, 0, 0)),
+        );
+        assert_eq!(render(&image), expected);
+    }
+
+    #[test]
+    fn invalid() {
+        let image = Image::rgb(&[255, 0, 0], 2, 2);
+        assert_eq!(render(&image), Buffer::with_lines(["  "]));
+    }
+}
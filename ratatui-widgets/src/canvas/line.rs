@@ -4,6 +4,9 @@ use ratatui_core::style::Color;
 use crate::canvas::{Painter, Shape};
 
 /// A line from `(x1, y1)` to `(x2, y2)` with the given color
+///
+/// The color can be interpolated along the line up to an [end color](Line::end_color), e.g. to
+/// convey a magnitude in vector fields, and the line can be [dashed or dotted](Line::stroke).
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct Line {
     /// `x` of the starting point
@@ -14,8 +17,12 @@ pub struct Line {
     pub x2: f64,
     /// `y` of the ending point
     pub y2: f64,
-    /// Color of the line
+    /// Color of the line, or of its starting point if it has an end color
     pub color: Color,
+    /// Color of the ending point, if the color is interpolated along the line
+    pub end_color: Option<Color>,
+    /// Whether the line is solid, dashed or dotted
+    pub stroke: Stroke,
 }
 
 impl Line {
@@ -27,8 +34,68 @@ impl Line {
             x2,
             y2,
             color,
+            end_color: None,
+            stroke: Stroke::Solid,
         }
     }
+
+    /// Interpolates the color of the line from its color at `(x1, y1)` to the given color at
+    /// `(x2, y2)`.
+    ///
+    /// The colors are interpolated in RGB (see [`Color::lerp`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{style::Color, widgets::canvas::Line};
+    ///
+    /// let line =
+    ///     Line::new(0.0, 0.0, 10.0, 5.0, Color::Rgb(0, 0, 255)).end_color(Color::Rgb(255, 0, 0));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn end_color(mut self, color: Color) -> Self {
+        self.end_color = Some(color);
+        self
+    }
+
+    /// Sets whether the line is solid, dashed or dotted.
+    ///
+    /// The pattern starts at `(x1, y1)` and is measured in points of the grid of the canvas, along
+    /// the longest axis of the line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::Color,
+    ///     widgets::canvas::{Line, Stroke},
+    /// };
+    ///
+    /// let line = Line::new(0.0, 0.0, 10.0, 5.0, Color::Red).stroke(Stroke::Dashed);
+    /// let custom = Line::new(0.0, 0.0, 10.0, 5.0, Color::Red).stroke(Stroke::Pattern {
+    ///     dash: 6,
+    ///     gap: 1,
+    /// });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn stroke(mut self, stroke: Stroke) -> Self {
+        self.stroke = stroke;
+        self
+    }
+
+    /// Returns the color at the given point of the line
+    fn color_at(&self, x: f64, y: f64) -> Color {
+        let Some(end_color) = self.end_color else {
+            return self.color;
+        };
+        // the position along the longest axis of the line
+        let t = if (self.x2 - self.x1).abs() >= (self.y2 - self.y1).abs() {
+            (x - self.x1) / (self.x2 - self.x1)
+        } else {
+            (y - self.y1) / (self.y2 - self.y1)
+        };
+        self.color.lerp(end_color, t)
+    }
 }
 
 impl Shape for Line {
@@ -46,6 +113,28 @@ impl Shape for Line {
         let Some((x2, y2)) = painter.get_point(world_x2, world_y2) else {
             return;
         };
+        // the colors at the ends of the clipped line, interpolated on the grid
+        let (color1, color2) = (
+            self.color_at(world_x1, world_y1),
+            self.color_at(world_x2, world_y2),
+        );
+        // the color of each point of the line, or none for the gaps of the stroke
+        let color = |x: usize, y: usize| {
+            let (dx, dy) = (x2.abs_diff(x1), y2.abs_diff(y1));
+            let step = if dx >= dy {
+                x.abs_diff(x1)
+            } else {
+                y.abs_diff(y1)
+            };
+            if !self.stroke.paints(step) {
+                return None;
+            }
+            if self.end_color.is_none() {
+                return Some(self.color);
+            }
+            let t = step as f64 / dx.max(dy).max(1) as f64;
+            Some(color1.lerp(color2, t))
+        };
 
         let (dx, x_range) = if x2 >= x1 {
             (x2 - x1, x1..=x2)
@@ -60,26 +149,64 @@ impl Shape for Line {
 
         if dx == 0 {
             for y in y_range {
-                painter.paint(x1, y, self.color);
+                if let Some(color) = color(x1, y) {
+                    painter.paint(x1, y, color);
+                }
             }
         } else if dy == 0 {
             for x in x_range {
-                painter.paint(x, y1, self.color);
+                if let Some(color) = color(x, y1) {
+                    painter.paint(x, y1, color);
+                }
             }
         } else if dy < dx {
             if x1 > x2 {
-                draw_line_low(painter, x2, y2, x1, y1, self.color);
+                draw_line_low(painter, x2, y2, x1, y1, color);
             } else {
-                draw_line_low(painter, x1, y1, x2, y2, self.color);
+                draw_line_low(painter, x1, y1, x2, y2, color);
             }
         } else if y1 > y2 {
-            draw_line_high(painter, x2, y2, x1, y1, self.color);
+            draw_line_high(painter, x2, y2, x1, y1, color);
         } else {
-            draw_line_high(painter, x1, y1, x2, y2, self.color);
+            draw_line_high(painter, x1, y1, x2, y2, color);
         }
     }
 }
 
+/// Whether a line is solid, dashed or dotted
+///
+/// See [`Line::stroke`] and [`Rectangle::stroke`](crate::canvas::Rectangle::stroke)
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Stroke {
+    /// A continuous line. This is the default.
+    #[default]
+    Solid,
+    /// Dashes of 4 points separated by gaps of 2 points
+    Dashed,
+    /// Single points separated by gaps of 1 point
+    Dotted,
+    /// Dashes and gaps of the given numbers of points
+    Pattern {
+        /// The number of points of each dash
+        dash: u16,
+        /// The number of points of each gap
+        gap: u16,
+    },
+}
+
+impl Stroke {
+    /// Returns whether the point at the given step from the start of a line is painted
+    fn paints(self, step: usize) -> bool {
+        let (dash, gap) = match self {
+            Self::Solid => return true,
+            Self::Dashed => (4, 2),
+            Self::Dotted => (1, 1),
+            Self::Pattern { dash, gap } => (usize::from(dash), usize::from(gap)),
+        };
+        step % (dash + gap).max(1) < dash
+    }
+}
+
 fn clip_line(
     &[xmin, xmax]: &[f64; 2],
     &[ymin, ymax]: &[f64; 2],
@@ -101,13 +228,22 @@ fn clip_line(
     }
 }
 
-fn draw_line_low(painter: &mut Painter, x1: usize, y1: usize, x2: usize, y2: usize, color: Color) {
+fn draw_line_low(
+    painter: &mut Painter,
+    x1: usize,
+    y1: usize,
+    x2: usize,
+    y2: usize,
+    color: impl Fn(usize, usize) -> Option<Color>,
+) {
     let dx = (x2 - x1) as isize;
     let dy = (y2 as isize - y1 as isize).abs();
     let mut d = 2 * dy - dx;
     let mut y = y1;
     for x in x1..=x2 {
-        painter.paint(x, y, color);
+        if let Some(color) = color(x, y) {
+            painter.paint(x, y, color);
+        }
         if d > 0 {
             y = if y1 > y2 {
                 y.saturating_sub(1)
@@ -120,13 +256,22 @@ fn draw_line_low(painter: &mut Painter, x1: usize, y1: usize, x2: usize, y2: usi
     }
 }
 
-fn draw_line_high(painter: &mut Painter, x1: usize, y1: usize, x2: usize, y2: usize, color: Color) {
+fn draw_line_high(
+    painter: &mut Painter,
+    x1: usize,
+    y1: usize,
+    x2: usize,
+    y2: usize,
+    color: impl Fn(usize, usize) -> Option<Color>,
+) {
     let dx = (x2 as isize - x1 as isize).abs();
     let dy = (y2 - y1) as isize;
     let mut d = 2 * dx - dy;
     let mut x = x1;
     for y in y1..=y2 {
-        painter.paint(x, y, color);
+        if let Some(color) = color(x, y) {
+            painter.paint(x, y, color);
+        }
         if d > 0 {
             x = if x1 > x2 {
                 x.saturating_sub(1)
@@ -305,4 +450,43 @@ mod tests {
         }
         assert_eq!(buffer, expected);
     }
+
+    #[rstest]
+    #[case::solid(Stroke::Solid, "••••••••••")]
+    #[case::dashed(Stroke::Dashed, "••••  ••••")]
+    #[case::dotted(Stroke::Dotted, "• • • • • ")]
+    #[case::pattern(Stroke::Pattern { dash: 2, gap: 1 }, "•• •• •• •")]
+    #[case::empty_pattern(Stroke::Pattern { dash: 0, gap: 0 }, "          ")]
+    fn render_stroke(#[case] stroke: Stroke, #[case] expected: &str) {
+        let line = Line::new(0.0, 0.0, 9.0, 0.0, Color::Reset).stroke(stroke);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 9.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|context| context.draw(&line))
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn gradient() {
+        let line =
+            Line::new(0.0, 0.0, 4.0, 0.0, Color::Rgb(0, 0, 0)).end_color(Color::Rgb(200, 0, 0));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 4.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|context| context.draw(&line))
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["•••••"]);
+        for (x, red) in [0, 50, 100, 150, 200].into_iter().enumerate() {
+            expected.set_style(
+                Rect::new(x as u16, 0, 1, 1),
+                Style::new().fg(Color::Rgb(red, 0, 0)),
+            );
+        }
+        assert_eq!(buffer, expected);
+    }
 }
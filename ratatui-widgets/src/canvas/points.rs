@@ -3,27 +3,90 @@ use ratatui_core::style::Color;
 use crate::canvas::{Painter, Shape};
 
 /// A group of points with a given color
+///
+/// Each point can also have its own color (see [`Points::colors`]), e.g. to convey a magnitude
+/// along a trajectory.
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct Points<'a> {
     /// List of points to draw
     pub coords: &'a [(f64, f64)],
     /// Color of the points
     pub color: Color,
+    /// Colors of each point, in the same order as the coordinates
+    ///
+    /// The points without a color in this list use [`color`](Points::color).
+    pub colors: &'a [Color],
 }
 
 impl<'a> Points<'a> {
     /// Create a new Points shape with the given coordinates and color
     pub const fn new(coords: &'a [(f64, f64)], color: Color) -> Self {
-        Self { coords, color }
+        Self {
+            coords,
+            color,
+            colors: &[],
+        }
+    }
+
+    /// Sets the color of each point, in the same order as the coordinates.
+    ///
+    /// The points without a color in this list keep the [color](Points::color) of the shape.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{style::Color, widgets::canvas::Points};
+    ///
+    /// let coords = [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)];
+    /// let colors = [Color::Green, Color::Yellow, Color::Red];
+    /// let points = Points::new(&coords, Color::White).colors(&colors);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn colors(mut self, colors: &'a [Color]) -> Self {
+        self.colors = colors;
+        self
     }
 }
 
 impl Shape for Points<'_> {
     fn draw(&self, painter: &mut Painter) {
-        for (x, y) in self.coords {
+        for (index, (x, y)) in self.coords.iter().enumerate() {
             if let Some((x, y)) = painter.get_point(*x, *y) {
-                painter.paint(x, y, self.color);
+                let color = self.colors.get(index).copied().unwrap_or(self.color);
+                painter.paint(x, y, color);
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{Style, Stylize},
+        symbols::Marker,
+        widgets::Widget,
+    };
+
+    use super::*;
+    use crate::canvas::Canvas;
+
+    #[test]
+    fn colors() {
+        let coords = [(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)];
+        let points = Points::new(&coords, Color::Red).colors(&[Color::Green, Color::Blue]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 2.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|context| context.draw(&points))
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["•••"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().green());
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().blue());
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+}
@@ -0,0 +1,201 @@
+use ratatui_core::style::Color;
+
+use crate::canvas::{Line, Painter, Shape};
+
+/// A closed polygon going through the given points, with an outline color and an optional fill
+/// color
+///
+/// The polygon is closed automatically, so the last point should not repeat the first one. The
+/// inside of the polygon is filled row by row on the grid of the canvas (see
+/// [`Polygon::fill`]), which makes it possible to highlight areas without drawing a dense cloud
+/// of [`Points`](crate::canvas::Points). Self-intersecting polygons are filled with the even-odd
+/// rule.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Polygon<'a> {
+    /// List of the points of the polygon
+    pub coords: &'a [(f64, f64)],
+    /// Color of the outline
+    pub color: Color,
+    /// Color of the inside, if the polygon is filled
+    pub fill_color: Option<Color>,
+}
+
+impl<'a> Polygon<'a> {
+    /// Create a new polygon going through the given points, with the given outline color
+    pub const fn new(coords: &'a [(f64, f64)], color: Color) -> Self {
+        Self {
+            coords,
+            color,
+            fill_color: None,
+        }
+    }
+
+    /// Fills the inside of the polygon with the given color.
+    ///
+    /// The outline is drawn on top of the filled area, so use the same color for both to draw a
+    /// solid polygon.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn fill(mut self, color: Color) -> Self {
+        self.fill_color = Some(color);
+        self
+    }
+
+    /// Returns the edges of the polygon, including the one closing it
+    fn edges(&self) -> impl Iterator<Item = ((f64, f64), (f64, f64))> + '_ {
+        self.coords
+            .iter()
+            .copied()
+            .zip(self.coords.iter().copied().cycle().skip(1))
+    }
+
+    /// Fills the inside of the polygon, one row of the grid at a time
+    fn draw_fill(&self, painter: &mut Painter, color: Color) {
+        let (&[left, right], &[bottom, top]) = painter.bounds();
+        let (width, height) = painter.resolution;
+        if right <= left || top <= bottom {
+            return;
+        }
+        // the points in the coordinate system of the grid, without rounding
+        let to_grid = |(x, y): (f64, f64)| {
+            (
+                (x - left) * (width - 1.0) / (right - left),
+                (top - y) * (height - 1.0) / (top - bottom),
+            )
+        };
+        let edges: Vec<_> = self
+            .edges()
+            .map(|(start, end)| (to_grid(start), to_grid(end)))
+            .collect();
+
+        let mut crossings = Vec::new();
+        for row in 0..height as usize {
+            let y = row as f64;
+            crossings.clear();
+            for &((x1, y1), (x2, y2)) in &edges {
+                // each edge includes its upper end but not its lower end, so that rows going
+                // through a vertex are crossed the right number of times
+                if (y1 <= y && y < y2) || (y2 <= y && y < y1) {
+                    crossings.push((y - y1).mul_add((x2 - x1) / (y2 - y1), x1));
+                }
+            }
+            crossings.sort_by(f64::total_cmp);
+            for pair in crossings.chunks_exact(2) {
+                let start = pair[0].ceil().max(0.0);
+                let end = pair[1].floor().min(width - 1.0);
+                if start > end {
+                    continue;
+                }
+                for x in start as usize..=end as usize {
+                    painter.paint(x, row, color);
+                }
+            }
+        }
+    }
+}
+
+impl Shape for Polygon<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        if let Some(color) = self.fill_color {
+            self.draw_fill(painter, color);
+        }
+        if self.coords.len() < 2 {
+            return;
+        }
+        for ((x1, y1), (x2, y2)) in self.edges() {
+            Line::new(x1, y1, x2, y2, self.color).draw(painter);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{Style, Stylize},
+        symbols::Marker,
+        widgets::Widget,
+    };
+
+    use super::*;
+    use crate::canvas::Canvas;
+
+    fn render(polygon: &Polygon) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 10));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 10.0])
+            .paint(|context| context.draw(polygon))
+            .render(buffer.area, &mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn outline() {
+        let coords = [(2.0, 2.0), (7.0, 2.0), (7.0, 7.0), (2.0, 7.0)];
+        let mut expected = Buffer::with_lines([
+            "          ",
+            "          ",
+            "          ",
+            "  •••••   ",
+            "  •   •   ",
+            "  •   •   ",
+            "  •   •   ",
+            "  •••••   ",
+            "          ",
+            "          ",
+        ]);
+        for cell in &mut expected.content {
+            if cell.symbol() == "•" {
+                cell.set_style(Style::new().red());
+            }
+        }
+        assert_eq!(render(&Polygon::new(&coords, Color::Red)), expected);
+    }
+
+    #[test]
+    fn filled() {
+        let coords = [(2.0, 2.0), (7.0, 2.0), (7.0, 7.0), (2.0, 7.0)];
+        let polygon = Polygon::new(&coords, Color::Red).fill(Color::Blue);
+        let mut expected = Buffer::with_lines([
+            "          ",
+            "          ",
+            "          ",
+            "  •••••   ",
+            "  •••••   ",
+            "  •••••   ",
+            "  •••••   ",
+            "  •••••   ",
+            "          ",
+            "          ",
+        ]);
+        expected.set_style(Rect::new(2, 3, 5, 5), Style::new().red());
+        expected.set_style(Rect::new(3, 4, 3, 3), Style::new().blue());
+        assert_eq!(render(&polygon), expected);
+    }
+
+    #[test]
+    fn filled_triangle() {
+        let coords = [(0.0, 0.0), (10.0, 0.0), (0.0, 10.0)];
+        let polygon = Polygon::new(&coords, Color::Red).fill(Color::Red);
+        let mut expected = Buffer::with_lines([
+            "•         ",
+            "••        ",
+            "•••       ",
+            "••••      ",
+            "•••••     ",
+            "••••••    ",
+            "•••••••   ",
+            "••••••••  ",
+            "••••••••• ",
+            "••••••••••",
+        ]);
+        for cell in &mut expected.content {
+            if cell.symbol() == "•" {
+                cell.set_style(Style::new().red());
+            }
+        }
+        assert_eq!(render(&polygon), expected);
+    }
+}
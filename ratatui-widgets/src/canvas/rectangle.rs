@@ -1,6 +1,6 @@
 use ratatui_core::style::Color;
 
-use crate::canvas::{Line, Painter, Shape};
+use crate::canvas::{Line, Painter, Shape, Stroke};
 
 /// A rectangle to draw on a [`Canvas`](crate::canvas::Canvas)
 ///
@@ -22,6 +22,8 @@ pub struct Rectangle {
     pub height: f64,
     /// The color of the rectangle.
     pub color: Color,
+    /// Whether the sides of the rectangle are solid, dashed or dotted.
+    pub stroke: Stroke,
 }
 
 impl Rectangle {
@@ -33,44 +35,33 @@ impl Rectangle {
             width,
             height,
             color,
+            stroke: Stroke::Solid,
         }
     }
+
+    /// Sets whether the sides of the rectangle are solid, dashed or dotted.
+    ///
+    /// The pattern of each side starts from its bottom or left end. See [`Line::stroke`] for more
+    /// details.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn stroke(mut self, stroke: Stroke) -> Self {
+        self.stroke = stroke;
+        self
+    }
 }
 
 impl Shape for Rectangle {
     fn draw(&self, painter: &mut Painter) {
+        let (left, bottom) = (self.x, self.y);
+        let (right, top) = (self.x + self.width, self.y + self.height);
         let lines: [Line; 4] = [
-            Line {
-                x1: self.x,
-                y1: self.y,
-                x2: self.x,
-                y2: self.y + self.height,
-                color: self.color,
-            },
-            Line {
-                x1: self.x,
-                y1: self.y + self.height,
-                x2: self.x + self.width,
-                y2: self.y + self.height,
-                color: self.color,
-            },
-            Line {
-                x1: self.x + self.width,
-                y1: self.y,
-                x2: self.x + self.width,
-                y2: self.y + self.height,
-                color: self.color,
-            },
-            Line {
-                x1: self.x,
-                y1: self.y,
-                x2: self.x + self.width,
-                y2: self.y,
-                color: self.color,
-            },
+            Line::new(left, bottom, left, top, self.color),
+            Line::new(left, top, right, top, self.color),
+            Line::new(right, bottom, right, top, self.color),
+            Line::new(left, bottom, right, bottom, self.color),
         ];
-        for line in &lines {
-            line.draw(painter);
+        for line in lines {
+            line.stroke(self.stroke).draw(painter);
         }
     }
 }
@@ -102,6 +93,7 @@ mod tests {
                     width: 10.0,
                     height: 10.0,
                     color: Color::Red,
+                    stroke: Stroke::Solid,
                 });
             });
         canvas.render(buffer.area, &mut buffer);
@@ -122,6 +114,38 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn draw_dotted_lines() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 10));
+        let canvas = Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 9.0])
+            .y_bounds([0.0, 9.0])
+            .paint(|context| {
+                context
+                    .draw(&Rectangle::new(0.0, 0.0, 9.0, 9.0, Color::Red).stroke(Stroke::Dotted));
+            });
+        canvas.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "█ █ █ █ █ ",
+            "█        █",
+            "          ",
+            "█        █",
+            "          ",
+            "█        █",
+            "          ",
+            "█        █",
+            "          ",
+            "█ █ █ █ ██",
+        ]);
+        for cell in &mut expected.content {
+            if cell.symbol() == "█" {
+                cell.set_style(Style::new().red());
+            }
+        }
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn draw_half_block_lines() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 10));
@@ -136,6 +160,7 @@ mod tests {
                     width: 10.0,
                     height: 10.0,
                     color: Color::Red,
+                    stroke: Stroke::Solid,
                 });
             });
         canvas.render(buffer.area, &mut buffer);
@@ -172,6 +197,7 @@ mod tests {
                     width: 20.0,
                     height: 20.0,
                     color: Color::Red,
+                    stroke: Stroke::Solid,
                 });
                 // a rectangle that will draw the inside part of the braille
                 context.draw(&Rectangle {
@@ -180,6 +206,7 @@ mod tests {
                     width: 12.0,
                     height: 12.0,
                     color: Color::Green,
+                    stroke: Stroke::Solid,
                 });
             });
         canvas.render(buffer.area, &mut buffer);
@@ -0,0 +1,164 @@
+use ratatui_core::style::Color;
+
+use crate::canvas::{Painter, Shape};
+
+/// A grid of colored points stamped on the canvas, with transparent points left unpainted
+///
+/// Each point of the sprite is painted on a point of the grid of the canvas, with the marker of
+/// the canvas, so the sprite keeps the same size whatever the bounds of the canvas. The sprite is
+/// positioned from its top left point, at `(x, y)`, and the parts outside of the canvas are not
+/// drawn. This makes it possible to draw an asset once and stamp it in several places, e.g. the
+/// characters of a game.
+///
+/// The [`HalfBlock`](ratatui_core::symbols::Marker::HalfBlock) marker, which has a color for each
+/// point of the grid, is the best suited for sprites.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{style::Color, widgets::canvas::Sprite};
+///
+/// let invader = Sprite::from_lines([" # # ", "#####", "# # #"], |c| {
+///     (c == '#').then_some(Color::Green)
+/// });
+/// let left = invader.clone().position(10.0, 20.0);
+/// let right = invader.position(30.0, 20.0);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Sprite {
+    /// The `x` position of the sprite, from its top left point
+    pub x: f64,
+    /// The `y` position of the sprite, from its top left point
+    pub y: f64,
+    /// The number of points in each row
+    pub width: usize,
+    /// The color of the points, row by row from the top left point, or `None` for transparent
+    /// points
+    pub pixels: Vec<Option<Color>>,
+}
+
+impl Sprite {
+    /// Create a new sprite with the given number of points in each row and colors of the points
+    pub const fn new(width: usize, pixels: Vec<Option<Color>>) -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            width,
+            pixels,
+        }
+    }
+
+    /// Create a new sprite from lines of characters, with the color of each character given by a
+    /// function
+    ///
+    /// The sprite is as wide as the longest line, and shorter lines are padded with transparent
+    /// points. Characters without a color are transparent.
+    pub fn from_lines<'a, I, F>(lines: I, color: F) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+        F: Fn(char) -> Option<Color>,
+    {
+        let rows: Vec<Vec<_>> = lines
+            .into_iter()
+            .map(|line| line.chars().map(&color).collect())
+            .collect();
+        let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+        let pixels = rows
+            .into_iter()
+            .flat_map(|mut row| {
+                row.resize(width, None);
+                row
+            })
+            .collect();
+        Self::new(width, pixels)
+    }
+
+    /// Sets the position of the top left point of the sprite.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn position(mut self, x: f64, y: f64) -> Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+}
+
+impl Shape for Sprite {
+    fn draw(&self, painter: &mut Painter) {
+        let (&[left, right], &[bottom, top]) = painter.bounds();
+        let (width, height) = painter.resolution;
+        if self.width == 0 || right <= left || top <= bottom {
+            return;
+        }
+        // the position of the top left point on the grid, which may be outside of the canvas
+        let x = ((self.x - left) * (width - 1.0) / (right - left)).round() as isize;
+        let y = ((top - self.y) * (height - 1.0) / (top - bottom)).round() as isize;
+        for (row, pixels) in self.pixels.chunks(self.width).enumerate() {
+            let Ok(grid_y) = usize::try_from(y + row as isize) else {
+                continue;
+            };
+            if grid_y as f64 >= height {
+                break;
+            }
+            for (column, pixel) in pixels.iter().enumerate() {
+                let (Some(color), Ok(grid_x)) = (pixel, usize::try_from(x + column as isize))
+                else {
+                    continue;
+                };
+                if grid_x as f64 >= width {
+                    break;
+                }
+                painter.paint(grid_x, grid_y, *color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{Style, Stylize},
+        symbols::Marker,
+        widgets::Widget,
+    };
+    use rstest::rstest;
+
+    use super::*;
+    use crate::canvas::Canvas;
+
+    #[test]
+    fn from_lines() {
+        let sprite = Sprite::from_lines(["#", "##"], |c| (c == '#').then_some(Color::Red));
+        assert_eq!(
+            sprite,
+            Sprite::new(
+                2,
+                vec![Some(Color::Red), None, Some(Color::Red), Some(Color::Red)]
+            )
+        );
+    }
+
+    #[rstest]
+    #[case::inside((1.0, 2.0), ["     ", " # # ", " ### ", "     "])]
+    #[case::clipped((-1.0, 1.0), ["     ", "     ", " #   ", "##   "])]
+    #[case::outside((6.0, 3.0), ["     ", "     ", "     ", "     "])]
+    fn draw(#[case] (x, y): (f64, f64), #[case] expected: [&str; 4]) {
+        let sprite =
+            Sprite::from_lines(["# #", "###"], |c| (c == '#').then_some(Color::Red)).position(x, y);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 4));
+        Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 4.0])
+            .y_bounds([0.0, 3.0])
+            .paint(|context| context.draw(&sprite))
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(expected.map(|line| line.replace('#', "█")));
+        for cell in &mut expected.content {
+            if cell.symbol() == "█" {
+                cell.set_style(Style::new().red());
+            }
+        }
+        assert_eq!(buffer, expected);
+    }
+}
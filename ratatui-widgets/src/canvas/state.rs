@@ -0,0 +1,264 @@
+use ratatui_core::layout::{Position, Rect};
+
+/// State of the [`Canvas`] widget holding the visible bounds, to pan and zoom around a scene
+///
+/// The bounds of the state start as the [`x_bounds`](super::Canvas::x_bounds) and
+/// [`y_bounds`](super::Canvas::y_bounds) of the canvas at the first render, and then replace them
+/// when the canvas is rendered as a stateful widget. The state can then be
+/// [panned](CanvasState::pan) and [zoomed](CanvasState::zoom) from key or mouse events, and maps
+/// the positions of the mouse to coordinates of the canvas, so that scenes in world coordinates
+/// can be navigated without recomputing the bounds in the application.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Position,
+///     widgets::canvas::{Canvas, CanvasState},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// // This should be stored outside of the function in your application state.
+/// let mut state = CanvasState::new([-180.0, 180.0], [-90.0, 90.0]);
+///
+/// let canvas = Canvas::default().paint(|ctx| {});
+/// frame.render_stateful_widget(&canvas, frame.area(), &mut state);
+///
+/// // on mouse wheel up, zoom in around the mouse
+/// state.zoom_at(Position::new(10, 5), 1);
+/// // on mouse drag, follow the mouse
+/// state.start_pan(Position::new(10, 5));
+/// state.drag_pan(Position::new(12, 5));
+/// state.end_pan();
+/// # }
+/// ```
+///
+/// [`Canvas`]: super::Canvas
+#[derive(Debug, Default, Clone)]
+pub struct CanvasState {
+    /// The visible X and Y bounds, or none to use the bounds of the canvas
+    pub(super) bounds: Option<[[f64; 2]; 2]>,
+    /// Area of the canvas at the last render
+    pub(super) area: Rect,
+    /// The position where a pan drag started and the bounds at that time
+    pan: Option<(Position, [[f64; 2]; 2])>,
+}
+
+/// Compares the visible bounds of the states, ignoring the area of the last render and the pan
+/// drag that are only kept between renders for the interaction with the canvas.
+impl PartialEq for CanvasState {
+    fn eq(&self, other: &Self) -> bool {
+        self.bounds == other.bounds
+    }
+}
+
+/// The zoom factor of each step of [`CanvasState::zoom_at`]
+const ZOOM_STEP: f64 = 1.25;
+
+impl CanvasState {
+    /// Creates a new state with the given visible bounds
+    pub const fn new(x_bounds: [f64; 2], y_bounds: [f64; 2]) -> Self {
+        Self {
+            bounds: Some([x_bounds, y_bounds]),
+            area: Rect::ZERO,
+            pan: None,
+        }
+    }
+
+    /// Returns the visible bounds of the X axis
+    ///
+    /// These are the bounds of the canvas at the last render if the state has no bounds yet.
+    pub fn x_bounds(&self) -> [f64; 2] {
+        self.bounds.unwrap_or_default()[0]
+    }
+
+    /// Returns the visible bounds of the Y axis
+    ///
+    /// These are the bounds of the canvas at the last render if the state has no bounds yet.
+    pub fn y_bounds(&self) -> [f64; 2] {
+        self.bounds.unwrap_or_default()[1]
+    }
+
+    /// Sets the visible bounds
+    pub fn set_bounds(&mut self, x_bounds: [f64; 2], y_bounds: [f64; 2]) {
+        self.bounds = Some([x_bounds, y_bounds]);
+    }
+
+    /// Shows the bounds of the canvas again at the next render
+    pub fn reset(&mut self) {
+        self.bounds = None;
+        self.pan = None;
+    }
+
+    /// Returns the coordinates of the canvas at the given position, e.g. under the mouse
+    ///
+    /// Returns `None` if the position is outside of the canvas at the last render.
+    pub fn coordinates(&self, position: Position) -> Option<(f64, f64)> {
+        if !self.area.contains(position) {
+            return None;
+        }
+        let [x, y] = self.bounds?;
+        let (column, row) = (position.x - self.area.x, position.y - self.area.y);
+        let (column_width, row_height) = self.cell_size();
+        Some((
+            f64::from(column).mul_add(column_width, x[0]),
+            f64::from(row).mul_add(-row_height, y[1]),
+        ))
+    }
+
+    /// Zooms by the given factor around the coordinates at the given position
+    ///
+    /// A factor greater than 1 zooms in, and a factor between 0 and 1 zooms out. The coordinates
+    /// at `anchor` stay at the same position, or the center of the canvas stays in place if
+    /// `anchor` is outside of the canvas.
+    pub fn zoom(&mut self, factor: f64, anchor: Position) {
+        if factor <= 0.0 || !factor.is_finite() {
+            return;
+        }
+        let Some([x, y]) = self.bounds else {
+            return;
+        };
+        let (x_anchor, y_anchor) = self
+            .coordinates(anchor)
+            .unwrap_or(((x[0] + x[1]) / 2.0, (y[0] + y[1]) / 2.0));
+        self.bounds = Some([
+            [
+                x_anchor - (x_anchor - x[0]) / factor,
+                x_anchor + (x[1] - x_anchor) / factor,
+            ],
+            [
+                y_anchor - (y_anchor - y[0]) / factor,
+                y_anchor + (y[1] - y_anchor) / factor,
+            ],
+        ]);
+    }
+
+    /// Zooms in or out by steps around the coordinates at the given position, e.g. for the mouse
+    /// wheel
+    ///
+    /// Each step zooms in by a factor of 1.25, and negative steps zoom out.
+    pub fn zoom_at(&mut self, position: Position, steps: i16) {
+        self.zoom(ZOOM_STEP.powi(i32::from(steps)), position);
+    }
+
+    /// Moves the visible bounds by the given number of columns and rows, e.g. with the arrow keys
+    ///
+    /// Positive values show coordinates further right and down.
+    pub fn pan(&mut self, columns: i16, rows: i16) {
+        if let Some(bounds) = self.bounds {
+            self.bounds = Some(self.shifted(bounds, columns.into(), rows.into()));
+        }
+    }
+
+    /// Starts panning by dragging the mouse from the given position
+    ///
+    /// Returns `false` and leaves the state untouched if the position is outside of the canvas at
+    /// the last render.
+    pub fn start_pan(&mut self, position: Position) -> bool {
+        let Some(bounds) = self.bounds else {
+            return false;
+        };
+        if !self.area.contains(position) {
+            return false;
+        }
+        self.pan = Some((position, bounds));
+        true
+    }
+
+    /// Pans so that the coordinates under the position where the drag started follow the mouse
+    pub fn drag_pan(&mut self, position: Position) {
+        let Some((start, bounds)) = self.pan else {
+            return;
+        };
+        let columns = i32::from(start.x) - i32::from(position.x);
+        let rows = i32::from(start.y) - i32::from(position.y);
+        self.bounds = Some(self.shifted(bounds, columns, rows));
+    }
+
+    /// Ends panning with the mouse
+    pub fn end_pan(&mut self) {
+        self.pan = None;
+    }
+
+    /// Returns the size of a cell in coordinates of the canvas
+    fn cell_size(&self) -> (f64, f64) {
+        let [x, y] = self.bounds.unwrap_or_default();
+        (
+            (x[1] - x[0]) / f64::from(self.area.width.saturating_sub(1).max(1)),
+            (y[1] - y[0]) / f64::from(self.area.height.saturating_sub(1).max(1)),
+        )
+    }
+
+    /// Returns the bounds moved by the given number of columns to the right and rows down
+    fn shifted(&self, [x, y]: [[f64; 2]; 2], columns: i32, rows: i32) -> [[f64; 2]; 2] {
+        let (column_width, row_height) = self.cell_size();
+        let dx = f64::from(columns) * column_width;
+        let dy = f64::from(rows) * row_height;
+        [[x[0] + dx, x[1] + dx], [y[0] - dy, y[1] - dy]]
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    fn state() -> CanvasState {
+        CanvasState {
+            area: Rect::new(0, 0, 11, 11),
+            ..CanvasState::new([0.0, 10.0], [0.0, 10.0])
+        }
+    }
+
+    #[test]
+    fn equality_ignores_render_area_and_pan() {
+        let mut state = state();
+        state.start_pan(Position::new(5, 5));
+        assert_eq!(state, CanvasState::new([0.0, 10.0], [0.0, 10.0]));
+
+        state.drag_pan(Position::new(6, 5));
+        assert_ne!(state, CanvasState::new([0.0, 10.0], [0.0, 10.0]));
+    }
+
+    #[test]
+    fn coordinates() {
+        let state = state();
+        assert_eq!(state.coordinates(Position::new(0, 0)), Some((0.0, 10.0)));
+        assert_eq!(state.coordinates(Position::new(5, 10)), Some((5.0, 0.0)));
+        assert_eq!(state.coordinates(Position::new(11, 0)), None);
+    }
+
+    #[test]
+    fn zoom() {
+        let mut state = state();
+        state.zoom(2.0, Position::new(0, 10));
+        assert_eq!(state.x_bounds(), [0.0, 5.0]);
+        assert_eq!(state.y_bounds(), [0.0, 5.0]);
+        state.zoom(0.5, Position::new(20, 20));
+        assert_eq!(state.x_bounds(), [-2.5, 7.5]);
+        assert_eq!(state.y_bounds(), [-2.5, 7.5]);
+    }
+
+    #[test]
+    fn pan() {
+        let mut state = state();
+        state.pan(2, -3);
+        assert_eq!(state.x_bounds(), [2.0, 12.0]);
+        assert_eq!(state.y_bounds(), [3.0, 13.0]);
+    }
+
+    #[test]
+    fn drag_pan() {
+        let mut state = state();
+        assert!(!state.start_pan(Position::new(20, 0)));
+        assert!(state.start_pan(Position::new(5, 5)));
+        state.drag_pan(Position::new(7, 6));
+        state.drag_pan(Position::new(8, 4));
+        assert_eq!(state.x_bounds(), [-3.0, 7.0]);
+        assert_eq!(state.y_bounds(), [-1.0, 9.0]);
+        state.end_pan();
+        state.drag_pan(Position::new(0, 0));
+        assert_eq!(state.x_bounds(), [-3.0, 7.0]);
+    }
+}
@@ -1,21 +1,24 @@
 //! The [`Chart`] widget is used to plot one or more [`Dataset`] in a cartesian coordinate system.
-use std::{cmp::max, ops::Not};
+use std::{borrow::Cow, cmp::max, collections::BTreeSet, ops::Not};
 
 use ratatui_core::{
     buffer::Buffer,
-    layout::{Alignment, Constraint, Flex, Layout, Position, Rect},
-    style::{Color, Style, Styled},
+    layout::{Alignment, Constraint, Direction, Flex, Layout, Position, Rect},
+    style::{Color, Modifier, Style, Styled},
     symbols::{self},
-    text::Line,
-    widgets::Widget,
+    text::{Line, Span},
+    widgets::{StatefulWidget, Widget},
 };
 use strum::{Display, EnumString};
 
+pub use self::reference::{ReferenceBand, ReferenceLine};
 use crate::{
     block::{Block, BlockExt},
-    canvas::{Canvas, Line as CanvasLine, Points},
+    canvas::{Canvas, Line as CanvasLine, Painter, Points, Shape},
 };
 
+mod reference;
+
 /// An X or Y axis for the [`Chart`] widget
 ///
 /// An axis can have a [title](Axis::title) which will be displayed at the end of the axis. For an
@@ -52,6 +55,20 @@ pub struct Axis<'a> {
     style: Style,
     /// The alignment of the labels of the Axis
     labels_alignment: Alignment,
+    /// How values are mapped to positions on the axis
+    scale: Scale,
+    /// Whether the bounds are computed from the data of the datasets
+    auto_bounds: bool,
+    /// The padding added on both sides of automatic bounds, in percent of their range
+    bounds_padding: f64,
+    /// Whether automatic bounds are extended to nice numbers
+    nice_bounds: bool,
+    /// The offset used to display the values of a time axis, if the axis is a time axis
+    #[cfg(feature = "chart-time")]
+    time_offset: Option<time::UtcOffset>,
+    /// The positions of the labels along the axis, from 0.0 at the min bound to 1.0 at the max
+    /// bound, or empty if the labels are evenly spaced
+    label_positions: Vec<f64>,
 }
 
 impl<'a> Axis<'a> {
@@ -81,6 +98,62 @@ impl<'a> Axis<'a> {
         self
     }
 
+    /// Computes the bounds of this axis from the data of the datasets
+    ///
+    /// The bounds are set to the lowest and highest values of the visible datasets plotted against
+    /// the axis each time the chart is rendered, so they don't need to be computed for every frame.
+    /// This includes the [candles](Dataset::candles) and [error bars](Dataset::error_bars) of the
+    /// datasets, and the base of [bars](GraphType::Bar) and [stacked](Dataset::stack_group) areas.
+    /// The bounds set with [`Axis::bounds`] are used when no dataset has any data.
+    ///
+    /// See [`Axis::bounds_padding`] to add some space around the data and [`Axis::nice_bounds`]
+    /// to round the bounds. If no [labels](Axis::labels) are set, evenly spaced labels are
+    /// generated.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Axis;
+    ///
+    /// let axis = Axis::default()
+    ///     .bounds_auto()
+    ///     .bounds_padding(5.0)
+    ///     .nice_bounds(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bounds_auto(mut self) -> Self {
+        self.auto_bounds = true;
+        self
+    }
+
+    /// Sets the padding added on both sides of the [automatic bounds](Axis::bounds_auto), in
+    /// percent of the range of the data
+    ///
+    /// The default is no padding.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bounds_padding(mut self, percent: f64) -> Self {
+        self.bounds_padding = percent;
+        self
+    }
+
+    /// Sets whether the [automatic bounds](Axis::bounds_auto) are extended to nice numbers
+    ///
+    /// On a linear axis, the bounds are extended to multiples of a step of 1, 2, 2.5 or 5 times a
+    /// power of ten, and the generated labels are placed at each step (e.g. 0, 25, 50, 75 and 100
+    /// for data between 3 and 97). On a logarithmic axis, the bounds are extended to powers of
+    /// ten. The default is `false`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn nice_bounds(mut self, nice: bool) -> Self {
+        self.nice_bounds = nice;
+        self
+    }
+
     /// Sets the axis labels
     ///
     /// - For the X axis, the labels are displayed left to right.
@@ -153,6 +226,568 @@ impl<'a> Axis<'a> {
         self.labels_alignment = alignment;
         self
     }
+
+    /// Sets the scale of the axis
+    ///
+    /// The default is [`Scale::Linear`]. With [`Scale::Log10`], each order of magnitude takes the
+    /// same space on the axis, which is useful for data spanning several orders of magnitude
+    /// (e.g. latencies). Data points that can't be represented on a logarithmic axis (zero and
+    /// negative values) are not drawn, and the bounds must be positive.
+    ///
+    /// If no [labels](Axis::labels) are set on a logarithmic axis, labels are generated at evenly
+    /// spaced positions, i.e. at each power of ten when the bounds are powers of ten.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Axis, Scale};
+    ///
+    /// let axis = Axis::default().bounds([1.0, 1000.0]).scale(Scale::Log10);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Makes this axis a time axis, displayed in the given offset from UTC
+    ///
+    /// The bounds of a time axis and the values of the datasets on it are Unix timestamps in
+    /// seconds. If no [labels](Axis::labels) are set, labels are generated from the visible range:
+    /// a step is picked between one second and a century so that at most five labels are shown
+    /// and they fit in the length of the axis. The labels are placed at the boundaries of the step
+    /// (e.g. at midnight, on the first day of a month or of a quarter) and are formatted with a
+    /// precision matching the step (`HH:MM:SS`, `HH:MM`, `YYYY-MM-DD`, `YYYY-MM` or `YYYY`).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Axis;
+    /// use time::UtcOffset;
+    ///
+    /// // the last hour, in UTC
+    /// let now = 1_700_000_000.0;
+    /// let axis = Axis::default()
+    ///     .bounds([now - 3600.0, now])
+    ///     .time(UtcOffset::UTC);
+    /// ```
+    #[cfg(feature = "chart-time")]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn time(mut self, offset: time::UtcOffset) -> Self {
+        self.time_offset = Some(offset);
+        self
+    }
+
+    /// Returns the bounds of the axis mapped by its scale
+    fn scaled_bounds(&self) -> [f64; 2] {
+        self.bounds.map(|bound| self.scale.apply(bound))
+    }
+
+    /// Returns a copy of the axis with bounds computed from the given values
+    ///
+    /// The bounds are kept if none of the values can be represented on the axis.
+    fn with_auto_bounds(&self, values: impl Iterator<Item = f64>) -> Self {
+        let mut axis = self.clone();
+        let (min, max) = values
+            .map(|value| self.scale.apply(value))
+            .filter(|value| value.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+        if min > max {
+            return axis;
+        }
+        let (mut min, mut max) = if max - min > 0.0 {
+            (min, max)
+        } else {
+            // a single value is displayed in the middle of the axis
+            let half = if min == 0.0 { 1.0 } else { min.abs() / 10.0 };
+            (min - half, max + half)
+        };
+        let padding = (max - min) * self.bounds_padding.max(0.0) / 100.0;
+        min -= padding;
+        max += padding;
+        if self.nice_bounds {
+            let step = match self.scale {
+                Scale::Linear => nice_step(max - min),
+                Scale::Log10 => 1.0,
+            };
+            min = (min / step).floor() * step;
+            max = (max / step).ceil() * step;
+        }
+        axis.bounds = [min, max].map(|bound| self.scale.invert(bound));
+        axis
+    }
+
+    /// Returns a copy of the axis showing the given bounds, mapped by the scale of the axis
+    ///
+    /// The labels are replaced to match the bounds: linear axes get the same number of evenly
+    /// spaced labels, and the labels of the other axes are generated.
+    // an axis that isn't zoomed nor panned shows exactly its bounds
+    #[allow(clippy::float_cmp)]
+    fn with_view(&self, bounds: [f64; 2]) -> Self {
+        let mut axis = self.clone();
+        if bounds == self.scaled_bounds() {
+            return axis;
+        }
+        axis.bounds = bounds.map(|bound| self.scale.invert(bound));
+        #[cfg(feature = "chart-time")]
+        let generated = self.scale != Scale::Linear || self.time_offset.is_some();
+        #[cfg(not(feature = "chart-time"))]
+        let generated = self.scale != Scale::Linear;
+        let count = self.labels.len();
+        axis.labels = if generated || count < 2 {
+            vec![]
+        } else {
+            let [min, max] = bounds;
+            (0..count)
+                .map(|i| {
+                    let value = (max - min).mul_add(i as f64 / (count - 1) as f64, min);
+                    Line::from(format_axis_value(value))
+                })
+                .collect()
+        };
+        axis
+    }
+
+    /// Returns whether the labels of the axis are generated for the length of the axis
+    fn has_time_labels(&self) -> bool {
+        #[cfg(feature = "chart-time")]
+        let is_time = self.time_offset.is_some();
+        #[cfg(not(feature = "chart-time"))]
+        let is_time = false;
+        is_time && self.labels.is_empty()
+    }
+
+    /// Returns labels generated for the scale of the axis, if the axis needs them, along with
+    /// their positions
+    ///
+    /// The labels of a time axis are placed at step boundaries and only as many as fit in the
+    /// `length` of the axis (in columns, or rows if the axis is `vertical`) are generated. The
+    /// labels of the other axes are evenly spaced, and have no positions.
+    #[cfg_attr(not(feature = "chart-time"), allow(unused_variables))]
+    fn generated_labels(
+        &self,
+        length: u16,
+        vertical: bool,
+    ) -> Option<(Vec<Line<'static>>, Vec<f64>)> {
+        if !self.labels.is_empty() {
+            return None;
+        }
+        #[cfg(feature = "chart-time")]
+        if let Some(offset) = self.time_offset {
+            return time_labels(self.bounds, offset, length, vertical);
+        }
+        let labels = if self.scale == Scale::Linear {
+            if self.auto_bounds {
+                linear_labels(self.bounds)
+            } else {
+                None
+            }
+        } else {
+            self.log_labels()
+        };
+        labels.map(|labels| (labels, Vec::new()))
+    }
+
+    /// Generates one label per order of magnitude of a logarithmic axis
+    fn log_labels(&self) -> Option<Vec<Line<'static>>> {
+        let [min, max] = self.scaled_bounds();
+        if !min.is_finite() || !max.is_finite() {
+            return None;
+        }
+        // one label per order of magnitude, but not too many to fit
+        let count = ((max - min).abs().round() as usize + 1).clamp(2, 11);
+        let labels = (0..count)
+            .map(|i| {
+                let value = (max - min).mul_add(i as f64 / (count - 1) as f64, min);
+                Line::from(format_axis_value(self.scale.invert(value)))
+            })
+            .collect();
+        Some(labels)
+    }
+}
+
+/// Returns a step of 1, 2, 2.5 or 5 times a power of ten splitting the range in at most 4 intervals
+fn nice_step(range: f64) -> f64 {
+    let step = range / 4.0;
+    let magnitude = 10f64.powf(step.log10().floor());
+    [1.0, 2.0, 2.5, 5.0]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .find(|&nice| nice >= step)
+        .unwrap_or(10.0 * magnitude)
+}
+
+/// Generates evenly spaced labels for a linear axis: one at each step of nice numbers if the
+/// bounds are multiples of it, or 3 labels otherwise
+fn linear_labels([min, max]: [f64; 2]) -> Option<Vec<Line<'static>>> {
+    let range = max - min;
+    if range <= 0.0 || !range.is_finite() {
+        return None;
+    }
+    let step = nice_step(range);
+    let is_multiple = |value: f64| ((value / step).round() - value / step).abs() < 1e-6;
+    let count = if is_multiple(min) && is_multiple(max) {
+        (range / step).round() as usize + 1
+    } else {
+        3
+    };
+    let labels = (0..count)
+        .map(|i| {
+            let value = range.mul_add(i as f64 / (count - 1) as f64, min);
+            Line::from(format_axis_value(value))
+        })
+        .collect();
+    Some(labels)
+}
+
+/// Formats a value for an axis label, using the scientific notation for very large and very small
+/// values
+fn format_axis_value(value: f64) -> String {
+    let abs = value.abs();
+    if abs != 0.0 && !(1e-3..1e6).contains(&abs) {
+        return format!("{value:e}");
+    }
+    let value = format!("{value:.3}");
+    value
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+#[cfg(feature = "chart-time")]
+const MINUTE: f64 = 60.0;
+#[cfg(feature = "chart-time")]
+const HOUR: f64 = 60.0 * MINUTE;
+#[cfg(feature = "chart-time")]
+const DAY: f64 = 24.0 * HOUR;
+#[cfg(feature = "chart-time")]
+const YEAR: f64 = 365.0 * DAY;
+
+/// The interval between two labels of a time axis
+#[cfg(feature = "chart-time")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TimeStep {
+    /// A fixed number of seconds
+    Seconds(f64),
+    /// A number of calendar months
+    Months(u8),
+    /// A number of calendar years
+    Years(i32),
+}
+
+#[cfg(feature = "chart-time")]
+impl TimeStep {
+    /// The steps between labels, from the smallest to the largest
+    const ALL: [Self; 24] = [
+        Self::Seconds(1.0),
+        Self::Seconds(5.0),
+        Self::Seconds(15.0),
+        Self::Seconds(30.0),
+        Self::Seconds(MINUTE),
+        Self::Seconds(5.0 * MINUTE),
+        Self::Seconds(15.0 * MINUTE),
+        Self::Seconds(30.0 * MINUTE),
+        Self::Seconds(HOUR),
+        Self::Seconds(3.0 * HOUR),
+        Self::Seconds(6.0 * HOUR),
+        Self::Seconds(12.0 * HOUR),
+        Self::Seconds(DAY),
+        Self::Seconds(2.0 * DAY),
+        Self::Seconds(7.0 * DAY),
+        Self::Months(1),
+        Self::Months(3),
+        Self::Months(6),
+        Self::Years(1),
+        Self::Years(2),
+        Self::Years(5),
+        Self::Years(10),
+        Self::Years(50),
+        Self::Years(100),
+    ];
+
+    /// Returns the average duration of the step in seconds
+    fn seconds(self) -> f64 {
+        match self {
+            Self::Seconds(seconds) => seconds,
+            Self::Months(months) => f64::from(months) * YEAR / 12.0,
+            Self::Years(years) => f64::from(years) * YEAR,
+        }
+    }
+
+    /// Returns the Unix timestamps of the step boundaries between `min` and `max`, in the given
+    /// offset, stopping after `limit` boundaries
+    ///
+    /// Steps of a week start on Mondays, and steps of several months or years start on months and
+    /// years that are multiples of the step (e.g. quarters start in January, April, July and
+    /// October).
+    fn boundaries(self, [min, max]: [f64; 2], offset: time::UtcOffset, limit: usize) -> Vec<f64> {
+        let local = f64::from(offset.whole_seconds());
+        let Ok(start) = time::OffsetDateTime::from_unix_timestamp(min.floor() as i64) else {
+            return Vec::new();
+        };
+        let start = start.to_offset(offset);
+        // the boundary before or at `min`, as a number of steps
+        let first = match self {
+            Self::Seconds(step) => ((min + local - week_origin(step)) / step).floor() as i64,
+            Self::Months(months) => {
+                (i64::from(start.year()) * 12 + i64::from(u8::from(start.month())) - 1)
+                    .div_euclid(i64::from(months))
+            }
+            Self::Years(years) => i64::from(start.year()).div_euclid(i64::from(years)),
+        };
+        let boundary = |index: i64| match self {
+            Self::Seconds(step) => Some((index as f64).mul_add(step, week_origin(step) - local)),
+            Self::Months(months) => {
+                let index = index * i64::from(months);
+                let month = time::Month::try_from((index.rem_euclid(12) + 1) as u8).ok()?;
+                let year = i32::try_from(index.div_euclid(12)).ok()?;
+                local_timestamp(year, month, offset)
+            }
+            Self::Years(years) => {
+                let year = i32::try_from(index * i64::from(years)).ok()?;
+                local_timestamp(year, time::Month::January, offset)
+            }
+        };
+        let mut boundaries = Vec::new();
+        for timestamp in (first..).map_while(boundary) {
+            if timestamp > max || boundaries.len() == limit {
+                break;
+            }
+            if timestamp >= min {
+                boundaries.push(timestamp);
+            }
+        }
+        boundaries
+    }
+
+    /// Formats a label with a precision matching the step
+    fn format(self, datetime: time::OffsetDateTime) -> String {
+        let (year, month, day) = datetime.to_calendar_date();
+        let (hour, minute, second) = datetime.to_hms();
+        match self {
+            Self::Seconds(step) if step < MINUTE => format!("{hour:02}:{minute:02}:{second:02}"),
+            Self::Seconds(step) if step < DAY => format!("{hour:02}:{minute:02}"),
+            Self::Seconds(_) => format!("{year}-{:02}-{day:02}", u8::from(month)),
+            Self::Months(_) => format!("{year}-{:02}", u8::from(month)),
+            Self::Years(_) => year.to_string(),
+        }
+    }
+}
+
+/// Returns the origin of the boundaries of a step in seconds, so that weeks start on Mondays
+#[cfg(feature = "chart-time")]
+fn week_origin(step: f64) -> f64 {
+    // 1970-01-05, the first Monday after the epoch
+    if step >= 7.0 * DAY {
+        4.0 * DAY
+    } else {
+        0.0
+    }
+}
+
+/// Returns the Unix timestamp of the first day of the month at midnight in the given offset
+#[cfg(feature = "chart-time")]
+fn local_timestamp(year: i32, month: time::Month, offset: time::UtcOffset) -> Option<f64> {
+    let date = time::Date::from_calendar_date(year, month, 1).ok()?;
+    let datetime = date.midnight().assume_offset(offset);
+    Some(datetime.unix_timestamp() as f64)
+}
+
+/// Generates the labels of a time axis and their positions
+///
+/// The smallest step is picked for which there are at least two and at most five labels at the
+/// boundaries of the step, and the labels don't overlap on an axis of the given `length` (in
+/// columns, or rows if the axis is `vertical`). If there is no such step, the bounds are labeled.
+#[cfg(feature = "chart-time")]
+fn time_labels(
+    [min, max]: [f64; 2],
+    offset: time::UtcOffset,
+    length: u16,
+    vertical: bool,
+) -> Option<(Vec<Line<'static>>, Vec<f64>)> {
+    const MAX_LABELS: usize = 5;
+    let range = max - min;
+    if !range.is_finite() || range == 0.0 {
+        return None;
+    }
+    let bounds = if range > 0.0 { [min, max] } else { [max, min] };
+    let cells = f64::from(length.saturating_sub(1));
+    let label = |step: TimeStep, timestamp: f64| {
+        let datetime = time::OffsetDateTime::from_unix_timestamp(timestamp.round() as i64).ok()?;
+        Some(step.format(datetime.to_offset(offset)))
+    };
+    let position = |timestamp: f64| (timestamp - min) / range;
+    for step in TimeStep::ALL {
+        if range.abs() / step.seconds() > (MAX_LABELS + 1) as f64 {
+            continue;
+        }
+        let boundaries = step.boundaries(bounds, offset, MAX_LABELS + 1);
+        if !(2..=MAX_LABELS).contains(&boundaries.len()) {
+            continue;
+        }
+        let Some(labels) = boundaries
+            .iter()
+            .map(|&timestamp| label(step, timestamp))
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+        // the labels (made of ASCII characters) are placed like `Chart::render_positioned_x_labels`
+        // does: centered on their position but kept inside the axis, and the labels of a
+        // horizontal axis are separated by at least one column
+        let (width, size) = if vertical {
+            (1.0, 1.0)
+        } else {
+            let width = labels.iter().map(String::len).max().unwrap_or(0) as f64;
+            (width, width + 1.0)
+        };
+        let start = |timestamp: f64| {
+            ((position(timestamp) * cells).round() - (width / 2.0).floor())
+                .min(cells + 1.0 - width)
+                .max(0.0)
+        };
+        let gap = boundaries
+            .windows(2)
+            .map(|pair| (start(pair[1]) - start(pair[0])).abs())
+            .fold(f64::INFINITY, f64::min);
+        if gap >= size {
+            let positions = boundaries.into_iter().map(position).collect();
+            return Some((labels.into_iter().map(Line::from).collect(), positions));
+        }
+    }
+    // the precision of the largest step in the range tells the bounds apart
+    let step = TimeStep::ALL
+        .into_iter()
+        .rev()
+        .find(|step| step.seconds() <= range.abs())
+        .unwrap_or(TimeStep::Seconds(1.0));
+    let labels = [min, max]
+        .into_iter()
+        .map(|timestamp| label(step, timestamp).map(Line::from))
+        .collect::<Option<_>>()?;
+    Some((labels, vec![0.0, 1.0]))
+}
+
+/// Reduces each run of consecutive points that fall in the same of `buckets` horizontal buckets of
+/// the bounds to its first, minimum, maximum and last points
+///
+/// Runs of points outside of the bounds are reduced the same way, so that the lines leaving and
+/// entering the bounds are preserved.
+fn downsample(data: &[(f64, f64)], [left, right]: [f64; 2], buckets: usize) -> Vec<(f64, f64)> {
+    let width = right - left;
+    let bucket = |x: f64| {
+        ((left..=right).contains(&x) && width > 0.0)
+            .then(|| (((x - left) / width * buckets as f64) as usize).min(buckets - 1))
+    };
+    let mut downsampled = Vec::with_capacity(4 * buckets);
+    let mut start = 0;
+    while start < data.len() {
+        let key = bucket(data[start].0);
+        let len = data[start..]
+            .iter()
+            .take_while(|(x, _)| bucket(*x) == key)
+            .count();
+        let run = &data[start..start + len];
+        let min = run
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.1.total_cmp(&b.1))
+            .map_or(0, |(index, _)| index);
+        let max = run
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| b.1.total_cmp(&a.1))
+            .map_or(0, |(index, _)| index);
+        let mut indices = [0, min, max, len - 1];
+        indices.sort_unstable();
+        let mut previous = None;
+        for index in indices {
+            if previous != Some(index) {
+                downsampled.push(run[index]);
+                previous = Some(index);
+            }
+        }
+        start += len;
+    }
+    downsampled
+}
+
+/// Returns the value at the given offset of a grid of `cells` cells spanning the bounds
+fn view_value([from, to]: [f64; 2], offset: u16, cells: u16) -> f64 {
+    let steps = f64::from(cells.saturating_sub(1));
+    if steps == 0.0 {
+        return from;
+    }
+    (to - from).mul_add(f64::from(offset) / steps, from)
+}
+
+/// Moves and shrinks the bounds to fit in the extent
+fn clamp_bounds([min, max]: [f64; 2], [extent_min, extent_max]: [f64; 2]) -> [f64; 2] {
+    let extent = extent_max - extent_min;
+    if extent <= 0.0 || !extent.is_finite() || !(max - min).is_finite() {
+        return [extent_min, extent_max];
+    }
+    let width = (max - min).clamp(0.0, extent);
+    let min = min.max(extent_min).min(extent_max - width);
+    [min, min + width]
+}
+
+/// Returns the offsets of the first and last cells covering the range of values in a grid of
+/// `cells` cells spanning the bounds, or `None` if the range is entirely out of the bounds
+fn grid_span([from, to]: [f64; 2], [min, max]: [f64; 2], cells: u16) -> Option<(u16, u16)> {
+    let (low, high) = (from.min(to), from.max(to));
+    if !low.is_finite() || !high.is_finite() || high < min || low > max {
+        return None;
+    }
+    let low = grid_offset(low.max(min), [min, max], cells)?;
+    let high = grid_offset(high.min(max), [min, max], cells)?;
+    Some((low, high))
+}
+
+/// Returns the offset of the cell where the value is displayed in a grid of `cells` cells
+/// spanning the bounds, or `None` if the value is out of the bounds
+fn grid_offset(value: f64, [min, max]: [f64; 2], cells: u16) -> Option<u16> {
+    if !(min..=max).contains(&value) || max - min <= 0.0 {
+        return None;
+    }
+    let steps = f64::from(cells.saturating_sub(1));
+    Some(((value - min) * steps / (max - min)).round() as u16)
+}
+
+/// The scale of an [`Axis`], i.e. how values are mapped to positions on the axis
+///
+/// See [`Axis::scale`]
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Scale {
+    /// Values are mapped linearly to positions. This is the default.
+    #[default]
+    Linear,
+    /// The logarithm in base 10 of values is mapped linearly to positions
+    Log10,
+}
+
+impl Scale {
+    /// Maps a value to its position in the linear space of the chart
+    fn apply(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 => value.log10(),
+        }
+    }
+
+    /// Maps a position in the linear space of the chart back to a value
+    fn invert(self, position: f64) -> f64 {
+        match self {
+            Self::Linear => position,
+            Self::Log10 => 10f64.powf(position),
+        }
+    }
 }
 
 /// Used to determine which style of graphing to use
@@ -170,6 +805,30 @@ pub enum GraphType {
 
     /// Draw a bar chart. This will draw a bar for each point in the dataset.
     Bar,
+
+    /// Draw horizontal and vertical segments between each following point.
+    ///
+    /// This is appropriate for counters, states and other discrete metrics whose value changes
+    /// at a point in time. See [`StepPosition`] for where the vertical segments are drawn.
+    Step(StepPosition),
+
+    /// Draw a candlestick for each open/high/low/close entry of the dataset.
+    ///
+    /// The wick spans from the low to the high value and the body from the open to the close
+    /// value. See [`Dataset::candles`] and [`Dataset::candle_styles`].
+    Candlestick,
+}
+
+/// Where the vertical segments of a [`GraphType::Step`] graph are drawn
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum StepPosition {
+    /// The value of a point holds until the next point: the horizontal segment is drawn first,
+    /// then the vertical segment at the X coordinate of the next point. This is the default.
+    #[default]
+    After,
+    /// The value of a point holds from the previous point: the vertical segment is drawn first,
+    /// at the X coordinate of the previous point, then the horizontal segment.
+    Before,
 }
 
 /// Allow users to specify the position of a legend in a [`Chart`]
@@ -194,9 +853,90 @@ pub enum LegendPosition {
     BottomRight,
     /// Legend is in the bottom-left corner
     BottomLeft,
+    /// Legend is outside of the graph, at the top of a column reserved on the right of the chart
+    OutsideRight,
+    /// Legend is outside of the graph, at the top of a column reserved on the left of the chart
+    OutsideLeft,
+    /// Legend is outside of the graph, on the right of rows reserved above the chart
+    OutsideTop,
+    /// Legend is outside of the graph, on the right of rows reserved below the chart
+    OutsideBottom,
 }
 
 impl LegendPosition {
+    /// Returns whether the legend is placed outside of the graph
+    const fn is_outside(self) -> bool {
+        matches!(
+            self,
+            Self::OutsideRight | Self::OutsideLeft | Self::OutsideTop | Self::OutsideBottom
+        )
+    }
+
+    /// Reserves room for a legend placed outside of the graph, returning the area of the legend
+    /// and the area left for the rest of the chart, or `None` if the legend doesn't fit
+    fn split_outside(
+        self,
+        area: Rect,
+        legend_width: u16,
+        legend_height: u16,
+    ) -> Option<(Rect, Rect)> {
+        let (legend, rest) = match self {
+            Self::OutsideRight | Self::OutsideLeft => {
+                if legend_width >= area.width || legend_height > area.height {
+                    return None;
+                }
+                let rest_width = area.width - legend_width;
+                if self == Self::OutsideRight {
+                    let legend_x = area.left() + rest_width;
+                    (
+                        Rect::new(legend_x, area.top(), legend_width, legend_height),
+                        Rect {
+                            width: rest_width,
+                            ..area
+                        },
+                    )
+                } else {
+                    (
+                        Rect::new(area.left(), area.top(), legend_width, legend_height),
+                        Rect {
+                            x: area.left() + legend_width,
+                            width: rest_width,
+                            ..area
+                        },
+                    )
+                }
+            }
+            Self::OutsideTop | Self::OutsideBottom => {
+                if legend_height >= area.height || legend_width > area.width {
+                    return None;
+                }
+                let rest_height = area.height - legend_height;
+                let legend_x = area.right() - legend_width;
+                if self == Self::OutsideBottom {
+                    let legend_y = area.top() + rest_height;
+                    (
+                        Rect::new(legend_x, legend_y, legend_width, legend_height),
+                        Rect {
+                            height: rest_height,
+                            ..area
+                        },
+                    )
+                } else {
+                    (
+                        Rect::new(legend_x, area.top(), legend_width, legend_height),
+                        Rect {
+                            y: area.top() + legend_height,
+                            height: rest_height,
+                            ..area
+                        },
+                    )
+                }
+            }
+            _ => return None,
+        };
+        Some((legend, rest))
+    }
+
     fn layout(
         self,
         area: Rect,
@@ -284,12 +1024,47 @@ impl LegendPosition {
                     (x, area.bottom() - legend_height)
                 }
             }
+            // placed by `split_outside` before the layout of the graph
+            Self::OutsideRight | Self::OutsideLeft | Self::OutsideTop | Self::OutsideBottom => {
+                return None;
+            }
         };
 
         Some(Rect::new(x, y, legend_width, legend_height))
     }
 }
 
+/// An open/high/low/close entry of a candlestick [`Dataset`]
+///
+/// See [`Dataset::candles`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::Candle;
+///
+/// let candle = Candle {
+///     x: 0.0,
+///     open: 10.0,
+///     high: 12.5,
+///     low: 9.0,
+///     close: 12.0,
+/// };
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Candle {
+    /// The position of the candle on the X axis
+    pub x: f64,
+    /// The value at the start of the period
+    pub open: f64,
+    /// The highest value of the period
+    pub high: f64,
+    /// The lowest value of the period
+    pub low: f64,
+    /// The value at the end of the period
+    pub close: f64,
+}
+
 /// A group of data points
 ///
 /// This is the main element composing a [`Chart`].
@@ -332,6 +1107,24 @@ pub struct Dataset<'a> {
     graph_type: GraphType,
     /// Style used to plot this dataset
     style: Style,
+    /// Whether the dataset is plotted against the secondary Y axis of the chart
+    secondary_y_axis: bool,
+    /// Name of the stack group this dataset is accumulated in, if any
+    stack_group: Option<Cow<'a, str>>,
+    /// The open/high/low/close entries drawn as candlesticks
+    candles: &'a [Candle],
+    /// Style of the candlesticks whose close value is greater than or equal to the open value
+    up_style: Style,
+    /// Style of the candlesticks whose close value is less than the open value
+    down_style: Style,
+    /// Symbol displayed before the name of the dataset in the legend
+    legend_symbol: Option<Cow<'a, str>>,
+    /// The error range of each point, as low and high values of the Y axis
+    error_bars: &'a [(f64, f64)],
+    /// Style of the error bars, added to the style of the dataset
+    error_bar_style: Style,
+    /// Symbol drawn at each point instead of the marker
+    point_symbol: Option<Cow<'a, str>>,
 }
 
 impl<'a> Dataset<'a> {
@@ -370,6 +1163,88 @@ impl<'a> Dataset<'a> {
         self
     }
 
+    /// Sets the open/high/low/close entries of this dataset and draws them as candlesticks
+    ///
+    /// Each entry is a [`Candle`] and is drawn in its own column
+    /// with a thin wick (`│`) from the low to the high value and a thick body (`┃`) from the open
+    /// to the close value. This also sets the graph type to [`GraphType::Candlestick`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::{Candle, Dataset},
+    /// };
+    ///
+    /// let candles = [
+    ///     Candle {
+    ///         x: 0.0,
+    ///         open: 10.0,
+    ///         high: 12.5,
+    ///         low: 9.0,
+    ///         close: 12.0,
+    ///     },
+    ///     Candle {
+    ///         x: 1.0,
+    ///         open: 12.0,
+    ///         high: 12.2,
+    ///         low: 10.5,
+    ///         close: 11.0,
+    ///     },
+    /// ];
+    /// let dataset = Dataset::default()
+    ///     .name("ACME")
+    ///     .candles(&candles)
+    ///     .candle_styles(Style::new().green(), Style::new().red());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn candles(mut self, candles: &'a [Candle]) -> Self {
+        self.candles = candles;
+        self.graph_type = GraphType::Candlestick;
+        self
+    }
+
+    /// Sets the styles of the rising and falling candlesticks
+    ///
+    /// A candlestick is rising when its close value is greater than or equal to its open value.
+    /// The styles are added to the [style](Dataset::style) of the dataset.
+    ///
+    /// `up` and `down` accept any type that is convertible to [`Style`] (e.g. [`Style`],
+    /// [`Color`], or your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn candle_styles<U: Into<Style>, D: Into<Style>>(mut self, up: U, down: D) -> Self {
+        self.up_style = up.into();
+        self.down_style = down.into();
+        self
+    }
+
+    /// Sets the symbol drawn at each point of the dataset, e.g. `●`, `×` or `◆`
+    ///
+    /// The symbol takes a whole cell and is drawn on top of the lines of the dataset instead of the
+    /// [marker](Dataset::marker), which still determines the resolution of the lines. This makes
+    /// overlapping scatter plots distinguishable without colors. By default, points are drawn with
+    /// the marker.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Dataset;
+    ///
+    /// let dataset = Dataset::default().point_symbol("×");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn point_symbol<T: Into<Cow<'a, str>>>(mut self, symbol: T) -> Self {
+        self.point_symbol = Some(symbol.into());
+        self
+    }
+
     /// Sets the kind of character to use to display this dataset
     ///
     /// You can use dots (`•`), blocks (`█`), bars (`▄`), braille (`⠓`, `⣇`, `⣿`) or half-blocks
@@ -390,7 +1265,8 @@ impl<'a> Dataset<'a> {
     /// [`Chart`] can draw [scatter](GraphType::Scatter), [line](GraphType::Line) or
     /// [bar](GraphType::Bar) charts. A scatter chart draws only the points in the dataset, a line
     /// char draws a line between each point, and a bar chart draws a line from the x axis to the
-    /// point.  See [`GraphType`] for more details
+    /// point. Candlestick charts are drawn from the entries set with [`Dataset::candles`]. See
+    /// [`GraphType`] for more details
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     #[must_use = "method moves the value of self and returns the modified value"]
@@ -424,14 +1300,209 @@ impl<'a> Dataset<'a> {
         self.style = style.into();
         self
     }
-}
 
-/// A container that holds all the infos about where to display each elements of the chart (axis,
-/// labels, legend, ...).
-struct ChartLayout {
-    /// Location of the title of the x axis
-    title_x: Option<Position>,
-    /// Location of the title of the y axis
+    /// Sets whether the dataset is plotted against the secondary Y axis of the chart
+    ///
+    /// By default, datasets are plotted against the Y axis on the left. Datasets plotted against
+    /// the secondary Y axis use its bounds and scale instead, which allows plotting two series with
+    /// different units in the same chart. This has no effect if the chart has no secondary Y axis
+    /// (see [`Chart::secondary_y_axis`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Dataset;
+    ///
+    /// let dataset = Dataset::default().name("MB/s").secondary_y_axis(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn secondary_y_axis(mut self, secondary: bool) -> Self {
+        self.secondary_y_axis = secondary;
+        self
+    }
+
+    /// Adds the dataset to a stack group
+    ///
+    /// Datasets that share the same stack group (and Y axis) are accumulated in the order they are
+    /// given to the [`Chart`]: the values of each dataset are added on top of the values of the
+    /// previous datasets in the group, and the area between them is filled with the color of the
+    /// dataset. This is useful to show how a total is composed over time.
+    ///
+    /// The values of the previous datasets are linearly interpolated, so the datasets don't need
+    /// to share the same X coordinates, but the points of each dataset must be sorted by X. The
+    /// [`GraphType`] of a stacked dataset is ignored.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{style::Stylize, widgets::Dataset};
+    ///
+    /// let user = [(0.0, 20.0), (1.0, 35.0)];
+    /// let system = [(0.0, 10.0), (1.0, 5.0)];
+    /// let datasets = vec![
+    ///     Dataset::default().name("user").data(&user).stack_group("cpu").green(),
+    ///     Dataset::default().name("system").data(&system).stack_group("cpu").red(),
+    /// ];
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn stack_group<T: Into<Cow<'a, str>>>(mut self, group: T) -> Self {
+        self.stack_group = Some(group.into());
+        self
+    }
+
+    /// Sets the error range of each point of the dataset, drawn as vertical whiskers
+    ///
+    /// The n-th range applies to the n-th [data point](Dataset::data) and is given as the `(low,
+    /// high)` values of the Y axis. Each range is drawn in the column of its point, with a `┬` at
+    /// the high value, a `┴` at the low value and a `│` in between, behind the point itself.
+    /// Ranges beyond the bounds of the Y axis are cut at the bounds.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Dataset;
+    ///
+    /// let data = [(0.0, 5.0), (1.0, 7.5)];
+    /// let errors = [(4.5, 5.5), (6.0, 9.0)];
+    /// let dataset = Dataset::default().data(&data).error_bars(&errors);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn error_bars(mut self, ranges: &'a [(f64, f64)]) -> Self {
+        self.error_bars = ranges;
+        self
+    }
+
+    /// Sets the style of the error bars
+    ///
+    /// The style is added to the [style](Dataset::style) of the dataset.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn error_bar_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.error_bar_style = style.into();
+        self
+    }
+
+    /// Sets a symbol displayed before the name of the dataset in the legend
+    ///
+    /// The symbol is drawn with the style of the dataset, which makes it easy to match the entries
+    /// of the legend with the series of the graph. By default, only the name is displayed.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{style::Stylize, widgets::Dataset};
+    ///
+    /// let dataset = Dataset::default().name("CPU").legend_symbol("━").cyan();
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn legend_symbol<T: Into<Cow<'a, str>>>(mut self, symbol: T) -> Self {
+        self.legend_symbol = Some(symbol.into());
+        self
+    }
+
+    /// Returns the width of the entry of the dataset in the legend, or `None` if the dataset is
+    /// not named
+    fn legend_width(&self) -> Option<u16> {
+        let symbol_width = self
+            .legend_symbol
+            .as_deref()
+            .map_or(0, |symbol| Span::raw(symbol).width() + 1);
+        Some((self.name.as_ref()?.width() + symbol_width) as u16)
+    }
+
+    /// Returns the entry of the dataset in the legend, or `None` if the dataset is not named
+    fn legend_entry(&self) -> Option<Line<'a>> {
+        let name = self.name.clone()?;
+        let Some(symbol) = self.legend_symbol.clone() else {
+            return Some(name.patch_style(self.style));
+        };
+        let mut entry = Line::from(vec![Span::raw(symbol), Span::raw(" ")]);
+        entry.extend(name.spans);
+        Some(entry.style(name.style).patch_style(self.style))
+    }
+}
+
+/// The filled area of a stacked [`Dataset`], between the accumulated values of the previous
+/// datasets of its stack group (or the base of the chart) and its own accumulated values
+struct StackedArea<'d> {
+    lower: Option<&'d [(f64, f64)]>,
+    upper: &'d [(f64, f64)],
+    base: f64,
+    color: Color,
+}
+
+impl Shape for StackedArea<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        let (&[left, right], &[bottom, top]) = painter.bounds();
+        let Some((max_column, _)) = painter.get_point(right, bottom) else {
+            return;
+        };
+        for column in 0..=max_column {
+            let x = if max_column == 0 {
+                left
+            } else {
+                (left + (right - left) * column as f64 / max_column as f64).min(right)
+            };
+            let Some(upper) = interpolate(self.upper, x) else {
+                continue;
+            };
+            let lower = self
+                .lower
+                .and_then(|lower| interpolate(lower, x))
+                .unwrap_or(self.base);
+            let (Some((_, y1)), Some((_, y2))) = (
+                painter.get_point(x, upper.max(bottom).min(top)),
+                painter.get_point(x, lower.max(bottom).min(top)),
+            ) else {
+                continue;
+            };
+            for y in y1.min(y2)..=y1.max(y2) {
+                painter.paint(column, y, self.color);
+            }
+        }
+    }
+}
+
+/// The accumulated data of a stacked dataset and the index of the previous dataset in its stack
+/// group
+type StackedData = (Vec<(f64, f64)>, Option<usize>);
+
+/// Returns the value of the piecewise linear function through the points at `x`, or `None` if `x`
+/// is outside of the range of the points
+fn interpolate(data: &[(f64, f64)], x: f64) -> Option<f64> {
+    match data {
+        [(x1, y1)] if (x1 - x).abs() < f64::EPSILON => Some(*y1),
+        _ => data.windows(2).find_map(|window| {
+            let [(x1, y1), (x2, y2)] = [window[0], window[1]];
+            if x < x1 || x > x2 {
+                None
+            } else if (x2 - x1).abs() < f64::EPSILON {
+                Some(y2)
+            } else {
+                Some(y1 + (y2 - y1) * (x - x1) / (x2 - x1))
+            }
+        }),
+    }
+}
+
+/// A container that holds all the infos about where to display each elements of the chart (axis,
+/// labels, legend, ...).
+struct ChartLayout {
+    /// Location of the title of the x axis
+    title_x: Option<Position>,
+    /// Location of the title of the y axis
     title_y: Option<Position>,
     /// Location of the first label of the x axis
     label_x: Option<u16>,
@@ -441,6 +1512,12 @@ struct ChartLayout {
     axis_x: Option<u16>,
     /// X coordinate of the vertical axis
     axis_y: Option<u16>,
+    /// Location of the title of the secondary y axis
+    title_y2: Option<Position>,
+    /// X coordinate of the labels of the secondary y axis
+    label_y2: Option<u16>,
+    /// X coordinate of the secondary vertical axis
+    axis_y2: Option<u16>,
     /// Area of the legend
     legend_area: Option<Rect>,
     /// Area of the graph
@@ -464,6 +1541,10 @@ struct ChartLayout {
 /// Additionally, `Chart` allows configuring the legend [position](Chart::legend_position) and
 /// [hiding constraints](Chart::hidden_legend_constraints).
 ///
+/// When rendered as a [`StatefulWidget`] with a [`ChartState`], the user can select an X-range by
+/// dragging the mouse over the graph (see [`ChartState::start_brush`]), which is highlighted with
+/// the [brush style](Chart::brush_style).
+///
 /// # Examples
 ///
 /// ```
@@ -519,6 +1600,8 @@ pub struct Chart<'a> {
     x_axis: Axis<'a>,
     /// The vertical axis
     y_axis: Axis<'a>,
+    /// The optional vertical axis on the right
+    secondary_y_axis: Option<Axis<'a>>,
     /// A reference to the datasets
     datasets: Vec<Dataset<'a>>,
     /// The widget base style
@@ -528,6 +1611,16 @@ pub struct Chart<'a> {
     /// The position determine where the length is shown or hide regardless of
     /// `hidden_legend_constraints`
     legend_position: Option<LegendPosition>,
+    /// The style of the columns selected with the brush of the [`ChartState`]
+    brush_style: Style,
+    /// The style of the crosshair drawn at the cursor of the [`ChartState`]
+    crosshair_style: Style,
+    /// Whether large datasets drawn with lines are downsampled to the resolution of the graph
+    downsample: bool,
+    /// The lines marking values of the axes, drawn behind the datasets
+    reference_lines: Vec<ReferenceLine<'a>>,
+    /// The shaded ranges of values of the axes, drawn behind the datasets
+    reference_bands: Vec<ReferenceBand<'a>>,
 }
 
 impl<'a> Chart<'a> {
@@ -563,10 +1656,16 @@ impl<'a> Chart<'a> {
             block: None,
             x_axis: Axis::default(),
             y_axis: Axis::default(),
+            secondary_y_axis: None,
             style: Style::default(),
             datasets,
             hidden_legend_constraints: (Constraint::Ratio(1, 4), Constraint::Ratio(1, 4)),
             legend_position: Some(LegendPosition::default()),
+            brush_style: Style::new().add_modifier(Modifier::REVERSED),
+            crosshair_style: Style::new().fg(Color::DarkGray),
+            downsample: true,
+            reference_lines: vec![],
+            reference_bands: vec![],
         }
     }
 
@@ -641,6 +1740,35 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Sets the secondary Y [`Axis`], displayed on the right of the graph
+    ///
+    /// The secondary axis has its own bounds, scale, labels and title (displayed at the top
+    /// right). Datasets are plotted against it when [`Dataset::secondary_y_axis`] is set, which
+    /// allows two series with different units (e.g. CPU % and MB/s) to share one chart. There is no
+    /// secondary Y axis by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Axis, Chart, Dataset};
+    ///
+    /// let cpu = [(0.0, 25.0), (1.0, 50.0)];
+    /// let io = [(0.0, 120.0), (1.0, 80.0)];
+    /// let chart = Chart::new(vec![
+    ///     Dataset::default().name("CPU %").data(&cpu),
+    ///     Dataset::default().name("MB/s").data(&io).secondary_y_axis(true),
+    /// ])
+    /// .y_axis(Axis::default().bounds([0.0, 100.0]).labels(["0", "100"]))
+    /// .secondary_y_axis(Axis::default().bounds([0.0, 200.0]).labels(["0", "200"]));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn secondary_y_axis(mut self, axis: Axis<'a>) -> Self {
+        self.secondary_y_axis = Some(axis);
+        self
+    }
+
     /// Sets the constraints used to determine whether the legend should be shown or not.
     ///
     /// The tuple's first constraint is used for the width and the second for the height. If the
@@ -729,116 +1857,794 @@ impl<'a> Chart<'a> {
         self
     }
 
-    /// Compute the internal layout of the chart given the area. If the area is too small some
-    /// elements may be automatically hidden
-    fn layout(&self, area: Rect) -> Option<ChartLayout> {
-        if area.height == 0 || area.width == 0 {
-            return None;
-        }
-        let mut x = area.left();
-        let mut y = area.bottom() - 1;
+    /// Sets the style of the X-range selected with the brush of the [`ChartState`]
+    ///
+    /// The style is applied to the columns of the graph area that are inside the selection. The
+    /// default is reversed.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn brush_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.brush_style = style.into();
+        self
+    }
 
-        let mut label_x = None;
-        if !self.x_axis.labels.is_empty() && y > area.top() {
-            label_x = Some(y);
-            y -= 1;
-        }
+    /// Sets the style of the crosshair drawn at the cursor of the [`ChartState`]
+    ///
+    /// The crosshair is only drawn on the cells of the graph area that are not used by the
+    /// datasets. The default is a dark gray foreground.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn crosshair_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.crosshair_style = style.into();
+        self
+    }
 
-        let label_y = self.y_axis.labels.is_empty().not().then_some(x);
-        x += self.max_width_of_labels_left_of_y_axis(area, !self.y_axis.labels.is_empty());
+    /// Sets whether large datasets are downsampled before being drawn
+    ///
+    /// When a dataset drawn with lines ([`GraphType::Line`], [`GraphType::Step`],
+    /// [`GraphType::Bar`] or a [stack group](Dataset::stack_group)) has many more points than the horizontal resolution of the
+    /// graph, only the first, minimum, maximum and last points of each run of consecutive points
+    /// falling in the same horizontal position are drawn. This keeps drawing millions of points
+    /// fast while preserving the shape of the lines, including their spikes. Scatter plots are
+    /// never downsampled.
+    ///
+    /// Downsampling is enabled by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn downsample(mut self, downsample: bool) -> Self {
+        self.downsample = downsample;
+        self
+    }
 
-        let mut axis_x = None;
-        if !self.x_axis.labels.is_empty() && y > area.top() {
-            axis_x = Some(y);
-            y -= 1;
-        }
+    /// Sets the reference lines of the chart
+    ///
+    /// [Reference lines](ReferenceLine) mark values of the X axis or of the (primary) Y axis, e.g. a
+    /// target. They are drawn behind the datasets.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::Stylize,
+    ///     widgets::{Chart, ReferenceLine},
+    /// };
+    ///
+    /// let chart = Chart::new(vec![])
+    ///     .reference_lines([ReferenceLine::horizontal(80.0).green().label("target")]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn reference_lines<I>(mut self, lines: I) -> Self
+    where
+        I: IntoIterator<Item = ReferenceLine<'a>>,
+    {
+        self.reference_lines = lines.into_iter().collect();
+        self
+    }
 
-        let mut axis_y = None;
-        if !self.y_axis.labels.is_empty() && x + 1 < area.right() {
-            axis_y = Some(x);
-            x += 1;
+    /// Sets the reference bands of the chart
+    ///
+    /// [Reference bands](ReferenceBand) shade ranges of values of the X axis or of the (primary) Y
+    /// axis, e.g. warning and critical thresholds. They are drawn behind the datasets and the
+    /// reference lines, in order.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::Stylize,
+    ///     widgets::{Chart, ReferenceBand},
+    /// };
+    ///
+    /// let chart = Chart::new(vec![]).reference_bands([
+    ///     ReferenceBand::horizontal([70.0, 90.0]).on_yellow(),
+    ///     ReferenceBand::horizontal([90.0, 100.0]).on_red().label("critical"),
+    /// ]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn reference_bands<I>(mut self, bands: I) -> Self
+    where
+        I: IntoIterator<Item = ReferenceBand<'a>>,
+    {
+        self.reference_bands = bands.into_iter().collect();
+        self
+    }
+
+    /// Downsamples the points of a dataset drawn with lines if there are many more points than
+    /// horizontal positions in the graph (see [`Chart::downsample`])
+    fn downsampled<'d>(
+        &self,
+        data: Cow<'d, [(f64, f64)]>,
+        marker: symbols::Marker,
+        width: u16,
+    ) -> Cow<'d, [(f64, f64)]> {
+        // the braille and octant grids have 2 dots per cell horizontally
+        let resolution = match marker {
+            symbols::Marker::Braille | symbols::Marker::Octant => 2,
+            _ => 1,
+        };
+        let buckets = usize::from(width) * resolution;
+        // each run of points is reduced to at most 4 points
+        if !self.downsample || buckets == 0 || data.len() <= 4 * buckets {
+            return data;
         }
+        Cow::Owned(downsample(&data, self.x_axis.scaled_bounds(), buckets))
+    }
 
-        let graph_width = area.right().saturating_sub(x);
-        let graph_height = y.saturating_sub(area.top()).saturating_add(1);
-        debug_assert_ne!(
-            graph_width, 0,
-            "Axis and labels should have been hidden due to the small area"
-        );
-        debug_assert_ne!(
-            graph_height, 0,
-            "Axis and labels should have been hidden due to the small area"
-        );
-        let graph_area = Rect::new(x, area.top(), graph_width, graph_height);
+    /// Returns a copy of the chart with the bounds of its automatic axes computed from the data of
+    /// the visible datasets, or `None` if no axis has automatic bounds (see [`Axis::bounds_auto`])
+    fn with_auto_bounds(&self, hidden: &BTreeSet<usize>) -> Option<Self> {
+        let secondary = self.secondary_y_axis.as_ref();
+        if !self.x_axis.auto_bounds
+            && !self.y_axis.auto_bounds
+            && !secondary.is_some_and(|axis| axis.auto_bounds)
+        {
+            return None;
+        }
+        let stacked = self.stacked_data(hidden);
+        let visible = || {
+            self.datasets
+                .iter()
+                .zip(&stacked)
+                .enumerate()
+                .filter(|(index, _)| !hidden.contains(index))
+                .map(|(_, dataset)| dataset)
+        };
+        let mut chart = self.clone();
+        if self.x_axis.auto_bounds {
+            let values = visible().flat_map(|(dataset, _)| {
+                let candles = dataset.candles.iter().map(|candle| candle.x);
+                dataset.data.iter().map(|(x, _)| *x).chain(candles)
+            });
+            chart.x_axis = self.x_axis.with_auto_bounds(values);
+        }
+        let y_values = |secondary: bool| {
+            visible()
+                .filter(move |(dataset, _)| {
+                    (dataset.secondary_y_axis && self.secondary_y_axis.is_some()) == secondary
+                })
+                .flat_map(|(dataset, stack)| {
+                    let data = stack
+                        .as_ref()
+                        .map_or(dataset.data, |(data, _)| data.as_slice());
+                    let base =
+                        (stack.is_some() || dataset.graph_type == GraphType::Bar).then_some(0.0);
+                    let candles = dataset
+                        .candles
+                        .iter()
+                        .flat_map(|candle| [candle.open, candle.high, candle.low, candle.close]);
+                    let errors = dataset
+                        .error_bars
+                        .iter()
+                        .flat_map(|&(low, high)| [low, high]);
+                    data.iter()
+                        .map(|(_, y)| *y)
+                        .chain(base)
+                        .chain(candles)
+                        .chain(errors)
+                })
+        };
+        if self.y_axis.auto_bounds {
+            chart.y_axis = self.y_axis.with_auto_bounds(y_values(false));
+        }
+        if let Some(axis) = secondary.filter(|axis| axis.auto_bounds) {
+            chart.secondary_y_axis = Some(axis.with_auto_bounds(y_values(true)));
+        }
+        Some(chart)
+    }
 
-        let mut title_x = None;
-        if let Some(ref title) = self.x_axis.title {
-            let w = title.width() as u16;
-            if w < graph_area.width && graph_area.height > 2 {
-                title_x = Some(Position::new(x + graph_area.width - w, y));
-            }
+    /// Returns a copy of the chart with generated labels for the axes that need them, or `None`
+    /// if no labels need to be generated
+    ///
+    /// The labels of time axes are generated for the size of the graph area of the chart rendered
+    /// in `area`. The Y labels are generated first, as their width changes the width of the graph.
+    fn with_generated_labels(&self, area: Rect) -> Option<Self> {
+        let graph_area = |chart: &Self| chart.layout(area).map_or(Rect::ZERO, |l| l.graph_area);
+        let has_time_y_labels = self.y_axis.has_time_labels()
+            || self
+                .secondary_y_axis
+                .as_ref()
+                .is_some_and(Axis::has_time_labels);
+        let height = if has_time_y_labels {
+            graph_area(self).height
+        } else {
+            0
+        };
+        let y_labels = self.y_axis.generated_labels(height, true);
+        let secondary_labels = self
+            .secondary_y_axis
+            .as_ref()
+            .and_then(|axis| axis.generated_labels(height, true));
+        let mut chart = (y_labels.is_some() || secondary_labels.is_some()).then(|| self.clone());
+        if let (Some(chart), Some((labels, positions))) = (chart.as_mut(), y_labels) {
+            chart.y_axis.labels = labels;
+            chart.y_axis.label_positions = positions;
+        }
+        let secondary_axis = chart
+            .as_mut()
+            .and_then(|chart| chart.secondary_y_axis.as_mut());
+        if let (Some(axis), Some((labels, positions))) = (secondary_axis, secondary_labels) {
+            axis.labels = labels;
+            axis.label_positions = positions;
+        }
+        let width = if self.x_axis.has_time_labels() {
+            graph_area(chart.as_ref().unwrap_or(self)).width
+        } else {
+            0
+        };
+        if let Some((labels, positions)) = self.x_axis.generated_labels(width, false) {
+            let chart = chart.get_or_insert_with(|| self.clone());
+            chart.x_axis.labels = labels;
+            chart.x_axis.label_positions = positions;
         }
+        chart
+    }
 
-        let mut title_y = None;
-        if let Some(ref title) = self.y_axis.title {
-            let w = title.width() as u16;
-            if w + 1 < graph_area.width && graph_area.height > 2 {
-                title_y = Some(Position::new(x, area.top()));
-            }
+    /// Returns a copy of the chart showing the given view of the [`ChartState`], or `None` if
+    /// there is no view
+    fn with_view(&self, view: Option<[[f64; 2]; 2]>) -> Option<Self> {
+        let [x, y] = view?;
+        let mut chart = self.clone();
+        chart.x_axis = self.x_axis.with_view(x);
+        chart.y_axis = self.y_axis.with_view(y);
+        Some(chart)
+    }
+
+    /// Returns the Y axis the dataset is plotted against
+    const fn dataset_y_axis(&self, dataset: &Dataset) -> &Axis<'a> {
+        match &self.secondary_y_axis {
+            Some(axis) if dataset.secondary_y_axis => axis,
+            _ => &self.y_axis,
         }
+    }
 
-        let mut legend_area = None;
-        if let Some(legend_position) = self.legend_position {
-            let legends = self
-                .datasets
-                .iter()
-                .filter_map(|d| Some(d.name.as_ref()?.width() as u16));
-
-            if let Some(inner_width) = legends.clone().max() {
-                let legend_width = inner_width + 2;
-                let legend_height = legends.count() as u16 + 2;
-
-                let [max_legend_width] = Layout::horizontal([self.hidden_legend_constraints.0])
-                    .flex(Flex::Start)
-                    .areas(graph_area);
-
-                let [max_legend_height] = Layout::vertical([self.hidden_legend_constraints.1])
-                    .flex(Flex::Start)
-                    .areas(graph_area);
-
-                if inner_width > 0
-                    && legend_width <= max_legend_width.width
-                    && legend_height <= max_legend_height.height
-                {
-                    legend_area = legend_position.layout(
-                        graph_area,
-                        legend_width,
-                        legend_height,
-                        title_x
-                            .and(self.x_axis.title.as_ref())
-                            .map(|t| t.width() as u16)
-                            .unwrap_or_default(),
-                        title_y
-                            .and(self.y_axis.title.as_ref())
-                            .map(|t| t.width() as u16)
-                            .unwrap_or_default(),
-                    );
-                }
+    /// Returns the area of the graph covered by the range of values of the X axis (vertical) or of
+    /// the Y axis (horizontal), or `None` if the range is out of the bounds of the axis
+    fn reference_area(
+        &self,
+        graph_area: Rect,
+        direction: Direction,
+        bounds: [f64; 2],
+    ) -> Option<Rect> {
+        if graph_area.is_empty() {
+            return None;
+        }
+        match direction {
+            Direction::Horizontal => {
+                let scale = self.y_axis.scale;
+                let bounds = bounds.map(|y| scale.apply(y));
+                let (low, high) =
+                    grid_span(bounds, self.y_axis.scaled_bounds(), graph_area.height)?;
+                Some(Rect {
+                    y: graph_area.bottom() - 1 - high,
+                    height: high - low + 1,
+                    ..graph_area
+                })
+            }
+            Direction::Vertical => {
+                let scale = self.x_axis.scale;
+                let bounds = bounds.map(|x| scale.apply(x));
+                let (low, high) = grid_span(bounds, self.x_axis.scaled_bounds(), graph_area.width)?;
+                Some(Rect {
+                    x: graph_area.left() + low,
+                    width: high - low + 1,
+                    ..graph_area
+                })
             }
         }
-        Some(ChartLayout {
-            title_x,
-            title_y,
-            label_x,
-            label_y,
-            axis_x,
-            axis_y,
-            legend_area,
-            graph_area,
-        })
     }
 
-    fn max_width_of_labels_left_of_y_axis(&self, area: Rect, has_y_axis: bool) -> u16 {
+    /// Draws the labels of the reference bands and lines
+    ///
+    /// The labels are drawn before the datasets so that the datasets are drawn over them.
+    fn render_reference_labels(&self, buf: &mut Buffer, graph_area: Rect) {
+        let bands = self.reference_bands.iter().filter_map(|band| {
+            let area = self.reference_area(graph_area, band.direction, band.bounds)?;
+            Some((band.label.as_ref()?, band.style, area.as_position()))
+        });
+        let lines = self.reference_lines.iter().filter_map(|line| {
+            let label = line.label.as_ref()?;
+            let area = self.reference_area(graph_area, line.direction, [line.value; 2])?;
+            let width = label.width() as u16;
+            let position = match line.direction {
+                Direction::Horizontal => {
+                    Position::new(graph_area.right().saturating_sub(width).max(area.x), area.y)
+                }
+                Direction::Vertical if area.x + 1 + width <= graph_area.right() => {
+                    Position::new(area.x + 1, area.y)
+                }
+                Direction::Vertical => {
+                    Position::new(area.x.saturating_sub(width).max(graph_area.x), area.y)
+                }
+            };
+            Some((label, line.style, position))
+        });
+        for (label, style, Position { x, y }) in bands.chain(lines) {
+            let width = graph_area
+                .right()
+                .saturating_sub(x)
+                .min(label.width() as u16);
+            buf.set_style(Rect::new(x, y, width, 1), style);
+            buf.set_line(x, y, label, width);
+        }
+    }
+
+    /// Draws the reference bands and lines on the cells of the graph that are not used by the
+    /// datasets
+    ///
+    /// The cells of a band that are used by the datasets only get the background color of the
+    /// band.
+    fn render_references(&self, buf: &mut Buffer, graph_area: Rect) {
+        for band in &self.reference_bands {
+            let Some(area) = self.reference_area(graph_area, band.direction, band.bounds) else {
+                continue;
+            };
+            for position in area.positions() {
+                let cell = &mut buf[position];
+                match band.style.bg {
+                    _ if cell.symbol() == " " => {
+                        cell.set_style(band.style);
+                    }
+                    Some(bg) => {
+                        cell.set_bg(bg);
+                    }
+                    None => {}
+                }
+            }
+        }
+        for line in &self.reference_lines {
+            let Some(area) = self.reference_area(graph_area, line.direction, [line.value; 2])
+            else {
+                continue;
+            };
+            let symbol = match line.direction {
+                Direction::Horizontal => symbols::line::HORIZONTAL,
+                Direction::Vertical => symbols::line::VERTICAL,
+            };
+            for position in area.positions() {
+                let cell = &mut buf[position];
+                if cell.symbol() == " " {
+                    cell.set_symbol(symbol).set_style(line.style);
+                }
+            }
+        }
+    }
+
+    /// Draws the crosshair at the cursor of the state and the values of the points nearest to it
+    fn render_crosshair(&self, buf: &mut Buffer, state: &mut ChartState) {
+        state.nearest_points.clear();
+        let graph_area = state.graph_area;
+        let Some((cursor_x, cursor_y)) = state.cursor else {
+            return;
+        };
+        if graph_area.is_empty() {
+            return;
+        }
+        let (column, row) = (state.column(cursor_x), state.row(cursor_y));
+        for y in graph_area.top()..graph_area.bottom() {
+            for x in graph_area.left()..graph_area.right() {
+                let symbol = match (x == column, y == row) {
+                    (true, true) => symbols::line::CROSS,
+                    (true, false) => symbols::line::VERTICAL,
+                    (false, true) => symbols::line::HORIZONTAL,
+                    (false, false) => continue,
+                };
+                let cell = &mut buf[(x, y)];
+                if cell.symbol() == " " {
+                    cell.set_symbol(symbol).set_style(self.crosshair_style);
+                }
+            }
+        }
+
+        let cursor_x = self.x_axis.scale.apply(cursor_x);
+        for (index, dataset) in self.datasets.iter().enumerate() {
+            if !state.is_dataset_visible(index) {
+                continue;
+            }
+            let candles = dataset
+                .candles
+                .iter()
+                .map(|candle| (candle.x, candle.close));
+            let nearest = dataset
+                .data
+                .iter()
+                .copied()
+                .chain(candles)
+                .filter(|&(x, _)| self.x_axis.scale.apply(x).is_finite())
+                .min_by(|(a, _), (b, _)| {
+                    let a = (self.x_axis.scale.apply(*a) - cursor_x).abs();
+                    let b = (self.x_axis.scale.apply(*b) - cursor_x).abs();
+                    a.total_cmp(&b)
+                });
+            let Some(point) = nearest else {
+                continue;
+            };
+            state.nearest_points.push((index, point));
+            self.render_point_value(buf, graph_area, dataset, point);
+        }
+    }
+
+    /// Draws the values of a point of the dataset next to it
+    fn render_point_value(
+        &self,
+        buf: &mut Buffer,
+        graph_area: Rect,
+        dataset: &Dataset,
+        (x, y): (f64, f64),
+    ) {
+        let y_axis = self.dataset_y_axis(dataset);
+        let (Some(column), Some(row)) = (
+            grid_offset(
+                self.x_axis.scale.apply(x),
+                self.x_axis.scaled_bounds(),
+                graph_area.width,
+            ),
+            grid_offset(
+                y_axis.scale.apply(y),
+                y_axis.scaled_bounds(),
+                graph_area.height,
+            ),
+        ) else {
+            return;
+        };
+        let label = format!("({}, {})", format_axis_value(x), format_axis_value(y));
+        let width = label.len() as u16;
+        let column = graph_area.left() + column;
+        let label_x = if column + 1 + width <= graph_area.right() {
+            column + 1
+        } else {
+            column.saturating_sub(width).max(graph_area.left())
+        };
+        let row = graph_area.bottom() - 1 - row;
+        buf.set_stringn(
+            label_x,
+            row,
+            label,
+            usize::from(graph_area.right() - label_x),
+            dataset.style,
+        );
+    }
+
+    /// Draws the point symbol of the dataset, if any, directly to the buffer at each of the scaled
+    /// data points
+    fn render_point_symbols(
+        &self,
+        buf: &mut Buffer,
+        graph_area: Rect,
+        dataset: &Dataset,
+        y_axis: &Axis,
+        data: &[(f64, f64)],
+    ) {
+        let Some(symbol) = dataset.point_symbol.as_deref() else {
+            return;
+        };
+        let (x_bounds, y_bounds) = (self.x_axis.scaled_bounds(), y_axis.scaled_bounds());
+        for &(x, y) in data {
+            let (Some(column), Some(row)) = (
+                grid_offset(x, x_bounds, graph_area.width),
+                grid_offset(y, y_bounds, graph_area.height),
+            ) else {
+                continue;
+            };
+            buf[(graph_area.left() + column, graph_area.bottom() - 1 - row)]
+                .set_symbol(symbol)
+                .set_fg(dataset.style.fg.unwrap_or(Color::Reset));
+        }
+    }
+
+    /// Draws the error bars of the dataset directly to the buffer, in the columns of their points
+    fn render_error_bars(
+        &self,
+        buf: &mut Buffer,
+        graph_area: Rect,
+        dataset: &Dataset,
+        y_axis: &Axis,
+    ) {
+        let x_bounds = self.x_axis.scaled_bounds();
+        let [bottom, top] = y_axis.scaled_bounds();
+        if top - bottom <= 0.0 || !(top - bottom).is_finite() || graph_area.is_empty() {
+            return;
+        }
+        let style = dataset.style.patch(dataset.error_bar_style);
+        let row = |y: f64| {
+            let offset = grid_offset(y.clamp(bottom, top), [bottom, top], graph_area.height);
+            offset.map(|offset| graph_area.bottom() - 1 - offset)
+        };
+        for (&(x, _), &(low, high)) in dataset.data.iter().zip(dataset.error_bars) {
+            let x = self.x_axis.scale.apply(x);
+            let [low, high] = [low, high].map(|y| y_axis.scale.apply(y));
+            let Some(column) = grid_offset(x, x_bounds, graph_area.width) else {
+                continue;
+            };
+            if low.is_nan() || high.is_nan() {
+                continue;
+            }
+            let (Some(high), Some(low)) = (row(high.max(low)), row(high.min(low))) else {
+                continue;
+            };
+            for y in high..=low {
+                let symbol = match (y == high, y == low) {
+                    (true, true) => symbols::line::CROSS,
+                    (true, false) => symbols::line::HORIZONTAL_DOWN,
+                    (false, true) => symbols::line::HORIZONTAL_UP,
+                    (false, false) => symbols::line::VERTICAL,
+                };
+                buf[(graph_area.left() + column, y)]
+                    .set_symbol(symbol)
+                    .set_style(style);
+            }
+        }
+    }
+
+    /// Draws the candlesticks of the dataset directly to the buffer, one column per entry
+    fn render_candles(&self, buf: &mut Buffer, graph_area: Rect, dataset: &Dataset, y_axis: &Axis) {
+        let [left, right] = self.x_axis.scaled_bounds();
+        let [bottom, top] = y_axis.scaled_bounds();
+        let (width, height) = (right - left, top - bottom);
+        if width <= 0.0 || height <= 0.0 || !height.is_finite() || graph_area.is_empty() {
+            return;
+        }
+        let column = |x: f64| ((x - left) * f64::from(graph_area.width - 1) / width).round() as u16;
+        let row = |y: f64| {
+            ((top - y.clamp(bottom, top)) * f64::from(graph_area.height - 1) / height).round()
+                as u16
+        };
+        let (x_scale, y_scale) = (self.x_axis.scale, y_axis.scale);
+        for &Candle {
+            x,
+            open,
+            high,
+            low,
+            close,
+        } in dataset.candles
+        {
+            let x = x_scale.apply(x);
+            let [open, high, low, close] = [open, high, low, close].map(|y| y_scale.apply(y));
+            if !(left..=right).contains(&x)
+                || [open, high, low, close].iter().any(|y| !y.is_finite())
+            {
+                continue;
+            }
+            let style = if close >= open {
+                dataset.style.patch(dataset.up_style)
+            } else {
+                dataset.style.patch(dataset.down_style)
+            };
+            let x = graph_area.left() + column(x);
+            let (body_top, body_bottom) = (row(open.max(close)), row(open.min(close)));
+            let wick_top = row(high.max(low)).min(body_top);
+            let wick_bottom = row(high.min(low)).max(body_bottom);
+            for y in wick_top..=wick_bottom {
+                let symbol = if (body_top..=body_bottom).contains(&y) {
+                    symbols::line::THICK_VERTICAL
+                } else {
+                    symbols::line::VERTICAL
+                };
+                buf[(x, graph_area.top() + y)]
+                    .set_symbol(symbol)
+                    .set_style(style);
+            }
+        }
+    }
+
+    /// Returns the accumulated data of each stacked dataset along with the index of the previous
+    /// dataset in its stack group, or `None` for datasets that are not stacked or hidden
+    fn stacked_data(&self, hidden: &BTreeSet<usize>) -> Vec<Option<StackedData>> {
+        let mut stacked: Vec<Option<StackedData>> = vec![];
+        let mut tops: Vec<(&str, bool, usize)> = vec![];
+        for (index, dataset) in self.datasets.iter().enumerate() {
+            let group = dataset.stack_group.as_deref();
+            let Some(group) = group.filter(|_| !hidden.contains(&index)) else {
+                stacked.push(None);
+                continue;
+            };
+            let secondary = dataset.secondary_y_axis && self.secondary_y_axis.is_some();
+            let top = tops
+                .iter_mut()
+                .find(|(name, is_secondary, _)| *name == group && *is_secondary == secondary);
+            let previous = top.as_ref().map(|(_, _, previous)| *previous);
+            let data = dataset
+                .data
+                .iter()
+                .map(|&(x, y)| {
+                    let below = previous
+                        .and_then(|previous| stacked[previous].as_ref())
+                        .and_then(|(data, _)| interpolate(data, x))
+                        .unwrap_or_default();
+                    (x, y + below)
+                })
+                .collect();
+            stacked.push(Some((data, previous)));
+            match top {
+                Some(top) => top.2 = index,
+                None => tops.push((group, secondary, index)),
+            }
+        }
+        stacked
+    }
+
+    /// Maps the data points by the scales of the axes, dropping the points that can't be
+    /// represented (e.g. negative values on a logarithmic axis)
+    fn scaled_data<'d>(&self, data: &'d [(f64, f64)], y_axis: &Axis) -> Cow<'d, [(f64, f64)]> {
+        let (x_scale, y_scale) = (self.x_axis.scale, y_axis.scale);
+        if x_scale == Scale::Linear && y_scale == Scale::Linear {
+            return Cow::Borrowed(data);
+        }
+        data.iter()
+            .map(|&(x, y)| (x_scale.apply(x), y_scale.apply(y)))
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .collect()
+    }
+
+    /// Compute the internal layout of the chart given the area. If the area is too small some
+    /// elements may be automatically hidden
+    #[allow(clippy::too_many_lines)]
+    fn layout(&self, area: Rect) -> Option<ChartLayout> {
+        if area.height == 0 || area.width == 0 {
+            return None;
+        }
+        let mut outside_legend_area = None;
+        let mut area = area;
+        if let Some(position) = self.legend_position.filter(|p| p.is_outside()) {
+            let split = self
+                .legend_size(area)
+                .and_then(|(width, height)| position.split_outside(area, width, height));
+            if let Some((legend_area, rest)) = split {
+                outside_legend_area = Some(legend_area);
+                area = rest;
+            }
+        }
+        let mut x = area.left();
+        let mut y = area.bottom() - 1;
+
+        let mut label_x = None;
+        if !self.x_axis.labels.is_empty() && y > area.top() {
+            label_x = Some(y);
+            y -= 1;
+        }
+
+        let label_y = self.y_axis.labels.is_empty().not().then_some(x);
+        x += self.max_width_of_labels_left_of_y_axis(area, !self.y_axis.labels.is_empty());
+
+        let mut axis_x = None;
+        if !self.x_axis.labels.is_empty() && y > area.top() {
+            axis_x = Some(y);
+            y -= 1;
+        }
+
+        let mut axis_y = None;
+        if !self.y_axis.labels.is_empty() && x + 1 < area.right() {
+            axis_y = Some(x);
+            x += 1;
+        }
+
+        let mut right = area.right();
+        let mut label_y2 = None;
+        let mut axis_y2 = None;
+        if let Some(axis) = self.secondary_y_axis.as_ref() {
+            let labels_width = axis
+                .labels
+                .iter()
+                .map(Line::width)
+                .max()
+                .unwrap_or_default() as u16;
+            let labels_width = labels_width.min(area.width / 3);
+            if !axis.labels.is_empty() && x + labels_width + 1 < right {
+                right -= labels_width + 1;
+                axis_y2 = Some(right);
+                label_y2 = Some(right + 1);
+            }
+        }
+
+        let graph_width = right.saturating_sub(x);
+        let graph_height = y.saturating_sub(area.top()).saturating_add(1);
+        debug_assert_ne!(
+            graph_width, 0,
+            "Axis and labels should have been hidden due to the small area"
+        );
+        debug_assert_ne!(
+            graph_height, 0,
+            "Axis and labels should have been hidden due to the small area"
+        );
+        let graph_area = Rect::new(x, area.top(), graph_width, graph_height);
+
+        let mut title_x = None;
+        if let Some(ref title) = self.x_axis.title {
+            let w = title.width() as u16;
+            if w < graph_area.width && graph_area.height > 2 {
+                title_x = Some(Position::new(x + graph_area.width - w, y));
+            }
+        }
+
+        let mut title_y = None;
+        if let Some(ref title) = self.y_axis.title {
+            let w = title.width() as u16;
+            if w + 1 < graph_area.width && graph_area.height > 2 {
+                title_y = Some(Position::new(x, area.top()));
+            }
+        }
+
+        let mut title_y2 = None;
+        if let Some(title) = self
+            .secondary_y_axis
+            .as_ref()
+            .and_then(|a| a.title.as_ref())
+        {
+            let w = title.width() as u16;
+            if w + 1 < graph_area.width && graph_area.height > 2 {
+                title_y2 = Some(Position::new(graph_area.right() - w, area.top()));
+            }
+        }
+
+        let mut legend_area = outside_legend_area;
+        if let Some(legend_position) = self.legend_position.filter(|p| !p.is_outside()) {
+            if let Some((legend_width, legend_height)) = self.legend_size(graph_area) {
+                legend_area = legend_position.layout(
+                    graph_area,
+                    legend_width,
+                    legend_height,
+                    title_x
+                        .and(self.x_axis.title.as_ref())
+                        .map(|t| t.width() as u16)
+                        .unwrap_or_default(),
+                    title_y
+                        .and(self.y_axis.title.as_ref())
+                        .map(|t| t.width() as u16)
+                        .unwrap_or_default(),
+                );
+            }
+        }
+        Some(ChartLayout {
+            title_x,
+            title_y,
+            label_x,
+            label_y,
+            axis_x,
+            axis_y,
+            title_y2,
+            label_y2,
+            axis_y2,
+            legend_area,
+            graph_area,
+        })
+    }
+
+    /// Returns the size of the legend (including its borders), or `None` if there are no named
+    /// datasets or the legend is hidden by the [hidden legend
+    /// constraints](Chart::hidden_legend_constraints) relative to `area`
+    fn legend_size(&self, area: Rect) -> Option<(u16, u16)> {
+        let legends = self.datasets.iter().filter_map(Dataset::legend_width);
+        let inner_width = legends.clone().max()?;
+        let legend_width = inner_width + 2;
+        let legend_height = legends.count() as u16 + 2;
+
+        let [max_legend_width] = Layout::horizontal([self.hidden_legend_constraints.0])
+            .flex(Flex::Start)
+            .areas(area);
+
+        let [max_legend_height] = Layout::vertical([self.hidden_legend_constraints.1])
+            .flex(Flex::Start)
+            .areas(area);
+
+        (inner_width > 0
+            && legend_width <= max_legend_width.width
+            && legend_height <= max_legend_height.height)
+            .then_some((legend_width, legend_height))
+    }
+
+    fn max_width_of_labels_left_of_y_axis(&self, area: Rect, has_y_axis: bool) -> u16 {
         let mut max_width = self
             .y_axis
             .labels
@@ -847,7 +2653,11 @@ impl<'a> Chart<'a> {
             .max()
             .unwrap_or_default() as u16;
 
-        if let Some(first_x_label) = self.x_axis.labels.first() {
+        // labels at given positions are kept inside the graph area
+        let first_x_label = self.x_axis.labels.first();
+        if let Some(first_x_label) =
+            first_x_label.filter(|_| self.x_axis.label_positions.is_empty())
+        {
             let first_label_width = first_x_label.width() as u16;
             let width_left_of_y_axis = match self.x_axis.labels_alignment {
                 Alignment::Left => {
@@ -874,6 +2684,10 @@ impl<'a> Chart<'a> {
     ) {
         let Some(y) = layout.label_x else { return };
         let labels = &self.x_axis.labels;
+        if !self.x_axis.label_positions.is_empty() {
+            Self::render_positioned_x_labels(buf, &self.x_axis, y, graph_area);
+            return;
+        }
         let labels_len = labels.len() as u16;
         if labels_len < 2 {
             return;
@@ -912,6 +2726,27 @@ impl<'a> Chart<'a> {
         Self::render_label(buf, labels.last().unwrap(), label_area, Alignment::Right);
     }
 
+    /// Renders the labels of an X axis centered at their positions, skipping the labels that
+    /// would overlap the previous one
+    fn render_positioned_x_labels(buf: &mut Buffer, axis: &Axis, y: u16, graph_area: Rect) {
+        let columns = f64::from(graph_area.width.saturating_sub(1));
+        let mut free = graph_area.left();
+        for (label, position) in axis.labels.iter().zip(&axis.label_positions) {
+            let width = label.width() as u16;
+            let center = graph_area.left() + (position * columns).round() as u16;
+            let x = center
+                .saturating_sub(width / 2)
+                .min(graph_area.right().saturating_sub(width))
+                .max(graph_area.left());
+            if x < free {
+                continue;
+            }
+            let label_area = Rect::new(x, y, width.min(graph_area.right() - x), 1);
+            Self::render_label(buf, label, label_area, Alignment::Left);
+            free = x.saturating_add(width).saturating_add(1);
+        }
+    }
+
     fn first_x_label_area(
         &self,
         y: u16,
@@ -952,23 +2787,555 @@ impl<'a> Chart<'a> {
         graph_area: Rect,
     ) {
         let Some(x) = layout.label_y else { return };
-        let labels = &self.y_axis.labels;
+        let width = (graph_area.left() - chart_area.left()).saturating_sub(1);
+        Self::render_vertical_labels(buf, &self.y_axis, x, width, graph_area);
+    }
+
+    fn render_secondary_y_labels(&self, buf: &mut Buffer, layout: &ChartLayout, chart_area: Rect) {
+        let (Some(x), Some(axis)) = (layout.label_y2, self.secondary_y_axis.as_ref()) else {
+            return;
+        };
+        let width = chart_area.right().saturating_sub(x);
+        Self::render_vertical_labels(buf, axis, x, width, layout.graph_area);
+    }
+
+    fn render_vertical_labels(buf: &mut Buffer, axis: &Axis, x: u16, width: u16, graph_area: Rect) {
+        let labels = &axis.labels;
+        if !axis.label_positions.is_empty() {
+            let rows = f64::from(graph_area.height.saturating_sub(1));
+            let mut free = graph_area.bottom();
+            for (label, position) in labels.iter().zip(&axis.label_positions) {
+                let dy = (position * rows).round() as u16;
+                let y = graph_area.bottom().saturating_sub(1).saturating_sub(dy);
+                // skip the labels that would overlap the previous one
+                if y >= free {
+                    continue;
+                }
+                let label_area = Rect::new(x, y, width, 1);
+                Self::render_label(buf, label, label_area, axis.labels_alignment);
+                free = y;
+            }
+            return;
+        }
         let labels_len = labels.len() as u16;
         for (i, label) in labels.iter().enumerate() {
             let dy = i as u16 * (graph_area.height - 1) / (labels_len - 1);
             if dy < graph_area.bottom() {
-                let label_area = Rect::new(
-                    x,
-                    graph_area.bottom().saturating_sub(1) - dy,
-                    (graph_area.left() - chart_area.left()).saturating_sub(1),
-                    1,
-                );
-                Self::render_label(buf, label, label_area, self.y_axis.labels_alignment);
+                let label_area = Rect::new(x, graph_area.bottom().saturating_sub(1) - dy, width, 1);
+                Self::render_label(buf, label, label_area, axis.labels_alignment);
             }
         }
     }
 }
 
+/// State of the [`Chart`] widget
+///
+/// The state holds the brush, an X-range that the user selects by dragging the mouse over the
+/// graph (e.g. to zoom to the selection in a log or metric explorer). The chart highlights the
+/// selected columns with its [brush style](Chart::brush_style).
+///
+/// Feed the mouse events of the backend to [`ChartState::start_brush`],
+/// [`ChartState::drag_brush`] and [`ChartState::end_brush`]. The positions are mapped to values
+/// of the X axis using the graph area and the bounds of the X axis of the last render, and the
+/// selection is stored in axis values, so that it doesn't move when the chart is resized.
+///
+/// The state also holds an optional cursor, drawn as a crosshair with the [crosshair
+/// style](Chart::crosshair_style) along with the values of the point of each dataset that is the
+/// nearest to the cursor on the X axis. The cursor follows the mouse with
+/// [`ChartState::set_cursor`] and is moved with the keyboard with [`ChartState::move_cursor`].
+/// Like the brush, it is stored in axis values. The values of the nearest points are available
+/// after rendering with [`ChartState::nearest_points`].
+///
+/// Finally, the state holds the view of the chart when it is zoomed ([`ChartState::zoom`],
+/// [`ChartState::zoom_at`] for the mouse wheel) or panned ([`ChartState::pan`] for the keyboard,
+/// [`ChartState::start_pan`], [`ChartState::drag_pan`] and [`ChartState::end_pan`] for the mouse).
+/// The bounds of the axes of the chart are the full extent of the view, which is clamped to
+/// them. While zoomed, the labels of the X and Y axes are replaced to match the visible bounds.
+/// The secondary Y axis is not affected by the view.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     widgets::{Axis, Chart, ChartState, Dataset},
+///     Frame,
+/// };
+///
+/// struct App {
+///     bounds: [f64; 2],
+///     state: ChartState,
+/// }
+///
+/// fn draw(frame: &mut Frame, app: &mut App, data: &[(f64, f64)]) {
+///     let chart = Chart::new(vec![Dataset::default().data(data)])
+///         .x_axis(Axis::default().bounds(app.bounds))
+///         .y_axis(Axis::default().bounds([0.0, 100.0]));
+///     frame.render_stateful_widget(&chart, frame.area(), &mut app.state);
+/// }
+///
+/// // on mouse up, zoom to the selection
+/// fn on_mouse_up(app: &mut App) {
+///     if let Some(selection) = app.state.end_brush() {
+///         app.bounds = selection;
+///         app.state.clear_brush();
+///     }
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ChartState {
+    /// Area of the graph at the last render
+    graph_area: Rect,
+    /// Bounds of the X axis at the last render, mapped by the scale of the axis
+    x_bounds: [f64; 2],
+    /// Scale of the X axis at the last render
+    x_scale: Scale,
+    /// Bounds of the Y axis at the last render, mapped by the scale of the axis
+    y_bounds: [f64; 2],
+    /// Scale of the Y axis at the last render
+    y_scale: Scale,
+    brush: Option<Brush>,
+    /// The position of the cursor, in values of the X and Y axes
+    cursor: Option<(f64, f64)>,
+    /// Indices of the datasets and their points nearest to the cursor at the last render
+    nearest_points: Vec<(usize, (f64, f64))>,
+    /// Indices of the datasets that are not plotted
+    hidden_datasets: BTreeSet<usize>,
+    /// Indices of the datasets and areas of their entries in the legend at the last render
+    legend_entries: Vec<(usize, Rect)>,
+    /// Bounds of the X axis of the chart at the last render, mapped by the scale of the axis
+    x_extent: [f64; 2],
+    /// Bounds of the Y axis of the chart at the last render, mapped by the scale of the axis
+    y_extent: [f64; 2],
+    /// The visible X and Y bounds when zoomed or panned, mapped by the scales of the axes
+    view: Option<[[f64; 2]; 2]>,
+    /// The position where a pan drag started and the view at that time
+    pan: Option<(Position, [[f64; 2]; 2])>,
+}
+
+/// Compares the brush, cursor, hidden datasets and view of the states, ignoring the layout of the
+/// last render and the pan drag that are only kept between renders for the interaction with the
+/// chart.
+impl PartialEq for ChartState {
+    fn eq(&self, other: &Self) -> bool {
+        self.brush == other.brush
+            && self.cursor == other.cursor
+            && self.hidden_datasets == other.hidden_datasets
+            && self.view == other.view
+    }
+}
+
+/// An X-range selected with the brush, in values of the X axis
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Brush {
+    /// The value where the drag started
+    anchor: f64,
+    /// The value under the mouse
+    cursor: f64,
+    /// Whether the drag is still in progress
+    dragging: bool,
+}
+
+impl ChartState {
+    /// Returns whether the dataset at the given index is plotted
+    ///
+    /// All datasets are visible by default.
+    pub fn is_dataset_visible(&self, index: usize) -> bool {
+        !self.hidden_datasets.contains(&index)
+    }
+
+    /// Shows or hides the dataset at the given index
+    ///
+    /// Hidden datasets are not plotted (nor accumulated in their stack group), and their entry in
+    /// the legend is dimmed.
+    pub fn set_dataset_visible(&mut self, index: usize, visible: bool) {
+        if visible {
+            self.hidden_datasets.remove(&index);
+        } else {
+            self.hidden_datasets.insert(index);
+        }
+    }
+
+    /// Toggles the visibility of the dataset at the given index
+    pub fn toggle_dataset(&mut self, index: usize) {
+        self.set_dataset_visible(index, !self.is_dataset_visible(index));
+    }
+
+    /// Returns the index of the dataset whose legend entry is at the given position at the last
+    /// render
+    pub fn legend_entry_at(&self, position: Position) -> Option<usize> {
+        self.legend_entries
+            .iter()
+            .find(|(_, area)| area.contains(position))
+            .map(|(index, _)| *index)
+    }
+
+    /// Toggles the visibility of the dataset whose legend entry is at the given position, and
+    /// returns its index
+    ///
+    /// This is meant to be called with the position of a mouse click.
+    pub fn handle_legend_click(&mut self, position: Position) -> Option<usize> {
+        let index = self.legend_entry_at(position)?;
+        self.toggle_dataset(index);
+        Some(index)
+    }
+
+    /// Returns the area of the graph (i.e. without the axes, labels and block) at the last render
+    pub const fn graph_area(&self) -> Rect {
+        self.graph_area
+    }
+
+    /// Returns the value of the X axis at the given column of the last render
+    ///
+    /// Returns `None` if the column is outside of the graph area.
+    pub fn x_value(&self, column: u16) -> Option<f64> {
+        let area = self.graph_area;
+        if column < area.left() || column >= area.right() {
+            return None;
+        }
+        Some(self.x_value_unchecked(column))
+    }
+
+    fn x_value_unchecked(&self, column: u16) -> f64 {
+        let [left, right] = self.x_bounds;
+        let steps = f64::from(self.graph_area.width.saturating_sub(1));
+        if steps == 0.0 {
+            return left;
+        }
+        let offset = f64::from(column.saturating_sub(self.graph_area.left()));
+        self.x_scale
+            .invert((right - left).mul_add(offset / steps, left))
+    }
+
+    /// Returns the column of the graph area of the last render where the given value of the X
+    /// axis is displayed, clamped to the graph area
+    fn column(&self, value: f64) -> u16 {
+        let [left, right] = self.x_bounds;
+        let steps = f64::from(self.graph_area.width.saturating_sub(1));
+        let value = self.x_scale.apply(value);
+        let offset = if right - left == 0.0 {
+            0.0
+        } else {
+            ((value - left) * steps / (right - left))
+                .round()
+                .clamp(0.0, steps)
+        };
+        self.graph_area.left() + offset as u16
+    }
+
+    fn y_value_unchecked(&self, row: u16) -> f64 {
+        let [bottom, top] = self.y_bounds;
+        let steps = f64::from(self.graph_area.height.saturating_sub(1));
+        if steps == 0.0 {
+            return top;
+        }
+        let offset = f64::from(row.saturating_sub(self.graph_area.top()));
+        self.y_scale
+            .invert((bottom - top).mul_add(offset / steps, top))
+    }
+
+    /// Returns the row of the graph area of the last render where the given value of the Y axis
+    /// is displayed, clamped to the graph area
+    fn row(&self, value: f64) -> u16 {
+        let [bottom, top] = self.y_bounds;
+        let steps = f64::from(self.graph_area.height.saturating_sub(1));
+        let value = self.y_scale.apply(value);
+        let offset = if top - bottom == 0.0 {
+            0.0
+        } else {
+            ((top - value) * steps / (top - bottom))
+                .round()
+                .clamp(0.0, steps)
+        };
+        self.graph_area.top() + offset as u16
+    }
+
+    /// Returns the values of the X and Y axes at the given position of the last render
+    ///
+    /// This maps a mouse position to data coordinates. Returns `None` if the position is outside
+    /// of the graph area.
+    pub fn data_coordinates(&self, position: Position) -> Option<(f64, f64)> {
+        if !self.graph_area.contains(position) {
+            return None;
+        }
+        Some((
+            self.x_value_unchecked(position.x),
+            self.y_value_unchecked(position.y),
+        ))
+    }
+
+    /// Returns the position of the cursor, in values of the X and Y axes
+    pub const fn cursor(&self) -> Option<(f64, f64)> {
+        self.cursor
+    }
+
+    /// Moves the cursor to the given position, e.g. the position of the mouse
+    ///
+    /// Returns `false` and leaves the state untouched if the position is outside of the graph
+    /// area of the last render.
+    pub fn set_cursor(&mut self, position: Position) -> bool {
+        let Some(coordinates) = self.data_coordinates(position) else {
+            return false;
+        };
+        self.cursor = Some(coordinates);
+        true
+    }
+
+    /// Moves the cursor by the given number of columns and rows, e.g. with the arrow keys
+    ///
+    /// Positive values move the cursor right and down. The cursor stays inside the graph area of
+    /// the last render. If there is no cursor, it is placed at the center of the graph area first.
+    pub fn move_cursor(&mut self, columns: i16, rows: i16) {
+        let area = self.graph_area;
+        if area.is_empty() {
+            return;
+        }
+        let (column, row) = self.cursor.map_or(
+            (area.x + area.width / 2, area.y + area.height / 2),
+            |(x, y)| (self.column(x), self.row(y)),
+        );
+        let column = column
+            .saturating_add_signed(columns)
+            .clamp(area.left(), area.right() - 1);
+        let row = row
+            .saturating_add_signed(rows)
+            .clamp(area.top(), area.bottom() - 1);
+        self.set_cursor(Position::new(column, row));
+    }
+
+    /// Removes the cursor
+    pub fn clear_cursor(&mut self) {
+        self.cursor = None;
+        self.nearest_points.clear();
+    }
+
+    /// Returns the index of each visible dataset along with its point nearest to the cursor on
+    /// the X axis at the last render
+    ///
+    /// This is empty if there is no cursor.
+    pub fn nearest_points(&self) -> &[(usize, (f64, f64))] {
+        &self.nearest_points
+    }
+
+    /// Starts a brush selection at the given position
+    ///
+    /// This replaces the current selection. Returns `false` and leaves the state untouched if the
+    /// position is outside of the graph area.
+    pub fn start_brush(&mut self, position: Position) -> bool {
+        if !self.graph_area.contains(position) {
+            return false;
+        }
+        let value = self.x_value_unchecked(position.x);
+        self.brush = Some(Brush {
+            anchor: value,
+            cursor: value,
+            dragging: true,
+        });
+        true
+    }
+
+    /// Extends the brush selection to the given position
+    ///
+    /// Positions outside of the graph area are clamped to it, so that the selection can be dragged
+    /// to the edges of the graph. This does nothing if no drag is in progress.
+    pub fn drag_brush(&mut self, position: Position) {
+        let area = self.graph_area;
+        if area.is_empty() {
+            return;
+        }
+        let column = position.x.clamp(area.left(), area.right() - 1);
+        let value = self.x_value_unchecked(column);
+        if let Some(brush) = self.brush.as_mut().filter(|brush| brush.dragging) {
+            brush.cursor = value;
+        }
+    }
+
+    /// Ends the brush selection and returns the selected X-range
+    ///
+    /// A drag that didn't leave its start column is a click: it clears the selection and returns
+    /// `None`. The selection of a drag stays highlighted until [`ChartState::clear_brush`] is
+    /// called or a new drag is started.
+    pub fn end_brush(&mut self) -> Option<[f64; 2]> {
+        let brush = self.brush.as_mut().filter(|brush| brush.dragging)?;
+        brush.dragging = false;
+        let (anchor, cursor) = (brush.anchor, brush.cursor);
+        if self.column(anchor) == self.column(cursor) {
+            self.brush = None;
+            return None;
+        }
+        self.brush()
+    }
+
+    /// Returns whether a brush selection is being dragged
+    pub fn is_brushing(&self) -> bool {
+        self.brush.is_some_and(|brush| brush.dragging)
+    }
+
+    /// Returns the X-range selected with the brush, ordered from the lowest to the highest value
+    ///
+    /// This returns the selection while it is being dragged as well as after the drag ended.
+    pub fn brush(&self) -> Option<[f64; 2]> {
+        self.brush.map(|brush| {
+            [
+                brush.anchor.min(brush.cursor),
+                brush.anchor.max(brush.cursor),
+            ]
+        })
+    }
+
+    /// Clears the brush selection
+    pub fn clear_brush(&mut self) {
+        self.brush = None;
+    }
+
+    /// Returns the visible bounds of the X axis at the last render
+    ///
+    /// These are the bounds of the X axis of the chart unless the chart is zoomed or panned.
+    pub fn x_bounds(&self) -> [f64; 2] {
+        self.x_bounds.map(|bound| self.x_scale.invert(bound))
+    }
+
+    /// Returns the visible bounds of the Y axis at the last render
+    ///
+    /// These are the bounds of the Y axis of the chart unless the chart is zoomed or panned.
+    pub fn y_bounds(&self) -> [f64; 2] {
+        self.y_bounds.map(|bound| self.y_scale.invert(bound))
+    }
+
+    /// Returns whether the chart is zoomed or panned
+    pub const fn is_zoomed(&self) -> bool {
+        self.view.is_some()
+    }
+
+    /// Zooms by the given factor around the values at the given position
+    ///
+    /// A factor greater than 1 zooms in, and a factor between 0 and 1 zooms out. The values at
+    /// `anchor` stay at the same position, or the center of the graph stays in place if `anchor`
+    /// is outside of the graph area. The visible bounds never go beyond the bounds of the axes of
+    /// the chart, so zooming out stops at the full chart.
+    pub fn zoom(&mut self, factor: f64, anchor: Position) {
+        if factor <= 0.0 || !factor.is_finite() {
+            return;
+        }
+        let [x, y] = self.current_view();
+        let area = self.graph_area;
+        let (column, row) = if area.contains(anchor) {
+            (anchor.x - area.left(), anchor.y - area.top())
+        } else {
+            (area.width / 2, area.height / 2)
+        };
+        let [x_anchor, y_anchor] = [
+            view_value(x, column, area.width),
+            view_value([y[1], y[0]], row, area.height),
+        ];
+        self.set_view([
+            [
+                x_anchor - (x_anchor - x[0]) / factor,
+                x_anchor + (x[1] - x_anchor) / factor,
+            ],
+            [
+                y_anchor - (y_anchor - y[0]) / factor,
+                y_anchor + (y[1] - y_anchor) / factor,
+            ],
+        ]);
+    }
+
+    /// Zooms in or out by steps around the values at the given position, e.g. for the mouse wheel
+    ///
+    /// Each step zooms in by a factor of 1.25, and negative steps zoom out.
+    pub fn zoom_at(&mut self, position: Position, steps: i16) {
+        const ZOOM_STEP: f64 = 1.25;
+        self.zoom(ZOOM_STEP.powi(i32::from(steps)), position);
+    }
+
+    /// Moves the visible bounds by the given number of columns and rows, e.g. with the arrow keys
+    ///
+    /// Positive values show values further right and down. The visible bounds never go beyond the
+    /// bounds of the axes of the chart.
+    pub fn pan(&mut self, columns: i16, rows: i16) {
+        let view = self.shifted_view(self.current_view(), columns.into(), rows.into());
+        self.set_view(view);
+    }
+
+    /// Starts panning by dragging the mouse from the given position
+    ///
+    /// Returns `false` and leaves the state untouched if the position is outside of the graph
+    /// area of the last render.
+    pub fn start_pan(&mut self, position: Position) -> bool {
+        if !self.graph_area.contains(position) {
+            return false;
+        }
+        self.pan = Some((position, self.current_view()));
+        true
+    }
+
+    /// Pans so that the values under the position where the drag started follow the mouse
+    pub fn drag_pan(&mut self, position: Position) {
+        let Some((start, view)) = self.pan else {
+            return;
+        };
+        let columns = i32::from(start.x) - i32::from(position.x);
+        let rows = i32::from(start.y) - i32::from(position.y);
+        let view = self.shifted_view(view, columns, rows);
+        self.set_view(view);
+    }
+
+    /// Ends panning with the mouse
+    pub fn end_pan(&mut self) {
+        self.pan = None;
+    }
+
+    /// Shows the full bounds of the axes of the chart again
+    pub fn reset_view(&mut self) {
+        self.view = None;
+        self.pan = None;
+    }
+
+    /// Returns the visible X and Y bounds, mapped by the scales of the axes
+    fn current_view(&self) -> [[f64; 2]; 2] {
+        self.view.unwrap_or([self.x_extent, self.y_extent])
+    }
+
+    /// Returns the view moved by the given number of columns to the right and rows down
+    fn shifted_view(&self, [x, y]: [[f64; 2]; 2], columns: i32, rows: i32) -> [[f64; 2]; 2] {
+        let area = self.graph_area;
+        let column_width = (x[1] - x[0]) / f64::from(area.width.saturating_sub(1).max(1));
+        let row_height = (y[1] - y[0]) / f64::from(area.height.saturating_sub(1).max(1));
+        let dx = f64::from(columns) * column_width;
+        let dy = f64::from(rows) * row_height;
+        [[x[0] + dx, x[1] + dx], [y[0] - dy, y[1] - dy]]
+    }
+
+    /// Sets the view, clamped to the bounds of the axes of the chart
+    ///
+    /// The view is removed when it shows the full bounds of the axes.
+    fn set_view(&mut self, [x, y]: [[f64; 2]; 2]) {
+        let view = [
+            clamp_bounds(x, self.x_extent),
+            clamp_bounds(y, self.y_extent),
+        ];
+        self.view = (view != [self.x_extent, self.y_extent]).then_some(view);
+    }
+
+    /// Highlights the columns of the brush selection
+    fn render_brush(&self, buf: &mut Buffer, style: Style) {
+        let Some([start, end]) = self.brush() else {
+            return;
+        };
+        let [left, right] = self.x_bounds;
+        let (start_position, end_position) = (self.x_scale.apply(start), self.x_scale.apply(end));
+        if end_position < left.min(right) || start_position > left.max(right) {
+            return;
+        }
+        let (start, end) = (self.column(start), self.column(end));
+        let area = Rect {
+            x: start.min(end),
+            width: start.abs_diff(end) + 1,
+            ..self.graph_area
+        };
+        buf.set_style(area, style);
+    }
+}
+
 impl Widget for Chart<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         Widget::render(&self, area, buf);
@@ -976,8 +3343,39 @@ impl Widget for Chart<'_> {
 }
 
 impl Widget for &Chart<'_> {
-    #[allow(clippy::too_many_lines)]
     fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = ChartState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+// `StatefulWidget` is only implemented for `&Chart` so that calling `render` on an owned chart
+// stays unambiguous when both widget traits are in scope.
+impl StatefulWidget for &Chart<'_> {
+    type State = ChartState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let auto = self.with_auto_bounds(&state.hidden_datasets);
+        let chart = auto.as_ref().unwrap_or(self);
+        state.x_extent = chart.x_axis.scaled_bounds();
+        state.y_extent = chart.y_axis.scaled_bounds();
+        if let Some(view) = state.view {
+            // keep the view inside the bounds of the axes when they change
+            state.set_view(view);
+        }
+        let view = chart.with_view(state.view);
+        let chart = view.as_ref().unwrap_or(chart);
+        let labeled = chart.with_generated_labels(chart.block.inner_if_some(area));
+        labeled
+            .as_ref()
+            .unwrap_or(chart)
+            .render_chart(area, buf, state);
+    }
+}
+
+impl Chart<'_> {
+    #[allow(clippy::too_many_lines)]
+    fn render_chart(&self, area: Rect, buf: &mut Buffer, state: &mut ChartState) {
         buf.set_style(area, self.style);
 
         self.block.as_ref().render(area, buf);
@@ -986,6 +3384,11 @@ impl Widget for &Chart<'_> {
             return;
         };
         let graph_area = layout.graph_area;
+        state.graph_area = graph_area;
+        state.x_bounds = self.x_axis.scaled_bounds();
+        state.x_scale = self.x_axis.scale;
+        state.y_bounds = self.y_axis.scaled_bounds();
+        state.y_scale = self.y_axis.scale;
 
         // Sample the style of the entire widget. This sample will be used to reset the style of
         // the cells that are part of the components put on top of the grah area (i.e legend and
@@ -994,6 +3397,7 @@ impl Widget for &Chart<'_> {
 
         self.render_x_labels(buf, &layout, chart_area, graph_area);
         self.render_y_labels(buf, &layout, chart_area, graph_area);
+        self.render_secondary_y_labels(buf, &layout, chart_area);
 
         if let Some(y) = layout.axis_x {
             for x in graph_area.left()..graph_area.right() {
@@ -1003,11 +3407,19 @@ impl Widget for &Chart<'_> {
             }
         }
 
-        if let Some(x) = layout.axis_y {
+        if let Some(x) = layout.axis_y {
+            for y in graph_area.top()..graph_area.bottom() {
+                buf[(x, y)]
+                    .set_symbol(symbols::line::VERTICAL)
+                    .set_style(self.y_axis.style);
+            }
+        }
+
+        if let (Some(x), Some(axis)) = (layout.axis_y2, self.secondary_y_axis.as_ref()) {
             for y in graph_area.top()..graph_area.bottom() {
                 buf[(x, y)]
                     .set_symbol(symbols::line::VERTICAL)
-                    .set_style(self.y_axis.style);
+                    .set_style(axis.style);
             }
         }
 
@@ -1017,48 +3429,129 @@ impl Widget for &Chart<'_> {
                     .set_symbol(symbols::line::BOTTOM_LEFT)
                     .set_style(self.x_axis.style);
             }
+            if let Some(x) = layout.axis_y2 {
+                buf[(x, y)]
+                    .set_symbol(symbols::line::BOTTOM_RIGHT)
+                    .set_style(self.x_axis.style);
+            }
         }
 
-        for dataset in &self.datasets {
+        self.render_reference_labels(buf, graph_area);
+
+        let stacked = self.stacked_data(&state.hidden_datasets);
+        for (index, (dataset, stack)) in self.datasets.iter().zip(&stacked).enumerate() {
+            if !state.is_dataset_visible(index) {
+                continue;
+            }
+            let y_axis = self.dataset_y_axis(dataset);
+            let [y_min, _] = y_axis.scaled_bounds();
+            let bar_base = Some(y_axis.scale.apply(0.0))
+                .filter(|base| base.is_finite())
+                .unwrap_or(y_min);
+            let data = match stack {
+                Some((data, _)) => self.scaled_data(data, y_axis),
+                None => self.scaled_data(dataset.data, y_axis),
+            };
+            let data = if stack.is_some()
+                || matches!(
+                    dataset.graph_type,
+                    GraphType::Line | GraphType::Bar | GraphType::Step(_)
+                ) {
+                self.downsampled(data, dataset.marker, graph_area.width)
+            } else {
+                data
+            };
+            let lower = stack
+                .as_ref()
+                .and_then(|(_, previous)| stacked[(*previous)?].as_ref())
+                .map(|(data, _)| self.scaled_data(data, y_axis))
+                .map(|data| self.downsampled(data, dataset.marker, graph_area.width));
+            self.render_error_bars(buf, graph_area, dataset, y_axis);
             Canvas::default()
                 .background_color(self.style.bg.unwrap_or(Color::Reset))
-                .x_bounds(self.x_axis.bounds)
-                .y_bounds(self.y_axis.bounds)
+                .x_bounds(self.x_axis.scaled_bounds())
+                .y_bounds(y_axis.scaled_bounds())
                 .marker(dataset.marker)
                 .paint(|ctx| {
-                    ctx.draw(&Points {
-                        coords: dataset.data,
-                        color: dataset.style.fg.unwrap_or(Color::Reset),
-                    });
+                    if dataset.graph_type == GraphType::Candlestick {
+                        // the candles are drawn by `render_candles`
+                        return;
+                    }
+                    if stack.is_some() {
+                        ctx.draw(&StackedArea {
+                            lower: lower.as_deref(),
+                            upper: &data,
+                            base: bar_base,
+                            color: dataset.style.fg.unwrap_or(Color::Reset),
+                        });
+                        return;
+                    }
+                    if dataset.point_symbol.is_none() {
+                        ctx.draw(&Points {
+                            coords: &data,
+                            color: dataset.style.fg.unwrap_or(Color::Reset),
+                            colors: &[],
+                        });
+                    }
                     match dataset.graph_type {
                         GraphType::Line => {
-                            for data in dataset.data.windows(2) {
-                                ctx.draw(&CanvasLine {
-                                    x1: data[0].0,
-                                    y1: data[0].1,
-                                    x2: data[1].0,
-                                    y2: data[1].1,
-                                    color: dataset.style.fg.unwrap_or(Color::Reset),
-                                });
+                            for data in data.windows(2) {
+                                ctx.draw(&CanvasLine::new(
+                                    data[0].0,
+                                    data[0].1,
+                                    data[1].0,
+                                    data[1].1,
+                                    dataset.style.fg.unwrap_or(Color::Reset),
+                                ));
+                            }
+                        }
+                        GraphType::Step(position) => {
+                            for data in data.windows(2) {
+                                let (x, y) = match position {
+                                    StepPosition::After => (data[1].0, data[0].1),
+                                    StepPosition::Before => (data[0].0, data[1].1),
+                                };
+                                for (from, to) in [(data[0], (x, y)), ((x, y), data[1])] {
+                                    ctx.draw(&CanvasLine::new(
+                                        from.0,
+                                        from.1,
+                                        to.0,
+                                        to.1,
+                                        dataset.style.fg.unwrap_or(Color::Reset),
+                                    ));
+                                }
                             }
                         }
                         GraphType::Bar => {
-                            for (x, y) in dataset.data {
-                                ctx.draw(&CanvasLine {
-                                    x1: *x,
-                                    y1: 0.0,
-                                    x2: *x,
-                                    y2: *y,
-                                    color: dataset.style.fg.unwrap_or(Color::Reset),
-                                });
+                            for (x, y) in data.iter() {
+                                ctx.draw(&CanvasLine::new(
+                                    *x,
+                                    bar_base,
+                                    *x,
+                                    *y,
+                                    dataset.style.fg.unwrap_or(Color::Reset),
+                                ));
                             }
                         }
-                        GraphType::Scatter => {}
+                        GraphType::Scatter | GraphType::Candlestick => {}
                     }
                 })
                 .render(graph_area, buf);
+            match (dataset.graph_type, &stack) {
+                (GraphType::Candlestick, _) => {
+                    self.render_candles(buf, graph_area, dataset, y_axis);
+                }
+                (_, None) => {
+                    self.render_point_symbols(buf, graph_area, dataset, y_axis, &data);
+                }
+                (_, Some(_)) => {}
+            }
         }
 
+        self.render_references(buf, graph_area);
+        state.render_brush(buf, self.brush_style);
+        self.render_crosshair(buf, state);
+
         if let Some(Position { x, y }) = layout.title_x {
             let title = self.x_axis.title.as_ref().unwrap();
             let width = graph_area
@@ -1095,26 +3588,45 @@ impl Widget for &Chart<'_> {
             buf.set_line(x, y, title, width);
         }
 
+        if let Some(Position { x, y }) = layout.title_y2 {
+            let title = self
+                .secondary_y_axis
+                .as_ref()
+                .and_then(|a| a.title.as_ref())
+                .unwrap();
+            let width = graph_area
+                .right()
+                .saturating_sub(x)
+                .min(title.width() as u16);
+            buf.set_style(Rect::new(x, y, width, 1), original_style);
+            buf.set_line(x, y, title, width);
+        }
+
+        state.legend_entries.clear();
         if let Some(legend_area) = layout.legend_area {
             buf.set_style(legend_area, original_style);
             Block::bordered().render(legend_area, buf);
 
-            for (i, (dataset_name, dataset_style)) in self
+            for (i, (index, entry)) in self
                 .datasets
                 .iter()
-                .filter_map(|ds| Some((ds.name.as_ref()?, ds.style())))
+                .enumerate()
+                .filter_map(|(index, ds)| Some((index, ds.legend_entry()?)))
                 .enumerate()
             {
-                let name = dataset_name.clone().patch_style(dataset_style);
-                name.render(
-                    Rect {
-                        x: legend_area.x + 1,
-                        y: legend_area.y + 1 + i as u16,
-                        width: legend_area.width - 2,
-                        height: 1,
-                    },
-                    buf,
-                );
+                let entry = if state.is_dataset_visible(index) {
+                    entry
+                } else {
+                    entry.patch_style(Modifier::DIM)
+                };
+                let entry_area = Rect {
+                    x: legend_area.x + 1,
+                    y: legend_area.y + 1 + i as u16,
+                    width: legend_area.width - 2,
+                    height: 1,
+                };
+                entry.render(entry_area, buf);
+                state.legend_entries.push((index, entry_area));
             }
         }
     }
@@ -1157,6 +3669,7 @@ impl Styled for Chart<'_> {
 }
 
 #[cfg(test)]
+#[allow(clippy::float_cmp)]
 mod tests {
     use ratatui_core::style::{Modifier, Stylize};
     use rstest::rstest;
@@ -1242,6 +3755,10 @@ mod tests {
         assert_eq!(GraphType::Scatter.to_string(), "Scatter");
         assert_eq!(GraphType::Line.to_string(), "Line");
         assert_eq!(GraphType::Bar.to_string(), "Bar");
+        assert_eq!(GraphType::Candlestick.to_string(), "Candlestick");
+        assert_eq!(GraphType::Step(StepPosition::Before).to_string(), "Step");
+        assert_eq!(StepPosition::After.to_string(), "After");
+        assert_eq!(StepPosition::Before.to_string(), "Before");
     }
 
     #[test]
@@ -1249,6 +3766,15 @@ mod tests {
         assert_eq!("Scatter".parse::<GraphType>(), Ok(GraphType::Scatter));
         assert_eq!("Line".parse::<GraphType>(), Ok(GraphType::Line));
         assert_eq!("Bar".parse::<GraphType>(), Ok(GraphType::Bar));
+        assert_eq!(
+            "Candlestick".parse::<GraphType>(),
+            Ok(GraphType::Candlestick)
+        );
+        assert_eq!(
+            "Step".parse::<GraphType>(),
+            Ok(GraphType::Step(StepPosition::After))
+        );
+        assert_eq!("Before".parse::<StepPosition>(), Ok(StepPosition::Before));
         assert_eq!("".parse::<GraphType>(), Err(ParseError::VariantNotFound));
     }
 
@@ -1390,6 +3916,95 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[rstest]
+    #[case::right(LegendPosition::OutsideRight, Rect::new(0, 0, 10, 3), [
+        "    ┌────┐",
+        "    │Data│",
+        "•   └────┘",
+    ])]
+    #[case::left(LegendPosition::OutsideLeft, Rect::new(0, 0, 10, 3), [
+        "┌────┐    ",
+        "│Data│    ",
+        "└────┘•   ",
+    ])]
+    #[case::top(LegendPosition::OutsideTop, Rect::new(0, 0, 8, 4), [
+        "  ┌────┐",
+        "  │Data│",
+        "  └────┘",
+        "•       ",
+    ])]
+    #[case::bottom(LegendPosition::OutsideBottom, Rect::new(0, 0, 8, 4), [
+        "•       ",
+        "  ┌────┐",
+        "  │Data│",
+        "  └────┘",
+    ])]
+    fn legend_outside<'line, Lines>(
+        #[case] position: LegendPosition,
+        #[case] area: Rect,
+        #[case] expected: Lines,
+    ) where
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let data = [(0.0, 0.0)];
+        let chart = Chart::new(vec![Dataset::default().name("Data").data(&data)])
+            .x_axis(Axis::default().bounds([0.0, 1.0]))
+            .y_axis(Axis::default().bounds([0.0, 1.0]))
+            .hidden_legend_constraints((Constraint::Percentage(100), Constraint::Percentage(100)))
+            .legend_position(Some(position));
+        let mut buffer = Buffer::empty(area);
+        Widget::render(&chart, buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn legend_symbol() {
+        let chart = Chart::new(vec![Dataset::default()
+            .name("Data")
+            .legend_symbol("━")
+            .red()])
+        .hidden_legend_constraints((Constraint::Percentage(100), Constraint::Percentage(100)));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["┌──────┐", "│━ Data│", "└──────┘"]);
+        expected.set_style(Rect::new(1, 1, 6, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn toggle_dataset_from_legend() {
+        let (a, b) = ([(0.0, 0.0)], [(0.0, 1.0)]);
+        let chart = Chart::new(vec![
+            Dataset::default().name("a").data(&a),
+            Dataset::default().name("b").data(&b),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 1.0]))
+        .y_axis(Axis::default().bounds([0.0, 1.0]))
+        .hidden_legend_constraints((Constraint::Percentage(100), Constraint::Percentage(100)));
+        let mut state = ChartState::default();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 4));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["•  ┌─┐", "   │a│", "   │b│", "•  └─┘"])
+        );
+
+        assert_eq!(state.legend_entry_at(Position::new(4, 1)), Some(0));
+        assert_eq!(state.handle_legend_click(Position::new(4, 2)), Some(1));
+        assert!(!state.is_dataset_visible(1));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 4));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines(["   ┌─┐", "   │a│", "   │b│", "•  └─┘"]);
+        expected.set_style(Rect::new(4, 2, 1, 1), Modifier::DIM);
+        assert_eq!(buffer, expected);
+
+        state.toggle_dataset(1);
+        assert!(state.is_dataset_visible(1));
+        assert_eq!(state.handle_legend_click(Position::new(0, 0)), None);
+    }
+
     #[test]
     fn test_legend_area_can_fit_same_chart_area() {
         let name = "Data";
@@ -1544,4 +4159,751 @@ mod tests {
         ]);
         assert_eq!(buffer, expected);
     }
+
+    #[test]
+    fn brush_selection() {
+        let chart = Chart::new(vec![]).x_axis(Axis::default().bounds([0.0, 10.0]));
+        let mut state = ChartState::default();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 3));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.graph_area(), buffer.area);
+        assert_eq!(state.x_value(5), Some(5.0));
+        assert_eq!(state.x_value(11), None);
+
+        assert!(state.start_brush(Position::new(6, 1)));
+        state.drag_brush(Position::new(2, 1));
+        assert!(state.is_brushing());
+        assert_eq!(state.brush(), Some([2.0, 6.0]));
+
+        // dragging outside of the graph area is clamped to it
+        state.drag_brush(Position::new(20, 1));
+        assert_eq!(state.end_brush(), Some([6.0, 10.0]));
+        assert!(!state.is_brushing());
+        assert_eq!(state.brush(), Some([6.0, 10.0]));
+
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines(["           "; 3]);
+        expected.set_style(Rect::new(6, 0, 5, 3), Style::new().reversed());
+        assert_eq!(buffer, expected);
+
+        state.clear_brush();
+        assert_eq!(state.brush(), None);
+    }
+
+    #[test]
+    fn brush_click_clears_selection() {
+        let chart = Chart::new(vec![]).x_axis(Axis::default().bounds([0.0, 10.0]));
+        let mut state = ChartState::default();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 3));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+
+        assert!(!state.start_brush(Position::new(12, 1)));
+        assert!(state.start_brush(Position::new(3, 1)));
+        assert_eq!(state.end_brush(), None);
+        assert_eq!(state.brush(), None);
+    }
+
+    #[test]
+    fn brush_style() {
+        let chart = Chart::new(vec![])
+            .x_axis(Axis::default().bounds([0.0, 4.0]))
+            .brush_style(Color::Blue);
+        let mut state = ChartState::default();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        state.start_brush(Position::new(1, 0));
+        state.drag_brush(Position::new(2, 0));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines(["     "]);
+        expected.set_style(Rect::new(1, 0, 2, 1), Color::Blue);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn log_scale() {
+        let data = [(0.0, 1.0), (1.0, 10.0), (1.5, 0.0), (2.0, 100.0)];
+        let chart = Chart::new(vec![Dataset::default().data(&data)])
+            .x_axis(Axis::default().bounds([0.0, 2.0]))
+            .y_axis(Axis::default().bounds([1.0, 100.0]).scale(Scale::Log10));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "100│     •",
+            "   │      ",
+            "10 │   •  ",
+            "   │      ",
+            "1  │•     ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn secondary_y_axis() {
+        let primary = [(0.0, 10.0)];
+        let secondary = [(1.0, 50.0)];
+        let chart = Chart::new(vec![
+            Dataset::default().data(&primary),
+            Dataset::default().data(&secondary).secondary_y_axis(true),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 1.0]))
+        .y_axis(Axis::default().bounds([0.0, 10.0]).labels(["0", "10"]))
+        .secondary_y_axis(Axis::default().bounds([0.0, 100.0]).labels(["0", "100"]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 4));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "10│•    │100",
+            "  │     │   ",
+            "  │    •│   ",
+            "0 │     │0  ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn secondary_y_axis_flag_without_axis() {
+        let data = [(1.0, 10.0)];
+        let chart = Chart::new(vec![Dataset::default().data(&data).secondary_y_axis(true)])
+            .x_axis(Axis::default().bounds([0.0, 1.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["  •", "   "]));
+    }
+
+    #[test]
+    fn stacked_area() {
+        let first = [(0.0, 1.0), (4.0, 1.0)];
+        let second = [(0.0, 1.0), (4.0, 3.0)];
+        let chart = Chart::new(vec![
+            Dataset::default().data(&first).stack_group("stack").red(),
+            Dataset::default().data(&second).stack_group("stack").blue(),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 4.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 4));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["   ••", " ••••", "•••••", "•••••"]);
+        expected.set_style(Rect::new(3, 0, 2, 1), Style::new().blue());
+        expected.set_style(Rect::new(1, 1, 4, 1), Style::new().blue());
+        expected.set_style(Rect::new(0, 2, 5, 1), Style::new().blue());
+        expected.set_style(Rect::new(0, 3, 5, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::after(StepPosition::After, ["  •••", "  • •", "•••  "])]
+    #[case::before(StepPosition::Before, ["•••  ", "• •••", "•    "])]
+    fn step(#[case] position: StepPosition, #[case] expected: [&str; 3]) {
+        let data = [(0.0, 0.0), (2.0, 2.0), (4.0, 1.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .graph_type(GraphType::Step(position))])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 2.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn point_symbol() {
+        let data = [(0.0, 0.0), (2.0, 1.0), (4.0, 2.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Braille)
+            .point_symbol("×")
+            .red()])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 2.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["    ×", "  ×  ", "×    "]);
+        for (x, y) in [(0, 2), (2, 1), (4, 0)] {
+            expected[(x, y)].set_fg(Color::Red);
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn reference_lines_and_bands() {
+        let data = [(1.0, 3.0)];
+        let chart = Chart::new(vec![Dataset::default().data(&data).blue()])
+            .x_axis(Axis::default().bounds([0.0, 5.0]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]))
+            .reference_bands([ReferenceBand::horizontal([3.0, 5.0]).on_red().label("hi")])
+            .reference_lines([
+                ReferenceLine::horizontal(1.0).green().label("1"),
+                ReferenceLine::vertical(4.0).yellow().label("v"),
+            ]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 5));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["hi  │v", " •  │ ", "    │ ", "─────1", "    │ "]);
+        expected.set_style(Rect::new(0, 0, 6, 2), Style::new().on_red());
+        expected.set_style(Rect::new(0, 3, 6, 1), Style::new().green());
+        expected.set_style(Rect::new(1, 1, 1, 1), Style::new().blue());
+        for (x, y) in [(4, 0), (4, 1), (4, 2), (4, 4), (5, 0)] {
+            expected[(x, y)].set_fg(Color::Yellow);
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn reference_out_of_bounds() {
+        let chart = Chart::new(vec![])
+            .x_axis(Axis::default().bounds([0.0, 2.0]))
+            .y_axis(Axis::default().bounds([0.0, 2.0]))
+            .reference_bands([ReferenceBand::vertical([3.0, 4.0]).on_red()])
+            .reference_lines([ReferenceLine::horizontal(-1.0).label("low")]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["   "; 3]));
+    }
+
+    #[test]
+    fn error_bars() {
+        let data = [(0.0, 2.0), (2.0, 1.0)];
+        let errors = [(1.0, 3.0), (1.0, 1.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .error_bars(&errors)
+            .error_bar_style(Style::new().green())
+            .red()])
+        .x_axis(Axis::default().bounds([0.0, 2.0]))
+        .y_axis(Axis::default().bounds([0.0, 4.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 5));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["   ", "┬  ", "•  ", "┴ •", "   "]);
+        expected.set_style(Rect::new(0, 1, 1, 3), Style::new().green());
+        expected.set_style(Rect::new(0, 2, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(2, 3, 1, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn candlesticks() {
+        let candles = [
+            Candle {
+                x: 0.0,
+                open: 1.0,
+                high: 4.0,
+                low: 0.0,
+                close: 3.0,
+            },
+            Candle {
+                x: 2.0,
+                open: 3.0,
+                high: 3.0,
+                low: 1.0,
+                close: 2.0,
+            },
+        ];
+        // the data points of a candlestick dataset are not drawn
+        let data = [(1.0, 4.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .candles(&candles)
+            .candle_styles(Style::new().green(), Style::new().red())])
+        .x_axis(Axis::default().bounds([0.0, 2.0]))
+        .y_axis(Axis::default().bounds([0.0, 4.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 5));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["│  ", "┃ ┃", "┃ ┃", "┃ │", "│  "]);
+        expected.set_style(Rect::new(0, 0, 1, 5), Style::new().green());
+        expected.set_style(Rect::new(2, 1, 1, 3), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn stacked_data() {
+        let first = [(0.0, 1.0), (2.0, 3.0)];
+        let second = [(0.0, 1.0), (1.0, 1.0), (3.0, 1.0)];
+        let other = [(0.0, 5.0)];
+        let chart = Chart::new(vec![
+            Dataset::default().data(&first).stack_group("a"),
+            Dataset::default().data(&other),
+            Dataset::default().data(&other).stack_group("b"),
+            Dataset::default().data(&second).stack_group("a"),
+        ]);
+        assert_eq!(
+            chart.stacked_data(&BTreeSet::new()),
+            vec![
+                Some((vec![(0.0, 1.0), (2.0, 3.0)], None)),
+                None,
+                Some((vec![(0.0, 5.0)], None)),
+                Some((vec![(0.0, 2.0), (1.0, 3.0), (3.0, 1.0)], Some(0))),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::before(-1.0, None)]
+    #[case::first(0.0, Some(0.0))]
+    #[case::between(1.0, Some(2.0))]
+    #[case::last(2.0, Some(4.0))]
+    #[case::after(3.0, None)]
+    fn interpolate(#[case] x: f64, #[case] expected: Option<f64>) {
+        assert_eq!(super::interpolate(&[(0.0, 0.0), (2.0, 4.0)], x), expected);
+    }
+
+    #[cfg(feature = "chart-time")]
+    #[rstest]
+    #[case::seconds([0.0, 10.0], &["00:00:00", "00:00:05", "00:00:10"])]
+    #[case::hour([0.0, 3600.0], &["00:00", "00:15", "00:30", "00:45", "01:00"])]
+    #[case::days([0.0, 3.0 * DAY], &["1970-01-01", "1970-01-02", "1970-01-03", "1970-01-04"])]
+    #[case::weeks([0.0, 30.0 * DAY], &["1970-01-05", "1970-01-12", "1970-01-19", "1970-01-26"])]
+    #[case::quarters(
+        [1_705_276_800.0, 1_732_060_800.0],
+        &["2024-04", "2024-07", "2024-10"]
+    )]
+    #[case::months_over_years(
+        [1_706_745_600.0, 1_748_736_000.0],
+        &["2024-04", "2024-07", "2024-10", "2025-01", "2025-04"]
+    )]
+    #[case::years([0.0, 10.0 * YEAR], &["1970", "1972", "1974", "1976", "1978"])]
+    fn time_labels(#[case] bounds: [f64; 2], #[case] expected: &[&str]) {
+        let axis = Axis::default().bounds(bounds).time(time::UtcOffset::UTC);
+        let expected = expected.iter().copied().map(Line::from).collect();
+        let labels = axis.generated_labels(80, false).map(|(labels, _)| labels);
+        assert_eq!(labels, Some(expected));
+    }
+
+    #[cfg(feature = "chart-time")]
+    #[test]
+    fn time_labels_are_aligned_to_the_step() {
+        let axis = Axis::default()
+            .bounds([7.0, 47.0])
+            .time(time::UtcOffset::UTC);
+        let expected = ["00:00:15", "00:00:30", "00:00:45"]
+            .map(Line::from)
+            .to_vec();
+        assert_eq!(
+            axis.generated_labels(80, false),
+            Some((expected, vec![0.2, 0.575, 0.95]))
+        );
+    }
+
+    #[cfg(feature = "chart-time")]
+    #[rstest]
+    #[case::wide(80, &["00:00", "00:15", "00:30", "00:45", "01:00"])]
+    #[case::narrow(20, &["00:00", "00:30", "01:00"])]
+    #[case::narrower(12, &["00:00", "01:00"])]
+    #[case::too_narrow(2, &["00:00", "01:00"])]
+    fn time_labels_fit_the_length(#[case] length: u16, #[case] expected: &[&str]) {
+        let axis = Axis::default()
+            .bounds([0.0, HOUR])
+            .time(time::UtcOffset::UTC);
+        let expected = expected.iter().copied().map(Line::from).collect();
+        let labels = axis
+            .generated_labels(length, false)
+            .map(|(labels, _)| labels);
+        assert_eq!(labels, Some(expected));
+        // a row per label is enough on a vertical axis
+        let labels = axis
+            .generated_labels(5, true)
+            .map(|(labels, _)| labels.len());
+        assert_eq!(labels, Some(5));
+    }
+
+    #[cfg(feature = "chart-time")]
+    #[rstest]
+    #[case::aligned(36, "     00:00:15    00:00:30   00:00:45")]
+    #[case::bounds(30, "  00:00:07            00:00:47")]
+    fn render_time_labels(#[case] width: u16, #[case] labels: &str) {
+        let chart = Chart::new(vec![])
+            .x_axis(
+                Axis::default()
+                    .bounds([7.0, 47.0])
+                    .time(time::UtcOffset::UTC),
+            )
+            .y_axis(Axis::default().bounds([0.0, 1.0]).labels(["0", "1"]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, width, 4));
+        Widget::render(&chart, buffer.area, &mut buffer);
+        let axis = format!(" └{}", "─".repeat(usize::from(width) - 2));
+        let expected = Buffer::with_lines([
+            format!("1│{:1$}", "", usize::from(width) - 2),
+            format!("0│{:1$}", "", usize::from(width) - 2),
+            axis,
+            format!("{labels:0$}", usize::from(width)),
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[cfg(feature = "chart-time")]
+    #[test]
+    fn time_labels_offset() {
+        let offset = time::UtcOffset::from_hms(1, 30, 0).unwrap();
+        let axis = Axis::default().bounds([0.0, 3600.0]).time(offset);
+        let expected = ["01:30", "01:45", "02:00", "02:15", "02:30"].map(Line::from);
+        let labels = axis.generated_labels(80, false).map(|(labels, _)| labels);
+        assert_eq!(labels, Some(expected.to_vec()));
+    }
+
+    #[rstest]
+    #[case::data(0.0, false, [3.0, 97.0])]
+    #[case::padding(50.0, false, [-44.0, 144.0])]
+    #[case::nice(0.0, true, [0.0, 100.0])]
+    #[case::padding_nice(50.0, true, [-50.0, 150.0])]
+    fn auto_bounds(#[case] padding: f64, #[case] nice: bool, #[case] expected: [f64; 2]) {
+        let axis = Axis::default()
+            .bounds_auto()
+            .bounds_padding(padding)
+            .nice_bounds(nice)
+            .with_auto_bounds([50.0, 97.0, 3.0].into_iter());
+        assert_eq!(axis.bounds, expected);
+    }
+
+    #[test]
+    fn auto_bounds_edge_cases() {
+        let axis = Axis::default().bounds([1.0, 2.0]).bounds_auto();
+        assert_eq!(axis.with_auto_bounds([].into_iter()).bounds, [1.0, 2.0]);
+        assert_eq!(axis.with_auto_bounds([5.0].into_iter()).bounds, [4.5, 5.5]);
+        let axis = axis.scale(Scale::Log10).nice_bounds(true);
+        let values = [-1.0, 2.0, 500.0].into_iter();
+        assert_eq!(axis.with_auto_bounds(values).bounds, [1.0, 1000.0]);
+    }
+
+    #[test]
+    fn auto_bounds_labels() {
+        let values = [3.0, 97.0];
+        let axis = Axis::default().bounds_auto();
+        let labels = axis
+            .with_auto_bounds(values.into_iter())
+            .generated_labels(0, false);
+        let expected = ["3", "50", "97"].map(Line::from).to_vec();
+        assert_eq!(labels, Some((expected, vec![])));
+        let axis = axis.nice_bounds(true);
+        let labels = axis
+            .with_auto_bounds(values.into_iter())
+            .generated_labels(0, false);
+        let expected = ["0", "25", "50", "75", "100"].map(Line::from).to_vec();
+        assert_eq!(labels, Some((expected, vec![])));
+    }
+
+    #[test]
+    fn auto_bounds_from_datasets() {
+        let data = [(1.0, 3.0), (3.0, 9.0)];
+        let candles = [Candle {
+            x: 4.0,
+            open: 5.0,
+            high: 12.0,
+            low: 1.0,
+            close: 6.0,
+        }];
+        let hidden = [(10.0, 100.0)];
+        let chart = Chart::new(vec![
+            Dataset::default().data(&data),
+            Dataset::default().candles(&candles),
+            Dataset::default().data(&hidden),
+        ])
+        .x_axis(Axis::default().bounds_auto())
+        .y_axis(Axis::default().bounds_auto().nice_bounds(true));
+        let mut state = ChartState::default();
+        state.set_dataset_visible(2, false);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 10));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.x_bounds(), [1.0, 4.0]);
+        assert_eq!(state.y_bounds(), [0.0, 15.0]);
+    }
+
+    #[test]
+    fn crosshair() {
+        let data = [(2.0, 0.0), (6.0, 2.0)];
+        let chart = Chart::new(vec![Dataset::default().data(&data).red()])
+            .x_axis(Axis::default().bounds([0.0, 8.0]))
+            .y_axis(Axis::default().bounds([0.0, 2.0]));
+        let mut state = ChartState::default();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 3));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        assert!(state.set_cursor(Position::new(5, 1)));
+        assert_eq!(state.cursor(), Some((5.0, 1.0)));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 3));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines(["(6, 2)•  ", "─────┼───", "  •  │   "]);
+        expected.set_style(Rect::new(0, 0, 7, 1), Style::new().red());
+        expected.set_style(Rect::new(0, 1, 9, 1), Style::new().dark_gray());
+        expected.set_style(Rect::new(2, 2, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(5, 2, 1, 1), Style::new().dark_gray());
+        assert_eq!(buffer, expected);
+        assert_eq!(state.nearest_points(), &[(0, (6.0, 2.0))]);
+
+        state.clear_cursor();
+        assert_eq!(state.cursor(), None);
+        assert!(state.nearest_points().is_empty());
+    }
+
+    #[test]
+    fn state_equality_ignores_render_layout() {
+        let chart = Chart::new(vec![])
+            .x_axis(Axis::default().bounds([0.0, 8.0]))
+            .y_axis(Axis::default().bounds([0.0, 2.0]));
+        let mut state = ChartState::default();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 3));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state, ChartState::default());
+
+        state.move_cursor(0, 0);
+        assert_ne!(state, ChartState::default());
+    }
+
+    #[test]
+    fn move_cursor() {
+        let chart = Chart::new(vec![])
+            .x_axis(Axis::default().bounds([0.0, 8.0]))
+            .y_axis(Axis::default().bounds([0.0, 2.0]));
+        let mut state = ChartState::default();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 3));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+
+        state.move_cursor(0, 0);
+        assert_eq!(state.cursor(), Some((4.0, 1.0)));
+        state.move_cursor(-1, 1);
+        assert_eq!(state.cursor(), Some((3.0, 0.0)));
+        state.move_cursor(100, -100);
+        assert_eq!(state.cursor(), Some((8.0, 2.0)));
+    }
+
+    #[test]
+    fn data_coordinates() {
+        let chart = Chart::new(vec![])
+            .x_axis(Axis::default().bounds([0.0, 8.0]))
+            .y_axis(Axis::default().bounds([0.0, 2.0]));
+        let mut state = ChartState::default();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 3));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(
+            state.data_coordinates(Position::new(0, 0)),
+            Some((0.0, 2.0))
+        );
+        assert_eq!(
+            state.data_coordinates(Position::new(8, 2)),
+            Some((8.0, 0.0))
+        );
+        assert_eq!(state.data_coordinates(Position::new(9, 0)), None);
+        assert!(!state.set_cursor(Position::new(9, 0)));
+    }
+
+    #[test]
+    fn downsample() {
+        let data: Vec<(f64, f64)> = (0..100)
+            .map(|i| (f64::from(i), f64::from(i % 10)))
+            .collect();
+        assert_eq!(
+            super::downsample(&data, [0.0, 99.0], 2),
+            vec![
+                (0.0, 0.0),
+                (9.0, 9.0),
+                (49.0, 9.0),
+                (50.0, 0.0),
+                (59.0, 9.0),
+                (99.0, 9.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn downsample_keeps_lines_out_of_bounds() {
+        let data = [
+            (-2.0, 5.0),
+            (-1.0, 7.0),
+            (0.0, 1.0),
+            (0.5, 3.0),
+            (1.0, 2.0),
+            (2.0, 4.0),
+        ];
+        assert_eq!(
+            super::downsample(&data, [0.0, 1.0], 1),
+            vec![
+                (-2.0, 5.0),
+                (-1.0, 7.0),
+                (0.0, 1.0),
+                (0.5, 3.0),
+                (1.0, 2.0),
+                (2.0, 4.0)
+            ]
+        );
+        let data = [
+            (0.0, 1.0),
+            (0.2, 0.0),
+            (0.4, 5.0),
+            (0.6, 2.0),
+            (0.8, 3.0),
+            (2.0, 4.0),
+        ];
+        assert_eq!(
+            super::downsample(&data, [0.0, 1.0], 1),
+            vec![(0.0, 1.0), (0.2, 0.0), (0.4, 5.0), (0.8, 3.0), (2.0, 4.0)]
+        );
+    }
+
+    #[test]
+    fn downsampled_only_large_datasets() {
+        let chart = Chart::new(vec![]).x_axis(Axis::default().bounds([0.0, 99.0]));
+        let data: Vec<(f64, f64)> = (0..100).map(|i| (f64::from(i), 0.0)).collect();
+        let downsampled = chart.downsampled(Cow::Borrowed(&data), symbols::Marker::Dot, 25);
+        assert!(matches!(downsampled, Cow::Borrowed(_)));
+        let downsampled = chart.downsampled(Cow::Borrowed(&data), symbols::Marker::Dot, 10);
+        assert_eq!(downsampled.len(), 20);
+        let downsampled = chart.downsampled(Cow::Borrowed(&data), symbols::Marker::Braille, 10);
+        assert_eq!(downsampled.len(), 40);
+        let downsampled = chart.downsampled(Cow::Borrowed(&data), symbols::Marker::Octant, 10);
+        assert_eq!(downsampled.len(), 40);
+        let chart = chart.downsample(false);
+        let downsampled = chart.downsampled(Cow::Borrowed(&data), symbols::Marker::Dot, 10);
+        assert_eq!(downsampled.len(), 100);
+    }
+
+    fn zoom_chart() -> Chart<'static> {
+        Chart::new(vec![])
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]))
+    }
+
+    fn render_zoom_chart(state: &mut ChartState) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 11));
+        StatefulWidget::render(&zoom_chart(), buffer.area, &mut buffer, state);
+    }
+
+    #[test]
+    fn zoom() {
+        let mut state = ChartState::default();
+        render_zoom_chart(&mut state);
+        assert!(!state.is_zoomed());
+
+        state.zoom(2.0, Position::new(5, 5));
+        render_zoom_chart(&mut state);
+        assert!(state.is_zoomed());
+        assert_eq!(state.x_bounds(), [2.5, 7.5]);
+        assert_eq!(state.y_bounds(), [2.5, 7.5]);
+
+        state.zoom(0.25, Position::new(5, 5));
+        render_zoom_chart(&mut state);
+        assert!(!state.is_zoomed());
+        assert_eq!(state.x_bounds(), [0.0, 10.0]);
+    }
+
+    #[test]
+    fn zoom_at_anchor() {
+        let mut state = ChartState::default();
+        render_zoom_chart(&mut state);
+        state.zoom(2.0, Position::new(0, 0));
+        render_zoom_chart(&mut state);
+        assert_eq!(state.x_bounds(), [0.0, 5.0]);
+        assert_eq!(state.y_bounds(), [5.0, 10.0]);
+
+        state.reset_view();
+        state.zoom_at(Position::new(5, 5), 1);
+        render_zoom_chart(&mut state);
+        assert_eq!(state.x_bounds(), [1.0, 9.0]);
+    }
+
+    #[test]
+    fn pan() {
+        let mut state = ChartState::default();
+        render_zoom_chart(&mut state);
+        state.pan(1, 0);
+        assert!(!state.is_zoomed());
+
+        state.zoom(2.0, Position::new(5, 5));
+        state.pan(1, 1);
+        render_zoom_chart(&mut state);
+        assert_eq!(state.x_bounds(), [3.0, 8.0]);
+        assert_eq!(state.y_bounds(), [2.0, 7.0]);
+
+        state.pan(100, -100);
+        render_zoom_chart(&mut state);
+        assert_eq!(state.x_bounds(), [5.0, 10.0]);
+        assert_eq!(state.y_bounds(), [5.0, 10.0]);
+    }
+
+    #[test]
+    fn drag_pan() {
+        let mut state = ChartState::default();
+        render_zoom_chart(&mut state);
+        state.zoom(2.0, Position::new(5, 5));
+        assert!(!state.start_pan(Position::new(20, 5)));
+        assert!(state.start_pan(Position::new(5, 5)));
+        state.drag_pan(Position::new(6, 5));
+        state.drag_pan(Position::new(7, 4));
+        state.end_pan();
+        state.drag_pan(Position::new(0, 0));
+        render_zoom_chart(&mut state);
+        assert_eq!(state.x_bounds(), [1.5, 6.5]);
+        assert_eq!(state.y_bounds(), [2.0, 7.0]);
+    }
+
+    #[test]
+    fn zoom_replaces_labels() {
+        let chart = zoom_chart()
+            .x_axis(Axis::default().bounds([0.0, 10.0]).labels(["0", "10"]))
+            .y_axis(Axis::default().bounds([0.0, 10.0]).labels(["low", "high"]));
+        let chart = chart.with_view(Some([[2.5, 7.5], [0.0, 10.0]])).unwrap();
+        assert_eq!(
+            chart.x_axis.labels,
+            vec![Line::from("2.5"), Line::from("7.5")]
+        );
+        assert_eq!(
+            chart.y_axis.labels,
+            vec![Line::from("low"), Line::from("high")]
+        );
+    }
+
+    #[test]
+    fn log_scale_brush() {
+        let chart = Chart::new(vec![])
+            .x_axis(
+                Axis::default()
+                    .bounds([1.0, 1000.0])
+                    .scale(Scale::Log10)
+                    .labels_alignment(Alignment::Right),
+            )
+            .y_axis(Axis::default().bounds([0.0, 1.0]));
+        let mut state = ChartState::default();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.x_value(0).map(f64::round), Some(1.0));
+        assert_eq!(state.x_value(1).map(f64::round), Some(10.0));
+        assert_eq!(state.x_value(3).map(f64::round), Some(1000.0));
+    }
+
+    #[test]
+    fn log_scale_generated_labels() {
+        let axis = Axis::default().bounds([1.0, 1e6]).scale(Scale::Log10);
+        assert_eq!(
+            axis.generated_labels(0, false),
+            Some((
+                vec![
+                    Line::from("1"),
+                    Line::from("10"),
+                    Line::from("100"),
+                    Line::from("1000"),
+                    Line::from("10000"),
+                    Line::from("100000"),
+                    Line::from("1e6"),
+                ],
+                vec![]
+            ))
+        );
+        // labels given by the user are kept
+        let labels = axis.clone().labels(["a", "b"]).generated_labels(0, false);
+        assert_eq!(labels, None);
+        // linear axes don't generate labels
+        let labels = Axis::default()
+            .bounds([1.0, 1e6])
+            .generated_labels(0, false);
+        assert_eq!(labels, None);
+    }
+
+    #[rstest]
+    #[case(0.0, "0")]
+    #[case(1.0, "1")]
+    #[case(0.5, "0.5")]
+    #[case(0.001, "0.001")]
+    #[case(0.0001, "1e-4")]
+    #[case(123_456.0, "123456")]
+    #[case(1_000_000.0, "1e6")]
+    #[case(-25.0, "-25")]
+    fn format_axis_value(#[case] value: f64, #[case] expected: &str) {
+        assert_eq!(super::format_axis_value(value), expected);
+    }
 }
@@ -0,0 +1,242 @@
+use ratatui_core::{
+    layout::Direction,
+    style::{Style, Styled},
+    text::Line,
+};
+
+/// A straight line marking a value of an axis of the [`Chart`](super::Chart) widget
+///
+/// A [horizontal](ReferenceLine::horizontal) line marks a value of the Y axis and a
+/// [vertical](ReferenceLine::vertical) line marks a value of the X axis. Reference lines are drawn
+/// behind the datasets, on the cells of the graph area that are not used by them.
+///
+/// A reference line can have an optional [label](ReferenceLine::label), displayed at the right end
+/// of a horizontal line or next to the top of a vertical line.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     style::{Style, Stylize},
+///     widgets::ReferenceLine,
+/// };
+///
+/// let target = ReferenceLine::horizontal(80.0).green().label("target");
+/// let deploy = ReferenceLine::vertical(12.5).style(Style::new().dark_gray());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceLine<'a> {
+    /// The direction of the line
+    pub(super) direction: Direction,
+    /// The value of the axis marked by the line
+    pub(super) value: f64,
+    /// The style of the line and its label
+    pub(super) style: Style,
+    /// The optional label of the line
+    pub(super) label: Option<Line<'a>>,
+}
+
+impl<'a> ReferenceLine<'a> {
+    /// Creates a horizontal line marking the given value of the Y axis
+    pub const fn horizontal(y: f64) -> Self {
+        Self {
+            direction: Direction::Horizontal,
+            value: y,
+            style: Style::new(),
+            label: None,
+        }
+    }
+
+    /// Creates a vertical line marking the given value of the X axis
+    pub const fn vertical(x: f64) -> Self {
+        Self {
+            direction: Direction::Vertical,
+            value: x,
+            style: Style::new(),
+            label: None,
+        }
+    }
+
+    /// Sets the style of the line and its label
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the label of the line
+    ///
+    /// `label` can be a [`&str`], [`String`] or anything that can be converted into [`Line`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label<T: Into<Line<'a>>>(mut self, label: T) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+impl Styled for ReferenceLine<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// A shaded range of values of an axis of the [`Chart`](super::Chart) widget, e.g. a warning or
+/// critical threshold
+///
+/// A [horizontal](ReferenceBand::horizontal) band spans a range of the Y axis over the whole width
+/// of the graph and a [vertical](ReferenceBand::vertical) band spans a range of the X axis over its
+/// whole height. Bands are drawn behind the datasets: the cells used by the datasets keep their
+/// symbol and foreground color and only get the background color of the band.
+///
+/// A band can have an optional [label](ReferenceBand::label), displayed at its top left corner.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{style::Stylize, widgets::ReferenceBand};
+///
+/// let warning = ReferenceBand::horizontal([70.0, 90.0]).on_yellow();
+/// let critical = ReferenceBand::horizontal([90.0, 100.0])
+///     .on_red()
+///     .label("critical");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceBand<'a> {
+    /// The direction of the band
+    pub(super) direction: Direction,
+    /// The range of values of the axis spanned by the band
+    pub(super) bounds: [f64; 2],
+    /// The style of the band and its label
+    pub(super) style: Style,
+    /// The optional label of the band
+    pub(super) label: Option<Line<'a>>,
+}
+
+impl<'a> ReferenceBand<'a> {
+    /// Creates a horizontal band spanning the given range of the Y axis
+    pub const fn horizontal(bounds: [f64; 2]) -> Self {
+        Self {
+            direction: Direction::Horizontal,
+            bounds,
+            style: Style::new(),
+            label: None,
+        }
+    }
+
+    /// Creates a vertical band spanning the given range of the X axis
+    pub const fn vertical(bounds: [f64; 2]) -> Self {
+        Self {
+            direction: Direction::Vertical,
+            bounds,
+            style: Style::new(),
+            label: None,
+        }
+    }
+
+    /// Sets the style of the band and its label
+    ///
+    /// Usually only a background color is set, as the foreground color is applied to the empty
+    /// cells of the band only.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the label of the band
+    ///
+    /// `label` can be a [`&str`], [`String`] or anything that can be converted into [`Line`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label<T: Into<Line<'a>>>(mut self, label: T) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+impl Styled for ReferenceBand<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::style::{Color, Stylize};
+
+    use super::*;
+
+    #[test]
+    fn reference_line() {
+        let line = ReferenceLine::horizontal(1.5).red().label("target");
+        let expected = ReferenceLine {
+            direction: Direction::Horizontal,
+            value: 1.5,
+            style: Style::new().fg(Color::Red),
+            label: Some(Line::from("target")),
+        };
+        assert_eq!(line, expected);
+
+        let line = ReferenceLine::vertical(2.0);
+        let expected = ReferenceLine {
+            direction: Direction::Vertical,
+            value: 2.0,
+            style: Style::new(),
+            label: None,
+        };
+        assert_eq!(line, expected);
+    }
+
+    #[test]
+    fn reference_band() {
+        let band = ReferenceBand::horizontal([1.0, 2.0])
+            .on_red()
+            .label("critical");
+        let expected = ReferenceBand {
+            direction: Direction::Horizontal,
+            bounds: [1.0, 2.0],
+            style: Style::new().bg(Color::Red),
+            label: Some(Line::from("critical")),
+        };
+        assert_eq!(band, expected);
+
+        let band = ReferenceBand::vertical([3.0, 4.0]);
+        let expected = ReferenceBand {
+            direction: Direction::Vertical,
+            bounds: [3.0, 4.0],
+            style: Style::new(),
+            label: None,
+        };
+        assert_eq!(band, expected);
+    }
+}
@@ -0,0 +1,268 @@
+//! A gallery of demos that showcase the widgets of this crate.
+//!
+//! Each [`Demo`] has a name, a short description and a function that renders an example of the
+//! widget with representative content. The demos are used by the gallery example, and can be
+//! embedded by other tools (e.g. documentation generators or a web backend) to display an
+//! interactive widget gallery without reimplementing the examples.
+//!
+//! # Example
+//!
+//! ```
+//! use ratatui_core::{buffer::Buffer, layout::Rect, widgets::Widget};
+//! use ratatui_widgets::gallery;
+//!
+//! let mut buf = Buffer::empty(Rect::new(0, 0, 40, 10));
+//! for demo in gallery::demos() {
+//!     assert!(!demo.description().is_empty());
+//!     demo.render(buf.area, &mut buf);
+//! }
+//!
+//! let gauge = gallery::find("gauge").expect("the gauge demo exists");
+//! assert_eq!(gauge.name(), "gauge");
+//! ```
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::{
+    barchart::BarChart,
+    block::Block,
+    gauge::{Gauge, LineGauge},
+    list::List,
+    paragraph::{Paragraph, Wrap},
+    scrollbar::{Scrollbar, ScrollbarOrientation, ScrollbarState},
+    sparkline::Sparkline,
+    table::{Row, Table},
+    tabs::Tabs,
+};
+
+/// A named demo of a widget
+///
+/// A demo renders an example of a widget with representative content. It implements [`Widget`]
+/// (by reference), so it can be rendered like any other widget.
+#[derive(Debug, Clone, Copy)]
+pub struct Demo {
+    name: &'static str,
+    description: &'static str,
+    render: fn(Rect, &mut Buffer),
+}
+
+impl Demo {
+    /// Returns the name of the demo, which is the name of the widget in kebab-case
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns a one line description of the widget
+    pub const fn description(&self) -> &'static str {
+        self.description
+    }
+}
+
+impl Widget for &Demo {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (self.render)(area, buf);
+    }
+}
+
+/// The demos of the gallery, sorted by name
+const DEMOS: &[Demo] = &[
+    Demo {
+        name: "barchart",
+        description: "Displays multiple datasets as bars with optional grouping",
+        render: barchart,
+    },
+    Demo {
+        name: "block",
+        description: "Draws a block with optional borders, titles and styles",
+        render: block,
+    },
+    Demo {
+        name: "gauge",
+        description: "Displays progress percentage using block characters",
+        render: gauge,
+    },
+    Demo {
+        name: "line-gauge",
+        description: "Displays progress as a line",
+        render: line_gauge,
+    },
+    Demo {
+        name: "list",
+        description: "Displays a list of items and allows selection",
+        render: list,
+    },
+    Demo {
+        name: "paragraph",
+        description: "Displays a paragraph of optionally styled and wrapped text",
+        render: paragraph,
+    },
+    Demo {
+        name: "scrollbar",
+        description: "Displays a scrollbar",
+        render: scrollbar,
+    },
+    Demo {
+        name: "sparkline",
+        description: "Displays a single dataset as a sparkline",
+        render: sparkline,
+    },
+    Demo {
+        name: "table",
+        description: "Displays multiple rows and columns in a grid and allows selection",
+        render: table,
+    },
+    Demo {
+        name: "tabs",
+        description: "Displays a tab bar and allows selection",
+        render: tabs,
+    },
+];
+
+/// Returns all the demos of the gallery, sorted by name
+pub const fn demos() -> &'static [Demo] {
+    DEMOS
+}
+
+/// Returns the demo with the given name, if any
+pub fn find(name: &str) -> Option<&'static Demo> {
+    DEMOS.iter().find(|demo| demo.name == name)
+}
+
+fn barchart(area: Rect, buf: &mut Buffer) {
+    BarChart::default()
+        .block(Block::bordered().title("BarChart"))
+        .bar_width(3)
+        .bar_gap(1)
+        .bar_style(Color::Yellow)
+        .value_style(Style::new().black().on_yellow())
+        .data(&[("B1", 9), ("B2", 12), ("B3", 5), ("B4", 8), ("B5", 2)])
+        .render(area, buf);
+}
+
+fn block(area: Rect, buf: &mut Buffer) {
+    Block::bordered()
+        .title("Block")
+        .title_bottom(Line::from("bottom title").right_aligned())
+        .border_style(Color::Cyan)
+        .render(area, buf);
+}
+
+fn gauge(area: Rect, buf: &mut Buffer) {
+    Gauge::default()
+        .block(Block::bordered().title("Gauge"))
+        .gauge_style(Color::Green)
+        .percent(42)
+        .render(area, buf);
+}
+
+fn line_gauge(area: Rect, buf: &mut Buffer) {
+    LineGauge::default()
+        .block(Block::bordered().title("LineGauge"))
+        .filled_style(Color::Magenta)
+        .ratio(0.7)
+        .render(area, buf);
+}
+
+fn list(area: Rect, buf: &mut Buffer) {
+    Widget::render(
+        List::new(["Item 1", "Item 2", "Item 3"])
+            .block(Block::bordered().title("List"))
+            .highlight_symbol(">> ")
+            .highlight_style(Style::new().reversed()),
+        area,
+        buf,
+    );
+}
+
+fn paragraph(area: Rect, buf: &mut Buffer) {
+    Paragraph::new(vec![
+        Line::from(vec!["Paragraphs ".into(), "style".bold(), " text.".into()]),
+        Line::from("Long lines are wrapped to fit in the area.".italic()),
+    ])
+    .block(Block::bordered().title("Paragraph"))
+    .wrap(Wrap { trim: true })
+    .render(area, buf);
+}
+
+fn scrollbar(area: Rect, buf: &mut Buffer) {
+    let mut state = ScrollbarState::new(100).position(30);
+    Block::bordered().title("Scrollbar").render(area, buf);
+    Scrollbar::new(ScrollbarOrientation::VerticalRight).render(area, buf, &mut state);
+}
+
+fn sparkline(area: Rect, buf: &mut Buffer) {
+    Sparkline::default()
+        .block(Block::bordered().title("Sparkline"))
+        .style(Color::Red)
+        .data([0_u64, 2, 3, 4, 1, 4, 10, 6, 5, 8, 3, 2, 7, 9, 4, 1])
+        .render(area, buf);
+}
+
+fn table(area: Rect, buf: &mut Buffer) {
+    let rows = [
+        Row::new(["Cell 1", "Cell 2", "Cell 3"]),
+        Row::new(["Cell 4", "Cell 5", "Cell 6"]),
+    ];
+    Widget::render(
+        Table::new(rows, [Constraint::Length(8); 3])
+            .header(Row::new(["Col 1", "Col 2", "Col 3"]).bold())
+            .block(Block::bordered().title("Table")),
+        area,
+        buf,
+    );
+}
+
+fn tabs(area: Rect, buf: &mut Buffer) {
+    Tabs::new(["Tab 1", "Tab 2", "Tab 3"])
+        .block(Block::bordered().title("Tabs"))
+        .highlight_style(Style::new().yellow())
+        .select(1)
+        .render(area, buf);
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn demos_are_sorted_and_unique() {
+        let names = demos().iter().map(Demo::name).collect::<Vec<_>>();
+        let mut sorted = names.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(names, sorted);
+    }
+
+    #[test]
+    fn find() {
+        assert_eq!(super::find("gauge").map(Demo::name), Some("gauge"));
+        assert!(super::find("unknown").is_none());
+    }
+
+    #[test]
+    fn render_all_demos() {
+        for area in [Rect::ZERO, Rect::new(0, 0, 1, 1), Rect::new(0, 0, 40, 10)] {
+            let mut buf = Buffer::empty(area);
+            for demo in demos() {
+                demo.render(area, &mut buf);
+            }
+        }
+    }
+
+    #[test]
+    fn render_block() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 3));
+        super::find("block").unwrap().render(buf.area, &mut buf);
+        assert_eq!(
+            buf.to_plain_text(true),
+            "┌Block─────────┐\n│              │\n└──bottom title┘"
+        );
+    }
+}
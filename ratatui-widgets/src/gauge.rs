@@ -1,14 +1,18 @@
-//! The [`Gauge`] widget is used to display a horizontal progress bar.
+//! The [`Gauge`] widget is used to display a horizontal or vertical progress bar.
 use ratatui_core::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{Direction, Rect},
     style::{Color, Style, Styled},
     symbols::{self},
     text::{Line, Span},
     widgets::Widget,
 };
+use strum::{Display, EnumString};
 
-use crate::block::{Block, BlockExt};
+use crate::{
+    block::{Block, BlockExt},
+    callback::Callback,
+};
 
 /// A widget to display a progress bar.
 ///
@@ -16,11 +20,18 @@ use crate::block::{Block, BlockExt};
 /// [`Gauge::ratio`]. The bar width and height are defined by the [`Rect`] it is
 /// [rendered](Widget::render) in.
 ///
-/// The associated label is always centered horizontally and vertically. If not set with
-/// [`Gauge::label`], the label is the percentage of the bar filled.
+/// The associated label is centered horizontally and vertically by default, see
+/// [`Gauge::label_position`]. If not set with [`Gauge::label`] or [`Gauge::label_formatter`], the
+/// label is the percentage of the bar filled.
 ///
 /// You might want to have a higher precision bar using [`Gauge::use_unicode`].
 ///
+/// The bar is filled from left to right by default, or from bottom to top with a
+/// [vertical direction](Gauge::direction), e.g. for level meters in narrow columns.
+///
+/// The bar can also be split in several [segments](Gauge::segments) with their own style and
+/// label, e.g. to display the used, cached and free memory.
+///
 /// This can be useful to indicate the progression of a task, like a download.
 ///
 /// # Example
@@ -41,7 +52,7 @@ use crate::block::{Block, BlockExt};
 ///
 /// - [`LineGauge`] for a thin progress bar
 #[allow(clippy::struct_field_names)] // gauge_style needs to be differentiated to style
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Gauge<'a> {
     block: Option<Block<'a>>,
     ratio: f64,
@@ -49,6 +60,32 @@ pub struct Gauge<'a> {
     use_unicode: bool,
     style: Style,
     gauge_style: Style,
+    fill_color: Option<FillColor>,
+    direction: Direction,
+    segments: Vec<GaugeSegment<'a>>,
+    label_formatter: Option<Callback<f64, Span<'static>>>,
+    label_position: GaugeLabelPosition,
+    indeterminate: Option<u64>,
+}
+
+impl Default for Gauge<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            ratio: 0.0,
+            label: None,
+            use_unicode: false,
+            style: Style::default(),
+            gauge_style: Style::default(),
+            fill_color: None,
+            // the default direction of layouts is vertical, but gauges fill horizontally
+            direction: Direction::Horizontal,
+            segments: Vec::new(),
+            label_formatter: None,
+            label_position: GaugeLabelPosition::default(),
+            indeterminate: None,
+        }
+    }
 }
 
 impl<'a> Gauge<'a> {
@@ -116,6 +153,41 @@ impl<'a> Gauge<'a> {
         self
     }
 
+    /// Sets a function producing the label from the ratio of the gauge.
+    ///
+    /// This replaces the default label, which is the percentage filled, and is useful when the
+    /// label changes with the progress (e.g. a number of bytes or a remaining time). A label set
+    /// with [`Gauge::label`] takes precedence.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{text::Span, widgets::Gauge};
+    ///
+    /// Gauge::default()
+    ///     .label_formatter(|ratio| Span::raw(format!("{:.1}/10 GB", ratio * 10.0)))
+    ///     .ratio(0.42);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_formatter<F>(mut self, formatter: F) -> Self
+    where
+        F: Fn(f64) -> Span<'static> + Send + Sync + 'static,
+    {
+        self.label_formatter = Some(Callback::new(formatter));
+        self
+    }
+
+    /// Sets where the label is displayed in the bar.
+    ///
+    /// [`GaugeLabelPosition::Center`] is the default. The label is always on the middle row of the
+    /// bar, except for [`GaugeLabelPosition::InsideEnd`] in a [vertical](Gauge::direction) gauge,
+    /// which follows the top of the filled part.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn label_position(mut self, position: GaugeLabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
+
     /// Sets the widget style.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -139,6 +211,162 @@ impl<'a> Gauge<'a> {
         self
     }
 
+    /// Colors the filled part of the bar with a gradient from `start` to `end`.
+    ///
+    /// The left end of the bar has the `start` color and its right end has the `end` color, so the
+    /// color of each column only depends on its position, and the end of the filled part shifts
+    /// color as the bar progresses (e.g. from green to red). The colors are interpolated in RGB
+    /// (see [`Color::lerp`]).
+    ///
+    /// The gradient replaces the foreground color of the [gauge style](Gauge::gauge_style).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{style::Color, widgets::Gauge};
+    ///
+    /// Gauge::default()
+    ///     .gradient(Color::Rgb(0, 255, 0), Color::Rgb(255, 0, 0))
+    ///     .percent(60);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn gradient(mut self, start: Color, end: Color) -> Self {
+        self.fill_color = Some(FillColor::Gradient(start, end));
+        self
+    }
+
+    /// Colors the filled part of the bar according to the ratio of the gauge.
+    ///
+    /// The function is given the ratio and returns the color of the whole filled part, which
+    /// replaces the foreground color of the [gauge style](Gauge::gauge_style). Like with
+    /// [`Gauge::gradient`], which this replaces, this allows progress bars to change color
+    /// without recomputing their style.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{style::Color, widgets::Gauge};
+    ///
+    /// Gauge::default()
+    ///     .value_color(|ratio| Color::Rgb(0, 255, 0).lerp(Color::Rgb(255, 0, 0), ratio))
+    ///     .percent(60);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn value_color<F>(mut self, color: F) -> Self
+    where
+        F: Fn(f64) -> Color + Send + Sync + 'static,
+    {
+        self.fill_color = Some(FillColor::Ratio(Callback::new(color)));
+        self
+    }
+
+    /// Colors the filled part of the bar according to the thresholds reached by the ratio.
+    ///
+    /// `thresholds` is a list of ratios with a color, in any order. The filled part gets the color
+    /// of the highest threshold less than or equal to the ratio of the gauge, and keeps the
+    /// foreground color of the [gauge style](Gauge::gauge_style) below all the thresholds. Like
+    /// with [`Gauge::value_color`], which this replaces, the color switches as the gauge
+    /// progresses without recomputing its style.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{style::Color, widgets::Gauge};
+    ///
+    /// Gauge::default()
+    ///     .gauge_style(Color::Green)
+    ///     .thresholds([(0.8, Color::Red), (0.5, Color::Yellow)])
+    ///     .percent(60);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn thresholds<T>(mut self, thresholds: T) -> Self
+    where
+        T: IntoIterator<Item = (f64, Color)>,
+    {
+        self.fill_color = Some(FillColor::thresholds(thresholds));
+        self
+    }
+
+    /// Splits the bar in segments, filled one after the other.
+    ///
+    /// Each [`GaugeSegment`] fills a part of the bar proportional to its ratio, with the
+    /// foreground color of its style, and the segments are stacked in the
+    /// [direction](Gauge::direction) of the gauge. This replaces the [ratio](Gauge::ratio) of the
+    /// gauge, and the ratios of the segments are capped so that their sum is at most `1.0`. The
+    /// segments are drawn with whole cells only.
+    ///
+    /// The label of each segment is centered in its part of the bar if it fits. If no segment has
+    /// a label, the [label](Gauge::label) of the gauge is displayed instead, which is the total
+    /// percentage filled by default, at its [position](Gauge::label_position).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::Color,
+    ///     widgets::{Gauge, GaugeSegment},
+    /// };
+    ///
+    /// Gauge::default().segments([
+    ///     GaugeSegment::new(0.5).style(Color::Red).label("used"),
+    ///     GaugeSegment::new(0.2).style(Color::Yellow).label("cached"),
+    /// ]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn segments<I>(mut self, segments: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<GaugeSegment<'a>>,
+    {
+        self.segments = segments.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Displays the bar in indeterminate mode, for operations with an unknown duration.
+    ///
+    /// Instead of being filled according to the [ratio](Gauge::ratio), the bar shows a segment
+    /// which moves by one cell at each `tick` and bounces between both ends of the bar. The
+    /// segment is a quarter of the bar long. Increment the tick between frames (e.g. from a frame
+    /// counter or the elapsed time) to animate the gauge.
+    ///
+    /// In this mode, the [segments](Gauge::segments) are ignored and only a label set with
+    /// [`Gauge::label`] is displayed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::Gauge;
+    ///
+    /// # let frame_count = 0;
+    /// Gauge::default().label("Loading…").indeterminate(frame_count);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn indeterminate(mut self, tick: u64) -> Self {
+        self.indeterminate = Some(tick);
+        self
+    }
+
+    /// Sets the direction in which the bar is filled.
+    ///
+    /// A [`Horizontal`](Direction::Horizontal) bar is filled from left to right, which is the
+    /// default, and a [`Vertical`](Direction::Vertical) bar is filled from bottom to top, which is
+    /// useful for level meters (e.g. volume, battery or queue depth). The label stays centered in
+    /// both directions, and [gradients](Gauge::gradient) go from the bottom to the top of vertical
+    /// bars.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{layout::Direction, widgets::Gauge};
+    ///
+    /// Gauge::default().direction(Direction::Vertical).percent(75);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
     /// Sets whether to use unicode characters to display the progress bar.
     ///
     /// This enables the use of
@@ -174,45 +402,386 @@ impl Gauge<'_> {
 
         buf.set_style(gauge_area, self.gauge_style);
 
-        // compute label value and its position
-        // label is put at the center of the gauge_area
-        let default_label = Span::raw(format!("{}%", f64::round(self.ratio * 100.0)));
-        let label = self.label.as_ref().unwrap_or(&default_label);
-        let clamped_label_width = gauge_area.width.min(label.width() as u16);
-        let label_col = gauge_area.left() + (gauge_area.width - clamped_label_width) / 2;
-        let label_row = gauge_area.top() + gauge_area.height / 2;
-
-        // the gauge will be filled proportionally to the ratio
-        let filled_width = f64::from(gauge_area.width) * self.ratio;
-        let end = if self.use_unicode {
-            gauge_area.left() + filled_width.floor() as u16
+        if !self.segments.is_empty() && self.indeterminate.is_none() {
+            self.render_segments(gauge_area, buf);
+            return;
+        }
+
+        // the gauge will be filled proportionally to the ratio, from the left or the bottom
+        let length = match self.direction {
+            Direction::Horizontal => gauge_area.width,
+            Direction::Vertical => gauge_area.height,
+        };
+        let filled_length = f64::from(length) * self.ratio;
+        let filled = if self.use_unicode {
+            filled_length.floor() as u16
         } else {
-            gauge_area.left() + filled_width.round() as u16
+            filled_length.round() as u16
+        };
+        // in indeterminate mode, only a moving segment is filled
+        let (start, filled) = match self.indeterminate {
+            Some(tick) => indeterminate_range(tick, length),
+            None => (0, filled),
         };
+
+        // compute label value and its position
+        let label = match (self.indeterminate, &self.label) {
+            (Some(_), None) => None,
+            _ => Some(self.resolved_label(self.ratio)),
+        };
+        let label_area = label
+            .as_ref()
+            .map(|label| self.label_area(gauge_area, label, filled));
         for y in gauge_area.top()..gauge_area.bottom() {
-            // render the filled area (left to end)
-            for x in gauge_area.left()..end {
-                // Use full block for the filled part of the gauge and spaces for the part that is
-                // covered by the label. Note that the background and foreground colors are swapped
-                // for the label part, otherwise the gauge will be inverted
-                if x < label_col || x > label_col + clamped_label_width || y != label_row {
-                    buf[(x, y)]
-                        .set_symbol(symbols::block::FULL)
-                        .set_fg(self.gauge_style.fg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.bg.unwrap_or(Color::Reset));
+            for x in gauge_area.left()..gauge_area.right() {
+                let offset = match self.direction {
+                    Direction::Horizontal => x - gauge_area.left(),
+                    Direction::Vertical => gauge_area.bottom() - 1 - y,
+                };
+                let fg = FillColor::color(self.fill_color.as_ref(), self.ratio, offset, length)
+                    .or(self.gauge_style.fg)
+                    .unwrap_or(Color::Reset);
+                if (start..filled).contains(&offset) {
+                    // Use full block for the filled part of the gauge and spaces for the part that
+                    // is covered by the label. Note that the background and foreground colors are
+                    // swapped for the label part, otherwise the gauge will be inverted
+                    let covered = label_area
+                        .is_some_and(|area| x >= area.x && x <= area.x + area.width && y == area.y);
+                    if covered {
+                        buf[(x, y)]
+                            .set_symbol(" ")
+                            .set_fg(self.gauge_style.bg.unwrap_or(Color::Reset))
+                            .set_bg(fg);
+                    } else {
+                        buf[(x, y)]
+                            .set_symbol(symbols::block::FULL)
+                            .set_fg(fg)
+                            .set_bg(self.gauge_style.bg.unwrap_or(Color::Reset));
+                    }
+                    continue;
+                }
+                // the last cell of the filled part can be partially filled
+                if offset == filled
+                    && self.use_unicode
+                    && self.ratio < 1.0
+                    && self.indeterminate.is_none()
+                {
+                    let symbol = match self.direction {
+                        Direction::Horizontal => get_unicode_block(filled_length % 1.0),
+                        Direction::Vertical => get_vertical_unicode_block(filled_length % 1.0),
+                    };
+                    buf[(x, y)].set_symbol(symbol).set_fg(fg);
+                }
+            }
+        }
+        // render the label
+        if let (Some(label), Some(area)) = (&label, label_area) {
+            buf.set_span(area.x, area.y, label, area.width);
+        }
+    }
+
+    /// Returns the label of the gauge for the given ratio
+    fn resolved_label(&self, ratio: f64) -> Span<'_> {
+        match (&self.label, &self.label_formatter) {
+            (Some(label), _) => label.clone(),
+            (None, Some(formatter)) => formatter.call(ratio),
+            (None, None) => Span::raw(format!("{}%", f64::round(ratio * 100.0))),
+        }
+    }
+
+    /// Returns the area of the label on a single row, according to the label position and the
+    /// number of filled cells
+    fn label_area(&self, gauge_area: Rect, label: &Span, filled: u16) -> Rect {
+        let width = gauge_area.width.min(label.width() as u16);
+        let centered = centered_label_area(gauge_area, label);
+        match (self.label_position, self.direction) {
+            (GaugeLabelPosition::Center, _) => centered,
+            (GaugeLabelPosition::Left, _) => Rect {
+                x: gauge_area.left(),
+                ..centered
+            },
+            (GaugeLabelPosition::Right, _) => Rect {
+                x: gauge_area.right() - width,
+                ..centered
+            },
+            (GaugeLabelPosition::InsideEnd, Direction::Horizontal) => Rect {
+                x: gauge_area.left() + filled.max(width) - width,
+                ..centered
+            },
+            (GaugeLabelPosition::InsideEnd, Direction::Vertical) => Rect {
+                y: gauge_area.bottom() - filled.max(1),
+                ..centered
+            },
+        }
+    }
+}
+
+/// Where the label of a [`Gauge`] is displayed
+///
+/// See [`Gauge::label_position`]
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GaugeLabelPosition {
+    /// The label is displayed at the left of the bar.
+    Left,
+    /// The label is centered in the bar. This is the default.
+    #[default]
+    Center,
+    /// The label is displayed at the right of the bar.
+    Right,
+    /// The label is displayed inside the filled part of the bar, at its end. It is displayed at
+    /// the start of the bar when the filled part is too short. In a vertical gauge, the label is
+    /// centered on the top row of the filled part.
+    InsideEnd,
+}
+
+/// Returns the start and end offsets of the moving segment of an indeterminate gauge of the given
+/// length
+///
+/// The segment moves by one cell per tick and bounces between both ends of the gauge.
+fn indeterminate_range(tick: u64, length: u16) -> (u16, u16) {
+    let segment = (length / 4).max(1);
+    let travel = u64::from(length - segment);
+    let start = if travel == 0 {
+        0
+    } else {
+        let position = tick % (2 * travel);
+        if position <= travel {
+            position
+        } else {
+            2 * travel - position
+        }
+    };
+    (start as u16, start as u16 + segment)
+}
+
+fn get_vertical_unicode_block<'a>(frac: f64) -> &'a str {
+    match (frac * 8.0).round() as u16 {
+        1 => symbols::bar::ONE_EIGHTH,
+        2 => symbols::bar::ONE_QUARTER,
+        3 => symbols::bar::THREE_EIGHTHS,
+        4 => symbols::bar::HALF,
+        5 => symbols::bar::FIVE_EIGHTHS,
+        6 => symbols::bar::THREE_QUARTERS,
+        7 => symbols::bar::SEVEN_EIGHTHS,
+        8 => symbols::bar::FULL,
+        _ => " ",
+    }
+}
+
+/// How the filled part of a gauge is colored
+///
+/// See [`Gauge::gradient`], [`Gauge::value_color`] and [`Gauge::thresholds`]
+#[derive(Debug, Clone, PartialEq)]
+enum FillColor {
+    /// The color is interpolated between the colors of both ends of the gauge
+    Gradient(Color, Color),
+    /// The color is given by a function of the ratio
+    Ratio(Callback<f64, Color>),
+    /// The color is the color of the highest threshold reached by the ratio, sorted by ratio
+    Thresholds(Vec<(f64, Color)>),
+}
+
+impl FillColor {
+    /// Returns the color of the column at the given offset in a gauge of the given width, if the
+    /// gauge has a fill color
+    fn color(fill_color: Option<&Self>, ratio: f64, offset: u16, width: u16) -> Option<Color> {
+        match fill_color? {
+            Self::Gradient(start, end) => {
+                let t = f64::from(offset) / f64::from(width.saturating_sub(1).max(1));
+                Some(start.lerp(*end, t))
+            }
+            Self::Ratio(color) => Some(color.call(ratio)),
+            Self::Thresholds(thresholds) => thresholds
+                .iter()
+                .rev()
+                .find(|(threshold, _)| *threshold <= ratio)
+                .map(|(_, color)| *color),
+        }
+    }
+
+    /// Creates threshold colors from unsorted thresholds
+    fn thresholds<T>(thresholds: T) -> Self
+    where
+        T: IntoIterator<Item = (f64, Color)>,
+    {
+        let mut thresholds: Vec<_> = thresholds.into_iter().collect();
+        thresholds.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        Self::Thresholds(thresholds)
+    }
+}
+
+impl Gauge<'_> {
+    /// Renders the segments one after the other, then their labels
+    fn render_segments(&self, gauge_area: Rect, buf: &mut Buffer) {
+        let length = match self.direction {
+            Direction::Horizontal => gauge_area.width,
+            Direction::Vertical => gauge_area.height,
+        };
+        let bg = self.gauge_style.bg.unwrap_or(Color::Reset);
+
+        // the label of the gauge is only displayed if no segment has a label
+        let total = self
+            .segments
+            .iter()
+            .map(|segment| segment.ratio)
+            .sum::<f64>()
+            .min(1.0);
+        let filled = (f64::from(length) * total).round() as u16;
+        let gauge_label = self
+            .segments
+            .iter()
+            .all(|segment| segment.label.is_none())
+            .then(|| self.resolved_label(total));
+        let gauge_label_area = gauge_label
+            .as_ref()
+            .map(|label| self.label_area(gauge_area, label, filled));
+
+        let mut start = 0;
+        let mut ratio = 0.0;
+        for segment in &self.segments {
+            ratio = f64::min(ratio + segment.ratio, 1.0);
+            let end = (f64::from(length) * ratio).round() as u16;
+            let segment_area = match self.direction {
+                Direction::Horizontal => Rect {
+                    x: gauge_area.left() + start,
+                    width: end - start,
+                    ..gauge_area
+                },
+                Direction::Vertical => Rect {
+                    y: gauge_area.bottom() - end,
+                    height: end - start,
+                    ..gauge_area
+                },
+            };
+            start = end;
+            // segments after the end of the filled part of the track get no cells
+            if segment_area.is_empty() {
+                continue;
+            }
+
+            let label = segment
+                .label
+                .as_ref()
+                .filter(|label| label.width() <= segment_area.width as usize);
+            let label_area = label.map(|label| centered_label_area(segment_area, label));
+            let fg = segment
+                .style
+                .fg
+                .or(self.gauge_style.fg)
+                .unwrap_or(Color::Reset);
+            for position in segment_area.positions() {
+                // swap the colors of the cells covered by a label, like the filled part of a gauge
+                let covered = [label_area, gauge_label_area]
+                    .iter()
+                    .flatten()
+                    .any(|area| area.contains(position));
+                if covered {
+                    buf[position].set_symbol(" ").set_fg(bg).set_bg(fg);
                 } else {
-                    buf[(x, y)]
-                        .set_symbol(" ")
-                        .set_fg(self.gauge_style.bg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.fg.unwrap_or(Color::Reset));
+                    buf[position]
+                        .set_symbol(symbols::block::FULL)
+                        .set_fg(fg)
+                        .set_bg(bg);
                 }
             }
-            if self.use_unicode && self.ratio < 1.0 {
-                buf[(end, y)].set_symbol(get_unicode_block(filled_width % 1.0));
+            if let (Some(label), Some(area)) = (label, label_area) {
+                buf.set_span(area.x, area.y, label, area.width);
             }
         }
-        // render the label
-        buf.set_span(label_col, label_row, label, clamped_label_width);
+        if let (Some(label), Some(area)) = (&gauge_label, gauge_label_area) {
+            buf.set_span(area.x, area.y, label, area.width);
+        }
+    }
+}
+
+/// Returns the area of a label centered in the given area, on a single row
+fn centered_label_area(area: Rect, label: &Span) -> Rect {
+    let width = area.width.min(label.width() as u16);
+    Rect {
+        x: area.left() + (area.width - width) / 2,
+        y: area.top() + area.height / 2,
+        width,
+        height: 1,
+    }
+}
+
+/// A segment of a [`Gauge`] split with [`Gauge::segments`]
+///
+/// A segment fills a part of the gauge proportional to its ratio, with the foreground color of its
+/// style, and can have a label centered in its part of the gauge.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     style::{Style, Stylize},
+///     widgets::GaugeSegment,
+/// };
+///
+/// let used = GaugeSegment::new(0.42).red().label("used");
+/// let cached = GaugeSegment::new(0.13).style(Style::new().yellow());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GaugeSegment<'a> {
+    ratio: f64,
+    style: Style,
+    label: Option<Span<'a>>,
+}
+
+impl<'a> GaugeSegment<'a> {
+    /// Creates a segment filling the given ratio of the gauge.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `ratio` is **not** between 0 and 1 inclusively.
+    pub fn new(ratio: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&ratio),
+            "Ratio should be between 0 and 1 inclusively."
+        );
+        Self {
+            ratio,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the style of the segment.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]). The foreground color is the color of the
+    /// segment.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the label displayed in the center of the segment, if it fits.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label<T>(mut self, label: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+impl From<f64> for GaugeSegment<'_> {
+    fn from(ratio: f64) -> Self {
+        Self::new(ratio)
+    }
+}
+
+impl Styled for GaugeSegment<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
     }
 }
 
@@ -241,7 +810,9 @@ fn get_unicode_block<'a>(frac: f64) -> &'a str {
 /// The associated label is always left-aligned. If not set with [`LineGauge::label`], the label is
 /// the percentage of the bar filled.
 ///
-/// You can also set the symbols used to draw the bar with [`LineGauge::line_set`].
+/// You can also set the symbols used to draw the bar with [`LineGauge::line_set`] and
+/// [`LineGauge::unfilled_symbol`], and display the end of the filled part with a higher precision
+/// with [`LineGauge::use_unicode`].
 ///
 /// To style the gauge line use [`LineGauge::filled_style`] and [`LineGauge::unfilled_style`] which
 /// let you pick a color for foreground (i.e. line) and background of the filled and unfilled part
@@ -275,6 +846,9 @@ pub struct LineGauge<'a> {
     style: Style,
     filled_style: Style,
     unfilled_style: Style,
+    fill_color: Option<FillColor>,
+    use_unicode: bool,
+    unfilled_symbol: Option<&'a str>,
 }
 
 impl<'a> LineGauge<'a> {
@@ -382,6 +956,72 @@ impl<'a> LineGauge<'a> {
         self.unfilled_style = style.into();
         self
     }
+
+    /// Sets the symbol of the unfilled part of the bar.
+    ///
+    /// By default, the unfilled part is drawn with the horizontal symbol of the
+    /// [line set](LineGauge::line_set), like the filled part. A distinct symbol (e.g. `"·"` or
+    /// `" "`) makes the unfilled track visible without relying on colors.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn unfilled_symbol(mut self, symbol: &'a str) -> Self {
+        self.unfilled_symbol = Some(symbol);
+        self
+    }
+
+    /// Sets whether to use unicode characters to display the end of the filled part.
+    ///
+    /// This draws the cell at the boundary between the filled and the unfilled parts with
+    /// [unicode block characters](https://en.wikipedia.org/wiki/Block_Elements), in the filled
+    /// style, which displays the progress with 8 extra fractional parts per cell.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn use_unicode(mut self, unicode: bool) -> Self {
+        self.use_unicode = unicode;
+        self
+    }
+
+    /// Colors the filled part of the line with a gradient from `start` to `end`.
+    ///
+    /// The start of the line has the `start` color and its end has the `end` color, and the
+    /// colors are interpolated in RGB (see [`Color::lerp`]). The gradient replaces the foreground
+    /// color of the [filled style](LineGauge::filled_style).
+    ///
+    /// See [`Gauge::gradient`] for more details.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn gradient(mut self, start: Color, end: Color) -> Self {
+        self.fill_color = Some(FillColor::Gradient(start, end));
+        self
+    }
+
+    /// Colors the filled part of the line according to the ratio of the gauge.
+    ///
+    /// The function is given the ratio and returns the color of the whole filled part, which
+    /// replaces the foreground color of the [filled style](LineGauge::filled_style).
+    ///
+    /// See [`Gauge::value_color`] for more details.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn value_color<F>(mut self, color: F) -> Self
+    where
+        F: Fn(f64) -> Color + Send + Sync + 'static,
+    {
+        self.fill_color = Some(FillColor::Ratio(Callback::new(color)));
+        self
+    }
+
+    /// Colors the filled part of the line according to the thresholds reached by the ratio.
+    ///
+    /// The filled part gets the color of the highest threshold less than or equal to the ratio of
+    /// the gauge, and keeps the foreground color of the [filled style](LineGauge::filled_style)
+    /// below all the thresholds.
+    ///
+    /// See [`Gauge::thresholds`] for more details.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn thresholds<T>(mut self, thresholds: T) -> Self
+    where
+        T: IntoIterator<Item = (f64, Color)>,
+    {
+        self.fill_color = Some(FillColor::thresholds(thresholds));
+        self
+    }
 }
 
 impl Widget for LineGauge<'_> {
@@ -408,16 +1048,32 @@ impl Widget for &LineGauge<'_> {
             return;
         }
 
-        let end = start
-            + (f64::from(gauge_area.right().saturating_sub(start)) * self.ratio).floor() as u16;
+        let width = gauge_area.right() - start;
+        let filled_length = f64::from(width) * self.ratio;
+        let mut end = start + filled_length.floor() as u16;
+        let filled_style = |col: u16| {
+            FillColor::color(self.fill_color.as_ref(), ratio, col - start, width)
+                .map_or(self.filled_style, |color| self.filled_style.fg(color))
+        };
         for col in start..end {
             buf[(col, row)]
                 .set_symbol(self.line_set.horizontal)
-                .set_style(self.filled_style);
+                .set_style(filled_style(col));
+        }
+        // the boundary cell displays the fractional part of the filled length, if any
+        if self.use_unicode && end < gauge_area.right() {
+            let symbol = get_unicode_block(filled_length % 1.0);
+            if symbol != " " {
+                buf[(end, row)]
+                    .set_symbol(symbol)
+                    .set_style(filled_style(end));
+                end += 1;
+            }
         }
+        let unfilled_symbol = self.unfilled_symbol.unwrap_or(self.line_set.horizontal);
         for col in end..gauge_area.right() {
             buf[(col, row)]
-                .set_symbol(self.line_set.horizontal)
+                .set_symbol(unfilled_symbol)
                 .set_style(self.unfilled_style);
         }
     }
@@ -450,9 +1106,11 @@ impl Styled for LineGauge<'_> {
 #[cfg(test)]
 mod tests {
     use ratatui_core::{
+        buffer::Cell,
         style::{Color, Modifier, Style, Stylize},
         symbols,
     };
+    use rstest::rstest;
 
     use super::*;
 
@@ -520,6 +1178,178 @@ mod tests {
         );
     }
 
+    #[test]
+    fn gauge_gradient() {
+        let gauge = Gauge::default()
+            .gradient(Color::Rgb(0, 0, 0), Color::Rgb(200, 0, 0))
+            .label("")
+            .ratio(0.6);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        gauge.render(buffer.area, &mut buffer);
+        // the empty label is at the center of the gauge, where the colors are swapped
+        let mut expected = Buffer::with_lines(["██   "]);
+        expected[(0, 0)].set_fg(Color::Rgb(0, 0, 0));
+        expected[(1, 0)].set_fg(Color::Rgb(50, 0, 0));
+        expected[(2, 0)].set_bg(Color::Rgb(100, 0, 0));
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn gauge_vertical() {
+        let gauge = Gauge::default()
+            .direction(Direction::Vertical)
+            .use_unicode(true)
+            .label("")
+            .ratio(0.6);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 4));
+        gauge.render(buffer.area, &mut buffer);
+        // the empty label is at the center of the gauge, where the colors are swapped
+        assert_eq!(buffer, Buffer::with_lines(["  ", "▃▃", "█ ", "██"]));
+    }
+
+    #[test]
+    fn gauge_segments() {
+        let gauge = Gauge::default().segments([
+            GaugeSegment::new(0.5).red().label("ab"),
+            GaugeSegment::new(0.3).blue(),
+        ]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        gauge.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["█ab█████  "]);
+        expected.set_style(Rect::new(0, 0, 5, 1), Style::new().red());
+        expected.set_style(
+            Rect::new(1, 0, 2, 1),
+            Style::new().fg(Color::Reset).bg(Color::Red),
+        );
+        expected.set_style(Rect::new(5, 0, 3, 1), Style::new().blue());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn gauge_segments_with_gauge_label() {
+        let gauge = Gauge::default()
+            .direction(Direction::Vertical)
+            .segments([GaugeSegment::new(0.25).red(), GaugeSegment::new(0.5).blue()]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 4));
+        gauge.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["   ", "███", "75%", "███"]);
+        expected.set_style(Rect::new(0, 1, 3, 2), Style::new().blue());
+        expected.set_style(
+            Rect::new(0, 2, 3, 1),
+            Style::new().fg(Color::Reset).bg(Color::Blue),
+        );
+        expected.set_style(Rect::new(0, 3, 3, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::sum_to_one(&[0.5, 0.5, 0.0], Rect::new(0, 0, 10, 4))]
+    #[case::sum_above_one(&[1.0, 0.1], Rect::new(0, 0, 10, 4))]
+    #[case::narrow(&[0.9, 0.9], Rect::new(0, 0, 3, 2))]
+    #[case::single_cell(&[0.5, 0.2], Rect::new(0, 0, 1, 1))]
+    fn gauge_segments_without_cells(#[case] ratios: &[f64], #[case] area: Rect) {
+        let gauge = Gauge::default()
+            .label("")
+            .segments(ratios.iter().map(|&ratio| GaugeSegment::new(ratio)));
+        let mut buffer = Buffer::empty(area);
+        gauge.render(area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::filled(area, Cell::new(symbols::block::FULL))
+        );
+    }
+
+    #[rstest]
+    #[case::left(GaugeLabelPosition::Left, "ab ██     ")]
+    #[case::center(GaugeLabelPosition::Center, "████ab    ")]
+    #[case::right(GaugeLabelPosition::Right, "█████   ab")]
+    #[case::inside_end(GaugeLabelPosition::InsideEnd, "███ab     ")]
+    fn gauge_label_position(#[case] position: GaugeLabelPosition, #[case] expected: &str) {
+        let gauge = Gauge::default()
+            .ratio(0.5)
+            .label("ab")
+            .label_position(position);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        gauge.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn gauge_label_formatter() {
+        // the closure captures the total
+        let total = 4.0;
+        let gauge = Gauge::default()
+            .ratio(0.5)
+            .label_formatter(move |ratio| Span::raw(format!("{:.0}/{total}", ratio * total)));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 1));
+        gauge.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["██2/4  "]));
+    }
+
+    #[rstest]
+    #[case::below(0.4, Color::Green)]
+    #[case::first(0.5, Color::Yellow)]
+    #[case::between(0.7, Color::Yellow)]
+    #[case::highest(0.9, Color::Red)]
+    fn gauge_thresholds(#[case] ratio: f64, #[case] expected: Color) {
+        let gauge = Gauge::default()
+            .gauge_style(Color::Green)
+            .thresholds([(0.8, Color::Red), (0.5, Color::Yellow)])
+            .label("")
+            .ratio(ratio);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        gauge.render(buffer.area, &mut buffer);
+        assert_eq!(buffer[(0, 0)].fg, expected);
+    }
+
+    #[rstest]
+    #[case::start(0, "██        ")]
+    #[case::moving(3, "   ██     ")]
+    #[case::end(8, "        ██")]
+    #[case::bouncing(11, "     ██   ")]
+    #[case::next_cycle(16, "██        ")]
+    fn gauge_indeterminate(#[case] tick: u64, #[case] expected: &str) {
+        let gauge = Gauge::default().ratio(0.9).indeterminate(tick);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        gauge.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn line_gauge_value_color() {
+        // the closure captures the threshold
+        let threshold = 0.5;
+        let gauge = LineGauge::default()
+            .value_color(move |ratio| {
+                if ratio > threshold {
+                    Color::Red
+                } else {
+                    Color::Green
+                }
+            })
+            .label("")
+            .ratio(0.75);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        gauge.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([" ────"]);
+        expected.set_style(Rect::new(1, 0, 3, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::line(false, " ──··")]
+    #[case::unicode(true, " ──▍·")]
+    fn line_gauge_unicode(#[case] use_unicode: bool, #[case] expected: &str) {
+        let gauge = LineGauge::default()
+            .use_unicode(use_unicode)
+            .unfilled_symbol("·")
+            .label("")
+            .ratio(0.6);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        gauge.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
     #[test]
     fn line_gauge_default() {
         assert_eq!(
@@ -531,7 +1361,10 @@ mod tests {
                 style: Style::default(),
                 line_set: symbols::line::NORMAL,
                 filled_style: Style::default(),
-                unfilled_style: Style::default()
+                unfilled_style: Style::default(),
+                fill_color: None,
+                use_unicode: false,
+                unfilled_symbol: None,
             }
         );
     }
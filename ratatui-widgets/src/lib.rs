@@ -44,10 +44,14 @@
 //! - [`List`]: displays a list of items and allows selection.
 //! - [`RatatuiLogo`]: displays the Ratatui logo.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
+//! - [`Remote`]: displays a widget whose data is loaded asynchronously, with loading and error
+//!   states.
+//! - [`HRuler`] and [`VRuler`]: display cell coordinates to help debugging layouts.
 //! - [`Scrollbar`]: displays a scrollbar.
 //! - [`Sparkline`]: displays a single dataset as a sparkline.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`VerticalText`]: displays text vertically, one character per row.
 //!
 //! [`BarChart`]: crate::barchart::BarChart
 //! [`Block`]: crate::block::Block
@@ -60,10 +64,14 @@
 //! [`List`]: crate::list::List
 //! [`RatatuiLogo`]: crate::logo::RatatuiLogo
 //! [`Paragraph`]: crate::paragraph::Paragraph
+//! [`Remote`]: crate::remote::Remote
+//! [`HRuler`]: crate::ruler::HRuler
+//! [`VRuler`]: crate::ruler::VRuler
 //! [`Scrollbar`]: crate::scrollbar::Scrollbar
 //! [`Sparkline`]: crate::sparkline::Sparkline
 //! [`Table`]: crate::table::Table
 //! [`Tabs`]: crate::tabs::Tabs
+//! [`VerticalText`]: crate::vertical_text::VerticalText
 //!
 //! All these widgets are re-exported directly under `ratatui::widgets` in the `ratatui` crate.
 #![cfg_attr(feature = "document-features", doc = "\n## Features")]
@@ -80,18 +88,26 @@
 pub mod barchart;
 pub mod block;
 pub mod borders;
+pub mod cached;
 pub mod canvas;
 pub mod chart;
 pub mod clear;
+#[cfg(feature = "gallery")]
+pub mod gallery;
 pub mod gauge;
 pub mod list;
 pub mod logo;
 pub mod paragraph;
+pub mod remote;
+pub mod ruler;
 pub mod scrollbar;
 pub mod sparkline;
 pub mod table;
 pub mod tabs;
+pub mod tilemap;
+pub mod vertical_text;
 
+mod callback;
 mod reflow;
 
 #[cfg(feature = "calendar")]
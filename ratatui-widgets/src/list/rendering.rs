@@ -1,13 +1,13 @@
 use ratatui_core::{
     buffer::Buffer,
     layout::Rect,
+    text::DisplayWidth,
     widgets::{StatefulWidget, Widget},
 };
-use unicode_width::UnicodeWidthStr;
 
 use crate::{
     block::BlockExt,
-    list::{List, ListDirection, ListState},
+    list::{List, ListDirection, ListItem, ListState},
 };
 
 impl Widget for List<'_> {
@@ -63,7 +63,7 @@ impl StatefulWidget for &List<'_> {
 
         // Get our set highlighted symbol (if one was set)
         let highlight_symbol = self.highlight_symbol.unwrap_or("");
-        let blank_symbol = " ".repeat(highlight_symbol.width());
+        let blank_symbol = " ".repeat(highlight_symbol.display_width());
 
         let mut current_height = 0;
         let selection_spacing = self.highlight_spacing.should_add(state.selected.is_some());
@@ -96,7 +96,8 @@ impl StatefulWidget for &List<'_> {
             let is_selected = state.selected.map_or(false, |s| s == i);
 
             let item_area = if selection_spacing {
-                let highlight_symbol_width = self.highlight_symbol.unwrap_or("").width() as u16;
+                let highlight_symbol_width =
+                    self.highlight_symbol.unwrap_or("").display_width() as u16;
                 Rect {
                     x: row_area.x + highlight_symbol_width,
                     width: row_area.width.saturating_sub(highlight_symbol_width),
@@ -142,125 +143,171 @@ impl List<'_> {
         offset: usize,
         max_height: usize,
     ) -> (usize, usize) {
-        let offset = offset.min(self.items.len().saturating_sub(1));
-
-        // Note: visible here implies visible in the given area
-        let mut first_visible_index = offset;
-        let mut last_visible_index = offset;
-
-        // Current height of all items in the list to render, beginning at the offset
-        let mut height_from_offset = 0;
-
-        // Calculate the last visible index and total height of the items
-        // that will fit in the available space
-        for item in self.items.iter().skip(offset) {
-            if height_from_offset + item.height() > max_height {
-                break;
-            }
+        items_bounds(
+            self.items.len(),
+            |index| self.items[index].height(),
+            self.scroll_padding,
+            selected,
+            offset,
+            max_height,
+        )
+    }
 
-            height_from_offset += item.height();
+    /// Returns the height of the items, the height of the viewport and the height of the items
+    /// before the offset, when the list is rendered in the given area
+    pub(crate) fn scroll_metrics(&self, state: &ListState, area: Rect) -> (usize, usize, usize) {
+        let viewport_height = self.block.inner_if_some(area).height as usize;
+        let content_height = self.items.iter().map(ListItem::height).sum();
+        let offset = self
+            .items
+            .iter()
+            .take(state.offset)
+            .map(ListItem::height)
+            .sum();
+        (content_height, viewport_height, offset)
+    }
+}
 
-            last_visible_index += 1;
+/// Given an offset, calculate which items can fit in a given area
+///
+/// The height of each item is queried through `item_height`, which makes it possible to compute
+/// the bounds without materializing the items (see [`ListState::visible_range`]).
+///
+/// `item_count` must not be zero.
+pub(crate) fn items_bounds<F>(
+    item_count: usize,
+    item_height: F,
+    scroll_padding: usize,
+    selected: Option<usize>,
+    offset: usize,
+    max_height: usize,
+) -> (usize, usize)
+where
+    F: Fn(usize) -> usize,
+{
+    let offset = offset.min(item_count.saturating_sub(1));
+
+    // Note: visible here implies visible in the given area
+    let mut first_visible_index = offset;
+    let mut last_visible_index = offset;
+
+    // Current height of all items in the list to render, beginning at the offset
+    let mut height_from_offset = 0;
+
+    // Calculate the last visible index and total height of the items
+    // that will fit in the available space
+    for index in offset..item_count {
+        let height = item_height(index);
+        if height_from_offset + height > max_height {
+            break;
         }
 
-        // Get the selected index and apply scroll_padding to it, but still honor the offset if
-        // nothing is selected. This allows for the list to stay at a position after select()ing
-        // None.
-        let index_to_display = self
-            .apply_scroll_padding_to_selected_index(
-                selected,
-                max_height,
-                first_visible_index,
-                last_visible_index,
-            )
-            .unwrap_or(offset);
-
-        // Recall that last_visible_index is the index of what we
-        // can render up to in the given space after the offset
-        // If we have an item selected that is out of the viewable area (or
-        // the offset is still set), we still need to show this item
-        while index_to_display >= last_visible_index {
-            height_from_offset =
-                height_from_offset.saturating_add(self.items[last_visible_index].height());
+        height_from_offset += height;
 
-            last_visible_index += 1;
+        last_visible_index += 1;
+    }
 
-            // Now we need to hide previous items since we didn't have space
-            // for the selected/offset item
-            while height_from_offset > max_height {
-                height_from_offset =
-                    height_from_offset.saturating_sub(self.items[first_visible_index].height());
+    // Get the selected index and apply scroll_padding to it, but still honor the offset if
+    // nothing is selected. This allows for the list to stay at a position after select()ing
+    // None.
+    let index_to_display = apply_scroll_padding_to_selected_index(
+        item_count,
+        &item_height,
+        scroll_padding,
+        selected,
+        max_height,
+        first_visible_index,
+        last_visible_index,
+    )
+    .unwrap_or(offset);
+
+    // Recall that last_visible_index is the index of what we
+    // can render up to in the given space after the offset
+    // If we have an item selected that is out of the viewable area (or
+    // the offset is still set), we still need to show this item
+    while index_to_display >= last_visible_index {
+        height_from_offset = height_from_offset.saturating_add(item_height(last_visible_index));
+
+        last_visible_index += 1;
+
+        // Now we need to hide previous items since we didn't have space
+        // for the selected/offset item
+        while height_from_offset > max_height {
+            height_from_offset =
+                height_from_offset.saturating_sub(item_height(first_visible_index));
 
-                // Remove this item to view by starting at the next item index
-                first_visible_index += 1;
-            }
+            // Remove this item to view by starting at the next item index
+            first_visible_index += 1;
         }
+    }
 
-        // Here we're doing something similar to what we just did above
-        // If the selected item index is not in the viewable area, let's try to show the item
-        while index_to_display < first_visible_index {
-            first_visible_index -= 1;
+    // Here we're doing something similar to what we just did above
+    // If the selected item index is not in the viewable area, let's try to show the item
+    while index_to_display < first_visible_index {
+        first_visible_index -= 1;
 
-            height_from_offset =
-                height_from_offset.saturating_add(self.items[first_visible_index].height());
+        height_from_offset = height_from_offset.saturating_add(item_height(first_visible_index));
 
-            // Don't show an item if it is beyond our viewable height
-            while height_from_offset > max_height {
-                last_visible_index -= 1;
+        // Don't show an item if it is beyond our viewable height
+        while height_from_offset > max_height {
+            last_visible_index -= 1;
 
-                height_from_offset =
-                    height_from_offset.saturating_sub(self.items[last_visible_index].height());
-            }
+            height_from_offset = height_from_offset.saturating_sub(item_height(last_visible_index));
         }
-
-        (first_visible_index, last_visible_index)
     }
 
-    /// Applies scroll padding to the selected index, reducing the padding value to keep the
-    /// selected item on screen even with items of inconsistent sizes
-    ///
-    /// This function is sensitive to how the bounds checking function handles item height
-    fn apply_scroll_padding_to_selected_index(
-        &self,
-        selected: Option<usize>,
-        max_height: usize,
-        first_visible_index: usize,
-        last_visible_index: usize,
-    ) -> Option<usize> {
-        let last_valid_index = self.items.len().saturating_sub(1);
-        let selected = selected?.min(last_valid_index);
-
-        // The bellow loop handles situations where the list item sizes may not be consistent,
-        // where the offset would have excluded some items that we want to include, or could
-        // cause the offset value to be set to an inconsistent value each time we render.
-        // The padding value will be reduced in case any of these issues would occur
-        let mut scroll_padding = self.scroll_padding;
-        while scroll_padding > 0 {
-            let mut height_around_selected = 0;
-            for index in selected.saturating_sub(scroll_padding)
-                ..=selected
-                    .saturating_add(scroll_padding)
-                    .min(last_valid_index)
-            {
-                height_around_selected += self.items[index].height();
-            }
-            if height_around_selected <= max_height {
-                break;
-            }
-            scroll_padding -= 1;
-        }
+    (first_visible_index, last_visible_index)
+}
 
-        Some(
-            if (selected + scroll_padding).min(last_valid_index) >= last_visible_index {
-                selected + scroll_padding
-            } else if selected.saturating_sub(scroll_padding) < first_visible_index {
-                selected.saturating_sub(scroll_padding)
-            } else {
-                selected
-            }
-            .min(last_valid_index),
-        )
+/// Applies scroll padding to the selected index, reducing the padding value to keep the
+/// selected item on screen even with items of inconsistent sizes
+///
+/// This function is sensitive to how the bounds checking function handles item height
+fn apply_scroll_padding_to_selected_index<F>(
+    item_count: usize,
+    item_height: F,
+    scroll_padding: usize,
+    selected: Option<usize>,
+    max_height: usize,
+    first_visible_index: usize,
+    last_visible_index: usize,
+) -> Option<usize>
+where
+    F: Fn(usize) -> usize,
+{
+    let last_valid_index = item_count.saturating_sub(1);
+    let selected = selected?.min(last_valid_index);
+
+    // The bellow loop handles situations where the list item sizes may not be consistent,
+    // where the offset would have excluded some items that we want to include, or could
+    // cause the offset value to be set to an inconsistent value each time we render.
+    // The padding value will be reduced in case any of these issues would occur
+    let mut scroll_padding = scroll_padding;
+    while scroll_padding > 0 {
+        let mut height_around_selected = 0;
+        for index in selected.saturating_sub(scroll_padding)
+            ..=selected
+                .saturating_add(scroll_padding)
+                .min(last_valid_index)
+        {
+            height_around_selected += item_height(index);
+        }
+        if height_around_selected <= max_height {
+            break;
+        }
+        scroll_padding -= 1;
     }
+
+    Some(
+        if (selected + scroll_padding).min(last_valid_index) >= last_visible_index {
+            selected + scroll_padding
+        } else if selected.saturating_sub(scroll_padding) < first_visible_index {
+            selected.saturating_sub(scroll_padding)
+        } else {
+            selected
+        }
+        .min(last_valid_index),
+    )
 }
 
 #[cfg(test)]
@@ -1,3 +1,7 @@
+use std::ops::Range;
+
+use super::rendering::items_bounds;
+
 /// State of the [`List`] widget
 ///
 /// This state can be used to scroll through items and select one. When the list is rendered as a
@@ -270,13 +274,74 @@ impl ListState {
         let selected = self.selected.unwrap_or_default();
         self.select(Some(selected.saturating_sub(amount as usize)));
     }
+
+    /// Computes the range of items that are visible in a viewport of the given height
+    ///
+    /// This applies the same scrolling rules as rendering a [`List`] does, but the height of each
+    /// item is provided lazily by `item_height` instead of by materialized [`ListItem`]s. This
+    /// makes it possible to build large virtualized lists with variable height items, where only
+    /// the items in the returned range need to be constructed.
+    ///
+    /// Like rendering, this updates the offset of the state to the first visible item and clamps
+    /// the selected index to the number of items.
+    ///
+    /// The items in the returned range can then be rendered as a [`List`] with the selection
+    /// shifted by the start of the range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListState};
+    ///
+    /// let heights = vec![1, 3, 2, 1, 4, 1];
+    /// let mut state = ListState::default().with_selected(Some(4));
+    /// let range = state.visible_range(heights.len(), 5, 0, |index| heights[index]);
+    /// assert_eq!(range, 3..5);
+    ///
+    /// let items = range.clone().map(|index| format!("Item {index}"));
+    /// let visible_state =
+    ///     ListState::default().with_selected(state.selected().map(|i| i - range.start));
+    /// let list = List::new(items);
+    /// ```
+    ///
+    /// [`List`]: super::List
+    /// [`ListItem`]: super::ListItem
+    pub fn visible_range<F>(
+        &mut self,
+        item_count: usize,
+        viewport_height: usize,
+        scroll_padding: usize,
+        item_height: F,
+    ) -> Range<usize>
+    where
+        F: Fn(usize) -> usize,
+    {
+        if item_count == 0 {
+            self.select(None);
+            return 0..0;
+        }
+        if self.selected.is_some_and(|s| s >= item_count) {
+            self.select(Some(item_count.saturating_sub(1)));
+        }
+        let (first, last) = items_bounds(
+            item_count,
+            item_height,
+            scroll_padding,
+            self.selected,
+            self.offset,
+            viewport_height,
+        );
+        self.offset = first;
+        first..last
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
+    use ratatui_core::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
 
-    use crate::list::ListState;
+    use crate::list::{List, ListItem, ListState};
 
     #[test]
     fn selected() {
@@ -356,4 +421,40 @@ mod tests {
         state.scroll_up_by(4);
         assert_eq!(state.selected, Some(0));
     }
+
+    #[test]
+    fn visible_range_empty() {
+        let mut state = ListState::default().with_selected(Some(1));
+        assert_eq!(state.visible_range(0, 10, 0, |_| 1), 0..0);
+        assert_eq!(state.selected, None);
+    }
+
+    #[test]
+    fn visible_range_clamps_selection() {
+        let mut state = ListState::default().with_selected(Some(10));
+        assert_eq!(state.visible_range(5, 2, 0, |_| 1), 3..5);
+        assert_eq!(state.selected, Some(4));
+        assert_eq!(state.offset, 3);
+    }
+
+    #[test]
+    fn visible_range_matches_rendering() {
+        let heights = [1, 3, 2, 1, 4, 1, 2];
+        let items: Vec<ListItem> = heights
+            .iter()
+            .map(|&height| ListItem::new(vec!["x"; height].join("\n")))
+            .collect();
+        let list = List::new(items).scroll_padding(1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 5));
+        for selected in 0..heights.len() {
+            let mut state = ListState::default().with_selected(Some(selected));
+            let mut lazy_state = state.clone();
+            StatefulWidget::render(&list, buf.area, &mut buf, &mut state);
+            let range = lazy_state.visible_range(heights.len(), 5, 1, |index| heights[index]);
+            assert_eq!(lazy_state, state);
+            assert_eq!(range.start, state.offset);
+            assert!(range.contains(&selected));
+            assert!(range.map(|index| heights[index]).sum::<usize>() <= 5);
+        }
+    }
 }
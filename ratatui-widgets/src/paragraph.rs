@@ -1,19 +1,35 @@
 //! The [`Paragraph`] widget and related types allows displaying a block of text with optional
 //! wrapping, alignment, and block styling.
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    mem,
+};
+
 use ratatui_core::{
     buffer::Buffer,
-    layout::{Alignment, Position, Rect},
+    layout::{Alignment, Position, Rect, VerticalAlignment},
     style::{Style, Styled},
-    text::{Line, StyledGrapheme, Text},
-    widgets::Widget,
+    text::{DisplayWidth, Line, StyledGrapheme, Text},
+    widgets::{StatefulWidget, Widget},
 };
-use unicode_width::UnicodeWidthStr;
 
+pub use self::{
+    measure::{measure_text, TextMetrics},
+    scroll::ParagraphScrollState,
+    state::ParagraphState,
+};
+use self::{state::RenderedLine, wrap_cache::WrapCache};
 use crate::{
     block::{Block, BlockExt},
     reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine},
 };
 
+mod measure;
+mod scroll;
+mod state;
+mod wrap_cache;
+
 const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Alignment) -> u16 {
     match alignment {
         Alignment::Center => (text_area_width / 2).saturating_sub(line_width / 2),
@@ -32,14 +48,23 @@ const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Align
 ///
 /// The text can be wrapped to the next line if it is too long to fit in the given area. The
 /// wrapping can be configured with the [`wrap`] method. For more complex wrapping, consider using
-/// the [Textwrap crate].
+/// the [Textwrap crate]. When the text is not wrapped, the lines that are too long are clipped, and
+/// the [`ellipsis`] method can be used to indicate the truncation with a symbol.
 ///
 /// The text can be aligned to the left, right, or center. The alignment can be configured with the
 /// [`alignment`] method or with the [`left_aligned`], [`right_aligned`], and [`centered`] methods.
 ///
+/// Text that is shorter than the area can be aligned to the top, center or bottom of the area with
+/// the [`vertical_alignment`] method.
+///
 /// The text can be scrolled to show a specific part of the text. The scroll offset can be set with
 /// the [`scroll`] method.
 ///
+/// The text can be selected (e.g. with the mouse) by rendering a reference to the paragraph as a
+/// [`StatefulWidget`] with a [`ParagraphState`]. The selected text is displayed with the
+/// [`selection_style`] and can be extracted from the state with
+/// [`ParagraphState::selected_text`].
+///
 /// The text can be surrounded by a [`Block`] with a title and borders. The block can be configured
 /// with the [`block`] method.
 ///
@@ -52,11 +77,14 @@ const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Align
 ///
 /// [Textwrap crate]: https://crates.io/crates/textwrap
 /// [`wrap`]: Self::wrap
+/// [`ellipsis`]: Self::ellipsis
 /// [`alignment`]: Self::alignment
 /// [`left_aligned`]: Self::left_aligned
 /// [`right_aligned`]: Self::right_aligned
 /// [`centered`]: Self::centered
+/// [`vertical_alignment`]: Self::vertical_alignment
 /// [`scroll`]: Self::scroll
+/// [`selection_style`]: Self::selection_style
 /// [`block`]: Self::block
 /// [`style`]: Self::style
 ///
@@ -95,12 +123,26 @@ pub struct Paragraph<'a> {
     style: Style,
     /// How to wrap the text
     wrap: Option<Wrap>,
+    /// Whether to break words at soft hyphens when wrapping
+    hyphenate: bool,
+    /// Whether to wrap lines with the Unicode line breaking algorithm
+    #[cfg(feature = "unicode-linebreak")]
+    unicode_line_breaks: bool,
+    /// The symbol appended to truncated lines when the text is not wrapped
+    ellipsis: Option<&'a str>,
     /// The text to display
     text: Text<'a>,
+    /// A number that changes whenever the text changes, used instead of the text to key the wrap
+    /// cache
+    text_generation: Option<u64>,
     /// Scroll
     scroll: Position,
     /// Alignment of the text
     alignment: Alignment,
+    /// Vertical alignment of the text
+    vertical_alignment: VerticalAlignment,
+    /// Style of the selected text
+    selection_style: Style,
 }
 
 /// Describes how to wrap text across lines.
@@ -173,9 +215,16 @@ impl<'a> Paragraph<'a> {
             block: None,
             style: Style::default(),
             wrap: None,
+            hyphenate: false,
+            #[cfg(feature = "unicode-linebreak")]
+            unicode_line_breaks: false,
+            ellipsis: None,
             text: text.into(),
+            text_generation: None,
             scroll: Position::ORIGIN,
             alignment: Alignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+            selection_style: Style::default(),
         }
     }
 
@@ -237,11 +286,123 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Enables breaking words at soft hyphens when wrapping the text
+    ///
+    /// When enabled, soft hyphens (U+00AD) in the text are invisible hyphenation points: a word
+    /// that doesn't fit on the current line is broken at its last soft hyphen that fits, and a
+    /// visible hyphen is displayed at the end of the line. This avoids ragged lines when long
+    /// words are wrapped in narrow areas. Soft hyphens where the word is not broken are not
+    /// displayed.
+    ///
+    /// This only has an effect when the text is wrapped (see [`Paragraph::wrap`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, Wrap};
+    ///
+    /// let paragraph = Paragraph::new("Ex\u{ad}tra\u{ad}or\u{ad}di\u{ad}na\u{ad}ry")
+    ///     .wrap(Wrap { trim: true })
+    ///     .hyphenate(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn hyphenate(mut self, hyphenate: bool) -> Self {
+        self.hyphenate = hyphenate;
+        self
+    }
+
+    /// Wraps the text with the Unicode line breaking algorithm ([UAX #14])
+    ///
+    /// By default, the text is only wrapped at whitespace, so text without spaces such as Chinese
+    /// or Japanese is broken at arbitrary characters when it doesn't fit, and punctuation can end
+    /// up at the start of a line. When enabled, lines are broken at the opportunities defined by
+    /// the algorithm instead: between CJK characters, after hyphens and slashes, but never before
+    /// closing punctuation (e.g. `，` or `)`).
+    ///
+    /// This only has an effect when the text is wrapped (see [`Paragraph::wrap`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, Wrap};
+    ///
+    /// let paragraph = Paragraph::new("我能吞下玻璃而不伤身体。")
+    ///     .wrap(Wrap { trim: true })
+    ///     .unicode_line_breaks(true);
+    /// ```
+    ///
+    /// [UAX #14]: https://www.unicode.org/reports/tr14/
+    #[cfg(feature = "unicode-linebreak")]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn unicode_line_breaks(mut self, unicode_line_breaks: bool) -> Self {
+        self.unicode_line_breaks = unicode_line_breaks;
+        self
+    }
+
+    /// Sets a generation number of the text that is used to reuse the wrapped lines between renders
+    ///
+    /// When the paragraph is wrapped and rendered with a [`ParagraphState`], the wrapped lines are
+    /// cached in the state and only wrapped again when the text, the wrapping options or the width
+    /// change. By default, the text is hashed on every render to detect changes, which is much
+    /// cheaper than wrapping it but still proportional to its length. With a generation number,
+    /// the text is not hashed and the cached lines are reused as long as the number is the same, so
+    /// the application must change it (e.g. by incrementing it) whenever it changes the text.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, Wrap};
+    ///
+    /// let log = String::from("a long log that is only changed when new entries are appended");
+    /// let log_generation = 1;
+    /// let paragraph = Paragraph::new(log.as_str())
+    ///     .wrap(Wrap { trim: true })
+    ///     .text_generation(log_generation);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn text_generation(mut self, generation: u64) -> Self {
+        self.text_generation = Some(generation);
+        self
+    }
+
+    /// Sets the symbol displayed at the end of the lines that are too long to fit in the area
+    ///
+    /// By default, lines that don't fit in the area are clipped without any visual indicator. With
+    /// an ellipsis (e.g. `"…"` or `"..."`), the end of each truncated line is replaced by the
+    /// symbol, so that it is visible that some content is hidden. The ellipsis has the style of
+    /// the first grapheme that was cut from the line.
+    ///
+    /// This only has an effect when the text is not wrapped (see [`Paragraph::wrap`]). The
+    /// ellipsis is not displayed if it is wider than the area.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// // renders "A very long l…" in an area that is 14 columns wide
+    /// let paragraph = Paragraph::new("A very long line of text").ellipsis("…");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn ellipsis(mut self, ellipsis: &'a str) -> Self {
+        self.ellipsis = Some(ellipsis);
+        self
+    }
+
     /// Set the scroll offset for the given paragraph
     ///
     /// The scroll offset is a tuple of (y, x) offset. The y offset is the number of lines to
-    /// scroll, and the x offset is the number of characters to scroll. The scroll offset is applied
-    /// after the text is wrapped and aligned.
+    /// scroll, and the x offset is the number of display columns to scroll. The scroll offset is
+    /// applied after the text is wrapped and aligned.
+    ///
+    /// The x offset is only applied to left aligned lines when the text is not wrapped. Wide
+    /// characters (e.g. CJK characters or emojis) take two columns, and a wide character that is
+    /// cut by the left edge of the area is replaced with a space. Use [`Paragraph::max_scroll`] to
+    /// clamp the offset to the size of the text.
     ///
     /// Note: the order of the tuple is (y, x) instead of (x, y), which is different from general
     /// convention across the crate.
@@ -257,6 +418,33 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Set the style of the selected text
+    ///
+    /// The selection is only displayed when the paragraph is rendered as a [`StatefulWidget`]
+    /// with a [`ParagraphState`] that has a selection. The style is patched onto the style of the
+    /// selected graphemes.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::Paragraph,
+    /// };
+    ///
+    /// let paragraph = Paragraph::new("Hello, world!").selection_style(Style::new().reversed());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selection_style = style.into();
+        self
+    }
+
     /// Set the text alignment for the given paragraph
     ///
     /// The alignment is a variant of the [`Alignment`] enum which can be one of Left, Right, or
@@ -323,6 +511,27 @@ impl<'a> Paragraph<'a> {
         self.alignment(Alignment::Right)
     }
 
+    /// Set the vertical alignment of the text within the area
+    ///
+    /// When the (wrapped) text has fewer lines than the height of the area, the text is placed at
+    /// the top (the default), in the center or at the bottom of the area. This makes it possible
+    /// to center a short message in a tall area without measuring the text and building a nested
+    /// layout. Text that fills the area is not affected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{layout::VerticalAlignment, widgets::Paragraph};
+    ///
+    /// let paragraph =
+    ///     Paragraph::new("Nothing to see here").vertical_alignment(VerticalAlignment::Center);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn vertical_alignment(mut self, vertical_alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = vertical_alignment;
+        self
+    }
+
     /// Calculates the number of lines needed to fully render.
     ///
     /// Given a max line width, this method calculates the number of lines that a paragraph will
@@ -358,30 +567,24 @@ impl<'a> Paragraph<'a> {
             .map(Block::vertical_space)
             .unwrap_or_default();
 
-        let count = if let Some(Wrap { trim }) = self.wrap {
-            let styled = self.text.iter().map(|line| {
-                let graphemes = line
-                    .spans
-                    .iter()
-                    .flat_map(|span| span.styled_graphemes(self.style));
-                let alignment = line.alignment.unwrap_or(self.alignment);
-                (graphemes, alignment)
-            });
-            let mut line_composer = WordWrapper::new(styled, width, trim);
-            let mut count = 0;
-            while line_composer.next_line().is_some() {
-                count += 1;
-            }
-            count
-        } else {
-            self.text.height()
-        };
+        let count = self.text_line_count(width);
 
         count
             .saturating_add(top as usize)
             .saturating_add(bottom as usize)
     }
 
+    /// Calculates the number of lines of the text when rendered with the given width, without
+    /// accounting for the block.
+    fn text_line_count(&self, width: u16) -> usize {
+        match self.wrap {
+            Some(Wrap { trim }) => {
+                measure::measure_lines(self.word_wrapper(width, trim)).line_count
+            }
+            None => measure_text(&self.text, width, None).line_count,
+        }
+    }
+
     /// Calculates the shortest line width needed to avoid any word being wrapped or truncated.
     ///
     /// Accounts for the [`Block`] if a block is set through [`Self::block`].
@@ -415,6 +618,48 @@ impl<'a> Paragraph<'a> {
             .saturating_add(left as usize)
             .saturating_add(right as usize)
     }
+
+    /// Returns the largest scroll offset that still shows some text when rendered in the given
+    /// area, as a tuple of (y, x) offset like [`Paragraph::scroll`].
+    ///
+    /// The y offset is the number of lines that don't fit in the area, and the x offset is the
+    /// number of display columns of the widest left aligned line that don't fit in the area. The
+    /// x offset is `0` when the text is wrapped, as wrapped text is not scrolled horizontally.
+    ///
+    /// Accounts for the [`Block`] if a block is set through [`Self::block`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{layout::Rect, widgets::Paragraph};
+    ///
+    /// let paragraph = Paragraph::new("Hello World\nhi\nコンピュータ");
+    /// assert_eq!(paragraph.max_scroll(Rect::new(0, 0, 5, 2)), (1, 7));
+    /// ```
+    pub fn max_scroll(&self, area: Rect) -> (Vertical, Horizontal) {
+        let area = self.block.inner_if_some(area);
+        if area.is_empty() {
+            return (0, 0);
+        }
+        let vertical = self
+            .text_line_count(area.width)
+            .saturating_sub(area.height as usize);
+        let horizontal = if self.wrap.is_some() {
+            0
+        } else {
+            self.text
+                .iter()
+                .filter(|line| line.alignment.unwrap_or(self.alignment) == Alignment::Left)
+                .map(Line::width)
+                .max()
+                .unwrap_or_default()
+                .saturating_sub(area.width as usize)
+        };
+        (
+            u16::try_from(vertical).unwrap_or(u16::MAX),
+            u16::try_from(horizontal).unwrap_or(u16::MAX),
+        )
+    }
 }
 
 impl Widget for Paragraph<'_> {
@@ -428,36 +673,182 @@ impl Widget for &Paragraph<'_> {
         buf.set_style(area, self.style);
         self.block.as_ref().render(area, buf);
         let inner = self.block.inner_if_some(area);
-        self.render_paragraph(inner, buf);
+        self.render_paragraph(inner, buf, None);
+    }
+}
+
+// `StatefulWidget` is only implemented for `&Paragraph` so that calling `render` on an owned
+// paragraph stays unambiguous when both widget traits are in scope.
+impl StatefulWidget for &Paragraph<'_> {
+    type State = ParagraphState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        self.render_paragraph(inner, buf, Some(state));
     }
 }
 
 impl Paragraph<'_> {
-    fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer) {
+    fn render_paragraph(
+        &self,
+        text_area: Rect,
+        buf: &mut Buffer,
+        mut state: Option<&mut ParagraphState>,
+    ) {
+        let mut scroll = self.scroll.y;
+        if let Some(state) = state.as_deref_mut() {
+            state.content_height = 0;
+            if !text_area.is_empty() {
+                self.update_wrap_cache(text_area.width, &mut state.wrap_cache);
+                state.content_height =
+                    self.cached_line_count(text_area.width, Some(&state.wrap_cache));
+                let max_offset = state
+                    .content_height
+                    .saturating_sub(text_area.height as usize)
+                    .saturating_sub(self.scroll.y as usize);
+                state
+                    .scroll_state
+                    .update(u16::try_from(max_offset).unwrap_or(u16::MAX));
+            }
+            scroll = scroll.saturating_add(state.scroll_state.offset());
+            state.area = text_area;
+            state.viewport = text_area;
+            state.scroll = scroll;
+            state.lines.clear();
+        }
         if text_area.is_empty() {
             return;
         }
 
         buf.set_style(text_area, self.style);
+        let cache = state.as_deref().map(|state| &state.wrap_cache);
+        let text_area = self.vertically_aligned_area(text_area, scroll, cache);
+        if let Some(state) = state.as_deref_mut() {
+            state.area = text_area;
+        }
+        if let Some(state) = state {
+            // the cache is moved out of the state while it is borrowed by the composer
+            let cache = mem::take(&mut state.wrap_cache);
+            self.render_text(cache.composer(), text_area, scroll, buf, Some(&mut *state));
+            state.wrap_cache = cache;
+            return;
+        }
+        if let Some(Wrap { trim }) = self.wrap {
+            let line_composer = self.word_wrapper(text_area.width, trim);
+            self.render_text(line_composer, text_area, scroll, buf, None);
+        } else {
+            let line_composer = self.line_truncator(text_area.width);
+            self.render_text(line_composer, text_area, scroll, buf, None);
+        }
+    }
+
+    /// Wraps the text into the cache if the text, the wrapping options or the width changed.
+    ///
+    /// When the text is not wrapped, the cache holds the truncated lines instead, so that the
+    /// selected text can be extracted from the whole text and not only from the visible rows.
+    fn update_wrap_cache(&self, width: u16, cache: &mut WrapCache) {
+        let mut hasher = DefaultHasher::new();
+        match self.text_generation {
+            Some(generation) => generation.hash(&mut hasher),
+            None => self.text.hash(&mut hasher),
+        }
+        self.alignment.hash(&mut hasher);
+        self.wrap.hash(&mut hasher);
+        if let Some(Wrap { trim }) = self.wrap {
+            self.hyphenate.hash(&mut hasher);
+            #[cfg(feature = "unicode-linebreak")]
+            self.unicode_line_breaks.hash(&mut hasher);
+            cache.update(hasher.finish(), width, || self.word_wrapper(width, trim));
+        } else {
+            self.scroll.x.hash(&mut hasher);
+            self.ellipsis.hash(&mut hasher);
+            cache.update(hasher.finish(), width, || self.line_truncator(width));
+        }
+    }
+
+    /// Returns a composer that wraps the text to the given width with the wrapping options of the
+    /// paragraph.
+    fn word_wrapper(&self, width: u16, trim: bool) -> impl LineComposer<'_> + '_ {
         let styled = self.text.iter().map(|line| {
             let graphemes = line.styled_graphemes(self.text.style);
             let alignment = line.alignment.unwrap_or(self.alignment);
             (graphemes, alignment)
         });
+        let mut line_composer = WordWrapper::new(styled, width, trim);
+        line_composer.set_hyphenate(self.hyphenate);
+        #[cfg(feature = "unicode-linebreak")]
+        line_composer.set_unicode_line_breaks(self.unicode_line_breaks);
+        line_composer
+    }
 
-        if let Some(Wrap { trim }) = self.wrap {
-            let line_composer = WordWrapper::new(styled, text_area.width, trim);
-            self.render_text(line_composer, text_area, buf);
-        } else {
-            let mut line_composer = LineTruncator::new(styled, text_area.width);
-            line_composer.set_horizontal_offset(self.scroll.x);
-            self.render_text(line_composer, text_area, buf);
+    /// Returns a composer that truncates the lines of the text to the given width, after the
+    /// horizontal scroll offset of the paragraph.
+    fn line_truncator(&self, width: u16) -> impl LineComposer<'_> + '_ {
+        let styled = self.text.iter().map(|line| {
+            let graphemes = line.styled_graphemes(self.text.style);
+            let alignment = line.alignment.unwrap_or(self.alignment);
+            (graphemes, alignment)
+        });
+        let mut line_composer = LineTruncator::new(styled, width);
+        line_composer.set_horizontal_offset(self.scroll.x);
+        line_composer.set_ellipsis(self.ellipsis);
+        line_composer
+    }
+
+    /// Returns the number of lines of the text for the given width, using the wrap cache if the
+    /// text is wrapped.
+    ///
+    /// The cache must have been updated for the width with [`Self::update_wrap_cache`].
+    fn cached_line_count(&self, width: u16, cache: Option<&WrapCache>) -> usize {
+        match cache {
+            Some(cache) if self.wrap.is_some() => cache.line_count(),
+            _ => self.text_line_count(width),
+        }
+    }
+
+    /// Returns the part of the text area where the lines are rendered according to the vertical
+    /// alignment.
+    fn vertically_aligned_area(
+        &self,
+        text_area: Rect,
+        scroll: u16,
+        cache: Option<&WrapCache>,
+    ) -> Rect {
+        if self.vertical_alignment == VerticalAlignment::Top {
+            return text_area;
+        }
+        let visible_lines = self
+            .cached_line_count(text_area.width, cache)
+            .saturating_sub(scroll as usize);
+        let free_space = (text_area.height as usize).saturating_sub(visible_lines) as u16;
+        let offset = match self.vertical_alignment {
+            VerticalAlignment::Top => 0,
+            VerticalAlignment::Center => free_space / 2,
+            VerticalAlignment::Bottom => free_space,
+        };
+        Rect {
+            y: text_area.y + offset,
+            height: text_area.height - offset,
+            ..text_area
         }
     }
 }
 
-impl<'a> Paragraph<'a> {
-    fn render_text<C: LineComposer<'a>>(&self, mut composer: C, area: Rect, buf: &mut Buffer) {
+impl Paragraph<'_> {
+    /// Renders the lines of the composer that are visible in the area.
+    ///
+    /// When a state is given, the visible lines are stored in the state, and the selection of the
+    /// state is highlighted.
+    fn render_text<'t, C: LineComposer<'t>>(
+        &self,
+        mut composer: C,
+        area: Rect,
+        scroll: u16,
+        buf: &mut Buffer,
+        mut state: Option<&mut ParagraphState>,
+    ) {
         let mut y = 0;
         while let Some(WrappedLine {
             line: current_line,
@@ -465,24 +856,48 @@ impl<'a> Paragraph<'a> {
             alignment: current_line_alignment,
         }) = composer.next_line()
         {
-            if y >= self.scroll.y {
-                let mut x = get_line_offset(current_line_width, area.width, current_line_alignment);
-                for StyledGrapheme { symbol, style } in current_line {
-                    let width = symbol.width();
-                    if width == 0 {
-                        continue;
-                    }
-                    // If the symbol is empty, the last char which rendered last time will
-                    // leave on the line. It's a quick fix.
-                    let symbol = if symbol.is_empty() { " " } else { symbol };
-                    buf[(area.left() + x, area.top() + y - self.scroll.y)]
+            let is_visible = y >= scroll && y < area.height.saturating_add(scroll);
+            let offset = get_line_offset(current_line_width, area.width, current_line_alignment);
+            let rendered_line = RenderedLine { offset };
+            #[cfg(feature = "unicode-bidi")]
+            let visual_line = StyledGrapheme::visual_order(current_line);
+            #[cfg(feature = "unicode-bidi")]
+            let current_line = visual_line.as_ref();
+            let mut x = 0;
+            for StyledGrapheme {
+                symbol,
+                style,
+                hyperlink,
+            } in current_line
+            {
+                let width = symbol.display_width();
+                if width == 0 {
+                    continue;
+                }
+                // If the symbol is empty, the last char which rendered last time will
+                // leave on the line. It's a quick fix.
+                let symbol = if symbol.is_empty() { " " } else { symbol };
+                if is_visible {
+                    let is_selected = state
+                        .as_deref()
+                        .is_some_and(|state| state.is_selected(Position::new(x, y)));
+                    let style = if is_selected {
+                        style.patch(self.selection_style)
+                    } else {
+                        *style
+                    };
+                    buf[(area.left() + offset + x, area.top() + y - scroll)]
                         .set_symbol(symbol)
-                        .set_style(*style);
-                    x += width as u16;
+                        .set_style(style)
+                        .set_hyperlink(*hyperlink);
                 }
+                x += width as u16;
+            }
+            if let Some(state) = state.as_deref_mut().filter(|_| is_visible) {
+                state.lines.push(rendered_line);
             }
             y += 1;
-            if y >= area.height + self.scroll.y {
+            if y >= area.height.saturating_add(scroll) {
                 break;
             }
         }
@@ -510,9 +925,10 @@ mod tests {
         text::{Line, Span, Text},
         widgets::Widget,
     };
+    use rstest::rstest;
 
     use super::*;
-    use crate::{block::Position, borders::Borders};
+    use crate::{block::Position as TitlePosition, borders::Borders};
 
     /// Tests the [`Paragraph`] widget against the expected [`Buffer`] by rendering it onto an equal
     /// area and comparing the rendered and expected content.
@@ -521,7 +937,7 @@ mod tests {
     #[track_caller]
     fn test_case(paragraph: &Paragraph, expected: &Buffer) {
         let mut buffer = Buffer::empty(Rect::new(0, 0, expected.area.width, expected.area.height));
-        paragraph.render(buffer.area, &mut buffer);
+        Widget::render(paragraph, buffer.area, &mut buffer);
         assert_eq!(buffer, *expected);
     }
 
@@ -709,7 +1125,7 @@ mod tests {
     fn test_render_paragraph_with_block_with_bottom_title_and_border() {
         let block = Block::new()
             .borders(Borders::BOTTOM)
-            .title_position(Position::Bottom)
+            .title_position(TitlePosition::Bottom)
             .title("Title");
         let paragraph = Paragraph::new("Hello, world!").block(block);
         test_case(
@@ -800,6 +1216,36 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::truncated(None, false, Rect::new(0, 0, 5, 1))]
+    #[case::wrapped(Some(Wrap { trim: true }), false, Rect::new(0, 0, 3, 2))]
+    #[case::cached(Some(Wrap { trim: true }), true, Rect::new(0, 0, 3, 2))]
+    fn render_hyperlinks(#[case] wrap: Option<Wrap>, #[case] stateful: bool, #[case] area: Rect) {
+        let line = Line::from(vec![
+            "ab ".into(),
+            Span::raw("cd").hyperlink("https://ratatui.rs"),
+        ]);
+        let mut paragraph = Paragraph::new(line);
+        if let Some(wrap) = wrap {
+            paragraph = paragraph.wrap(wrap);
+        }
+        let mut buf = Buffer::empty(area);
+        if stateful {
+            StatefulWidget::render(&paragraph, area, &mut buf, &mut ParagraphState::default());
+        } else {
+            Widget::render(&paragraph, area, &mut buf);
+        }
+        let links: Vec<_> = buf
+            .content
+            .iter()
+            .filter_map(|cell| cell.hyperlink().map(|url| (cell.symbol(), url)))
+            .collect();
+        assert_eq!(
+            links,
+            [("c", "https://ratatui.rs"), ("d", "https://ratatui.rs")]
+        );
+    }
+
     #[test]
     fn test_render_paragraph_with_left_alignment() {
         let text = "Hello, world!";
@@ -1163,6 +1609,201 @@ mod tests {
         assert_eq!(p.alignment, Alignment::Right);
     }
 
+    #[test]
+    fn hyphenate() {
+        let paragraph = Paragraph::new("A hyphen\u{ad}ated para\u{ad}graph")
+            .wrap(Wrap { trim: true })
+            .hyphenate(true);
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["A hyphen-", "ated para-", "graph     "]),
+        );
+        assert_eq!(paragraph.line_count(10), 3);
+    }
+
+    #[test]
+    fn ellipsis() {
+        let paragraph = Paragraph::new(vec![
+            Line::from(vec!["Hello, ".into(), "world!".red()]),
+            Line::from("Hi"),
+        ])
+        .ellipsis("…");
+        let mut expected = Buffer::with_lines(["Hello, …", "Hi      "]);
+        expected.set_style(Rect::new(7, 0, 1, 1), Style::new().red());
+        test_case(&paragraph, &expected);
+
+        let wrapped = paragraph.wrap(Wrap { trim: true });
+        let mut expected = Buffer::with_lines(["Hello,  ", "world!  ", "Hi      "]);
+        expected.set_style(Rect::new(0, 1, 6, 1), Style::new().red());
+        test_case(&wrapped, &expected);
+    }
+
+    #[rstest]
+    #[case::top(VerticalAlignment::Top, ["Hello", "world", "     ", "     ", "     "])]
+    #[case::center(VerticalAlignment::Center, ["     ", "Hello", "world", "     ", "     "])]
+    #[case::bottom(VerticalAlignment::Bottom, ["     ", "     ", "     ", "Hello", "world"])]
+    fn render_vertical_alignment(
+        #[case] vertical_alignment: VerticalAlignment,
+        #[case] expected: [&str; 5],
+    ) {
+        let paragraph = Paragraph::new("Hello world")
+            .wrap(Wrap { trim: true })
+            .vertical_alignment(vertical_alignment);
+        test_case(&paragraph, &Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn vertical_alignment_with_block_and_scroll() {
+        let paragraph = Paragraph::new("one\ntwo\nthree")
+            .block(Block::bordered())
+            .scroll((1, 0))
+            .vertical_alignment(VerticalAlignment::Bottom);
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["┌─────┐", "│     │", "│two  │", "│three│", "└─────┘"]),
+        );
+    }
+
+    #[test]
+    fn vertical_alignment_overflowing_text() {
+        let paragraph =
+            Paragraph::new("one\ntwo\nthree").vertical_alignment(VerticalAlignment::Center);
+        test_case(&paragraph, &Buffer::with_lines(["one  ", "two  "]));
+    }
+
+    #[test]
+    fn render_selection() {
+        let paragraph = Paragraph::new("Hello world, this is ratatui")
+            .wrap(Wrap { trim: true })
+            .selection_style(Modifier::REVERSED);
+        let mut state = ParagraphState::default();
+        state.select(Position::new(6, 0), Position::new(3, 1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+
+        let mut expected = Buffer::with_lines(["Hello world,", "this is     ", "ratatui     "]);
+        expected.set_style(Rect::new(6, 0, 6, 1), Modifier::REVERSED);
+        expected.set_style(Rect::new(0, 1, 4, 1), Modifier::REVERSED);
+        assert_eq!(buf, expected);
+        assert_eq!(state.selected_text(), Some("world,\nthis".to_string()));
+    }
+
+    #[test]
+    fn render_selection_scrolled_and_aligned() {
+        let paragraph = Paragraph::new(vec![
+            Line::from("one"),
+            Line::from("two"),
+            Line::from("three"),
+        ])
+        .scroll((1, 0))
+        .right_aligned()
+        .selection_style(Modifier::REVERSED);
+        let mut state = ParagraphState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+
+        // the screen position of the "w" of "two" maps to its position in the text
+        let position = state.position_at(Position::new(3, 0));
+        assert_eq!(position, Some(Position::new(1, 1)));
+        assert_eq!(state.position_at(Position::new(5, 0)), None);
+        state.start_selection(Position::new(1, 1));
+        state.extend_selection(Position::new(2, 2));
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+
+        let mut expected = Buffer::with_lines(["  two", "three"]);
+        expected.set_style(Rect::new(3, 0, 2, 1), Modifier::REVERSED);
+        expected.set_style(Rect::new(0, 1, 3, 1), Modifier::REVERSED);
+        assert_eq!(buf, expected);
+        assert_eq!(state.selected_text(), Some("wo\nthr".to_string()));
+    }
+
+    #[test]
+    fn selected_text_extends_past_viewport() {
+        let paragraph = Paragraph::new("one\ntwo\nthree");
+        let mut state = ParagraphState::default();
+        state.select(Position::new(0, 0), Position::new(3, 2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(state.selected_text(), Some("one\ntwo\nthre".to_string()));
+
+        // the selected lines that are scrolled out of view are included too
+        let paragraph = Paragraph::new("Hello world, this is ratatui")
+            .wrap(Wrap { trim: true })
+            .scroll((2, 0));
+        state.select(Position::new(6, 0), Position::new(3, 2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["ratatui     "]));
+        assert_eq!(
+            state.selected_text(),
+            Some("world,\nthis is\nrata".to_string())
+        );
+    }
+
+    #[cfg(feature = "unicode-linebreak")]
+    #[test]
+    fn unicode_line_breaks() {
+        let paragraph = Paragraph::new("你好，世界")
+            .wrap(Wrap { trim: true })
+            .unicode_line_breaks(true);
+        assert_eq!(paragraph.text_line_count(4), 3);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Widget::render(&paragraph, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["你  ", "好，", "世界"]));
+    }
+
+    #[test]
+    fn follow_wrapped_text() {
+        let mut state = ParagraphState::default()
+            .with_scroll_state(ParagraphScrollState::new().with_follow(true));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        let paragraph = Paragraph::new("one two").wrap(Wrap { trim: true });
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["one  ", "two  "]));
+
+        let paragraph = Paragraph::new("one two\nthree four").wrap(Wrap { trim: true });
+        buf.reset();
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["three", "four "]));
+        assert_eq!(state.scroll_state().offset(), 2);
+
+        state.scroll_state_mut().scroll_up_by(1);
+        let paragraph = Paragraph::new("one two\nthree four\nfive").wrap(Wrap { trim: true });
+        buf.reset();
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["two  ", "three"]));
+        assert_eq!(state.scroll_state().max_offset(), 3);
+
+        state.scroll_state_mut().scroll_to_bottom();
+        buf.reset();
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["four ", "five "]));
+    }
+
+    #[test]
+    fn viewport_and_content_height() {
+        let paragraph = Paragraph::new("one two\nthree four five")
+            .block(Block::bordered())
+            .wrap(Wrap { trim: true })
+            .scroll((1, 0));
+        let mut state =
+            ParagraphState::default().with_scroll_state(ParagraphScrollState::new().with_offset(1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 4));
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(state.viewport(), Rect::new(1, 1, 5, 2));
+        assert_eq!(state.content_height(), 5);
+        assert_eq!(state.max_offset(), 3);
+        assert_eq!(state.offset(), 2);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["┌─────┐", "│three│", "│four │", "└─────┘"])
+        );
+
+        StatefulWidget::render(&paragraph, Rect::new(0, 0, 2, 2), &mut buf, &mut state);
+        assert_eq!(state.viewport(), Rect::new(1, 1, 0, 0));
+        assert_eq!(state.content_height(), 0);
+    }
+
     /// Regression test for <https://github.com/ratatui/ratatui/issues/990>
     ///
     /// This test ensures that paragraphs with a block and styled text are rendered correctly.
@@ -1183,4 +1824,81 @@ mod tests {
         expected.set_style(Rect::new(1, 1, 11, 1), Style::default().fg(Color::Green));
         assert_eq!(buf, expected);
     }
+
+    #[test]
+    fn horizontal_scroll_cuts_wide_characters() {
+        let paragraph = Paragraph::new("コンピュータ");
+        let area = Rect::new(0, 0, 6, 1);
+        test_case(
+            &paragraph.clone().scroll((0, 2)),
+            &Buffer::with_lines(["ンピュ"]),
+        );
+        test_case(
+            &paragraph.clone().scroll((0, 3)),
+            &Buffer::with_lines([" ピュ "]),
+        );
+        let mut buf = Buffer::empty(area);
+        paragraph.scroll((0, 12)).render(area, &mut buf);
+        assert_eq!(buf, Buffer::empty(area));
+    }
+
+    #[rstest]
+    #[case::fits(Paragraph::new("abc\nde"), (0, 0))]
+    #[case::overflows(Paragraph::new("abcdefgh\n1\n2\n3"), (1, 3))]
+    #[case::wide(Paragraph::new("コンピュータ"), (0, 7))]
+    #[case::wrapped(Paragraph::new("abcdefgh ij").wrap(Wrap { trim: true }), (0, 0))]
+    #[case::centered(Paragraph::new("abcdefgh").centered(), (0, 0))]
+    #[case::block(Paragraph::new("abcdefgh\n1\n2").block(Block::bordered()), (2, 5))]
+    fn max_scroll(#[case] paragraph: Paragraph, #[case] expected: (u16, u16)) {
+        assert_eq!(paragraph.max_scroll(Rect::new(0, 0, 5, 3)), expected);
+    }
+
+    #[test]
+    fn wrap_cache_is_invalidated() {
+        let mut state = ParagraphState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        let paragraph = Paragraph::new("ab cd ef").wrap(Wrap { trim: true });
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["ab cd", "ef   "]));
+        assert_eq!(state.wrap_cache.hits, 0);
+
+        // rendering the same paragraph again reuses the wrapped lines
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["ab cd", "ef   "]));
+        assert_eq!(state.wrap_cache.hits, 1);
+
+        let paragraph = Paragraph::new("gh ij kl").wrap(Wrap { trim: true });
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["gh ij", "kl   "]));
+        assert_eq!(state.wrap_cache.hits, 1);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["gh ", "ij ", "kl "]));
+        assert_eq!(state.wrap_cache.hits, 1);
+    }
+
+    #[test]
+    fn wrap_cache_is_keyed_by_text_generation() {
+        let mut state = ParagraphState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        let paragraph = Paragraph::new("ab cd ef")
+            .wrap(Wrap { trim: true })
+            .text_generation(1);
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["ab cd", "ef   "]));
+
+        // the text is not compared, so the lines of the same generation are reused
+        let paragraph = Paragraph::new("gh ij kl")
+            .wrap(Wrap { trim: true })
+            .text_generation(1);
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["ab cd", "ef   "]));
+        assert_eq!(state.wrap_cache.hits, 1);
+
+        let paragraph = paragraph.text_generation(2);
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["gh ij", "kl   "]));
+        assert_eq!(state.wrap_cache.hits, 1);
+    }
 }
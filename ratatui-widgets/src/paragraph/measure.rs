@@ -0,0 +1,136 @@
+use ratatui_core::{
+    style::Style,
+    text::{Line, Text},
+};
+
+use super::Wrap;
+use crate::reflow::{LineComposer, WordWrapper, WrappedLine};
+
+/// The size of a [`Text`] laid out in a given width, as returned by [`measure_text`]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct TextMetrics {
+    /// The number of lines of the text, after wrapping
+    pub line_count: usize,
+    /// The width of the widest line of the text, after wrapping or truncation
+    pub max_line_width: u16,
+}
+
+/// Measures the size of a text when it is laid out in the given width
+///
+/// When `wrap` is `Some`, the lines are wrapped on word boundaries with the same logic as the
+/// [`Paragraph`] widget, so the result matches what a paragraph with the same text and
+/// [`Wrap`] settings displays. Otherwise, each line of the text takes one row and is truncated to
+/// the width.
+///
+/// This is useful to size the area of a widget to its content, e.g. the height of a popup that
+/// displays a message, without rendering it.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     text::Text,
+///     widgets::{measure_text, TextMetrics, Wrap},
+/// };
+///
+/// let text = Text::from("Hello world\nhi");
+/// let metrics = measure_text(&text, 8, Some(Wrap { trim: true }));
+/// assert_eq!(
+///     metrics,
+///     TextMetrics {
+///         line_count: 3,
+///         max_line_width: 5
+///     }
+/// );
+///
+/// let metrics = measure_text(&text, 8, None);
+/// assert_eq!(
+///     metrics,
+///     TextMetrics {
+///         line_count: 2,
+///         max_line_width: 8
+///     }
+/// );
+/// ```
+///
+/// [`Paragraph`]: super::Paragraph
+pub fn measure_text(text: &Text, width: u16, wrap: Option<Wrap>) -> TextMetrics {
+    if width == 0 {
+        return TextMetrics::default();
+    }
+    let Some(Wrap { trim }) = wrap else {
+        let max_line_width = text.iter().map(Line::width).max().unwrap_or_default();
+        return TextMetrics {
+            line_count: text.height(),
+            max_line_width: max_line_width.min(usize::from(width)) as u16,
+        };
+    };
+    let styled = text.iter().map(|line| {
+        let graphemes = line
+            .spans
+            .iter()
+            .flat_map(|span| span.styled_graphemes(Style::default()));
+        let alignment = line.alignment.unwrap_or_default();
+        (graphemes, alignment)
+    });
+    measure_lines(WordWrapper::new(styled, width, trim))
+}
+
+/// Measures the lines produced by a line composer
+pub(crate) fn measure_lines<'a>(mut line_composer: impl LineComposer<'a>) -> TextMetrics {
+    let mut metrics = TextMetrics::default();
+    while let Some(WrappedLine { width, .. }) = line_composer.next_line() {
+        metrics.line_count += 1;
+        metrics.max_line_width = metrics.max_line_width.max(width);
+    }
+    metrics
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::empty("", 10, None, 1, 0)]
+    #[case::zero_width("abc", 0, None, 0, 0)]
+    #[case::truncated("abcdef\nab", 4, None, 2, 4)]
+    #[case::wrapped("abc def ghi", 7, Some(Wrap { trim: true }), 2, 7)]
+    #[case::wide_wrapped("コンピュータ", 5, Some(Wrap { trim: true }), 3, 4)]
+    #[case::untrimmed("  ab cd", 4, Some(Wrap { trim: false }), 2, 4)]
+    fn measure_text(
+        #[case] text: &str,
+        #[case] width: u16,
+        #[case] wrap: Option<Wrap>,
+        #[case] line_count: usize,
+        #[case] max_line_width: u16,
+    ) {
+        assert_eq!(
+            super::measure_text(&Text::from(text), width, wrap),
+            TextMetrics {
+                line_count,
+                max_line_width
+            }
+        );
+    }
+
+    #[test]
+    fn hyphenated() {
+        let text = Text::from("soft\u{ad}ware");
+        let styled = text.iter().map(|line| {
+            let graphemes = line.styled_graphemes(Style::default());
+            (graphemes, line.alignment.unwrap_or_default())
+        });
+        let mut line_composer = WordWrapper::new(styled, 6, true);
+        line_composer.set_hyphenate(true);
+        assert_eq!(
+            measure_lines(line_composer),
+            TextMetrics {
+                line_count: 2,
+                max_line_width: 5
+            }
+        );
+    }
+}
@@ -0,0 +1,183 @@
+/// Vertical scroll state of a [`Paragraph`], with an optional follow mode
+///
+/// The scroll state is part of the [`ParagraphState`]. When the paragraph is rendered as a
+/// stateful widget, the offset of the scroll state is added to the vertical scroll of the
+/// paragraph (see [`Paragraph::scroll`]), and it is clamped so that the paragraph doesn't scroll
+/// past its last (wrapped) line.
+///
+/// In follow mode, the offset is updated on every render so that the last line of the text stays
+/// visible, which is the expected behavior for logs and other growing content. Follow mode is
+/// disengaged when the user scrolls up (see [`ParagraphScrollState::scroll_up_by`]) and engaged
+/// again with [`ParagraphScrollState::scroll_to_bottom`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::Rect,
+///     widgets::{Paragraph, ParagraphScrollState, ParagraphState, StatefulWidget},
+/// };
+///
+/// let mut state =
+///     ParagraphState::default().with_scroll_state(ParagraphScrollState::new().with_follow(true));
+/// let paragraph = Paragraph::new("line 1\nline 2\nline 3\nline 4");
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+/// StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+/// assert_eq!(buf, Buffer::with_lines(["line 3", "line 4"]));
+///
+/// // scrolling up disengages the follow mode
+/// state.scroll_state_mut().scroll_up_by(1);
+/// assert!(!state.scroll_state().is_following());
+/// ```
+///
+/// [`Paragraph`]: super::Paragraph
+/// [`Paragraph::scroll`]: super::Paragraph::scroll
+/// [`ParagraphState`]: super::ParagraphState
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct ParagraphScrollState {
+    offset: u16,
+    follow: bool,
+    /// The largest offset during the last render
+    max_offset: u16,
+}
+
+impl ParagraphScrollState {
+    /// Creates a new scroll state at the top of the text, with follow mode disabled
+    pub const fn new() -> Self {
+        Self {
+            offset: 0,
+            follow: false,
+            max_offset: 0,
+        }
+    }
+
+    /// Sets the offset of the scroll state
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: u16) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets whether the scroll state follows the end of the text
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_follow(mut self, follow: bool) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    /// Returns the number of lines that are scrolled past
+    pub const fn offset(&self) -> u16 {
+        self.offset
+    }
+
+    /// Returns whether the scroll state follows the end of the text
+    pub const fn is_following(&self) -> bool {
+        self.follow
+    }
+
+    /// Returns the largest offset of the last render, where the last line of the text is visible
+    pub const fn max_offset(&self) -> u16 {
+        self.max_offset
+    }
+
+    /// Enables or disables the follow mode
+    pub fn set_follow(&mut self, follow: bool) {
+        self.follow = follow;
+    }
+
+    /// Scrolls up by the given number of lines and disengages the follow mode
+    pub fn scroll_up_by(&mut self, amount: u16) {
+        if self.follow {
+            self.offset = self.max_offset;
+            self.follow = false;
+        }
+        self.offset = self.offset.saturating_sub(amount);
+    }
+
+    /// Scrolls down by the given number of lines
+    ///
+    /// The offset is clamped to the end of the text on the next render. This doesn't engage the
+    /// follow mode, use [`ParagraphScrollState::scroll_to_bottom`] instead.
+    pub fn scroll_down_by(&mut self, amount: u16) {
+        self.offset = self.offset.saturating_add(amount);
+    }
+
+    /// Scrolls to the top of the text and disengages the follow mode
+    pub fn scroll_to_top(&mut self) {
+        self.offset = 0;
+        self.follow = false;
+    }
+
+    /// Scrolls to the bottom of the text and engages the follow mode
+    pub fn scroll_to_bottom(&mut self) {
+        self.offset = self.max_offset;
+        self.follow = true;
+    }
+
+    /// Updates the offset with the largest offset of the current render
+    pub(crate) fn update(&mut self, max_offset: u16) {
+        self.max_offset = max_offset;
+        self.offset = if self.follow {
+            max_offset
+        } else {
+            self.offset.min(max_offset)
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn follow() {
+        let mut state = ParagraphScrollState::new().with_follow(true);
+        state.update(5);
+        assert_eq!(state.offset(), 5);
+        state.update(8);
+        assert_eq!(state.offset(), 8);
+        assert!(state.is_following());
+    }
+
+    #[test]
+    fn scroll_up_disengages_follow() {
+        let mut state = ParagraphScrollState::new().with_follow(true);
+        state.update(5);
+        state.scroll_up_by(2);
+        assert_eq!(state.offset(), 3);
+        assert!(!state.is_following());
+        state.update(8);
+        assert_eq!(state.offset(), 3);
+    }
+
+    #[test]
+    fn scroll_to_bottom_engages_follow() {
+        let mut state = ParagraphScrollState::new();
+        state.update(5);
+        state.scroll_to_bottom();
+        assert_eq!(state.offset(), 5);
+        assert!(state.is_following());
+        state.scroll_to_top();
+        assert_eq!(state.offset(), 0);
+        assert!(!state.is_following());
+    }
+
+    #[test]
+    fn offset_is_clamped() {
+        let mut state = ParagraphScrollState::new();
+        state.scroll_down_by(10);
+        assert_eq!(state.offset(), 10);
+        state.update(4);
+        assert_eq!(state.offset(), 4);
+        assert_eq!(state.max_offset(), 4);
+        assert!(!state.is_following());
+    }
+}
@@ -0,0 +1,386 @@
+use std::hash::{Hash, Hasher};
+
+#[cfg(feature = "unicode-bidi")]
+use ratatui_core::text::StyledGrapheme;
+use ratatui_core::{
+    layout::{Position, Rect},
+    text::DisplayWidth,
+};
+
+use super::{wrap_cache::WrapCache, ParagraphScrollState};
+use crate::reflow::{LineComposer, WrappedLine};
+
+/// State of the [`Paragraph`] widget
+///
+/// The state tracks a text selection, made of an anchor (where the selection started) and a
+/// cursor (where the selection currently ends). When the paragraph is rendered as a stateful
+/// widget, the selected graphemes are displayed with the [`Paragraph::selection_style`] and the
+/// layout of the rendered (wrapped) text is stored in the state, so that:
+///
+/// - mouse coordinates can be converted into text positions with [`ParagraphState::position_at`]
+/// - the selected text can be extracted with [`ParagraphState::selected_text`], e.g. to copy it to
+///   the clipboard
+///
+/// Text positions are expressed in the coordinates of the rendered text: `y` is the index of the
+/// (wrapped) line and `x` is the column within that line. Both the anchor and the cursor are
+/// included in the selection.
+///
+/// The state also holds a [`ParagraphScrollState`], which scrolls the text vertically and can
+/// follow the end of the text (e.g. for logs).
+///
+/// When the text is wrapped, the state caches the wrapped lines, so that re-rendering a large
+/// paragraph whose text and width didn't change doesn't wrap the text again. The cache is
+/// invalidated automatically when the text, the wrapping options or the width change.
+///
+/// The [`StatefulWidget`] trait is implemented for `&Paragraph`, so render the paragraph by
+/// reference (e.g. `frame.render_stateful_widget(&paragraph, area, &mut state)`).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::{Position, Rect},
+///     style::{Style, Stylize},
+///     widgets::{Paragraph, ParagraphState, StatefulWidget},
+/// };
+///
+/// let paragraph = Paragraph::new("Hello, world!").selection_style(Style::new().reversed());
+///
+/// // This should be stored outside of the render function in your application state.
+/// let mut state = ParagraphState::default();
+/// state.select(Position::new(0, 0), Position::new(4, 0));
+///
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+/// StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+/// assert_eq!(state.selected_text(), Some("Hello".to_string()));
+/// ```
+///
+/// [`Paragraph`]: super::Paragraph
+/// [`StatefulWidget`]: ratatui_core::widgets::StatefulWidget
+/// [`Paragraph::selection_style`]: super::Paragraph::selection_style
+#[derive(Debug, Default, Clone)]
+pub struct ParagraphState {
+    anchor: Option<Position>,
+    cursor: Option<Position>,
+    /// The vertical scroll state
+    pub(crate) scroll_state: ParagraphScrollState,
+    /// The area of the text during the last render, after vertical alignment
+    pub(crate) area: Rect,
+    /// The area available to the text during the last render
+    pub(crate) viewport: Rect,
+    /// The number of (wrapped) lines of the text during the last render
+    pub(crate) content_height: usize,
+    /// The vertical scroll offset during the last render
+    pub(crate) scroll: u16,
+    /// The rows of the text area during the last render, starting at the scroll offset
+    pub(crate) lines: Vec<RenderedLine>,
+    /// The wrapped (or truncated) lines of the text, reused while the text and the width don't
+    /// change
+    pub(crate) wrap_cache: WrapCache,
+}
+
+/// Compares the selection and the scroll of the states, ignoring the layout of the last render and
+/// the wrapped lines that are only kept between renders to map mouse positions to the text.
+impl PartialEq for ParagraphState {
+    fn eq(&self, other: &Self) -> bool {
+        self.anchor == other.anchor
+            && self.cursor == other.cursor
+            && self.scroll_state == other.scroll_state
+    }
+}
+
+impl Eq for ParagraphState {}
+
+impl Hash for ParagraphState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.anchor.hash(state);
+        self.cursor.hash(state);
+        self.scroll_state.hash(state);
+    }
+}
+
+/// A line of text as it was rendered by the paragraph
+#[derive(Debug, Default, Clone)]
+pub(crate) struct RenderedLine {
+    /// The horizontal offset of the line caused by its alignment
+    pub(crate) offset: u16,
+}
+
+impl ParagraphState {
+    /// Sets the scroll state
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_scroll_state(mut self, scroll_state: ParagraphScrollState) -> Self {
+        self.scroll_state = scroll_state;
+        self
+    }
+
+    /// Returns the scroll state
+    pub const fn scroll_state(&self) -> &ParagraphScrollState {
+        &self.scroll_state
+    }
+
+    /// Returns a mutable reference to the scroll state
+    pub fn scroll_state_mut(&mut self) -> &mut ParagraphScrollState {
+        &mut self.scroll_state
+    }
+
+    /// Returns the vertical scroll offset of the last render
+    ///
+    /// This is the number of (wrapped) lines that were scrolled past the top of the viewport,
+    /// including both the [`Paragraph::scroll`] offset and the offset of the scroll state.
+    ///
+    /// [`Paragraph::scroll`]: super::Paragraph::scroll
+    pub const fn offset(&self) -> u16 {
+        self.scroll
+    }
+
+    /// Returns the area available to the text during the last render
+    ///
+    /// This is the inner area of the paragraph's block, or the whole area if there is no block.
+    pub const fn viewport(&self) -> Rect {
+        self.viewport
+    }
+
+    /// Returns the total number of lines of the text during the last render
+    ///
+    /// When the text is wrapped, this is the number of lines after wrapping at the width of the
+    /// [viewport](Self::viewport), so it can be used as the content length of a scrollbar without
+    /// wrapping the text again.
+    pub const fn content_height(&self) -> usize {
+        self.content_height
+    }
+
+    /// Returns the largest vertical offset at which the last line of the text is still visible
+    ///
+    /// This is based on the last render, so it can be used to clamp the scroll position before the
+    /// next one.
+    pub const fn max_offset(&self) -> usize {
+        self.content_height
+            .saturating_sub(self.viewport.height as usize)
+    }
+
+    /// Returns the anchor of the selection, if any
+    pub const fn anchor(&self) -> Option<Position> {
+        self.anchor
+    }
+
+    /// Returns the cursor of the selection, if any
+    pub const fn cursor(&self) -> Option<Position> {
+        self.cursor
+    }
+
+    /// Returns the start and end (inclusive) of the selection, in text order
+    ///
+    /// Returns `None` if nothing is selected.
+    pub fn selection(&self) -> Option<(Position, Position)> {
+        let (anchor, cursor) = self.anchor.zip(self.cursor)?;
+        if (anchor.y, anchor.x) <= (cursor.y, cursor.x) {
+            Some((anchor, cursor))
+        } else {
+            Some((cursor, anchor))
+        }
+    }
+
+    /// Selects the text between `anchor` and `cursor` (both inclusive)
+    pub fn select(&mut self, anchor: Position, cursor: Position) {
+        self.anchor = Some(anchor);
+        self.cursor = Some(cursor);
+    }
+
+    /// Starts a new selection at `position`, e.g. when a mouse button is pressed
+    pub fn start_selection(&mut self, position: Position) {
+        self.select(position, position);
+    }
+
+    /// Moves the cursor of the selection to `position`, e.g. when the mouse is dragged
+    ///
+    /// Starts a new selection if there is none.
+    pub fn extend_selection(&mut self, position: Position) {
+        self.anchor = self.anchor.or(Some(position));
+        self.cursor = Some(position);
+    }
+
+    /// Clears the selection
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+        self.cursor = None;
+    }
+
+    /// Returns whether the grapheme at the given text position is selected
+    pub fn is_selected(&self, position: Position) -> bool {
+        self.selection().is_some_and(|(start, end)| {
+            let position = (position.y, position.x);
+            (start.y, start.x) <= position && position <= (end.y, end.x)
+        })
+    }
+
+    /// Converts a position on the screen into a position in the text
+    ///
+    /// This uses the layout of the last render, and returns `None` if the position is outside of
+    /// the text area. Positions to the left of an aligned line map to its first column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{layout::Position, widgets::ParagraphState};
+    ///
+    /// # fn handle_mouse(state: &mut ParagraphState, column: u16, row: u16) {
+    /// if let Some(position) = state.position_at(Position::new(column, row)) {
+    ///     state.extend_selection(position);
+    /// }
+    /// # }
+    /// ```
+    pub fn position_at(&self, position: Position) -> Option<Position> {
+        if !self.area.contains(position) {
+            return None;
+        }
+        let row = position.y - self.area.y;
+        let y = row.saturating_add(self.scroll);
+        let offset = self
+            .lines
+            .get(row as usize)
+            .map(|line| line.offset)
+            .unwrap_or_default();
+        let x = (position.x - self.area.x).saturating_sub(offset);
+        Some(Position::new(x, y))
+    }
+
+    /// Returns the selected text
+    ///
+    /// This uses the (wrapped) lines of the last render, so it contains the whole selection, even
+    /// the lines that are scrolled out of the text area, but not the whitespace trimmed when
+    /// wrapping. Lines are joined with a line feed. Returns `None` if nothing is selected.
+    pub fn selected_text(&self) -> Option<String> {
+        let (start, end) = self.selection()?;
+        let mut composer = self.wrap_cache.composer();
+        let mut lines = Vec::new();
+        let mut y = 0;
+        while let Some(WrappedLine { line, .. }) = composer.next_line() {
+            if y >= start.y {
+                #[cfg(feature = "unicode-bidi")]
+                let visual_line = StyledGrapheme::visual_order(line);
+                #[cfg(feature = "unicode-bidi")]
+                let line = visual_line.as_ref();
+                let mut x = 0;
+                let mut text = String::new();
+                for grapheme in line {
+                    let width = grapheme.symbol.display_width();
+                    if width == 0 {
+                        continue;
+                    }
+                    if self.is_selected(Position::new(x, y)) {
+                        text.push_str(grapheme.symbol);
+                    }
+                    x += width as u16;
+                }
+                lines.push(text);
+            }
+            if y == end.y {
+                break;
+            }
+            y += 1;
+        }
+        Some(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn selection_is_ordered() {
+        let mut state = ParagraphState::default();
+        assert_eq!(state.selection(), None);
+        state.select(Position::new(3, 1), Position::new(5, 0));
+        assert_eq!(
+            state.selection(),
+            Some((Position::new(5, 0), Position::new(3, 1)))
+        );
+        assert_eq!(state.anchor(), Some(Position::new(3, 1)));
+        assert_eq!(state.cursor(), Some(Position::new(5, 0)));
+    }
+
+    #[test]
+    fn extend_selection() {
+        let mut state = ParagraphState::default();
+        state.extend_selection(Position::new(1, 0));
+        state.extend_selection(Position::new(2, 0));
+        assert_eq!(
+            state.selection(),
+            Some((Position::new(1, 0), Position::new(2, 0)))
+        );
+        state.start_selection(Position::new(4, 0));
+        assert_eq!(
+            state.selection(),
+            Some((Position::new(4, 0), Position::new(4, 0)))
+        );
+        state.clear_selection();
+        assert_eq!(state.selection(), None);
+    }
+
+    #[test]
+    fn position_at() {
+        let mut state = ParagraphState {
+            area: Rect::new(2, 3, 10, 2),
+            scroll: 5,
+            lines: vec![RenderedLine::default(), RenderedLine { offset: 3 }],
+            ..ParagraphState::default()
+        };
+        assert_eq!(
+            state.position_at(Position::new(2, 3)),
+            Some(Position::new(0, 5))
+        );
+        // the second line is aligned 3 columns to the right
+        assert_eq!(
+            state.position_at(Position::new(7, 4)),
+            Some(Position::new(2, 6))
+        );
+        assert_eq!(
+            state.position_at(Position::new(3, 4)),
+            Some(Position::new(0, 6))
+        );
+        assert_eq!(state.position_at(Position::new(1, 3)), None);
+        assert_eq!(state.position_at(Position::new(2, 5)), None);
+
+        state.scroll = u16::MAX;
+        assert_eq!(
+            state.position_at(Position::new(2, 4)),
+            Some(Position::new(0, u16::MAX))
+        );
+    }
+
+    #[test]
+    fn equality_ignores_render_layout() {
+        let rendered = ParagraphState {
+            area: Rect::new(2, 3, 10, 2),
+            viewport: Rect::new(2, 3, 10, 2),
+            content_height: 4,
+            scroll: 1,
+            lines: vec![RenderedLine { offset: 3 }],
+            ..ParagraphState::default()
+        };
+        assert_eq!(rendered, ParagraphState::default());
+
+        let mut selected = rendered.clone();
+        selected.select(Position::new(0, 0), Position::new(1, 0));
+        assert_ne!(selected, rendered);
+    }
+
+    #[test]
+    fn is_selected() {
+        let mut state = ParagraphState::default();
+        state.select(Position::new(2, 0), Position::new(1, 1));
+        assert!(!state.is_selected(Position::new(1, 0)));
+        assert!(state.is_selected(Position::new(2, 0)));
+        assert!(state.is_selected(Position::new(9, 0)));
+        assert!(state.is_selected(Position::new(0, 1)));
+        assert!(state.is_selected(Position::new(1, 1)));
+        assert!(!state.is_selected(Position::new(2, 1)));
+    }
+}
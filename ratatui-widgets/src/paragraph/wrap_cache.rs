@@ -0,0 +1,227 @@
+use std::slice;
+
+use ratatui_core::{layout::Alignment, style::Style, text::StyledGrapheme};
+
+use crate::reflow::{LineComposer, WrappedLine};
+
+/// The wrapped lines of the text of a paragraph, kept between renders in the [`ParagraphState`]
+///
+/// Wrapping a large text (grapheme segmentation and word wrapping) is expensive, and it is needed
+/// on every render to compute the scroll bounds and to render the visible lines. The cache stores
+/// the result along with a key made of the hash of the text (or of its generation number, see
+/// [`Paragraph::text_generation`]) and the wrapping options, and the width it was wrapped to, so
+/// that the text is only wrapped again when one of them changes. When the text is not wrapped, the
+/// cache holds the truncated lines, which are used to extract the selected text.
+///
+/// [`ParagraphState`]: super::ParagraphState
+/// [`Paragraph::text_generation`]: super::Paragraph::text_generation
+#[derive(Debug, Default, Clone)]
+pub(crate) struct WrapCache {
+    /// The hash of the text and wrapping options, and the width of the cached lines
+    key: Option<(u64, u16)>,
+    lines: Vec<CachedLine>,
+    /// The number of updates that reused the cached lines
+    #[cfg(test)]
+    pub(crate) hits: usize,
+}
+
+/// A wrapped line of the cache
+#[derive(Debug, Default, Clone)]
+struct CachedLine {
+    /// The symbols of the graphemes of the line, concatenated
+    symbols: String,
+    /// The end of each grapheme in `symbols`, its style, and the index of its hyperlink in `links`
+    graphemes: Vec<(usize, Style, Option<usize>)>,
+    /// The URLs of the hyperlinks of the line
+    links: Vec<String>,
+    width: u16,
+    alignment: Alignment,
+}
+
+impl WrapCache {
+    /// Wraps the text again with the composer returned by `compose` if the key or width changed
+    pub(crate) fn update<'a, C, F>(&mut self, key: u64, width: u16, compose: F)
+    where
+        C: LineComposer<'a>,
+        F: FnOnce() -> C,
+    {
+        if self.key == Some((key, width)) {
+            #[cfg(test)]
+            {
+                self.hits += 1;
+            }
+            return;
+        }
+        self.lines.clear();
+        let mut composer = compose();
+        while let Some(WrappedLine {
+            line,
+            width: line_width,
+            alignment,
+        }) = composer.next_line()
+        {
+            let mut symbols = String::new();
+            let mut links: Vec<String> = Vec::new();
+            let graphemes = line
+                .iter()
+                .map(|grapheme| {
+                    symbols.push_str(grapheme.symbol);
+                    let link = grapheme.hyperlink.map(|url| {
+                        if links.last().map(String::as_str) != Some(url) {
+                            links.push(url.to_string());
+                        }
+                        links.len() - 1
+                    });
+                    (symbols.len(), grapheme.style, link)
+                })
+                .collect();
+            self.lines.push(CachedLine {
+                symbols,
+                graphemes,
+                links,
+                width: line_width,
+                alignment,
+            });
+        }
+        self.key = Some((key, width));
+    }
+
+    /// Returns the number of wrapped lines
+    pub(crate) fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns a composer that yields the cached lines
+    pub(crate) fn composer(&self) -> CachedComposer<'_> {
+        CachedComposer {
+            lines: self.lines.iter(),
+            current_line: Vec::new(),
+        }
+    }
+}
+
+/// A line composer that replays the lines of a [`WrapCache`]
+pub(crate) struct CachedComposer<'a> {
+    lines: slice::Iter<'a, CachedLine>,
+    current_line: Vec<StyledGrapheme<'a>>,
+}
+
+impl<'a> LineComposer<'a> for CachedComposer<'a> {
+    fn next_line<'lend>(&'lend mut self) -> Option<WrappedLine<'lend, 'a>> {
+        let line = self.lines.next()?;
+        self.current_line.clear();
+        let mut start = 0;
+        for &(end, style, link) in &line.graphemes {
+            let symbol = line.symbols.get(start..end).unwrap_or_default();
+            let hyperlink = link
+                .and_then(|index| line.links.get(index))
+                .map(String::as_str);
+            self.current_line
+                .push(StyledGrapheme::new(symbol, style).hyperlink(hyperlink));
+            start = end;
+        }
+        Some(WrappedLine {
+            line: &self.current_line,
+            width: line.width,
+            alignment: line.alignment,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use pretty_assertions::assert_eq;
+    use ratatui_core::{
+        style::{Color, Stylize},
+        text::{Line, Span},
+    };
+
+    use super::*;
+    use crate::reflow::WordWrapper;
+
+    #[test]
+    fn replays_wrapped_lines() {
+        let line = Line::from(vec!["hello ".red(), "world".into()]);
+        let mut cache = WrapCache::default();
+        cache.update(1, 7, || {
+            let lines = [(line.styled_graphemes(Style::new()), Alignment::Center)].into_iter();
+            WordWrapper::new(lines, 7, true)
+        });
+        assert_eq!(cache.line_count(), 2);
+
+        let mut composer = cache.composer();
+        let mut lines = Vec::new();
+        while let Some(WrappedLine {
+            line,
+            width,
+            alignment,
+        }) = composer.next_line()
+        {
+            assert_eq!(line[0].style, line[line.len() - 1].style);
+            let symbols = line
+                .iter()
+                .map(|grapheme| grapheme.symbol)
+                .collect::<String>();
+            lines.push((symbols, width, alignment, line[0].style));
+        }
+        assert_eq!(
+            lines,
+            [
+                ("hello".to_string(), 5, Alignment::Center, Color::Red.into()),
+                ("world".to_string(), 5, Alignment::Center, Style::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn replays_hyperlinks() {
+        let line = Line::from(vec![
+            Span::raw("docs").hyperlink("https://docs.rs"),
+            Span::raw(" "),
+            Span::raw("site").hyperlink("https://ratatui.rs"),
+        ]);
+        let mut cache = WrapCache::default();
+        cache.update(1, 20, || {
+            let lines = [(line.styled_graphemes(Style::new()), Alignment::Left)].into_iter();
+            WordWrapper::new(lines, 20, true)
+        });
+
+        let mut composer = cache.composer();
+        let WrappedLine { line, .. } = composer.next_line().unwrap();
+        let links: Vec<_> = line.iter().map(|grapheme| grapheme.hyperlink).collect();
+        assert_eq!(
+            links,
+            [
+                [Some("https://docs.rs"); 4].as_slice(),
+                &[None],
+                &[Some("https://ratatui.rs"); 4],
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn only_wraps_when_key_or_width_changes() {
+        let line = Line::from("a b");
+        let wraps = Cell::new(0);
+        let compose = |width| {
+            let line = &line;
+            let wraps = &wraps;
+            move || {
+                wraps.set(wraps.get() + 1);
+                let lines = [(line.styled_graphemes(Style::new()), Alignment::Left)].into_iter();
+                WordWrapper::new(lines, width, true)
+            }
+        };
+        let mut cache = WrapCache::default();
+        cache.update(1, 1, compose(1));
+        cache.update(1, 1, compose(1));
+        assert_eq!((cache.line_count(), wraps.get()), (2, 1));
+        cache.update(1, 3, compose(3));
+        assert_eq!((cache.line_count(), wraps.get()), (1, 2));
+        cache.update(2, 3, compose(3));
+        assert_eq!((cache.line_count(), wraps.get()), (1, 3));
+    }
+}
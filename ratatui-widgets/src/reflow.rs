@@ -1,9 +1,19 @@
 //! Internal module for reflowing text to fit into a certain width.
 use std::{collections::VecDeque, mem};
 
-use ratatui_core::{layout::Alignment, text::StyledGrapheme};
-use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
+use ratatui_core::{
+    layout::Alignment,
+    style::Style,
+    text::{DisplayWidth, StyledGrapheme},
+};
+#[cfg(feature = "unicode-linebreak")]
+use unicode_linebreak::BreakOpportunity;
+
+/// The soft hyphen (U+00AD), an invisible hyphenation point within a word
+const SOFT_HYPHEN: &str = "\u{00AD}";
+
+/// The symbol displayed at the end of a line that was broken at a soft hyphen
+const HYPHEN: &str = "-";
 
 /// A state machine to pack styled symbols into lines.
 /// Cannot implement it as Iterator since it yields slices of the internal buffer (need streaming
@@ -40,6 +50,11 @@ where
     current_line: Vec<StyledGrapheme<'a>>,
     /// Removes the leading whitespace from lines
     trim: bool,
+    /// Breaks words at soft hyphens when they don't fit on a line
+    hyphenate: bool,
+    /// Breaks lines at the opportunities of the Unicode line breaking algorithm
+    #[cfg(feature = "unicode-linebreak")]
+    unicode_line_breaks: bool,
 
     // These are cached allocations that hold no state across next_line invocations
     pending_word: Vec<StyledGrapheme<'a>>,
@@ -61,6 +76,9 @@ where
             current_alignment: Alignment::Left,
             current_line: vec![],
             trim,
+            hyphenate: false,
+            #[cfg(feature = "unicode-linebreak")]
+            unicode_line_breaks: false,
 
             pending_word: Vec::new(),
             pending_line_pool: Vec::new(),
@@ -68,9 +86,54 @@ where
         }
     }
 
+    /// Enable breaking words at soft hyphens (U+00AD).
+    ///
+    /// When enabled, soft hyphens are not displayed, and a word that doesn't fit on the current
+    /// line is broken at its last soft hyphen that fits, followed by a visible hyphen.
+    pub fn set_hyphenate(&mut self, hyphenate: bool) {
+        self.hyphenate = hyphenate;
+    }
+
+    /// Enable breaking lines with the Unicode line breaking algorithm ([UAX #14]).
+    ///
+    /// When enabled, lines are broken at the break opportunities defined by the algorithm instead
+    /// of only at whitespace, e.g. between CJK characters or after hyphens and slashes, and never
+    /// before closing punctuation. Words that don't fit on a line are still broken at the last
+    /// grapheme that fits.
+    ///
+    /// [UAX #14]: https://www.unicode.org/reports/tr14/
+    #[cfg(feature = "unicode-linebreak")]
+    pub fn set_unicode_line_breaks(&mut self, unicode_line_breaks: bool) {
+        self.unicode_line_breaks = unicode_line_breaks;
+    }
+
+    /// Returns the index of the last soft hyphen of the pending word where the word can be broken
+    /// so that its first part and a hyphen fit in the `available_width`.
+    fn soft_hyphen_break(&self, available_width: u16) -> Option<usize> {
+        let mut width = 0;
+        let mut break_index = None;
+        for (index, grapheme) in self.pending_word.iter().enumerate() {
+            if grapheme.symbol == SOFT_HYPHEN {
+                if width > 0 && width + HYPHEN.display_width() as u16 <= available_width {
+                    break_index = Some(index);
+                }
+            } else {
+                width += grapheme.symbol.display_width() as u16;
+            }
+        }
+        break_index
+    }
+
     /// Split an input line (`line_symbols`) into wrapped lines
     /// and cache them to be emitted later
+    #[allow(clippy::too_many_lines)]
     fn process_input(&mut self, line_symbols: impl IntoIterator<Item = StyledGrapheme<'a>>) {
+        #[cfg(feature = "unicode-linebreak")]
+        if self.unicode_line_breaks {
+            self.process_input_line_breaks(line_symbols);
+            return;
+        }
+
         let mut pending_line = self.pending_line_pool.pop().unwrap_or_default();
         let mut line_width = 0;
         let mut word_width = 0;
@@ -83,13 +146,57 @@ where
 
         for grapheme in line_symbols {
             let is_whitespace = grapheme.is_whitespace();
-            let symbol_width = grapheme.symbol.width() as u16;
+            let is_soft_hyphen = self.hyphenate && grapheme.symbol == SOFT_HYPHEN;
+            let symbol_width = if is_soft_hyphen {
+                0
+            } else {
+                grapheme.symbol.display_width() as u16
+            };
 
             // ignore symbols wider than line limit
             if symbol_width > self.max_line_width {
                 continue;
             }
 
+            // break the pending word at a soft hyphen if the symbol would overflow the line
+            let preceding_width = if pending_line.is_empty() && self.trim {
+                0
+            } else {
+                line_width + whitespace_width
+            };
+            let break_index = (self.hyphenate
+                && !is_whitespace
+                && preceding_width + word_width + symbol_width > self.max_line_width)
+                .then(|| {
+                    self.soft_hyphen_break(
+                        self.max_line_width - preceding_width.min(self.max_line_width),
+                    )
+                })
+                .flatten();
+            if let Some(index) = break_index {
+                if preceding_width > 0 {
+                    pending_line.extend(self.pending_whitespace.drain(..));
+                }
+                let rest = self.pending_word.split_off(index + 1);
+                let soft_hyphen = self.pending_word.pop();
+                pending_line.append(&mut self.pending_word);
+                pending_line.extend(soft_hyphen.map(|grapheme| StyledGrapheme {
+                    symbol: HYPHEN,
+                    ..grapheme
+                }));
+                self.wrapped_lines.push_back(mem::take(&mut pending_line));
+                self.pending_word = rest;
+                self.pending_whitespace.clear();
+                line_width = 0;
+                whitespace_width = 0;
+                word_width = self
+                    .pending_word
+                    .iter()
+                    .filter(|grapheme| grapheme.symbol != SOFT_HYPHEN)
+                    .map(|grapheme| grapheme.symbol.display_width() as u16)
+                    .sum();
+            }
+
             let word_found = non_whitespace_previous && is_whitespace;
             // current word would overflow after removing whitespace
             let trimmed_overflow = pending_line.is_empty()
@@ -134,7 +241,7 @@ where
 
                 // remove whitespace up to the end of line
                 while let Some(grapheme) = self.pending_whitespace.front() {
-                    let width = grapheme.symbol.width() as u16;
+                    let width = grapheme.symbol.display_width() as u16;
 
                     if width > remaining_width {
                         break;
@@ -186,6 +293,102 @@ where
         }
     }
 
+    /// Split an input line (`line_symbols`) into wrapped lines at the break opportunities of the
+    /// Unicode line breaking algorithm and cache them to be emitted later
+    #[cfg(feature = "unicode-linebreak")]
+    fn process_input_line_breaks(
+        &mut self,
+        line_symbols: impl IntoIterator<Item = StyledGrapheme<'a>>,
+    ) {
+        let hyphenate = self.hyphenate;
+        let symbol_width = move |grapheme: &StyledGrapheme| {
+            if hyphenate && grapheme.symbol == SOFT_HYPHEN {
+                0
+            } else {
+                grapheme.symbol.display_width() as u16
+            }
+        };
+        // ignore symbols wider than line limit
+        let graphemes = line_symbols
+            .into_iter()
+            .filter(|grapheme| symbol_width(grapheme) <= self.max_line_width)
+            .collect::<Vec<_>>();
+
+        // the break opportunities are byte offsets in the text of the line, which are converted to
+        // grapheme indices
+        let mut text = String::new();
+        let mut starts = Vec::with_capacity(graphemes.len());
+        for grapheme in &graphemes {
+            starts.push(text.len());
+            text.push_str(grapheme.symbol);
+        }
+        let breaks = unicode_linebreak::linebreaks(&text).filter_map(|(offset, opportunity)| {
+            let index = if offset == text.len() {
+                graphemes.len()
+            } else {
+                starts.binary_search(&offset).ok()?
+            };
+            Some((index, opportunity == BreakOpportunity::Mandatory))
+        });
+
+        let mut pending_line = self.pending_line_pool.pop().unwrap_or_default();
+        pending_line.clear();
+        let mut line_width = 0;
+        let mut start = 0;
+        for (end, mandatory) in breaks {
+            let segment = &graphemes[start..end];
+            start = end;
+            // trailing whitespace of the segment doesn't need to fit on the line
+            let content_width: u16 = segment
+                .iter()
+                .rev()
+                .skip_while(|grapheme| grapheme.is_whitespace())
+                .map(symbol_width)
+                .sum();
+            if !pending_line.is_empty() && line_width + content_width > self.max_line_width {
+                self.push_wrapped_line(&mut pending_line);
+                line_width = 0;
+            }
+            for grapheme in segment {
+                let width = symbol_width(grapheme);
+                if line_width + width > self.max_line_width {
+                    if grapheme.is_whitespace() {
+                        continue;
+                    }
+                    // the segment is longer than a line
+                    self.push_wrapped_line(&mut pending_line);
+                    line_width = 0;
+                }
+                if pending_line.is_empty() && self.trim && grapheme.is_whitespace() {
+                    continue;
+                }
+                pending_line.push(grapheme.clone());
+                line_width += width;
+            }
+            if mandatory && end < graphemes.len() {
+                self.push_wrapped_line(&mut pending_line);
+                line_width = 0;
+            }
+        }
+
+        #[allow(clippy::else_if_without_else)]
+        if !pending_line.is_empty() || self.wrapped_lines.is_empty() {
+            self.wrapped_lines.push_back(pending_line);
+        } else if pending_line.capacity() > 0 {
+            self.pending_line_pool.push(pending_line);
+        }
+    }
+
+    /// Adds a line that was wrapped to the cached lines, without its trailing whitespace
+    #[cfg(feature = "unicode-linebreak")]
+    fn push_wrapped_line(&mut self, line: &mut Vec<StyledGrapheme<'a>>) {
+        while line.last().is_some_and(StyledGrapheme::is_whitespace) {
+            line.pop();
+        }
+        let next_line = self.pending_line_pool.pop().unwrap_or_default();
+        self.wrapped_lines.push_back(mem::replace(line, next_line));
+    }
+
     fn replace_current_line(&mut self, line: Vec<StyledGrapheme<'a>>) {
         let cache = mem::replace(&mut self.current_line, line);
         if cache.capacity() > 0 {
@@ -207,10 +410,13 @@ where
 
         loop {
             // emit next cached line if present
-            if let Some(line) = self.wrapped_lines.pop_front() {
+            if let Some(mut line) = self.wrapped_lines.pop_front() {
+                if self.hyphenate {
+                    line.retain(|grapheme| grapheme.symbol != SOFT_HYPHEN);
+                }
                 let line_width = line
                     .iter()
-                    .map(|grapheme| grapheme.symbol.width() as u16)
+                    .map(|grapheme| grapheme.symbol.display_width() as u16)
                     .sum();
 
                 self.replace_current_line(line);
@@ -245,6 +451,8 @@ where
     current_line: Vec<StyledGrapheme<'a>>,
     /// Record the offset to skip render
     horizontal_offset: u16,
+    /// The symbol appended to the lines that are truncated
+    ellipsis: Option<&'a str>,
 }
 
 impl<'a, O, I> LineTruncator<'a, O, I>
@@ -259,6 +467,7 @@ where
             max_line_width,
             horizontal_offset: 0,
             current_line: vec![],
+            ellipsis: None,
         }
     }
 
@@ -266,6 +475,34 @@ where
     pub fn set_horizontal_offset(&mut self, horizontal_offset: u16) {
         self.horizontal_offset = horizontal_offset;
     }
+
+    /// Set the symbol that replaces the end of the lines that don't fit in the maximum width.
+    pub fn set_ellipsis(&mut self, ellipsis: Option<&'a str>) {
+        self.ellipsis = ellipsis;
+    }
+
+    /// Replaces the end of the current line with the ellipsis, if any.
+    ///
+    /// `style` is the style of the first grapheme that was cut from the line. Graphemes are removed
+    /// from the end of the line until the ellipsis fits.
+    fn append_ellipsis(&mut self, current_line_width: &mut u16, mut style: Style) {
+        let Some(ellipsis) = self.ellipsis else {
+            return;
+        };
+        let ellipsis_width = ellipsis.display_width() as u16;
+        if ellipsis_width > self.max_line_width {
+            return;
+        }
+        while *current_line_width + ellipsis_width > self.max_line_width {
+            let Some(grapheme) = self.current_line.pop() else {
+                break;
+            };
+            *current_line_width -= grapheme.symbol.display_width() as u16;
+            style = grapheme.style;
+        }
+        self.current_line.push(StyledGrapheme::new(ellipsis, style));
+        *current_line_width += ellipsis_width;
+    }
 }
 
 impl<'a, O, I> LineComposer<'a> for LineTruncator<'a, O, I>
@@ -284,39 +521,54 @@ where
         let mut lines_exhausted = true;
         let mut horizontal_offset = self.horizontal_offset as usize;
         let mut current_alignment = Alignment::Left;
+        let mut truncated_style = None;
         if let Some((current_line, alignment)) = &mut self.input_lines.next() {
             lines_exhausted = false;
             current_alignment = *alignment;
 
-            for StyledGrapheme { symbol, style } in current_line {
+            for grapheme in current_line {
+                let StyledGrapheme { symbol, style, .. } = grapheme;
+                let width = symbol.display_width();
                 // Ignore characters wider that the total max width.
-                if symbol.width() as u16 > self.max_line_width {
+                if width as u16 > self.max_line_width {
+                    continue;
+                }
+
+                // Skip the columns before the horizontal offset. A wide grapheme that is cut by the
+                // offset is replaced with spaces for its visible columns, so that the rest of the
+                // line stays aligned to the display columns.
+                if horizontal_offset > 0 && *alignment == Alignment::Left {
+                    if width <= horizontal_offset {
+                        horizontal_offset -= width;
+                        continue;
+                    }
+                    let visible_width = width - horizontal_offset;
+                    horizontal_offset = 0;
+                    for _ in 0..visible_width {
+                        self.current_line.push(StyledGrapheme {
+                            symbol: " ",
+                            ..grapheme
+                        });
+                    }
+                    current_line_width += visible_width as u16;
                     continue;
                 }
 
-                if current_line_width + symbol.width() as u16 > self.max_line_width {
+                if current_line_width + width as u16 > self.max_line_width {
                     // Truncate line
+                    truncated_style = Some(style);
                     break;
                 }
 
-                let symbol = if horizontal_offset == 0 || Alignment::Left != *alignment {
-                    symbol
-                } else {
-                    let w = symbol.width();
-                    if w > horizontal_offset {
-                        let t = trim_offset(symbol, horizontal_offset);
-                        horizontal_offset = 0;
-                        t
-                    } else {
-                        horizontal_offset -= w;
-                        ""
-                    }
-                };
-                current_line_width += symbol.width() as u16;
-                self.current_line.push(StyledGrapheme { symbol, style });
+                current_line_width += width as u16;
+                self.current_line.push(grapheme);
             }
         }
 
+        if let Some(style) = truncated_style {
+            self.append_ellipsis(&mut current_line_width, style);
+        }
+
         if lines_exhausted {
             None
         } else {
@@ -329,36 +581,34 @@ where
     }
 }
 
-/// This function will return a str slice which start at specified offset.
-/// As src is a unicode str, start offset has to be calculated with each character.
-fn trim_offset(src: &str, mut offset: usize) -> &str {
-    let mut start = 0;
-    for c in UnicodeSegmentation::graphemes(src, true) {
-        let w = c.width();
-        if w <= offset {
-            offset -= w;
-            start += c.len();
-        } else {
-            break;
-        }
-    }
-    #[allow(clippy::string_slice)] // Is safe as it comes from UnicodeSegmentation
-    &src[start..]
-}
-
 #[cfg(test)]
 mod tests {
     use ratatui_core::{
-        style::Style,
-        text::{Line, Text},
+        style::{Color, Style},
+        text::{Line, Span, Text},
     };
+    use rstest::rstest;
+    use unicode_segmentation::UnicodeSegmentation;
+    use unicode_width::UnicodeWidthStr;
 
     use super::*;
 
     #[derive(Clone, Copy)]
     enum Composer {
-        WordWrapper { trim: bool },
+        WordWrapper {
+            trim: bool,
+        },
+        HyphenatingWordWrapper {
+            trim: bool,
+        },
+        #[cfg(feature = "unicode-linebreak")]
+        LineBreakWrapper {
+            trim: bool,
+        },
         LineTruncator,
+        EllipsisLineTruncator {
+            ellipsis: &'static str,
+        },
     }
 
     fn run_composer<'a>(
@@ -379,7 +629,23 @@ mod tests {
             Composer::WordWrapper { trim } => {
                 Box::new(WordWrapper::new(styled_lines, text_area_width, trim))
             }
+            Composer::HyphenatingWordWrapper { trim } => {
+                let mut word_wrapper = WordWrapper::new(styled_lines, text_area_width, trim);
+                word_wrapper.set_hyphenate(true);
+                Box::new(word_wrapper)
+            }
+            #[cfg(feature = "unicode-linebreak")]
+            Composer::LineBreakWrapper { trim } => {
+                let mut word_wrapper = WordWrapper::new(styled_lines, text_area_width, trim);
+                word_wrapper.set_unicode_line_breaks(true);
+                Box::new(word_wrapper)
+            }
             Composer::LineTruncator => Box::new(LineTruncator::new(styled_lines, text_area_width)),
+            Composer::EllipsisLineTruncator { ellipsis } => {
+                let mut line_truncator = LineTruncator::new(styled_lines, text_area_width);
+                line_truncator.set_ellipsis(Some(ellipsis));
+                Box::new(line_truncator)
+            }
         };
         let mut lines = vec![];
         let mut widths = vec![];
@@ -723,4 +989,127 @@ mod tests {
         let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, line, width);
         assert_eq!(word_wrapper, ["foo", "bar"]);
     }
+
+    #[test]
+    fn line_composer_soft_hyphen() {
+        let text = "hyphen\u{ad}ation is great";
+        let (lines, widths, _) =
+            run_composer(Composer::HyphenatingWordWrapper { trim: true }, text, 8);
+        assert_eq!(lines, ["hyphen-", "ation is", "great"]);
+        assert_eq!(widths, [7, 8, 5]);
+    }
+
+    #[cfg(feature = "unicode-linebreak")]
+    #[rstest]
+    #[case::cjk_punctuation("你好，世界", 4, &["你", "好，", "世界"], &[2, 4, 4])]
+    #[case::hyphen_and_slash(
+        "a well-known path/to/file",
+        10,
+        &["a well-", "known", "path/to/", "file"],
+        &[7, 5, 8, 4]
+    )]
+    #[case::long_word("abcdefgh", 3, &["abc", "def", "gh"], &[3, 3, 2])]
+    #[case::empty("", 3, &[""], &[0])]
+    fn line_composer_unicode_line_breaks(
+        #[case] text: &str,
+        #[case] width: u16,
+        #[case] expected_lines: &[&str],
+        #[case] expected_widths: &[u16],
+    ) {
+        let (lines, widths, _) =
+            run_composer(Composer::LineBreakWrapper { trim: true }, text, width);
+        assert_eq!(lines, expected_lines);
+        assert_eq!(widths, expected_widths);
+    }
+
+    #[test]
+    fn line_composer_soft_hyphen_uses_last_break_that_fits() {
+        let text = "in\u{ad}com\u{ad}pre\u{ad}hen\u{ad}si\u{ad}ble";
+        let (lines, _, _) = run_composer(Composer::HyphenatingWordWrapper { trim: true }, text, 10);
+        assert_eq!(lines, ["incompre-", "hensible"]);
+    }
+
+    #[test]
+    fn line_composer_soft_hyphen_after_other_words() {
+        let text = "a long\u{ad}word";
+        let (lines, widths, _) =
+            run_composer(Composer::HyphenatingWordWrapper { trim: true }, text, 7);
+        assert_eq!(lines, ["a long-", "word"]);
+        assert_eq!(widths, [7, 4]);
+        let (lines, _, _) = run_composer(Composer::HyphenatingWordWrapper { trim: false }, text, 7);
+        assert_eq!(lines, ["a long-", "word"]);
+    }
+
+    #[test]
+    fn line_composer_soft_hyphen_without_break() {
+        let text = "soft\u{ad}ware";
+        let (lines, widths, _) =
+            run_composer(Composer::HyphenatingWordWrapper { trim: true }, text, 10);
+        assert_eq!(lines, ["software"]);
+        assert_eq!(widths, [8]);
+    }
+
+    #[rstest]
+    #[case::fits("abcde", 5, "…", "abcde")]
+    #[case::truncated("abcdefgh", 5, "…", "abcd…")]
+    #[case::multi_width_ellipsis("abcdefgh", 5, "...", "ab...")]
+    #[case::wide_graphemes("コンピュータ", 7, "…", "コンピ…")]
+    #[case::ellipsis_too_wide("abcdefgh", 2, "...", "ab")]
+    fn line_composer_ellipsis(
+        #[case] text: &str,
+        #[case] width: u16,
+        #[case] ellipsis: &'static str,
+        #[case] expected: &str,
+    ) {
+        let (lines, widths, _) =
+            run_composer(Composer::EllipsisLineTruncator { ellipsis }, text, width);
+        assert_eq!(lines, [expected]);
+        assert_eq!(widths, [expected.width() as u16]);
+    }
+
+    #[test]
+    fn line_composer_ellipsis_keeps_style() {
+        let line = Line::from(vec![Span::raw("abc"), Span::styled("def", Color::Red)]);
+        let mut truncator = LineTruncator::new(
+            [(line.styled_graphemes(Style::default()), Alignment::Left)].into_iter(),
+            4,
+        );
+        truncator.set_ellipsis(Some("…"));
+        let WrappedLine { line, .. } = truncator.next_line().unwrap();
+        assert_eq!(
+            line,
+            [
+                StyledGrapheme::new("a", Style::default()),
+                StyledGrapheme::new("b", Style::default()),
+                StyledGrapheme::new("c", Style::default()),
+                StyledGrapheme::new("…", Style::new().fg(Color::Red)),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::ascii("abcdef", 2, "cdef")]
+    #[case::wide_skipped("コンピュ", 2, "ンピュ")]
+    #[case::wide_cut("コンピュ", 3, " ピュ")]
+    #[case::wide_cut_truncated("aコンピュ", 2, " ンピ")]
+    #[case::past_the_end("abc", 5, "")]
+    fn line_truncator_horizontal_offset(
+        #[case] text: &str,
+        #[case] offset: u16,
+        #[case] expected: &str,
+    ) {
+        let line = Line::from(text);
+        let mut truncator = LineTruncator::new(
+            [(line.styled_graphemes(Style::default()), Alignment::Left)].into_iter(),
+            6,
+        );
+        truncator.set_horizontal_offset(offset);
+        let WrappedLine { line, width, .. } = truncator.next_line().unwrap();
+        let line = line
+            .iter()
+            .map(|grapheme| grapheme.symbol)
+            .collect::<String>();
+        assert_eq!(line, expected);
+        assert_eq!(width, expected.width() as u16);
+    }
 }
@@ -0,0 +1,232 @@
+//! The [`Remote`] type represents data that is loaded asynchronously.
+use core::fmt;
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Rect, VerticalAlignment},
+    style::{Style, Stylize},
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::paragraph::{Paragraph, Wrap};
+
+/// Data that is loaded from an asynchronous source, e.g. a network request or a background task
+///
+/// Applications that load the data of their widgets asynchronously need to display something
+/// while the data is loading, and when loading it failed. `Remote` represents these three states,
+/// and implements [`Widget`] and [`StatefulWidget`] when the ready value is a widget, so that all
+/// the widgets that display remote data show consistent loading and error states:
+///
+/// - [`Remote::Loading`] renders a dimmed "Loading…" message in the center of the area
+/// - [`Remote::Error`] renders the error in red in the center of the area, wrapped to its width
+/// - [`Remote::Ready`] renders the wrapped widget (with its state for stateful widgets)
+///
+/// Use [`Remote::map`] to turn the loaded data into a widget at render time. To display custom
+/// loading or error states, match on the variants instead of rendering the `Remote` directly.
+///
+/// A `Remote` can be created from a `Result` (e.g. the output of a future), or from an
+/// `Option<Result>` where `None` means that the data is still loading (e.g. the output of
+/// `try_recv` on a channel).
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::{
+///     widgets::{List, ListState, Remote},
+///     Frame,
+/// };
+///
+/// struct App {
+///     // updated by a background task
+///     files: Remote<Vec<String>>,
+///     state: ListState,
+/// }
+///
+/// fn draw(frame: &mut Frame, app: &mut App) {
+///     let list = app.files.as_ref().map(|files| List::new(files.iter().map(String::as_str)));
+///     frame.render_stateful_widget(list, frame.area(), &mut app.state);
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Remote<T, E = String> {
+    /// The data is loading
+    #[default]
+    Loading,
+    /// The data is loaded
+    Ready(T),
+    /// Loading the data failed
+    Error(E),
+}
+
+impl<T, E> Remote<T, E> {
+    /// Returns `true` if the data is loading
+    pub const fn is_loading(&self) -> bool {
+        matches!(self, Self::Loading)
+    }
+
+    /// Returns `true` if the data is loaded
+    pub const fn is_ready(&self) -> bool {
+        matches!(self, Self::Ready(_))
+    }
+
+    /// Returns `true` if loading the data failed
+    pub const fn is_error(&self) -> bool {
+        matches!(self, Self::Error(_))
+    }
+
+    /// Returns the loaded data, if any
+    pub const fn ready(&self) -> Option<&T> {
+        match self {
+            Self::Ready(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the error, if any
+    pub const fn error(&self) -> Option<&E> {
+        match self {
+            Self::Error(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Converts from `&Remote<T, E>` to `Remote<&T, &E>`
+    pub const fn as_ref(&self) -> Remote<&T, &E> {
+        match self {
+            Self::Loading => Remote::Loading,
+            Self::Ready(value) => Remote::Ready(value),
+            Self::Error(error) => Remote::Error(error),
+        }
+    }
+
+    /// Maps the loaded data with the given function, leaving the other states untouched
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Remote<U, E> {
+        match self {
+            Self::Loading => Remote::Loading,
+            Self::Ready(value) => Remote::Ready(f(value)),
+            Self::Error(error) => Remote::Error(error),
+        }
+    }
+
+    /// Maps the error with the given function, leaving the other states untouched
+    pub fn map_err<E2, F: FnOnce(E) -> E2>(self, f: F) -> Remote<T, E2> {
+        match self {
+            Self::Loading => Remote::Loading,
+            Self::Ready(value) => Remote::Ready(value),
+            Self::Error(error) => Remote::Error(f(error)),
+        }
+    }
+}
+
+impl<T, E> From<Result<T, E>> for Remote<T, E> {
+    fn from(result: Result<T, E>) -> Self {
+        match result {
+            Ok(value) => Self::Ready(value),
+            Err(error) => Self::Error(error),
+        }
+    }
+}
+
+impl<T, E> From<Option<Result<T, E>>> for Remote<T, E> {
+    fn from(result: Option<Result<T, E>>) -> Self {
+        result.map_or(Self::Loading, Self::from)
+    }
+}
+
+impl<W: Widget, E: fmt::Display> Widget for Remote<W, E> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        match self {
+            Self::Loading => render_loading(area, buf),
+            Self::Ready(widget) => widget.render(area, buf),
+            Self::Error(error) => render_error(&error, area, buf),
+        }
+    }
+}
+
+impl<W: StatefulWidget, E: fmt::Display> StatefulWidget for Remote<W, E> {
+    type State = W::State;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        match self {
+            Self::Loading => render_loading(area, buf),
+            Self::Ready(widget) => widget.render(area, buf, state),
+            Self::Error(error) => render_error(&error, area, buf),
+        }
+    }
+}
+
+fn render_loading(area: Rect, buf: &mut Buffer) {
+    Paragraph::new("Loading…")
+        .style(Style::new().dim())
+        .centered()
+        .vertical_alignment(VerticalAlignment::Center)
+        .render(area, buf);
+}
+
+fn render_error<E: fmt::Display>(error: &E, area: Rect, buf: &mut Buffer) {
+    Paragraph::new(format!("Error: {error}"))
+        .style(Style::new().red())
+        .centered()
+        .vertical_alignment(VerticalAlignment::Center)
+        .wrap(Wrap { trim: true })
+        .render(area, buf);
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::text::Line;
+
+    use super::*;
+    use crate::list::{List, ListState};
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Remote::<u8, ()>::from(Ok(1)), Remote::Ready(1));
+        assert_eq!(Remote::<u8, &str>::from(Err("oops")), Remote::Error("oops"));
+        assert_eq!(Remote::<u8, ()>::from(None), Remote::Loading);
+        assert_eq!(Remote::<u8, ()>::from(Some(Ok(1))), Remote::Ready(1));
+    }
+
+    #[test]
+    fn accessors() {
+        let ready = Remote::<u8, ()>::Ready(1);
+        assert!(ready.is_ready());
+        assert_eq!(ready.ready(), Some(&1));
+        assert_eq!(ready.map(|value| value + 1), Remote::Ready(2));
+
+        let error = Remote::<u8, u8>::Error(2);
+        assert!(error.is_error());
+        assert_eq!(error.error(), Some(&2));
+        assert_eq!(error.map_err(|error| error * 2), Remote::Error(4));
+
+        assert!(Remote::<u8, ()>::default().is_loading());
+    }
+
+    #[test]
+    fn render_loading() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        Remote::<Line, String>::Loading.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["", "  Loading…  ", ""]);
+        expected.set_style(buf.area, Style::new().dim());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_error() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 3));
+        Remote::<Line, _>::Error("timeout").render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["", " Error: timeout ", ""]);
+        expected.set_style(buf.area, Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_ready() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        let mut state = ListState::default().with_selected(Some(1));
+        let list = Remote::<_, String>::Ready(List::new(["a", "b"]).highlight_symbol(">"));
+        StatefulWidget::render(list, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines([" a   ", ">b   "]));
+    }
+}
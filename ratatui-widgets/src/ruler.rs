@@ -0,0 +1,363 @@
+//! The [`HRuler`] and [`VRuler`] widgets display cell coordinates to help debugging layouts.
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled},
+    widgets::Widget,
+};
+
+/// The default interval between two labelled (major) ticks
+const DEFAULT_MAJOR_INTERVAL: u16 = 10;
+
+/// The default interval between two minor ticks
+const DEFAULT_MINOR_INTERVAL: u16 = 5;
+
+/// A debug widget that displays the column coordinates of the cells along a horizontal edge
+///
+/// The first row of the area displays a tick for every column: a major tick (`|`) every
+/// [`major_interval`] columns, a minor tick (`+`) every [`minor_interval`] columns and a dot
+/// (`.`) otherwise. If the area is at least two rows high, the second row displays the coordinate
+/// of each major tick.
+///
+/// The coordinates are the absolute column indices of the buffer, so rendering a ruler in the
+/// same area as a widget shows exactly which columns the widget occupies. This is useful to verify
+/// layout calculations and alignment while building an application.
+///
+/// [`major_interval`]: HRuler::major_interval
+/// [`minor_interval`]: HRuler::minor_interval
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::{layout::Rect, widgets::HRuler, Frame};
+///
+/// # fn draw(frame: &mut Frame) {
+/// let area = Rect::new(0, 0, 23, 2);
+/// frame.render_widget(HRuler::new(), area);
+/// # }
+/// ```
+///
+/// Renders:
+///
+/// ```text
+/// |....+....|....+....|..
+/// 0         10        20
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct HRuler {
+    style: Style,
+    major_interval: u16,
+    minor_interval: u16,
+}
+
+/// A debug widget that displays the row coordinates of the cells along a vertical edge
+///
+/// The first column of the area displays a tick for every row: a major tick (`-`) every
+/// [`major_interval`] rows, a minor tick (`+`) every [`minor_interval`] rows and a dot (`.`)
+/// otherwise. The remaining columns display the coordinate of each major tick.
+///
+/// The coordinates are the absolute row indices of the buffer. See [`HRuler`] for the horizontal
+/// counterpart.
+///
+/// [`major_interval`]: VRuler::major_interval
+/// [`minor_interval`]: VRuler::minor_interval
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::{layout::Rect, widgets::VRuler, Frame};
+///
+/// # fn draw(frame: &mut Frame) {
+/// let area = Rect::new(0, 0, 3, 12);
+/// frame.render_widget(VRuler::new(), area);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct VRuler {
+    style: Style,
+    major_interval: u16,
+    minor_interval: u16,
+}
+
+impl HRuler {
+    /// Creates a new horizontal ruler with a major tick every 10 and a minor tick every 5 columns
+    pub const fn new() -> Self {
+        Self {
+            style: Style::new(),
+            major_interval: DEFAULT_MAJOR_INTERVAL,
+            minor_interval: DEFAULT_MINOR_INTERVAL,
+        }
+    }
+
+    /// Sets the style of the ruler
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the number of columns between two labelled major ticks
+    ///
+    /// An interval of `0` disables the major ticks and labels.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn major_interval(mut self, interval: u16) -> Self {
+        self.major_interval = interval;
+        self
+    }
+
+    /// Sets the number of columns between two minor ticks
+    ///
+    /// An interval of `0` disables the minor ticks.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn minor_interval(mut self, interval: u16) -> Self {
+        self.minor_interval = interval;
+        self
+    }
+}
+
+impl VRuler {
+    /// Creates a new vertical ruler with a major tick every 10 and a minor tick every 5 rows
+    pub const fn new() -> Self {
+        Self {
+            style: Style::new(),
+            major_interval: DEFAULT_MAJOR_INTERVAL,
+            minor_interval: DEFAULT_MINOR_INTERVAL,
+        }
+    }
+
+    /// Sets the style of the ruler
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the number of rows between two labelled major ticks
+    ///
+    /// An interval of `0` disables the major ticks and labels.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn major_interval(mut self, interval: u16) -> Self {
+        self.major_interval = interval;
+        self
+    }
+
+    /// Sets the number of rows between two minor ticks
+    ///
+    /// An interval of `0` disables the minor ticks.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn minor_interval(mut self, interval: u16) -> Self {
+        self.minor_interval = interval;
+        self
+    }
+}
+
+impl Default for HRuler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for VRuler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns whether `coordinate` is a multiple of a non-zero `interval`
+const fn is_tick(coordinate: u16, interval: u16) -> bool {
+    interval != 0 && coordinate % interval == 0
+}
+
+impl Widget for HRuler {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &HRuler {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, self.style);
+        let mut label_end = area.left();
+        for x in area.left()..area.right() {
+            let symbol = if is_tick(x, self.major_interval) {
+                "|"
+            } else if is_tick(x, self.minor_interval) {
+                "+"
+            } else {
+                "."
+            };
+            buf[(x, area.top())].set_symbol(symbol);
+            // skip the labels that would overlap the previous one or that don't fit in the area
+            if area.height < 2 || !is_tick(x, self.major_interval) || x < label_end {
+                continue;
+            }
+            let label = x.to_string();
+            if label.len() <= (area.right() - x) as usize {
+                buf.set_string(x, area.top() + 1, &label, self.style);
+                label_end = x + label.len() as u16 + 1;
+            }
+        }
+    }
+}
+
+impl Widget for VRuler {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &VRuler {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, self.style);
+        for y in area.top()..area.bottom() {
+            let symbol = if is_tick(y, self.major_interval) {
+                "-"
+            } else if is_tick(y, self.minor_interval) {
+                "+"
+            } else {
+                "."
+            };
+            buf[(area.left(), y)].set_symbol(symbol);
+            if !is_tick(y, self.major_interval) {
+                continue;
+            }
+            let label = y.to_string();
+            if label.len() < area.width as usize {
+                buf.set_string(area.left() + 1, y, label, self.style);
+            }
+        }
+    }
+}
+
+impl Styled for HRuler {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Styled for VRuler {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    #[test]
+    fn hruler() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 23, 2));
+        HRuler::new().render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["|....+....|....+....|..", "0         10        20 "])
+        );
+    }
+
+    #[test]
+    fn hruler_uses_absolute_coordinates() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+        HRuler::new().render(Rect::new(3, 0, 9, 2), &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["   ..+....|.", "          10"]));
+    }
+
+    #[test]
+    fn hruler_skips_overlapping_labels() {
+        let area = Rect::new(98, 0, 7, 2);
+        let mut buf = Buffer::empty(area);
+        HRuler::new()
+            .major_interval(2)
+            .minor_interval(0)
+            .render(area, &mut buf);
+        let mut expected = Buffer::with_lines(["|.|.|.|", "98  102"]);
+        expected.area = area;
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn vruler_skips_truncated_labels() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 11));
+        VRuler::new().minor_interval(0).render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["-0", ". ", ". ", ". ", ". ", ". ", ". ", ". ", ". ", ". ", "- "])
+        );
+    }
+
+    #[test]
+    fn hruler_single_row() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        HRuler::new()
+            .major_interval(3)
+            .minor_interval(0)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["|..|.."]));
+    }
+
+    #[test]
+    fn vruler() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 12));
+        VRuler::new().render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "-0 ", ".  ", ".  ", ".  ", ".  ", "+  ", ".  ", ".  ", ".  ", ".  ", "-10", ".  ",
+            ])
+        );
+    }
+
+    #[test]
+    fn style() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        VRuler::new().red().render(Rect::new(0, 0, 1, 1), &mut buf);
+        let mut expected = Buffer::with_lines(["- "]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+}
@@ -7,17 +7,55 @@
     clippy::module_name_repetitions
 )]
 
-use std::iter;
+use std::{
+    hash::{Hash, Hasher},
+    iter, mem,
+    time::{Duration, Instant},
+};
 
+use itertools::Either;
 use ratatui_core::{
     buffer::Buffer,
-    layout::Rect,
-    style::Style,
-    symbols::scrollbar::{Set, DOUBLE_HORIZONTAL, DOUBLE_VERTICAL},
+    layout::{Position, Rect},
+    style::{Modifier, Style},
+    symbols::{
+        bar, block,
+        scrollbar::{Set, DOUBLE_HORIZONTAL, DOUBLE_VERTICAL},
+    },
+    text::DisplayWidth,
     widgets::StatefulWidget,
 };
 use strum::{Display, EnumString};
-use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    list::{List, ListState},
+    paragraph::ParagraphState,
+    table::{Table, TableState},
+};
+
+/// The symbols that fill the given number of eighths of a cell from the bottom
+const LOWER_EIGHTHS: [&str; 8] = [
+    " ",
+    bar::ONE_EIGHTH,
+    bar::ONE_QUARTER,
+    bar::THREE_EIGHTHS,
+    bar::HALF,
+    bar::FIVE_EIGHTHS,
+    bar::THREE_QUARTERS,
+    bar::SEVEN_EIGHTHS,
+];
+
+/// The symbols that fill the given number of eighths of a cell from the left
+const LEFT_EIGHTHS: [&str; 8] = [
+    " ",
+    block::ONE_EIGHTH,
+    block::ONE_QUARTER,
+    block::THREE_EIGHTHS,
+    block::HALF,
+    block::FIVE_EIGHTHS,
+    block::THREE_QUARTERS,
+    block::SEVEN_EIGHTHS,
+];
 
 /// A widget to display a scrollbar
 ///
@@ -34,6 +72,10 @@ use unicode_width::UnicodeWidthStr;
 /// └─────────── begin
 /// ```
 ///
+/// The length of the thumb is proportional to the ratio of the viewport length to the content
+/// length, so that the size of the content can be judged at a glance. Its minimum length can be
+/// set with [`Scrollbar::min_thumb_length`].
+///
 /// # Important
 ///
 /// You must specify the [`ScrollbarState::content_length`] before rendering the `Scrollbar`, or
@@ -96,6 +138,11 @@ pub struct Scrollbar<'a> {
     begin_style: Style,
     end_symbol: Option<&'a str>,
     end_style: Style,
+    min_thumb_length: u16,
+    auto_hide: ScrollbarAutoHide,
+    fade_after: Option<Duration>,
+    fade_style: Style,
+    smooth: bool,
 }
 
 /// This is the position of the scrollbar around a given area.
@@ -120,6 +167,20 @@ pub enum ScrollbarOrientation {
     HorizontalTop,
 }
 
+/// Defines what a [`Scrollbar`] displays when the content fits in the viewport.
+///
+/// See [`Scrollbar::auto_hide`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ScrollbarAutoHide {
+    /// The scrollbar is always displayed
+    #[default]
+    Never,
+    /// Nothing is displayed when the content fits in the viewport
+    Hide,
+    /// Only the begin and end symbols are displayed when the content fits in the viewport
+    KeepArrows,
+}
+
 /// A struct representing the state of a Scrollbar widget.
 ///
 /// # Important
@@ -145,7 +206,40 @@ pub enum ScrollbarOrientation {
 ///
 /// If you don't have multi-line content, you can leave the `viewport_content_length` set to the
 /// default and it'll use the track size as a `viewport_content_length`.
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+///
+/// # Mouse interaction
+///
+/// When the scrollbar is rendered, the layout of its parts is stored in the state, so that mouse
+/// events can be handled without computing the geometry of the scrollbar again:
+///
+/// - [`ScrollbarState::part_at`] returns the [`ScrollbarPart`] under the mouse cursor
+/// - [`ScrollbarState::position_at`] returns the content position to jump to when the track is
+///   clicked
+/// - [`ScrollbarState::start_drag`], [`ScrollbarState::drag_to`] and [`ScrollbarState::end_drag`]
+///   move the thumb with the mouse
+///
+/// ```rust
+/// use ratatui::{
+///     buffer::Buffer,
+///     layout::{Position, Rect},
+///     widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget},
+/// };
+///
+/// let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight);
+/// let mut state = ScrollbarState::new(100);
+/// let area = Rect::new(0, 0, 1, 10);
+/// let mut buf = Buffer::empty(area);
+/// scrollbar.render(area, &mut buf, &mut state);
+///
+/// // on mouse down
+/// state.start_drag(Position::new(0, 1));
+/// // on mouse drag
+/// state.drag_to(Position::new(0, 5));
+/// // on mouse up
+/// state.end_drag();
+/// assert!(state.get_position() > 0);
+/// ```
+#[derive(Debug, Default, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ScrollbarState {
     /// The total length of the scrollable content.
@@ -156,6 +250,139 @@ pub struct ScrollbarState {
     ///
     /// FIXME: this should be `Option<usize>`, but it will break serialization to change it.
     viewport_content_length: usize,
+    /// The markers displayed on the track of the scrollbar.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    markers: Vec<ScrollbarMarker>,
+    /// The layout of the scrollbar during the last render.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    layout: Option<ScrollbarLayout>,
+    /// The offset of the mouse cursor from the start of the thumb while it is dragged.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    drag_offset: Option<u16>,
+    /// Whether the scroll position was changed since the last render.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scrolled: bool,
+    /// The time of the first render of a fading scrollbar after the scroll position was changed.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_activity: Option<Instant>,
+}
+
+/// Compares the scroll position and the markers of the states, ignoring the layout, drag and
+/// activity state that is only kept between renders for the interaction with the scrollbar.
+impl PartialEq for ScrollbarState {
+    fn eq(&self, other: &Self) -> bool {
+        self.content_length == other.content_length
+            && self.position == other.position
+            && self.viewport_content_length == other.viewport_content_length
+            && self.markers == other.markers
+    }
+}
+
+impl Eq for ScrollbarState {}
+
+impl Hash for ScrollbarState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.content_length.hash(state);
+        self.position.hash(state);
+        self.viewport_content_length.hash(state);
+        self.markers.hash(state);
+    }
+}
+
+/// A part of a [`Scrollbar`], as returned by [`ScrollbarState::part_at`].
+///
+/// ```text
+/// <═══█████═══════>
+/// ^ ^   ^     ^   ^
+/// │ │   │     │   └ End
+/// │ │   │     └──── TrackEnd
+/// │ │   └────────── Thumb
+/// │ └────────────── TrackStart
+/// └──────────────── Begin
+/// ```
+#[derive(Debug, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ScrollbarPart {
+    /// The begin symbol (e.g. the up arrow of a vertical scrollbar)
+    Begin,
+    /// The part of the track before the thumb
+    TrackStart,
+    /// The thumb
+    Thumb,
+    /// The part of the track after the thumb
+    TrackEnd,
+    /// The end symbol (e.g. the down arrow of a vertical scrollbar)
+    End,
+}
+
+/// A marker displayed on the track of a [`Scrollbar`] at a position of the content.
+///
+/// Markers show where interesting parts of the content are, e.g. search matches, errors or
+/// bookmarks, like the overview ruler of an editor. A marker is displayed in the cell of the track
+/// that corresponds to its position, over the track and the thumb, by applying its style to the
+/// cell and replacing its symbol if the marker has one. See [`ScrollbarState::markers`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     style::Color,
+///     widgets::{ScrollbarMarker, ScrollbarState},
+/// };
+///
+/// let errors = [12, 48];
+/// let state = ScrollbarState::new(100).markers(
+///     errors
+///         .iter()
+///         .map(|&line| ScrollbarMarker::new(line, Color::Red).symbol("━")),
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ScrollbarMarker {
+    /// The position of the marker in the content
+    pub position: usize,
+    /// The style applied to the cell of the marker
+    pub style: Style,
+    /// The symbol of the marker, or `None` to keep the symbol of the track or thumb
+    pub symbol: Option<&'static str>,
+}
+
+impl ScrollbarMarker {
+    /// Creates a new marker at the given position of the content, with the given style.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    pub fn new<S: Into<Style>>(position: usize, style: S) -> Self {
+        Self {
+            position,
+            style: style.into(),
+            symbol: None,
+        }
+    }
+
+    /// Sets the symbol of the marker.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbol(mut self, symbol: &'static str) -> Self {
+        self.symbol = Some(symbol);
+        self
+    }
+}
+
+/// The layout of the parts of a scrollbar, stored in the [`ScrollbarState`] when it is rendered
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+struct ScrollbarLayout {
+    /// The area of the scrollbar, including the begin and end symbols
+    area: Rect,
+    vertical: bool,
+    /// The offset of the track from the start of the area
+    track_start: u16,
+    track_length: u16,
+    /// The offset of the thumb from the start of the track
+    thumb_start: u16,
+    thumb_length: u16,
 }
 
 /// An enum representing a scrolling direction.
@@ -206,6 +433,11 @@ impl<'a> Scrollbar<'a> {
             begin_style: Style::new(),
             end_symbol: Some(symbols.end),
             end_style: Style::new(),
+            min_thumb_length: 1,
+            auto_hide: ScrollbarAutoHide::Never,
+            fade_after: None,
+            fade_style: Style::new().add_modifier(Modifier::DIM),
+            smooth: false,
         }
     }
 
@@ -386,6 +618,118 @@ impl<'a> Scrollbar<'a> {
         self
     }
 
+    /// Sets the minimum length of the thumb.
+    ///
+    /// The length of the thumb is proportional to the ratio of the viewport length to the content
+    /// length, so it can get very small for long content. This makes sure that it is at least the
+    /// given number of cells long (or the length of the track if it is shorter), so that it stays
+    /// easy to see and to grab. Defaults to 1, and values lower than 1 are treated as 1.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Scrollbar, ScrollbarOrientation};
+    ///
+    /// let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight).min_thumb_length(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn min_thumb_length(mut self, min_thumb_length: u16) -> Self {
+        self.min_thumb_length = min_thumb_length;
+        self
+    }
+
+    /// Sets what the scrollbar displays when the content fits in the viewport.
+    ///
+    /// The content fits when the [content length](ScrollbarState::content_length) is not larger
+    /// than the [viewport length](ScrollbarState::viewport_content_length). By default, the
+    /// scrollbar is always displayed. See [`ScrollbarAutoHide`] for the other options.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Scrollbar, ScrollbarAutoHide, ScrollbarOrientation};
+    ///
+    /// let scrollbar =
+    ///     Scrollbar::new(ScrollbarOrientation::VerticalRight).auto_hide(ScrollbarAutoHide::Hide);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn auto_hide(mut self, auto_hide: ScrollbarAutoHide) -> Self {
+        self.auto_hide = auto_hide;
+        self
+    }
+
+    /// Fades the scrollbar out when it has not been scrolled for the given duration.
+    ///
+    /// The scrollbar is faded by applying the [fade style](Scrollbar::fade_style) over all its
+    /// parts. The methods of the [`ScrollbarState`] that change the position (e.g.
+    /// [`ScrollbarState::next`] or [`ScrollbarState::drag_to`]) and [`ScrollbarState::touch`]
+    /// mark the state as scrolled, and the next render of the scrollbar stores the current time in
+    /// the state. A scrollbar that has never been scrolled is faded.
+    ///
+    /// The scrollbar doesn't trigger redraws by itself, so the application must redraw after the
+    /// duration for the scrollbar to be faded.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use ratatui::widgets::{Scrollbar, ScrollbarOrientation};
+    ///
+    /// let scrollbar =
+    ///     Scrollbar::new(ScrollbarOrientation::VerticalRight).fade_after(Duration::from_secs(2));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn fade_after(mut self, duration: Duration) -> Self {
+        self.fade_after = Some(duration);
+        self
+    }
+
+    /// Sets the style applied over the scrollbar when it is faded.
+    ///
+    /// Defaults to the [`Modifier::DIM`] modifier. See [`Scrollbar::fade_after`].
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn fade_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.fade_style = style.into();
+        self
+    }
+
+    /// Moves the thumb smoothly, in eighths of cells.
+    ///
+    /// By default, the thumb is positioned in whole cells, so it jumps from one cell to the next
+    /// when the content is much longer than the track. When the scrollbar is smooth, the cells at
+    /// the ends of the thumb are displayed with partial block symbols (e.g. `▃` or `▌`), so that
+    /// the thumb moves by eighths of cells. This works best with a full block thumb symbol (`█`,
+    /// the default), and the partial symbols are displayed with the thumb style.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Scrollbar, ScrollbarOrientation};
+    ///
+    /// let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight).smooth(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn smooth(mut self, smooth: bool) -> Self {
+        self.smooth = smooth;
+        self
+    }
+
     /// Sets the style used for the various parts of the scrollbar from a [`Style`].
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -425,6 +769,11 @@ impl ScrollbarState {
             content_length,
             position: 0,
             viewport_content_length: 0,
+            markers: Vec::new(),
+            layout: None,
+            drag_offset: None,
+            scrolled: false,
+            last_activity: None,
         }
     }
 
@@ -460,9 +809,41 @@ impl ScrollbarState {
         self
     }
 
+    /// Sets the markers displayed on the track of the scrollbar.
+    ///
+    /// Markers show where interesting parts of the content are, e.g. search matches. See
+    /// [`ScrollbarMarker`] for more information.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn markers<I>(mut self, markers: I) -> Self
+    where
+        I: IntoIterator<Item = ScrollbarMarker>,
+    {
+        self.set_markers(markers);
+        self
+    }
+
+    /// Replaces the markers displayed on the track of the scrollbar.
+    ///
+    /// Use this to update the markers when the content changes, e.g. after a new search.
+    pub fn set_markers<I>(&mut self, markers: I)
+    where
+        I: IntoIterator<Item = ScrollbarMarker>,
+    {
+        self.markers = markers.into_iter().collect();
+    }
+
+    /// Returns the markers displayed on the track of the scrollbar.
+    #[must_use = "returns the markers of the scrollbar"]
+    pub fn get_markers(&self) -> &[ScrollbarMarker] {
+        &self.markers
+    }
+
     /// Decrements the scroll position by one, ensuring it doesn't go below zero.
     pub fn prev(&mut self) {
         self.position = self.position.saturating_sub(1);
+        self.touch();
     }
 
     /// Increments the scroll position by one, ensuring it doesn't exceed the length of the content.
@@ -471,16 +852,38 @@ impl ScrollbarState {
             .position
             .saturating_add(1)
             .min(self.content_length.saturating_sub(1));
+        self.touch();
     }
 
     /// Sets the scroll position to the start of the scrollable content.
     pub fn first(&mut self) {
         self.position = 0;
+        self.touch();
     }
 
     /// Sets the scroll position to the end of the scrollable content.
     pub fn last(&mut self) {
         self.position = self.content_length.saturating_sub(1);
+        self.touch();
+    }
+
+    /// Records that the content was scrolled, so that a [fading](Scrollbar::fade_after)
+    /// scrollbar is displayed again.
+    ///
+    /// This is called by the methods of the state that change the position. Call it when the
+    /// position is changed in another way, e.g. with [`ScrollbarState::position`].
+    ///
+    /// This doesn't read the clock: the time of the scroll is recorded by the next render of a
+    /// scrollbar that fades, so states used with other scrollbars don't depend on the time.
+    pub fn touch(&mut self) {
+        self.scrolled = true;
+    }
+
+    /// Returns the time at which a [fading](Scrollbar::fade_after) scrollbar was last rendered
+    /// after the content was scrolled, if it was.
+    #[must_use = "returns the last time the content was scrolled"]
+    pub const fn last_activity(&self) -> Option<Instant> {
+        self.last_activity
     }
 
     /// Changes the scroll position based on the provided [`ScrollDirection`].
@@ -500,35 +903,311 @@ impl ScrollbarState {
     pub const fn get_position(&self) -> usize {
         self.position
     }
+
+    /// Updates the content length, the viewport length and the position from the scroll offset
+    /// of a widget.
+    ///
+    /// `content_length` and `viewport_length` are the lengths of the content and of the area
+    /// that displays it, and `offset` is the length of the content scrolled past the start of
+    /// the viewport (e.g. in rows). The position is scaled so that the thumb reaches the end of the
+    /// track when the end of the content is displayed, i.e. when the offset is
+    /// `content_length - viewport_length`.
+    ///
+    /// [`ScrollbarState::sync_with_list`], [`ScrollbarState::sync_with_table`] and
+    /// [`ScrollbarState::sync_with_paragraph`] call this with the values of a rendered widget.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ScrollbarState;
+    ///
+    /// let mut state = ScrollbarState::default();
+    /// // 100 lines, 20 of which are displayed, scrolled to the end
+    /// state.sync_with_offset(100, 20, 80);
+    /// assert_eq!(state.get_position(), 99);
+    /// ```
+    pub fn sync_with_offset(
+        &mut self,
+        content_length: usize,
+        viewport_length: usize,
+        offset: usize,
+    ) {
+        let max_offset = content_length.saturating_sub(viewport_length);
+        let position = (offset.min(max_offset) * content_length.saturating_sub(1))
+            .checked_div(max_offset)
+            .unwrap_or(0);
+        if position != self.position {
+            self.touch();
+        }
+        self.content_length = content_length;
+        self.viewport_content_length = viewport_length;
+        self.position = position;
+    }
+
+    /// Updates the state from a [`List`] that was rendered in the given area with the given
+    /// state.
+    ///
+    /// The content and viewport lengths are measured in rows, so that lists with multi-line items
+    /// are displayed correctly. Call this after rendering the list, as rendering changes the offset
+    /// of the list state to keep the selected item visible.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     layout::Rect,
+    ///     widgets::{List, ListState, Scrollbar, ScrollbarState},
+    ///     Frame,
+    /// };
+    ///
+    /// # fn ui(frame: &mut Frame, list_state: &mut ListState, scrollbar_state: &mut ScrollbarState) {
+    /// let area = frame.area();
+    /// let list = List::new(["Item 1", "Item 2", "Item 3"]);
+    /// frame.render_stateful_widget(&list, area, list_state);
+    /// scrollbar_state.sync_with_list(&list, list_state, area);
+    /// frame.render_stateful_widget(Scrollbar::default(), area, scrollbar_state);
+    /// # }
+    /// ```
+    pub fn sync_with_list(&mut self, list: &List, list_state: &ListState, area: Rect) {
+        let (content_height, viewport_height, offset) = list.scroll_metrics(list_state, area);
+        self.sync_with_offset(content_height, viewport_height, offset);
+    }
+
+    /// Updates the state from a [`Table`] that was rendered in the given area with the given
+    /// state.
+    ///
+    /// The content and viewport lengths are measured in rows, including the margins of the rows
+    /// and excluding the header and the footer. Call this after rendering the table, as rendering
+    /// changes the offset of the table state to keep the selected row visible.
+    pub fn sync_with_table(&mut self, table: &Table, table_state: &TableState, area: Rect) {
+        let (content_height, viewport_height, offset) = table.scroll_metrics(table_state, area);
+        self.sync_with_offset(content_height, viewport_height, offset);
+    }
+
+    /// Updates the state from the state of a rendered [`Paragraph`].
+    ///
+    /// The content and viewport lengths are measured in (wrapped) lines, as they were during the
+    /// last render of the paragraph.
+    ///
+    /// [`Paragraph`]: crate::paragraph::Paragraph
+    pub fn sync_with_paragraph(&mut self, paragraph_state: &ParagraphState) {
+        self.sync_with_offset(
+            paragraph_state.content_height(),
+            usize::from(paragraph_state.viewport().height),
+            usize::from(paragraph_state.offset()),
+        );
+    }
+
+    /// Returns the part of the scrollbar at the given position of the screen.
+    ///
+    /// Returns `None` if the position is outside of the scrollbar, or if the scrollbar was not
+    /// rendered yet. This uses the layout of the last render.
+    #[must_use = "returns the part of the scrollbar at the given position"]
+    pub fn part_at(&self, position: Position) -> Option<ScrollbarPart> {
+        let layout = self.layout?;
+        if !layout.area.contains(position) {
+            return None;
+        }
+        let offset = layout.offset(position);
+        let part = if offset < layout.track_start {
+            ScrollbarPart::Begin
+        } else if offset >= layout.track_start + layout.track_length {
+            ScrollbarPart::End
+        } else if offset < layout.track_start + layout.thumb_start {
+            ScrollbarPart::TrackStart
+        } else if offset < layout.track_start + layout.thumb_start + layout.thumb_length {
+            ScrollbarPart::Thumb
+        } else {
+            ScrollbarPart::TrackEnd
+        };
+        Some(part)
+    }
+
+    /// Returns the content position that centers the thumb on the given position of the screen.
+    ///
+    /// This is useful to jump to the clicked position when the track is clicked. Returns `None`
+    /// if the position is not on the track (or the thumb) of the scrollbar, or if the scrollbar
+    /// was not rendered yet.
+    #[must_use = "returns the content position at the given position"]
+    pub fn position_at(&self, position: Position) -> Option<usize> {
+        if !matches!(
+            self.part_at(position)?,
+            ScrollbarPart::TrackStart | ScrollbarPart::Thumb | ScrollbarPart::TrackEnd
+        ) {
+            return None;
+        }
+        let layout = self.layout?;
+        let thumb_start = i32::from(layout.offset(position))
+            - i32::from(layout.track_start)
+            - i32::from(layout.thumb_length / 2);
+        Some(self.content_position(&layout, thumb_start))
+    }
+
+    /// Starts dragging the thumb if the given position of the screen is on the track.
+    ///
+    /// If the position is on the thumb, the thumb is grabbed where it was clicked. If it is on
+    /// the rest of the track, the scroll position first jumps so that the thumb is centered on
+    /// the position. Returns whether dragging started.
+    ///
+    /// Call [`ScrollbarState::drag_to`] when the mouse moves, and [`ScrollbarState::end_drag`]
+    /// when the mouse button is released.
+    pub fn start_drag(&mut self, position: Position) -> bool {
+        let Some(layout) = self.layout else {
+            return false;
+        };
+        match self.part_at(position) {
+            Some(ScrollbarPart::Thumb) => {
+                let offset = layout.offset(position) - layout.track_start - layout.thumb_start;
+                self.drag_offset = Some(offset);
+            }
+            Some(ScrollbarPart::TrackStart | ScrollbarPart::TrackEnd) => {
+                self.position = self.position_at(position).unwrap_or(self.position);
+                self.drag_offset = Some(layout.thumb_length / 2);
+            }
+            _ => return false,
+        }
+        self.touch();
+        true
+    }
+
+    /// Moves the thumb so that it follows the given position of the screen while it is dragged.
+    ///
+    /// Only the coordinate along the scrollbar is used, so dragging continues when the mouse
+    /// cursor leaves the scrollbar. Does nothing and returns `false` if the thumb is not being
+    /// dragged.
+    pub fn drag_to(&mut self, position: Position) -> bool {
+        let (Some(layout), Some(drag_offset)) = (self.layout, self.drag_offset) else {
+            return false;
+        };
+        let thumb_start = i32::from(layout.offset(position))
+            - i32::from(layout.track_start)
+            - i32::from(drag_offset);
+        self.position = self.content_position(&layout, thumb_start);
+        self.touch();
+        true
+    }
+
+    /// Stops dragging the thumb.
+    pub fn end_drag(&mut self) {
+        self.drag_offset = None;
+    }
+
+    /// Returns whether the thumb is being dragged.
+    #[must_use = "returns whether the thumb is being dragged"]
+    pub const fn is_dragging(&self) -> bool {
+        self.drag_offset.is_some()
+    }
+
+    /// Returns the content position for a thumb that starts at the given offset of the track
+    fn content_position(&self, layout: &ScrollbarLayout, thumb_start: i32) -> usize {
+        let max_position = self.content_length.saturating_sub(1);
+        let free_length = layout.track_length.saturating_sub(layout.thumb_length);
+        if free_length == 0 {
+            return 0;
+        }
+        let position = f64::from(thumb_start) * max_position as f64 / f64::from(free_length);
+        position.round().clamp(0.0, max_position as f64) as usize
+    }
+}
+
+impl ScrollbarLayout {
+    /// Returns the offset of the given position from the start of the scrollbar, along the
+    /// scrollbar
+    const fn offset(&self, position: Position) -> u16 {
+        if self.vertical {
+            position.y.saturating_sub(self.area.y)
+        } else {
+            position.x.saturating_sub(self.area.x)
+        }
+    }
 }
 
 impl StatefulWidget for Scrollbar<'_> {
     type State = ScrollbarState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.layout = None;
         if state.content_length == 0 || self.track_length_excluding_arrow_heads(area) == 0 {
             return;
         }
+        let Some(area) = self.scrollbar_area(area) else {
+            return;
+        };
 
-        if let Some(area) = self.scrollbar_area(area) {
-            let areas = area.columns().flat_map(Rect::rows);
-            let bar_symbols = self.bar_symbols(area, state);
-            for (area, bar) in areas.zip(bar_symbols) {
-                if let Some((symbol, style)) = bar {
-                    buf.set_string(area.x, area.y, symbol, style);
-                }
+        let fits = state.content_length <= self.viewport_length(state, area);
+        let bar_symbols = match self.auto_hide {
+            ScrollbarAutoHide::Hide if fits => return,
+            ScrollbarAutoHide::KeepArrows if fits => Either::Left(self.arrow_symbols(area)),
+            _ => {
+                let (thumb_start, thumb_length, _) = self.part_lengths(area, state);
+                state.layout = Some(ScrollbarLayout {
+                    area,
+                    vertical: self.orientation.is_vertical(),
+                    track_start: self.begin_symbol.map_or(0, |s| s.display_width() as u16),
+                    track_length: self.track_length_excluding_arrow_heads(area),
+                    thumb_start: thumb_start as u16,
+                    thumb_length: thumb_length as u16,
+                });
+                Either::Right(self.bar_symbols(area, state))
+            }
+        };
+        // the clock is only read by scrollbars that fade
+        if self.fade_after.is_some() && mem::take(&mut state.scrolled) {
+            state.last_activity = Some(Instant::now());
+        }
+        let faded = self.fade_after.is_some_and(
+            |duration| !matches!(state.last_activity, Some(time) if time.elapsed() < duration),
+        );
+        let fade_style = if faded { self.fade_style } else { Style::new() };
+
+        let areas = area.columns().flat_map(Rect::rows);
+        for (area, bar) in areas.zip(bar_symbols) {
+            if let Some((symbol, style)) = bar {
+                buf.set_string(area.x, area.y, symbol, style.patch(fade_style));
             }
         }
+        self.render_markers(area, buf, state, fade_style);
     }
 }
 
 impl Scrollbar<'_> {
+    /// Renders the markers on the track of the scrollbar
+    fn render_markers(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &ScrollbarState,
+        fade_style: Style,
+    ) {
+        let track_start = self.begin_symbol.map_or(0, |s| s.display_width() as u16);
+        let track_length = self.track_length_excluding_arrow_heads(area);
+        for marker in &state.markers {
+            // scaled in u128 so that large content lengths don't overflow
+            let position = marker.position.min(state.content_length - 1) as u128;
+            let cell = (position * u128::from(track_length) / state.content_length as u128) as u16;
+            let offset = track_start + cell;
+            let position = if self.orientation.is_vertical() {
+                (area.x, area.y + offset)
+            } else {
+                (area.x + offset, area.y)
+            };
+            let cell = &mut buf[position];
+            if let Some(symbol) = marker.symbol {
+                cell.set_symbol(symbol);
+            }
+            cell.set_style(marker.style.patch(fade_style));
+        }
+    }
+
     /// Returns an iterator over the symbols and styles of the scrollbar.
     fn bar_symbols(
         &self,
         area: Rect,
         state: &ScrollbarState,
     ) -> impl Iterator<Item = Option<(&str, Style)>> {
+        if self.smooth {
+            return Either::Left(self.smooth_bar_symbols(area, state));
+        }
         let (track_start_len, thumb_len, track_end_len) = self.part_lengths(area, state);
 
         let begin = self.begin_symbol.map(|s| Some((s, self.begin_style)));
@@ -537,7 +1216,7 @@ impl Scrollbar<'_> {
         let end = self.end_symbol.map(|s| Some((s, self.end_style)));
 
         // `<`
-        iter::once(begin)
+        let symbols = iter::once(begin)
             // `<═══`
             .chain(iter::repeat(track).take(track_start_len))
             // `<═══█████`
@@ -546,6 +1225,60 @@ impl Scrollbar<'_> {
             .chain(iter::repeat(track).take(track_end_len))
             // `<═══█████═══════>`
             .chain(iter::once(end))
+            .flatten();
+        Either::Right(symbols)
+    }
+
+    /// Returns an iterator over the symbols and styles of the scrollbar, with a thumb that is
+    /// positioned in eighths of cells.
+    ///
+    /// The cells that are partially covered by the thumb are displayed with partial block
+    /// symbols. As there are no partial block symbols for all the eighths that are aligned to the
+    /// top (or the right) of a cell, these are displayed by reversing the symbol of the uncovered
+    /// part of the cell.
+    fn smooth_bar_symbols(
+        &self,
+        area: Rect,
+        state: &ScrollbarState,
+    ) -> impl Iterator<Item = Option<(&str, Style)>> {
+        let (thumb_start, thumb_length) = self.thumb_range(area, state, 8);
+        let thumb_end = thumb_start + thumb_length;
+        let track_length = self.track_length_excluding_arrow_heads(area);
+        let vertical = self.orientation.is_vertical();
+        let reversed = self.thumb_style.add_modifier(Modifier::REVERSED);
+
+        let begin = self.begin_symbol.map(|s| Some((s, self.begin_style)));
+        let end = self.end_symbol.map(|s| Some((s, self.end_style)));
+        let track = (0..usize::from(track_length)).map(move |cell| {
+            let cell_start = cell * 8;
+            let covered_start = thumb_start.max(cell_start);
+            let covered = thumb_end.min(cell_start + 8).saturating_sub(covered_start);
+            let covers_start = covered_start == cell_start;
+            let symbol = match covered {
+                0 => return Some(self.track_symbol.map(|s| (s, self.track_style))),
+                8 => (self.thumb_symbol, self.thumb_style),
+                _ if vertical && covers_start => (LOWER_EIGHTHS[8 - covered], reversed),
+                _ if vertical => (LOWER_EIGHTHS[covered], self.thumb_style),
+                _ if covers_start => (LEFT_EIGHTHS[covered], self.thumb_style),
+                _ => (LEFT_EIGHTHS[8 - covered], reversed),
+            };
+            Some(Some(symbol))
+        });
+        iter::once(begin)
+            .chain(track)
+            .chain(iter::once(end))
+            .flatten()
+    }
+
+    /// Returns an iterator over the symbols and styles of the begin and end symbols of the
+    /// scrollbar, without the track and the thumb.
+    fn arrow_symbols(&self, area: Rect) -> impl Iterator<Item = Option<(&str, Style)>> {
+        let track_len = self.track_length_excluding_arrow_heads(area) as usize;
+        let begin = self.begin_symbol.map(|s| Some((s, self.begin_style)));
+        let end = self.end_symbol.map(|s| Some((s, self.end_style)));
+        iter::once(begin)
+            .chain(iter::repeat(Some(None)).take(track_len))
+            .chain(iter::once(end))
             .flatten()
     }
 
@@ -559,7 +1292,19 @@ impl Scrollbar<'_> {
     ///
     /// This method returns the length of the start, thumb, and end as a tuple.
     fn part_lengths(&self, area: Rect, state: &ScrollbarState) -> (usize, usize, usize) {
-        let track_length = f64::from(self.track_length_excluding_arrow_heads(area));
+        let (thumb_start, thumb_length) = self.thumb_range(area, state, 1);
+        let track_length = self.track_length_excluding_arrow_heads(area) as usize;
+        let track_end_length = track_length.saturating_sub(thumb_start + thumb_length);
+        (thumb_start, thumb_length, track_end_length)
+    }
+
+    /// Returns the start and the length of the thumb, in `1 / scale` cells
+    ///
+    /// A scale of 1 gives the thumb in whole cells, and a scale of 8 gives it in eighths of cells
+    /// for the [smooth](Scrollbar::smooth) scrollbar.
+    fn thumb_range(&self, area: Rect, state: &ScrollbarState, scale: u16) -> (usize, usize) {
+        let track_length =
+            f64::from(self.track_length_excluding_arrow_heads(area)) * f64::from(scale);
         let viewport_length = self.viewport_length(state, area) as f64;
 
         // Ensure that the position of the thumb is within the bounds of the content taking into
@@ -575,16 +1320,26 @@ impl Scrollbar<'_> {
         let thumb_start = start_position * track_length / max_viewport_position;
         let thumb_end = end_position * track_length / max_viewport_position;
 
-        // Make sure that the thumb is at least 1 cell long by ensuring that the start of the thumb
+        // Make sure that the thumb is at least 1 unit long by ensuring that the start of the thumb
         // is less than the track_len. We use the positions instead of the sizes and use nearest
         // integer instead of floor / ceil to avoid problems caused by rounding errors.
-        let thumb_start = thumb_start.round().clamp(0.0, track_length - 1.0) as usize;
+        let mut thumb_start = thumb_start.round().clamp(0.0, track_length - 1.0) as usize;
         let thumb_end = thumb_end.round().clamp(0.0, track_length) as usize;
 
-        let thumb_length = thumb_end.saturating_sub(thumb_start).max(1);
-        let track_end_length = (track_length as usize).saturating_sub(thumb_start + thumb_length);
+        // Grow the thumb around its center if it is shorter than the minimum length, keeping it
+        // inside of the track.
+        let min_thumb_length = (usize::from(self.min_thumb_length.max(1)) * usize::from(scale))
+            .min(track_length as usize);
+        let mut thumb_length = thumb_end.saturating_sub(thumb_start);
+        if thumb_length < min_thumb_length {
+            let center = (thumb_start + thumb_end) / 2;
+            thumb_start = center
+                .saturating_sub(min_thumb_length / 2)
+                .min(track_length as usize - min_thumb_length);
+            thumb_length = min_thumb_length;
+        }
 
-        (thumb_start, thumb_length, track_end_length)
+        (thumb_start, thumb_length)
     }
 
     fn scrollbar_area(&self, area: Rect) -> Option<Rect> {
@@ -604,8 +1359,8 @@ impl Scrollbar<'_> {
     /// <═══█████═══════>
     /// ```
     fn track_length_excluding_arrow_heads(&self, area: Rect) -> u16 {
-        let start_len = self.begin_symbol.map_or(0, |s| s.width() as u16);
-        let end_len = self.end_symbol.map_or(0, |s| s.width() as u16);
+        let start_len = self.begin_symbol.map_or(0, |s| s.display_width() as u16);
+        let end_len = self.end_symbol.map_or(0, |s| s.display_width() as u16);
         let arrows_len = start_len.saturating_add(end_len);
         if self.orientation.is_vertical() {
             area.height.saturating_sub(arrows_len)
@@ -643,11 +1398,13 @@ impl ScrollbarOrientation {
 mod tests {
     use std::str::FromStr;
 
-    use ratatui_core::{text::Text, widgets::Widget};
+    use ratatui_core::{layout::Constraint, style::Color, text::Text, widgets::Widget};
     use rstest::{fixture, rstest};
     use strum::ParseError;
+    use unicode_width::UnicodeWidthStr;
 
     use super::*;
+    use crate::{block::Block, paragraph::Paragraph, table::Row};
 
     #[test]
     fn scroll_direction_to_string() {
@@ -1071,6 +1828,333 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines([expected]));
     }
 
+    #[rstest]
+    #[case::position_0("###-------", 0)]
+    #[case::position_10("----###---", 10)]
+    #[case::position_19("-------###", 19)]
+    #[case::position_one_out_of_bounds("-------###", 20)]
+    fn min_thumb_length(
+        #[case] expected: &str,
+        #[case] position: usize,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let size = expected.width() as u16;
+        let mut buffer = Buffer::empty(Rect::new(0, 0, size, 1));
+        let mut state = ScrollbarState::new(20)
+            .position(position)
+            .viewport_content_length(1);
+        scrollbar_no_arrows
+            .min_thumb_length(3)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[rstest]
+    fn min_thumb_length_longer_than_track(scrollbar_no_arrows: Scrollbar) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        let mut state = ScrollbarState::new(100).position(50);
+        scrollbar_no_arrows
+            .min_thumb_length(6)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["####"]));
+    }
+
+    #[rstest]
+    #[case::outside(Position::new(0, 1), None)]
+    #[case::begin(Position::new(0, 0), Some(ScrollbarPart::Begin))]
+    #[case::thumb_start(Position::new(1, 0), Some(ScrollbarPart::Thumb))]
+    #[case::thumb_end(Position::new(6, 0), Some(ScrollbarPart::Thumb))]
+    #[case::track_end(Position::new(7, 0), Some(ScrollbarPart::TrackEnd))]
+    #[case::end(Position::new(11, 0), Some(ScrollbarPart::End))]
+    fn part_at(#[case] position: Position, #[case] expected: Option<ScrollbarPart>) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 2));
+        let mut state = ScrollbarState::new(10);
+        assert_eq!(state.part_at(position), None);
+        Scrollbar::new(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(Some("<"))
+            .end_symbol(Some(">"))
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.part_at(position), expected);
+    }
+
+    #[test]
+    fn part_at_vertical() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 10));
+        let mut state = ScrollbarState::new(10).position(9);
+        Scrollbar::new(ScrollbarOrientation::VerticalLeft)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(
+            state.part_at(Position::new(0, 0)),
+            Some(ScrollbarPart::TrackStart)
+        );
+        assert_eq!(
+            state.part_at(Position::new(0, 9)),
+            Some(ScrollbarPart::Thumb)
+        );
+        assert_eq!(state.part_at(Position::new(1, 9)), None);
+    }
+
+    #[test]
+    fn drag_thumb() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        let mut state = ScrollbarState::new(10);
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(Some("<"))
+            .end_symbol(Some(">"));
+        scrollbar.render(buffer.area, &mut buffer, &mut state);
+
+        assert!(!state.drag_to(Position::new(8, 0)));
+        assert!(state.start_drag(Position::new(3, 0)));
+        assert!(state.is_dragging());
+        assert!(state.drag_to(Position::new(8, 0)));
+        assert_eq!(state.get_position(), 9);
+        assert!(state.drag_to(Position::new(4, 5)));
+        assert_eq!(state.get_position(), 2);
+        assert!(state.drag_to(Position::new(0, 0)));
+        assert_eq!(state.get_position(), 0);
+        state.end_drag();
+        assert!(!state.is_dragging());
+        assert!(!state.drag_to(Position::new(8, 0)));
+        assert!(!state.start_drag(Position::new(0, 0)));
+    }
+
+    #[test]
+    fn equality_ignores_interaction_state() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        let mut state = ScrollbarState::new(10);
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(Some("<"))
+            .end_symbol(Some(">"));
+        scrollbar.render(buffer.area, &mut buffer, &mut state);
+        assert!(state.start_drag(Position::new(3, 0)));
+        state.next();
+
+        let expected = ScrollbarState::new(10).position(1);
+        assert_eq!(state, expected);
+        let hash = |state: &ScrollbarState| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            state.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&state), hash(&expected));
+        assert_ne!(state, expected.position(2));
+    }
+
+    #[test]
+    fn click_track() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        let mut state = ScrollbarState::new(10);
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(Some("<"))
+            .end_symbol(Some(">"));
+        scrollbar.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(state.position_at(Position::new(0, 0)), None);
+        assert_eq!(state.position_at(Position::new(7, 0)), Some(7));
+        assert!(state.start_drag(Position::new(7, 0)));
+        assert_eq!(state.get_position(), 7);
+        assert!(state.drag_to(Position::new(10, 0)));
+        assert_eq!(state.get_position(), 9);
+    }
+
+    #[rstest]
+    #[case::never(ScrollbarAutoHide::Never, 5, "<###-->")]
+    #[case::hide(ScrollbarAutoHide::Hide, 5, "       ")]
+    #[case::keep_arrows(ScrollbarAutoHide::KeepArrows, 5, "<     >")]
+    #[case::hide_overflowing(ScrollbarAutoHide::Hide, 8, "<##--->")]
+    fn render_auto_hide(
+        #[case] auto_hide: ScrollbarAutoHide,
+        #[case] content_length: usize,
+        #[case] expected: &str,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 1));
+        let mut state = ScrollbarState::new(content_length).viewport_content_length(5);
+        Scrollbar::new(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(Some("<"))
+            .end_symbol(Some(">"))
+            .track_symbol(Some("-"))
+            .thumb_symbol("#")
+            .auto_hide(auto_hide)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn fade_after_inactivity() {
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#")
+            .fade_style(Color::DarkGray);
+        let mut state = ScrollbarState::new(1);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        scrollbar
+            .clone()
+            .fade_after(Duration::from_secs(3600))
+            .render(buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines(["####"]);
+        expected.set_style(expected.area, Color::DarkGray);
+        assert_eq!(buffer, expected);
+
+        state.next();
+        assert!(state.last_activity().is_none());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        scrollbar
+            .clone()
+            .fade_after(Duration::from_secs(3600))
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["####"]));
+        assert!(state.last_activity().is_some());
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        scrollbar
+            .fade_after(Duration::ZERO)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::vertical(ScrollbarOrientation::VerticalRight, Rect::new(0, 0, 1, 4))]
+    #[case::horizontal(ScrollbarOrientation::HorizontalTop, Rect::new(0, 0, 4, 1))]
+    fn render_smooth(#[case] orientation: ScrollbarOrientation, #[case] area: Rect) {
+        let mut buffer = Buffer::empty(area);
+        let mut state = ScrollbarState::new(8).position(1);
+        Scrollbar::new(orientation.clone())
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("|"))
+            .smooth(true)
+            .render(buffer.area, &mut buffer, &mut state);
+        let expected = if orientation.is_vertical() {
+            let mut expected = Buffer::with_lines(["▅", "▁", "|", "|"]);
+            expected.set_style(Rect::new(0, 1, 1, 1), Modifier::REVERSED);
+            expected
+        } else {
+            let mut expected = Buffer::with_lines(["▍▉||"]);
+            expected.set_style(Rect::new(0, 0, 1, 1), Modifier::REVERSED);
+            expected
+        };
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn sync_with_offset() {
+        let mut state = ScrollbarState::default();
+        state.sync_with_offset(10, 4, 3);
+        assert!(state.scrolled);
+        assert_eq!(
+            state,
+            ScrollbarState::new(10)
+                .viewport_content_length(4)
+                .position(4)
+        );
+        state.sync_with_offset(10, 4, 20);
+        assert_eq!(state.get_position(), 9);
+        state.sync_with_offset(3, 4, 1);
+        assert_eq!(state.get_position(), 0);
+    }
+
+    #[test]
+    fn sync_with_list() {
+        let area = Rect::new(0, 0, 5, 6);
+        let mut buffer = Buffer::empty(area);
+        let list = List::new(["a", "b\nb", "c", "d", "e", "f", "g", "h"]).block(Block::bordered());
+        let mut list_state = ListState::default().with_selected(Some(7));
+        StatefulWidget::render(&list, area, &mut buffer, &mut list_state);
+        let mut state = ScrollbarState::default();
+        state.sync_with_list(&list, &list_state, area);
+        assert_eq!(
+            state,
+            ScrollbarState::new(9)
+                .viewport_content_length(4)
+                .position(8)
+        );
+    }
+
+    #[test]
+    fn sync_with_table() {
+        let area = Rect::new(0, 0, 5, 5);
+        let mut buffer = Buffer::empty(area);
+        let rows = (0..10).map(|i| Row::new([i.to_string()]));
+        let table = Table::new(rows, [Constraint::Length(2)]).header(Row::new(["#"]));
+        let mut table_state = TableState::default().with_offset(3);
+        StatefulWidget::render(&table, area, &mut buffer, &mut table_state);
+        let mut state = ScrollbarState::default();
+        state.sync_with_table(&table, &table_state, area);
+        assert_eq!(
+            state,
+            ScrollbarState::new(10)
+                .viewport_content_length(4)
+                .position(4)
+        );
+    }
+
+    #[test]
+    fn sync_with_paragraph() {
+        let area = Rect::new(0, 0, 5, 4);
+        let mut buffer = Buffer::empty(area);
+        let text = (0..10)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let paragraph = Paragraph::new(text).scroll((6, 0));
+        let mut paragraph_state = ParagraphState::default();
+        StatefulWidget::render(&paragraph, area, &mut buffer, &mut paragraph_state);
+        let mut state = ScrollbarState::default();
+        state.sync_with_paragraph(&paragraph_state);
+        assert_eq!(
+            state,
+            ScrollbarState::new(10)
+                .viewport_content_length(4)
+                .position(9)
+        );
+    }
+
+    #[test]
+    fn render_markers() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 7));
+        let mut state = ScrollbarState::new(50)
+            .viewport_content_length(10)
+            .markers([
+                ScrollbarMarker::new(0, Color::Red),
+                ScrollbarMarker::new(25, Color::Yellow).symbol("-"),
+                ScrollbarMarker::new(100, Color::Blue).symbol("="),
+            ]);
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(Some("^"))
+            .end_symbol(Some("v"))
+            .track_symbol(Some("|"))
+            .thumb_symbol("#")
+            .render(buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines(["^", "#", "|", "-", "|", "=", "v"]);
+        expected.set_style(Rect::new(0, 1, 1, 1), Color::Red);
+        expected.set_style(Rect::new(0, 3, 1, 1), Color::Yellow);
+        expected.set_style(Rect::new(0, 5, 1, 1), Color::Blue);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_markers_with_large_content_length() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 4));
+        let mut state = ScrollbarState::new(usize::MAX).markers([
+            ScrollbarMarker::new(usize::MAX / 2, Color::Red).symbol("-"),
+            ScrollbarMarker::new(usize::MAX, Color::Blue).symbol("="),
+        ]);
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_symbol(Some("|"))
+            .thumb_symbol("#")
+            .render(buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines(["#", "-", "|", "="]);
+        expected.set_style(Rect::new(0, 1, 1, 1), Color::Red);
+        expected.set_style(Rect::new(0, 3, 1, 1), Color::Blue);
+        assert_eq!(buffer, expected);
+    }
+
     #[rstest]
     #[case::scrollbar_height_0(10, 0)]
     #[case::scrollbar_width_0(0, 10)]
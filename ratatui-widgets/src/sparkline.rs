@@ -4,14 +4,17 @@ use std::cmp::min;
 use ratatui_core::{
     buffer::Buffer,
     layout::Rect,
-    style::{Style, Styled},
-    symbols::{self},
-    widgets::Widget,
+    style::{Color, Style, Styled},
+    symbols::{self, Marker},
+    widgets::{StatefulWidget, Widget},
 };
 use strum::{Display, EnumString};
 
+pub use self::state::SparklineState;
 use crate::block::{Block, BlockExt};
 
+mod state;
+
 /// Widget to render a sparkline over one or more lines.
 ///
 /// Each bar in a `Sparkline` represents a value from the provided dataset. The height of the bar
@@ -28,7 +31,8 @@ use crate::block::{Block, BlockExt};
 /// entire widget or for individual bars by setting individual [`SparklineBar::style`].
 ///
 /// The bars are rendered using a set of symbols. The default set is [`symbols::bar::NINE_LEVELS`].
-/// You can change the set using [`Sparkline::bar_set`].
+/// You can change the set using [`Sparkline::bar_set`], or draw the bars with braille dots for a
+/// higher resolution using [`Sparkline::marker`].
 ///
 /// If the data provided is a slice of `u64` or `Option<u64>`, the bars will be styled with the
 /// style of the sparkline. If the data is a slice of [`SparklineBar`], the bars will be
@@ -36,14 +40,21 @@ use crate::block::{Block, BlockExt};
 /// if it is set, otherwise the sparkline style will be used.
 ///
 /// Absent values and will be rendered with the style set by [`Sparkline::absent_value_style`] and
-/// the symbol set by [`Sparkline::absent_value_symbol`].
+/// the symbol set by [`Sparkline::absent_value_symbol`], over the height set by
+/// [`Sparkline::absent_value_fill`].
+///
+/// For live data, the sparkline can be rendered as a stateful widget with a [`SparklineState`]
+/// holding a rolling window of values.
 ///
 /// # Setter methods
 ///
 /// - [`Sparkline::block`] wraps the sparkline in a [`Block`]
 /// - [`Sparkline::data`] defines the dataset, you'll almost always want to use it
 /// - [`Sparkline::max`] sets the maximum value of bars
+/// - [`Sparkline::bounds`] sets the minimum and maximum values of bars
 /// - [`Sparkline::direction`] sets the render direction
+/// - [`Sparkline::baseline`] sets the row from which negative values are drawn downward
+/// - [`Sparkline::bar_gradient`] and [`Sparkline::bar_thresholds`] color the bars by value
 ///
 /// # Examples
 ///
@@ -73,15 +84,39 @@ pub struct Sparkline<'a> {
     absent_value_style: Style,
     /// The symbol to use for absent values
     absent_value_symbol: AbsentValueSymbol,
+    /// The part of the height of the sparkline filled for absent values
+    absent_value_fill: AbsentValueFill,
     /// A slice of the data to display
     data: Vec<SparklineBar>,
     /// The maximum value to take to compute the maximum bar height (if nothing is specified, the
     /// widget uses the max of the dataset)
     max: Option<u64>,
+    /// The value of an empty bar
+    min: u64,
     /// A set of bar symbols used to represent the give data
     bar_set: symbols::bar::Set,
+    /// The marker used to draw the bars, only [`Marker::Braille`] replaces the bar set
+    marker: Marker,
     /// The direction to render the sparkline, either from left to right, or from right to left
     direction: RenderDirection,
+    /// The row at the bottom of which the bars start, negative values being drawn below it (if
+    /// nothing is specified, the bars start at the bottom of the widget)
+    baseline: Option<u16>,
+    /// Style of the bars below the baseline
+    negative_style: Style,
+    /// How the bars are colored according to their value
+    value_color: Option<ValueColor>,
+}
+
+/// How the bars of a [`Sparkline`] are colored according to their value
+///
+/// See [`Sparkline::bar_gradient`] and [`Sparkline::bar_thresholds`]
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum ValueColor {
+    /// The color is interpolated between the colors of the minimum and maximum values
+    Gradient(Color, Color),
+    /// The color is the one of the highest threshold reached by the value, sorted by threshold
+    Thresholds(Vec<(u64, Color)>),
 }
 
 /// Defines the direction in which sparkline will be rendered.
@@ -96,6 +131,21 @@ pub enum RenderDirection {
     RightToLeft,
 }
 
+/// Defines how much of the height of a [`Sparkline`] is filled for absent values.
+///
+/// See [`Sparkline::absent_value_fill`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum AbsentValueFill {
+    /// The absent value symbol fills the whole height of the sparkline
+    #[default]
+    Full,
+    /// The absent value symbol fills the cell above the baseline only, like a marker on the floor
+    /// of the sparkline
+    Baseline,
+    /// Nothing is drawn, leaving a gap in the sparkline
+    Gap,
+}
+
 impl<'a> Sparkline<'a> {
     /// Wraps the sparkline with the given `block`.
     #[must_use = "method moves the value of self and returns the modified value"]
@@ -120,7 +170,8 @@ impl<'a> Sparkline<'a> {
 
     /// Sets the style to use for absent values.
     ///
-    /// Absent values are values in the dataset that are `None`.
+    /// Absent values are values in the dataset that are `None`. The style of an absent
+    /// [`SparklineBar`] patches this style, so that absent values can be styled individually.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
     /// your own type that implements [`Into<Style>`]).
@@ -145,6 +196,37 @@ impl<'a> Sparkline<'a> {
         self
     }
 
+    /// Sets how much of the height of the sparkline is filled for absent values.
+    ///
+    /// Absent values are values in the dataset that are `None`. They fill the whole height with the
+    /// [absent value symbol](Sparkline::absent_value_symbol) by default
+    /// ([`AbsentValueFill::Full`]). With [`AbsentValueFill::Baseline`], they fill one cell at the
+    /// [baseline](Sparkline::baseline) only, which makes them stand out as markers, e.g. for
+    /// packet loss in a graph of round-trip times. With [`AbsentValueFill::Gap`], nothing is drawn.
+    ///
+    /// Absent values are never drawn with the [braille marker](Sparkline::marker).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::{AbsentValueFill, Sparkline},
+    /// };
+    ///
+    /// // round-trip times in milliseconds, `None` for lost packets
+    /// Sparkline::default()
+    ///     .data([Some(12), Some(15), None, Some(14)])
+    ///     .absent_value_symbol("×")
+    ///     .absent_value_style(Style::new().red().bold())
+    ///     .absent_value_fill(AbsentValueFill::Baseline);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn absent_value_fill(mut self, fill: AbsentValueFill) -> Self {
+        self.absent_value_fill = fill;
+        self
+    }
+
     /// Sets the dataset for the sparkline.
     ///
     /// Each item in the dataset is a bar in the sparkline. The height of the bar is determined by
@@ -223,6 +305,29 @@ impl<'a> Sparkline<'a> {
         self
     }
 
+    /// Sets the minimum and maximum values of bars.
+    ///
+    /// Bars with a value of `min` or less are empty, and bars with a value of `max` or more take
+    /// the whole height of the sparkline. Setting both bounds keeps the scale stable across frames,
+    /// instead of scaling the bars to the maximum value of the data currently displayed, which
+    /// makes live graphs jump around. Bars with a negative [signed value](SparklineBar::signed)
+    /// are scaled with the same bounds, according to their absolute value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::Sparkline;
+    ///
+    /// // a CPU usage in percent
+    /// Sparkline::default().data(&[12, 35, 80]).bounds(0, 100);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bounds(mut self, min: u64, max: u64) -> Self {
+        self.min = min;
+        self.max = Some(max);
+        self
+    }
+
     /// Sets the characters used to display the bars.
     ///
     /// Can be [`symbols::bar::THREE_LEVELS`], [`symbols::bar::NINE_LEVELS`] (default) or a custom
@@ -233,6 +338,35 @@ impl<'a> Sparkline<'a> {
         self
     }
 
+    /// Sets the marker used to draw the bars.
+    ///
+    /// With [`Marker::Braille`], the bars are drawn with braille dots: each cell displays two
+    /// values side by side with four dots of height, which doubles the number of values displayed
+    /// and the vertical resolution of the bars. This is useful for dense metric strips in one or
+    /// two rows. As the two values of a cell share its style, the style of the second one takes
+    /// precedence. Absent values are not drawn.
+    ///
+    /// The other markers draw the bars with the [bar set](Sparkline::bar_set), which is the
+    /// default.
+    ///
+    /// Note: Support for braille dots is limited to terminals and fonts that support Unicode
+    /// Braille Patterns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{symbols::Marker, widgets::Sparkline};
+    ///
+    /// Sparkline::default()
+    ///     .data(&[0, 2, 3, 4, 1, 4, 10])
+    ///     .marker(Marker::Braille);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn marker(mut self, marker: Marker) -> Self {
+        self.marker = marker;
+        self
+    }
+
     /// Sets the direction of the sparkline.
     ///
     /// [`RenderDirection::LeftToRight`] by default.
@@ -241,6 +375,108 @@ impl<'a> Sparkline<'a> {
         self.direction = direction;
         self
     }
+
+    /// Sets the row from which the bars start, to draw negative values downward.
+    ///
+    /// The baseline is the bottom of the given row, counted from the top of the widget. Bars with a
+    /// positive value grow up from the baseline, and bars with a negative [signed
+    /// value](SparklineBar::signed) grow down from it, using whole cells only, with the
+    /// [negative style](Sparkline::negative_style). Both sides are scaled with the same
+    /// [maximum value](Sparkline::max), each to its number of rows. This is useful for delta
+    /// streams, e.g. the incoming and outgoing traffic of a network interface.
+    ///
+    /// If not set, the bars start at the bottom of the widget and negative values are not drawn.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::{Sparkline, SparklineBar},
+    /// };
+    ///
+    /// let deltas = [3, -2, 5, -4];
+    /// Sparkline::default()
+    ///     .data(deltas.into_iter().map(SparklineBar::signed))
+    ///     .baseline(1)
+    ///     .green()
+    ///     .negative_style(Style::new().red());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn baseline(mut self, row: u16) -> Self {
+        self.baseline = Some(row);
+        self
+    }
+
+    /// Colors each bar according to its value, by interpolating between two colors.
+    ///
+    /// The empty bars get the `low` color, the bars reaching the [maximum value](Sparkline::max)
+    /// (see also [`Sparkline::bounds`]) get the `high` color, and the other bars get a color in
+    /// between, interpolated in RGB (see [`Color::lerp`]). Bars with a negative
+    /// [signed value](SparklineBar::signed) are colored according to their absolute value. This
+    /// makes spikes stand out without a separate chart.
+    ///
+    /// The color is set as the foreground color of the bars, below the style of each
+    /// [`SparklineBar`]. Absent values are not colored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{style::Color, widgets::Sparkline};
+    ///
+    /// Sparkline::default()
+    ///     .data(&[1, 4, 9, 2])
+    ///     .bar_gradient(Color::Rgb(0, 128, 0), Color::Rgb(255, 0, 0));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bar_gradient(mut self, low: Color, high: Color) -> Self {
+        self.value_color = Some(ValueColor::Gradient(low, high));
+        self
+    }
+
+    /// Colors each bar according to the thresholds reached by its value.
+    ///
+    /// `thresholds` is a list of values with a color. Each bar gets the color of the highest
+    /// threshold less than or equal to its value (its absolute value for bars with a negative
+    /// [signed value](SparklineBar::signed)), and the bars below all the thresholds keep the
+    /// style of the sparkline. Like with [`Sparkline::bar_gradient`], which this replaces, the
+    /// color is set as the foreground color of the bars, below the style of each
+    /// [`SparklineBar`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{style::Color, widgets::Sparkline};
+    ///
+    /// Sparkline::default()
+    ///     .data(&[10, 40, 90, 20])
+    ///     .bar_thresholds([(0, Color::Green), (50, Color::Yellow), (80, Color::Red)]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bar_thresholds<T>(mut self, thresholds: T) -> Self
+    where
+        T: IntoIterator<Item = (u64, Color)>,
+    {
+        let mut thresholds: Vec<_> = thresholds.into_iter().collect();
+        thresholds.sort_by_key(|(threshold, _)| *threshold);
+        self.value_color = Some(ValueColor::Thresholds(thresholds));
+        self
+    }
+
+    /// Sets the style of the bars below the [baseline](Sparkline::baseline).
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style patches the style of the sparkline and is patched by the style of each
+    /// [`SparklineBar`].
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn negative_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.negative_style = style.into();
+        self
+    }
 }
 
 /// An bar in a `Sparkline`.
@@ -253,6 +489,8 @@ pub struct SparklineBar {
     ///
     /// If `None`, the bar is absent.
     value: Option<u64>,
+    /// Whether the value is negative, see [`SparklineBar::signed`]
+    negative: bool,
     /// The style of the bar.
     ///
     /// If `None`, the bar will use the style of the sparkline.
@@ -260,6 +498,26 @@ pub struct SparklineBar {
 }
 
 impl SparklineBar {
+    /// Creates a bar with the given signed value.
+    ///
+    /// Bars with a negative value are drawn below the [baseline](Sparkline::baseline) of the
+    /// sparkline.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::SparklineBar;
+    ///
+    /// let bar = SparklineBar::signed(-42);
+    /// ```
+    pub const fn signed(value: i64) -> Self {
+        Self {
+            value: Some(value.unsigned_abs()),
+            negative: value < 0,
+            style: None,
+        }
+    }
+
     /// Sets the style of the bar.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -281,7 +539,11 @@ impl SparklineBar {
 
 impl From<Option<u64>> for SparklineBar {
     fn from(value: Option<u64>) -> Self {
-        Self { value, style: None }
+        Self {
+            value,
+            negative: false,
+            style: None,
+        }
     }
 }
 
@@ -289,6 +551,7 @@ impl From<u64> for SparklineBar {
     fn from(value: u64) -> Self {
         Self {
             value: Some(value),
+            negative: false,
             style: None,
         }
     }
@@ -298,6 +561,7 @@ impl From<&u64> for SparklineBar {
     fn from(value: &u64) -> Self {
         Self {
             value: Some(*value),
+            negative: false,
             style: None,
         }
     }
@@ -307,6 +571,7 @@ impl From<&Option<u64>> for SparklineBar {
     fn from(value: &Option<u64>) -> Self {
         Self {
             value: *value,
+            negative: false,
             style: None,
         }
     }
@@ -334,7 +599,22 @@ impl Widget for &Sparkline<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         self.block.as_ref().render(area, buf);
         let inner = self.block.inner_if_some(area);
-        self.render_sparkline(inner, buf);
+        self.render_sparkline(inner, buf, &self.data);
+    }
+}
+
+// `StatefulWidget` is only implemented for `&Sparkline` so that calling `render` on an owned
+// sparkline stays unambiguous when both widget traits are in scope.
+impl StatefulWidget for &Sparkline<'_> {
+    type State = SparklineState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        // display the most recent values that fit in the area
+        let values = state.values.make_contiguous();
+        let first = values.len().saturating_sub(self.columns(inner.width));
+        self.render_sparkline(inner, buf, &values[first..]);
     }
 }
 
@@ -349,20 +629,35 @@ impl Default for AbsentValueSymbol {
 }
 
 impl Sparkline<'_> {
-    fn render_sparkline(&self, spark_area: Rect, buf: &mut Buffer) {
+    fn render_sparkline(&self, spark_area: Rect, buf: &mut Buffer, data: &[SparklineBar]) {
         if spark_area.is_empty() {
             return;
         }
         // determine the maximum height across all bars
         let max_height = self
             .max
-            .unwrap_or_else(|| self.data.iter().filter_map(|s| s.value).max().unwrap_or(1));
+            .unwrap_or_else(|| data.iter().filter_map(|s| s.value).max().unwrap_or(1))
+            .saturating_sub(self.min);
+
+        // determine the number of rows above and below the baseline
+        let rows_above = self.baseline.map_or(spark_area.height, |row| {
+            min(row.saturating_add(1), spark_area.height)
+        });
+        let rows_below = spark_area.height - rows_above;
+
+        if self.marker == Marker::Braille {
+            self.render_braille(spark_area, buf, data, max_height, rows_above);
+            return;
+        }
 
         // determine the maximum index to render
-        let max_index = min(spark_area.width as usize, self.data.len());
+        let max_index = min(spark_area.width as usize, data.len());
 
         // render each item in the data
-        for (i, item) in self.data.iter().take(max_index).enumerate() {
+        for (i, item) in data.iter().take(max_index).enumerate() {
+            if item.value.is_none() && self.absent_value_fill == AbsentValueFill::Gap {
+                continue;
+            }
             let x = match self.direction {
                 RenderDirection::LeftToRight => spark_area.left() + i as u16,
                 RenderDirection::RightToLeft => spark_area.right() - i as u16 - 1,
@@ -371,54 +666,202 @@ impl Sparkline<'_> {
             // determine the height, symbol and style to use for the item
             //
             // if the item is not absent:
-            // - the height is the value of the item scaled to the height of the spark area
-            // - the symbol is determined by the scaled height
+            // - the height is the value of the item scaled to the rows above the baseline, or the
+            //   depth is the value scaled to the rows below the baseline for negative values
+            // - the symbol is determined by the scaled height or depth
             // - the style is the style of the item, if one is set
             //
             // otherwise:
-            // - the height is the total height of the spark area
+            // - the height is the total height of the spark area, or the cell above the baseline
             // - the symbol is the absent value symbol
-            // - the style is the absent value style
-            let (mut height, symbol, style) = match item {
+            // - the style is the absent value style, patched by the style of the item
+            let (mut height, depth, symbol, style) = match item {
                 SparklineBar {
                     value: Some(value),
+                    negative,
                     style,
                 } => {
-                    let height = if max_height == 0 {
-                        0
+                    let rows = if *negative { rows_below } else { rows_above };
+                    let ticks = (value.saturating_sub(self.min) * u64::from(rows) * 8)
+                        .checked_div(max_height)
+                        .unwrap_or(0);
+                    if *negative {
+                        // bars below the baseline are drawn with whole cells, rounded to the
+                        // nearest one
+                        (0, (ticks + 4) / 8, None, *style)
                     } else {
-                        *value * u64::from(spark_area.height) * 8 / max_height
+                        (ticks, 0, None, *style)
+                    }
+                }
+                SparklineBar {
+                    value: None, style, ..
+                } => {
+                    let (height, depth) = match self.absent_value_fill {
+                        AbsentValueFill::Baseline => (8, 0),
+                        _ => (u64::from(rows_above) * 8, u64::from(rows_below)),
                     };
-                    (height, None, *style)
+                    let style = self.absent_value_style.patch(style.unwrap_or_default());
+                    (
+                        height,
+                        depth,
+                        Some(self.absent_value_symbol.0.as_str()),
+                        Some(style),
+                    )
                 }
-                _ => (
-                    u64::from(spark_area.height) * 8,
-                    Some(self.absent_value_symbol.0.as_str()),
-                    Some(self.absent_value_style),
-                ),
             };
+            let style = self
+                .value_style(item, max_height)
+                .patch(style.unwrap_or_default());
 
-            // render the item from top to bottom
+            // render the item from the baseline to the top, then from the baseline to the bottom
             //
-            // if the symbol is set it will be used for the entire height of the bar, otherwise the
-            // symbol will be determined by the _remaining_ height.
+            // if the symbol is set it will be used for the cells within the height or depth of the
+            // bar, otherwise the symbol will be determined by the _remaining_ height or depth.
             //
             // if the style is set it will be used for the entire height of the bar, otherwise the
             // sparkline style will be used.
-            for j in (0..spark_area.height).rev() {
-                let symbol = symbol.unwrap_or_else(|| self.symbol_for_height(height));
-                if height > 8 {
-                    height -= 8;
-                } else {
-                    height = 0;
-                }
+            for j in (0..rows_above).rev() {
+                let symbol = match symbol {
+                    Some(symbol) if height > 0 => symbol,
+                    _ => self.symbol_for_height(height),
+                };
+                height = height.saturating_sub(8);
                 buf[(x, spark_area.top() + j)]
                     .set_symbol(symbol)
-                    .set_style(self.style.patch(style.unwrap_or_default()));
+                    .set_style(style);
+            }
+            for j in 0..rows_below {
+                let symbol = match symbol {
+                    _ if u64::from(j) >= depth => self.bar_set.empty,
+                    Some(symbol) => symbol,
+                    None => self.bar_set.full,
+                };
+                buf[(x, spark_area.top() + rows_above + j)]
+                    .set_symbol(symbol)
+                    .set_style(style);
             }
         }
     }
 
+    /// Draws two values per cell with braille dots, from the baseline to the top for positive
+    /// values and from the baseline to the bottom for negative values
+    fn render_braille(
+        &self,
+        spark_area: Rect,
+        buf: &mut Buffer,
+        data: &[SparklineBar],
+        max_height: u64,
+        rows_above: u16,
+    ) {
+        let max_index = min(self.columns(spark_area.width), data.len());
+        let cells = max_index.div_ceil(2) as u16;
+        if cells == 0 {
+            return;
+        }
+        let cells_area = match self.direction {
+            RenderDirection::LeftToRight => Rect {
+                width: cells,
+                ..spark_area
+            },
+            RenderDirection::RightToLeft => Rect {
+                x: spark_area.right() - cells,
+                width: cells,
+                ..spark_area
+            },
+        };
+        for position in cells_area.positions() {
+            buf[position].set_symbol(" ").set_style(self.style);
+        }
+
+        for (i, item) in data.iter().take(max_index).enumerate() {
+            let Some(value) = item.value else {
+                continue;
+            };
+            let (x, column) = match self.direction {
+                RenderDirection::LeftToRight => (spark_area.left() + (i / 2) as u16, i % 2),
+                RenderDirection::RightToLeft => {
+                    (spark_area.right() - (i / 2) as u16 - 1, 1 - i % 2)
+                }
+            };
+            let rows = if item.negative {
+                spark_area.height - rows_above
+            } else {
+                rows_above
+            };
+            let dots = (value.saturating_sub(self.min) * u64::from(rows) * 4)
+                .checked_div(max_height)
+                .unwrap_or(0);
+            let style = self
+                .value_style(item, max_height)
+                .patch(item.style.unwrap_or_default());
+            for dot in 0..min(dots, u64::from(rows) * 4) as u16 {
+                let (y, row) = if item.negative {
+                    (spark_area.top() + rows_above + dot / 4, dot % 4)
+                } else {
+                    (spark_area.top() + rows_above - 1 - dot / 4, 3 - dot % 4)
+                };
+                // add the dot to the braille pattern of the cell, which is blank if the cell
+                // contains no braille dot yet
+                let cell = &mut buf[(x, y)];
+                let blank = u32::from(symbols::braille::BLANK);
+                let pattern = cell
+                    .symbol()
+                    .chars()
+                    .next()
+                    .map(u32::from)
+                    .filter(|ch| (blank..=blank + 0xFF).contains(ch))
+                    .map_or(0, |ch| ch - blank);
+                let pattern = pattern | u32::from(symbols::braille::DOTS[row as usize][column]);
+                let symbol = char::from_u32(blank + pattern).unwrap_or(' ');
+                cell.set_char(symbol).set_style(style);
+            }
+        }
+    }
+
+    /// Returns the number of values displayed in the given width
+    fn columns(&self, width: u16) -> usize {
+        if self.marker == Marker::Braille {
+            width as usize * 2
+        } else {
+            width as usize
+        }
+    }
+
+    /// Returns the style of a bar, before the style of the bar itself
+    fn value_style(&self, item: &SparklineBar, max_height: u64) -> Style {
+        let base_style = if item.negative {
+            self.style.patch(self.negative_style)
+        } else {
+            self.style
+        };
+        let color = item
+            .value
+            .and_then(|value| self.value_color(value, max_height))
+            .map_or_else(Style::new, |color| Style::new().fg(color));
+        base_style.patch(color)
+    }
+
+    /// Returns the color of a bar with the given value, if the bars are colored by value
+    ///
+    /// `range` is the difference between the maximum and minimum values of the bars.
+    fn value_color(&self, value: u64, range: u64) -> Option<Color> {
+        match self.value_color.as_ref()? {
+            ValueColor::Gradient(low, high) => {
+                let t = if range == 0 {
+                    0.0
+                } else {
+                    value.saturating_sub(self.min) as f64 / range as f64
+                };
+                Some(low.lerp(*high, t))
+            }
+            ValueColor::Thresholds(thresholds) => thresholds
+                .iter()
+                .rev()
+                .find(|(threshold, _)| *threshold <= value)
+                .map(|(_, color)| *color),
+        }
+    }
+
     const fn symbol_for_height(&self, height: u64) -> &str {
         match height {
             0 => self.bar_set.empty,
@@ -440,6 +883,7 @@ mod tests {
         buffer::Cell,
         style::{Color, Modifier, Stylize},
     };
+    use rstest::rstest;
     use strum::ParseError;
 
     use super::*;
@@ -661,6 +1105,39 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[rstest]
+    #[case::full(AbsentValueFill::Full, ["█   ▂▅█x", "█▂▅████x"])]
+    #[case::baseline(AbsentValueFill::Baseline, ["    ▂▅█x", "█▂▅████x"])]
+    #[case::gap(AbsentValueFill::Gap, ["x   ▂▅█x", "x▂▅████x"])]
+    fn it_renders_absent_value_fill(#[case] fill: AbsentValueFill, #[case] expected: [&str; 2]) {
+        let widget = Sparkline::default()
+            .absent_value_symbol(symbols::shade::FULL)
+            .absent_value_fill(fill)
+            .data([None, Some(1), Some(2), Some(3), Some(4), Some(5), Some(6)]);
+        let area = Rect::new(0, 0, 8, 2);
+        let mut buffer = Buffer::filled(area, Cell::new("x"));
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn it_renders_absent_values_with_their_style() {
+        let widget = Sparkline::default()
+            .absent_value_symbol("×")
+            .absent_value_style(Style::new().red())
+            .absent_value_fill(AbsentValueFill::Baseline)
+            .data([
+                SparklineBar::from(None),
+                SparklineBar::from(None).style(Style::new().bold()),
+                SparklineBar::from(8),
+            ]);
+        let buffer = render(widget, 4);
+        let mut expected = Buffer::with_lines(["××█x"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().red().bold());
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn it_renders_with_custom_bar_styles() {
         let widget = Sparkline::default().data(vec![
@@ -682,6 +1159,135 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn it_draws_negative_values_below_the_baseline() {
+        let widget = Sparkline::default()
+            .data([2, -1, -2, 0, 1].map(SparklineBar::signed))
+            .baseline(0)
+            .negative_style(Style::new().red());
+        let area = Rect::new(0, 0, 6, 2);
+        let mut buffer = Buffer::filled(area, Cell::new("x"));
+        widget.render(area, &mut buffer);
+        let mut expected = Buffer::with_lines(["█   ▄x", " ██  x"]);
+        expected.set_style(Rect::new(1, 0, 2, 2), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn it_does_not_draw_negative_values_without_baseline() {
+        let widget = Sparkline::default().data([2, -2].map(SparklineBar::signed));
+        let buffer = render(widget, 3);
+        assert_eq!(buffer, Buffer::with_lines(["█ x"]));
+    }
+
+    #[test]
+    fn it_draws_with_baseline_below_the_area() {
+        let widget = Sparkline::default()
+            .data([2, -2].map(SparklineBar::signed))
+            .baseline(u16::MAX);
+        let buffer = render(widget, 3);
+        assert_eq!(buffer, Buffer::with_lines(["█ x"]));
+    }
+
+    #[test]
+    fn it_draws_with_bounds() {
+        let widget = Sparkline::default()
+            .data([0, 10, 12, 14, 18, 20, 30])
+            .bounds(10, 18);
+        let buffer = render(widget, 8);
+        assert_eq!(buffer, Buffer::with_lines(["  ▂▄███x"]));
+    }
+
+    #[test]
+    fn it_draws_with_bar_gradient() {
+        let widget = Sparkline::default()
+            .data([0, 4, 8])
+            .bar_gradient(Color::Rgb(0, 0, 0), Color::Rgb(0, 200, 0));
+        let buffer = render(widget, 4);
+        let mut expected = Buffer::with_lines([" ▄█x"]);
+        for (x, green) in [(0, 0), (1, 100), (2, 200)] {
+            expected.set_style(
+                Rect::new(x, 0, 1, 1),
+                Style::new().fg(Color::Rgb(0, green, 0)),
+            );
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn it_draws_with_bar_thresholds() {
+        let widget = Sparkline::default()
+            .data(vec![
+                SparklineBar::from(2),
+                SparklineBar::from(4),
+                SparklineBar::from(8),
+                SparklineBar::from(8).style(Style::new().blue()),
+                SparklineBar::from(None),
+            ])
+            .bar_thresholds([(8, Color::Red), (4, Color::Yellow)]);
+        let buffer = render(widget, 6);
+        let mut expected = Buffer::with_lines(["▂▄██ x"]);
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().yellow());
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(3, 0, 1, 1), Style::new().blue());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn it_renders_the_most_recent_values_of_the_state() {
+        let mut state = SparklineState::new(10);
+        for value in 0..=8 {
+            state.push(value);
+        }
+        let area = Rect::new(0, 0, 4, 1);
+        let mut buffer = Buffer::filled(area, Cell::new("x"));
+        StatefulWidget::render(&Sparkline::default(), area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["▅▆▇█"]));
+    }
+
+    #[test]
+    fn it_draws_braille() {
+        let widget = Sparkline::default()
+            .data([0, 2, 4, 1])
+            .marker(Marker::Braille);
+        let buffer = render(widget, 3);
+        assert_eq!(buffer, Buffer::with_lines(["⢠⣇x"]));
+    }
+
+    #[test]
+    fn it_draws_braille_right_to_left() {
+        let widget = Sparkline::default()
+            .data([0, 2, 4, 1])
+            .marker(Marker::Braille)
+            .direction(RenderDirection::RightToLeft);
+        let buffer = render(widget, 3);
+        // the first value is in the right column of the last cell
+        assert_eq!(buffer, Buffer::with_lines(["x⣸⡄"]));
+    }
+
+    #[rstest]
+    #[case::left_to_right(RenderDirection::LeftToRight)]
+    #[case::right_to_left(RenderDirection::RightToLeft)]
+    fn it_draws_braille_without_data(#[case] direction: RenderDirection) {
+        let widget = Sparkline::default()
+            .marker(Marker::Braille)
+            .direction(direction);
+        let buffer = render(widget, 3);
+        assert_eq!(buffer, Buffer::with_lines(["xxx"]));
+    }
+
+    #[test]
+    fn it_draws_negative_values_with_braille() {
+        let widget = Sparkline::default()
+            .data([2, -2].map(SparklineBar::signed))
+            .baseline(0)
+            .marker(Marker::Braille);
+        let area = Rect::new(0, 0, 2, 2);
+        let mut buffer = Buffer::filled(area, Cell::new("x"));
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["⡇x", "⢸x"]));
+    }
+
     #[test]
     fn can_be_stylized() {
         assert_eq!(
@@ -0,0 +1,124 @@
+use std::collections::VecDeque;
+
+use super::SparklineBar;
+
+/// State of the [`Sparkline`] widget holding a rolling window of values
+///
+/// This state is a ring buffer with a fixed capacity: [`push`](SparklineState::push) adds a value
+/// at the end of the window and drops the oldest value when the window is full. This allows live
+/// metrics feeds to keep their latest values without maintaining and slicing their own buffer
+/// every frame.
+///
+/// When the sparkline is rendered as a stateful widget, the values of the state are displayed
+/// instead of the [data](super::Sparkline::data) of the sparkline. If there are more values than
+/// columns, the most recent values are displayed.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Rect,
+///     widgets::{Sparkline, SparklineState},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = SparklineState::new(100);
+/// state.push(42);
+/// state.push(None);
+///
+/// frame.render_stateful_widget(&Sparkline::default(), area, &mut state);
+/// # }
+/// ```
+///
+/// [`Sparkline`]: super::Sparkline
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct SparklineState {
+    pub(super) values: VecDeque<SparklineBar>,
+    capacity: usize,
+}
+
+impl SparklineState {
+    /// Creates a state holding at most `capacity` values
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            values: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Adds a value at the end of the window, dropping the oldest value if the window is full
+    ///
+    /// The value can be a `u64`, an `Option<u64>` or a [`SparklineBar`], like the
+    /// [data](super::Sparkline::data) of the sparkline.
+    pub fn push<T: Into<SparklineBar>>(&mut self, value: T) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.values.len() == self.capacity {
+            self.values.pop_front();
+        }
+        self.values.push_back(value.into());
+    }
+
+    /// Maximum number of values held by the state
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of values held by the state
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the state holds no value
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Removes all the values
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn push() {
+        let mut state = SparklineState::new(3);
+        assert!(state.is_empty());
+
+        state.push(1);
+        state.push(None);
+        state.push(SparklineBar::from(3));
+        assert_eq!(state.len(), 3);
+
+        state.push(4); // drops the oldest value
+        assert_eq!(
+            state.values,
+            [
+                SparklineBar::from(None),
+                SparklineBar::from(3),
+                SparklineBar::from(4),
+            ]
+        );
+
+        state.clear();
+        assert!(state.is_empty());
+        assert_eq!(state.capacity(), 3);
+    }
+
+    #[test]
+    fn push_without_capacity() {
+        let mut state = SparklineState::new(0);
+        state.push(1);
+        assert!(state.is_empty());
+    }
+}
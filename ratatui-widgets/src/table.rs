@@ -930,6 +930,24 @@ impl Table<'_> {
         }
     }
 
+    /// Returns the height of the rows, the height of the viewport and the height of the rows
+    /// before the offset, when the table is rendered in the given area
+    pub(crate) fn scroll_metrics(&self, state: &TableState, area: Rect) -> (usize, usize, usize) {
+        let (_, rows_area, _) = self.layout(self.block.inner_if_some(area));
+        let content_height = self
+            .rows
+            .iter()
+            .map(|row| usize::from(row.height_with_margin()))
+            .sum();
+        let offset = self
+            .rows
+            .iter()
+            .take(state.offset)
+            .map(|row| usize::from(row.height_with_margin()))
+            .sum();
+        (content_height, usize::from(rows_area.height), offset)
+    }
+
     /// Return the indexes of the visible rows.
     ///
     /// The algorithm works as follows:
@@ -1,4 +1,6 @@
-//! The [`Tabs`] widget displays a horizontal set of tabs with a single tab selected.
+//! The [`Tabs`] widget displays a horizontal or vertical set of tabs with a single tab selected.
+use std::ops::Range;
+
 use itertools::Itertools;
 use ratatui_core::{
     buffer::Buffer,
@@ -6,11 +8,22 @@ use ratatui_core::{
     style::{Modifier, Style, Styled},
     symbols::{self},
     text::{Line, Span},
-    widgets::Widget,
+    widgets::{StatefulWidget, Widget},
 };
+use strum::{Display, EnumString};
 
+use self::state::TabArea;
+pub use self::{
+    state::{TabHit, TabsState},
+    tab::{Tab, TabBadge},
+    view::TabView,
+};
 use crate::block::{Block, BlockExt};
 
+mod state;
+mod tab;
+mod view;
+
 const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
 
 /// A widget that displays a horizontal set of Tabs with a single tab selected.
@@ -22,6 +35,9 @@ const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVER
 ///
 /// The divider defaults to |, and padding defaults to a singular space on each side.
 ///
+/// `Tabs` can also be rendered as a [`StatefulWidget`] with a [`TabsState`], which keeps track of
+/// the selected tab so that applications don't need to implement tab switching themselves.
+///
 /// # Example
 ///
 /// ```
@@ -52,8 +68,8 @@ const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVER
 pub struct Tabs<'a> {
     /// A block to wrap this widget in if necessary
     block: Option<Block<'a>>,
-    /// One title for each tab
-    titles: Vec<Line<'a>>,
+    /// One title for each tab, with its own style and badge
+    titles: Vec<Tab<'a>>,
     /// The index of the selected tabs
     selected: Option<usize>,
     /// The style used to draw the text
@@ -66,6 +82,36 @@ pub struct Tabs<'a> {
     padding_left: Line<'a>,
     /// Tab Right Padding
     padding_right: Line<'a>,
+    /// Symbols rendered when tabs overflow to the left and right
+    scroll_indicators: Option<(Span<'a>, Span<'a>)>,
+    /// Whether the tabs are laid out horizontally or vertically
+    orientation: TabsOrientation,
+    /// Symbol rendered after each title to close the tab
+    close_symbol: Option<Span<'a>>,
+}
+
+/// The layout of the [`Tabs`] widget.
+///
+/// ```plain
+///                 Horizontal
+///            Tab1 │ Tab2 │ Tab3
+///
+/// VerticalLeft              VerticalRight
+///  Tab1 │                     │ Tab1
+///  Tab2                         Tab2
+///  Tab3 │                     │ Tab3
+/// ```
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TabsOrientation {
+    /// Renders the tabs in a row, separated by the divider
+    #[default]
+    Horizontal,
+    /// Renders the tabs stacked in a column with a border on the right, for a sidebar on the left
+    /// of the content
+    VerticalLeft,
+    /// Renders the tabs stacked in a column with a border on the left, for a sidebar on the right
+    /// of the content
+    VerticalRight,
 }
 
 impl Default for Tabs<'_> {
@@ -96,7 +142,8 @@ impl<'a> Tabs<'a> {
     /// Creates new `Tabs` from their titles.
     ///
     /// `titles` can be a [`Vec`] of [`&str`], [`String`] or anything that can be converted into
-    /// [`Line`]. As such, titles can be styled independently.
+    /// [`Line`]. As such, titles can be styled independently. To set the style of a whole tab or to
+    /// add a badge, use a [`Tab`].
     ///
     /// The selected tab can be set with [`Tabs::select`]. The first tab has index 0 (this is also
     /// the default index).
@@ -125,10 +172,23 @@ impl<'a> Tabs<'a> {
     ///
     /// let tabs = Tabs::new(vec!["Tab 1".red(), "Tab 2".blue()]);
     /// ```
+    ///
+    /// Tabs with badges
+    /// ```
+    /// use ratatui::{
+    ///     style::Stylize,
+    ///     widgets::{Tab, TabBadge, Tabs},
+    /// };
+    ///
+    /// let tabs = Tabs::new(vec![
+    ///     Tab::new("Inbox").badge(TabBadge::count(3).yellow()),
+    ///     Tab::new("Errors").badge(TabBadge::dot().red()),
+    /// ]);
+    /// ```
     pub fn new<Iter>(titles: Iter) -> Self
     where
         Iter: IntoIterator,
-        Iter::Item: Into<Line<'a>>,
+        Iter::Item: Into<Tab<'a>>,
     {
         let titles = titles.into_iter().map(Into::into).collect_vec();
         let selected = if titles.is_empty() { None } else { Some(0) };
@@ -141,12 +201,15 @@ impl<'a> Tabs<'a> {
             divider: Span::raw(symbols::line::VERTICAL),
             padding_left: Line::from(" "),
             padding_right: Line::from(" "),
+            scroll_indicators: None,
+            orientation: TabsOrientation::Horizontal,
+            close_symbol: None,
         }
     }
 
     /// Sets the titles of the tabs.
     ///
-    /// `titles` is an iterator whose elements can be converted into `Line`.
+    /// `titles` is an iterator whose elements can be converted into `Line` or [`Tab`].
     ///
     /// The selected tab can be set with [`Tabs::select`]. The first tab has index 0 (this is also
     /// the default index).
@@ -172,7 +235,7 @@ impl<'a> Tabs<'a> {
     pub fn titles<Iter>(mut self, titles: Iter) -> Self
     where
         Iter: IntoIterator,
-        Iter::Item: Into<Line<'a>>,
+        Iter::Item: Into<Tab<'a>>,
     {
         self.titles = titles.into_iter().map(Into::into).collect_vec();
         self.selected = if self.titles.is_empty() {
@@ -348,6 +411,83 @@ impl<'a> Tabs<'a> {
         self.padding_left = padding.into();
         self
     }
+
+    /// Enables horizontal scrolling of tabs that don't fit the area.
+    ///
+    /// By default, tabs that don't fit the area are clipped. When scroll indicators are set and
+    /// the tabs are wider than the area, the tabs are scrolled so that the selected tab is visible
+    /// and `left` and `right` are rendered at the edges of the area to indicate that there are more
+    /// tabs in that direction. The space for the indicators is reserved whenever the tabs overflow.
+    ///
+    /// When rendered as a [`StatefulWidget`], the scroll position is stored in the offset of the
+    /// [`TabsState`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::Tabs;
+    ///
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2"]).scroll_indicators("‹", "›");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn scroll_indicators<T, U>(mut self, left: T, right: U) -> Self
+    where
+        T: Into<Span<'a>>,
+        U: Into<Span<'a>>,
+    {
+        self.scroll_indicators = Some((left.into(), right.into()));
+        self
+    }
+
+    /// Sets the orientation of the tabs.
+    ///
+    /// Defaults to [`TabsOrientation::Horizontal`]. With a vertical orientation, the tabs are
+    /// stacked one per row in a column and a border is drawn between the tabs and the content,
+    /// which is left open next to the selected tab to connect it to the content. The divider and
+    /// scroll indicators are not used in vertical orientations, instead the tabs are always
+    /// scrolled to keep the selected tab visible.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{Tabs, TabsOrientation};
+    ///
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2"]).orientation(TabsOrientation::VerticalLeft);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn orientation(mut self, orientation: TabsOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets a symbol that is rendered after each title to close the tab.
+    ///
+    /// The symbol is rendered between the title and the right padding and is not highlighted
+    /// with the title of the selected tab. When the tabs are rendered as a [`StatefulWidget`],
+    /// [`TabsState::tab_at`] reports whether a position is on the close symbol of a tab, which
+    /// can be used to close tabs with the mouse.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{style::Stylize, widgets::Tabs};
+    ///
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2"]).close_symbol(" ×".dark_gray());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn close_symbol<T>(mut self, symbol: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.close_symbol = Some(symbol.into());
+        self
+    }
 }
 
 impl Styled for Tabs<'_> {
@@ -373,70 +513,250 @@ impl Widget for &Tabs<'_> {
         buf.set_style(area, self.style);
         self.block.as_ref().render(area, buf);
         let inner = self.block.inner_if_some(area);
-        self.render_tabs(inner, buf);
+        self.render_tabs(inner, buf, self.selected, 0, &mut Vec::new());
     }
 }
 
-impl Tabs<'_> {
-    fn render_tabs(&self, tabs_area: Rect, buf: &mut Buffer) {
-        if tabs_area.is_empty() {
+// `StatefulWidget` is only implemented for `&Tabs` so that calling `render` on owned tabs stays
+// unambiguous when both widget traits are in scope.
+impl StatefulWidget for &Tabs<'_> {
+    type State = TabsState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        state.tabs.clear();
+        state.len = self.titles.len();
+
+        if self.titles.is_empty() {
+            state.select(None);
             return;
         }
 
-        let mut x = tabs_area.left();
-        let titles_length = self.titles.len();
-        for (i, title) in self.titles.iter().enumerate() {
-            let last_title = titles_length - 1 == i;
-            let remaining_width = tabs_area.right().saturating_sub(x);
+        // If the selected index is out of bounds, set it to the last tab
+        if state.selected.is_some_and(|s| s >= self.titles.len()) {
+            state.select(Some(self.titles.len() - 1));
+        }
+        state.offset = state.offset.min(self.titles.len() - 1);
+        // Ensure that the selected tab is not scrolled out of view to the left
+        if let Some(selected) = state.selected {
+            state.offset = state.offset.min(selected);
+        }
 
-            if remaining_width == 0 {
-                break;
+        state.offset = self.render_tabs(inner, buf, state.selected, state.offset, &mut state.tabs);
+    }
+}
+
+impl Tabs<'_> {
+    /// Renders the tabs starting at `offset` and returns the offset that was actually used, which
+    /// differs from the given one when the tabs are scrolled to keep the selected tab visible.
+    ///
+    /// The areas of the rendered tabs are appended to `tabs`.
+    fn render_tabs(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        selected: Option<usize>,
+        offset: usize,
+        tabs: &mut Vec<TabArea>,
+    ) -> usize {
+        match self.orientation {
+            TabsOrientation::Horizontal => {
+                self.render_horizontal(area, buf, selected, offset, tabs)
+            }
+            TabsOrientation::VerticalLeft | TabsOrientation::VerticalRight => {
+                self.render_vertical(area, buf, selected, offset, tabs)
             }
+        }
+    }
 
-            // Left Padding
-            let pos = buf.set_line(x, tabs_area.top(), &self.padding_left, remaining_width);
-            x = pos.0;
-            let remaining_width = tabs_area.right().saturating_sub(x);
-            if remaining_width == 0 {
-                break;
+    fn render_vertical(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        selected: Option<usize>,
+        mut offset: usize,
+        tabs: &mut Vec<TabArea>,
+    ) -> usize {
+        if area.is_empty() {
+            return offset;
+        }
+
+        let (titles_area, border_x) = if self.orientation == TabsOrientation::VerticalLeft {
+            let titles_area = Rect {
+                width: area.width - 1,
+                ..area
+            };
+            (titles_area, area.right() - 1)
+        } else {
+            let titles_area = Rect {
+                x: area.x + 1,
+                width: area.width - 1,
+                ..area
+            };
+            (titles_area, area.left())
+        };
+
+        // keep the selected tab visible
+        let rows = area.height as usize;
+        offset = offset.min(self.titles.len().saturating_sub(1));
+        if let Some(selected) = selected.filter(|&selected| selected < self.titles.len()) {
+            offset = offset.clamp((selected + 1).saturating_sub(rows), selected);
+        }
+
+        for (row, i) in titles_area.rows().zip(offset..) {
+            let border = if selected == Some(i) && i < self.titles.len() {
+                " "
+            } else {
+                symbols::line::VERTICAL
+            };
+            buf[(border_x, row.y)].set_symbol(border);
+
+            if let Some(tab) = self.titles.get(i) {
+                let (_, tab_area) = self.render_tab(row, buf, i, tab, selected == Some(i));
+                tabs.push(tab_area);
             }
+        }
+        offset
+    }
 
-            // Title
-            let pos = buf.set_line(x, tabs_area.top(), title, remaining_width);
-            if Some(i) == self.selected {
-                buf.set_style(
-                    Rect {
-                        x,
-                        y: tabs_area.top(),
-                        width: pos.0.saturating_sub(x),
-                        height: 1,
-                    },
-                    self.highlight_style,
-                );
+    fn render_horizontal(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        selected: Option<usize>,
+        mut offset: usize,
+        tabs: &mut Vec<TabArea>,
+    ) -> usize {
+        if area.is_empty() {
+            return offset;
+        }
+
+        let mut titles_area = area;
+        if let Some((left, right)) = &self.scroll_indicators {
+            if self.tabs_width(0..self.titles.len()) > area.width as usize {
+                let left_width = (left.width() as u16).min(area.width);
+                let right_width = (right.width() as u16).min(area.width - left_width);
+                titles_area.x += left_width;
+                titles_area.width -= left_width + right_width;
+                offset = self.scroll_offset(titles_area.width as usize, selected, offset);
+                if offset > 0 {
+                    buf.set_span(area.left(), area.top(), left, left_width);
+                }
+                if self.tabs_width(offset..self.titles.len()) > titles_area.width as usize {
+                    buf.set_span(titles_area.right(), area.top(), right, right_width);
+                }
+            } else {
+                offset = 0;
             }
-            x = pos.0;
-            let remaining_width = tabs_area.right().saturating_sub(x);
+        }
+        if titles_area.is_empty() {
+            return offset;
+        }
+
+        let mut x = titles_area.left();
+        let titles_length = self.titles.len();
+        for (i, tab) in self.titles.iter().enumerate().skip(offset) {
+            let last_title = titles_length - 1 == i;
+            let remaining_width = titles_area.right().saturating_sub(x);
             if remaining_width == 0 {
                 break;
             }
 
-            // Right Padding
-            let pos = buf.set_line(x, tabs_area.top(), &self.padding_right, remaining_width);
-            x = pos.0;
-            let remaining_width = tabs_area.right().saturating_sub(x);
+            let remaining_area = Rect::new(x, titles_area.top(), remaining_width, 1);
+            let (end, tab_area) = self.render_tab(remaining_area, buf, i, tab, selected == Some(i));
+            tabs.push(tab_area);
+            x = end;
+            let remaining_width = titles_area.right().saturating_sub(x);
             if remaining_width == 0 || last_title {
                 break;
             }
 
-            let pos = buf.set_span(x, tabs_area.top(), &self.divider, remaining_width);
+            let pos = buf.set_span(x, titles_area.top(), &self.divider, remaining_width);
             x = pos.0;
         }
+        offset
+    }
+
+    /// Renders a single tab (padding, title, badge and close symbol) in the first row of `area`.
+    ///
+    /// Returns the x position after the tab and the area it occupies.
+    fn render_tab(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        index: usize,
+        tab: &Tab,
+        selected: bool,
+    ) -> (u16, TabArea) {
+        let y = area.top();
+        let width = (self.tabs_width(index..index + 1) as u16).min(area.width);
+        buf.set_style(Rect::new(area.left(), y, width, 1), tab.style);
+        let (mut x, _) = buf.set_line(area.left(), y, &self.padding_left, area.width);
+
+        let (title_end, _) = buf.set_line(x, y, &tab.title, area.right().saturating_sub(x));
+        let title_area = Rect::new(x, y, title_end.saturating_sub(x), 1);
+        if selected {
+            buf.set_style(title_area, self.highlight_style);
+        }
+        x = title_end;
+
+        if let Some(badge) = &tab.badge {
+            (x, _) = buf.set_span(x, y, &Span::raw(" "), area.right().saturating_sub(x));
+            (x, _) = buf.set_span(x, y, &badge.content, area.right().saturating_sub(x));
+        }
+
+        let mut close = None;
+        if let Some(symbol) = &self.close_symbol {
+            let (close_end, _) = buf.set_span(x, y, symbol, area.right().saturating_sub(x));
+            close = Some(Rect::new(x, y, close_end.saturating_sub(x), 1));
+            x = close_end;
+        }
+
+        let (end, _) = buf.set_line(x, y, &self.padding_right, area.right().saturating_sub(x));
+        let tab = TabArea {
+            index,
+            area: Rect::new(area.left(), y, end.saturating_sub(area.left()), 1),
+            title: title_area,
+            close,
+        };
+        (end, tab)
+    }
+
+    /// Returns the width of the tabs in `range` including the dividers between them.
+    fn tabs_width(&self, range: Range<usize>) -> usize {
+        let padding_width = self.padding_left.width()
+            + self.padding_right.width()
+            + self.close_symbol.as_ref().map_or(0, Span::width);
+        let titles_width: usize = self.titles[range.clone()]
+            .iter()
+            .map(|tab| tab.width() + padding_width)
+            .sum();
+        titles_width + range.len().saturating_sub(1) * self.divider.width()
+    }
+
+    /// Returns the offset of the first tab to render so that the selected tab fits in `width`.
+    fn scroll_offset(&self, width: usize, selected: Option<usize>, offset: usize) -> usize {
+        let len = self.titles.len();
+        let mut offset = offset.min(len.saturating_sub(1));
+        if let Some(selected) = selected.filter(|&selected| selected < len) {
+            offset = offset.min(selected);
+            while offset < selected && self.tabs_width(offset..selected + 1) > width {
+                offset += 1;
+            }
+        }
+        // don't leave empty space after the last tab when scrolling back would fill it
+        while offset > 0 && self.tabs_width(offset - 1..len) <= width {
+            offset -= 1;
+        }
+        offset
     }
 }
 
 impl<'a, Item> FromIterator<Item> for Tabs<'a>
 where
-    Item: Into<Line<'a>>,
+    Item: Into<Tab<'a>>,
 {
     fn from_iter<Iter: IntoIterator<Item = Item>>(iter: Iter) -> Self {
         Self::new(iter)
@@ -445,7 +765,11 @@ where
 
 #[cfg(test)]
 mod tests {
-    use ratatui_core::style::{Color, Stylize};
+    use ratatui_core::{
+        layout::Position,
+        style::{Color, Stylize},
+    };
+    use rstest::rstest;
 
     use super::*;
 
@@ -458,10 +782,10 @@ mod tests {
             Tabs {
                 block: None,
                 titles: vec![
-                    Line::from("Tab1"),
-                    Line::from("Tab2"),
-                    Line::from("Tab3"),
-                    Line::from("Tab4"),
+                    Tab::from("Tab1"),
+                    Tab::from("Tab2"),
+                    Tab::from("Tab3"),
+                    Tab::from("Tab4"),
                 ],
                 selected: Some(0),
                 style: Style::default(),
@@ -469,6 +793,9 @@ mod tests {
                 divider: Span::raw(symbols::line::VERTICAL),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
+                scroll_indicators: None,
+                orientation: TabsOrientation::Horizontal,
+                close_symbol: None,
             }
         );
     }
@@ -486,6 +813,9 @@ mod tests {
                 divider: Span::raw(symbols::line::VERTICAL),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
+                scroll_indicators: None,
+                orientation: TabsOrientation::Horizontal,
+                close_symbol: None,
             }
         );
     }
@@ -515,11 +845,11 @@ mod tests {
         assert_eq!(
             tabs.titles,
             vec![
-                Line::from("Tab0"),
-                Line::from("Tab1"),
-                Line::from("Tab2"),
-                Line::from("Tab3"),
-                Line::from("Tab4"),
+                Tab::from("Tab0"),
+                Tab::from("Tab1"),
+                Tab::from("Tab2"),
+                Tab::from("Tab3"),
+                Tab::from("Tab4"),
             ],
         );
     }
@@ -641,6 +971,220 @@ mod tests {
         test_case(tabs, Rect::new(0, 0, 30, 1), &expected);
     }
 
+    #[test]
+    fn render_stateful() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 1));
+        let mut state = TabsState::new(Some(1));
+        StatefulWidget::render(&tabs, buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines([" Tab1 │ Tab2 │ Tab3 │ Tab4    "]);
+        expected.set_style(Rect::new(8, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_stateful_clamps_selected() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 1));
+        let mut state = TabsState::default();
+        state.select_last();
+        StatefulWidget::render(&tabs, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.selected(), Some(3));
+
+        state.select_next();
+        StatefulWidget::render(&tabs, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.selected(), Some(3));
+
+        let mut state = TabsState::new(Some(2));
+        StatefulWidget::render(&Tabs::default(), buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.selected(), None);
+    }
+
+    #[test]
+    fn render_stateful_with_offset() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 1));
+        let mut state = TabsState::new(Some(3)).with_offset(2);
+        StatefulWidget::render(&tabs, buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines([" Tab3 │ Tab4                  "]);
+        expected.set_style(Rect::new(8, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(buffer, expected);
+
+        // selecting a tab before the offset scrolls it into view
+        state.select(Some(1));
+        StatefulWidget::render(&tabs, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.offset(), 1);
+    }
+
+    #[test]
+    fn render_scroll_indicators() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"]).scroll_indicators("‹", "›");
+
+        // fits, no indicators
+        let mut expected = Buffer::with_lines([" Tab1 │ Tab2 │ Tab3 │ Tab4    "]);
+        expected.set_style(Rect::new(1, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs.clone(), Rect::new(0, 0, 30, 1), &expected);
+
+        // overflows to the right
+        let mut expected = Buffer::with_lines(["  Tab1 │ Tab2 │ Tab›"]);
+        expected.set_style(Rect::new(2, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs.clone(), Rect::new(0, 0, 20, 1), &expected);
+
+        // scrolled to keep the selected tab visible
+        let mut expected = Buffer::with_lines(["‹ Tab3 │ Tab4       "]);
+        expected.set_style(Rect::new(9, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs.clone().select(3), Rect::new(0, 0, 20, 1), &expected);
+
+        // overflows in both directions
+        let mut expected = Buffer::with_lines(["‹ Tab2 │ Tab3 │ Tab›"]);
+        expected.set_style(Rect::new(2, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+        let mut state = TabsState::new(Some(1)).with_offset(1);
+        StatefulWidget::render(&tabs, buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, expected);
+        assert_eq!(state.offset(), 1);
+    }
+
+    #[test]
+    fn render_scroll_indicators_updates_offset() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"]).scroll_indicators("‹", "›");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+        let mut state = TabsState::new(Some(0));
+        for (selected, offset) in [(1, 0), (2, 1), (3, 2), (2, 2), (1, 1), (0, 0)] {
+            state.select(Some(selected));
+            StatefulWidget::render(&tabs, buffer.area, &mut buffer, &mut state);
+            assert_eq!(state.offset(), offset, "selected {selected}");
+        }
+    }
+
+    #[test]
+    fn render_vertical_left() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3"])
+            .orientation(TabsOrientation::VerticalLeft)
+            .select(1);
+        let mut expected = Buffer::with_lines([" Tab1  │", " Tab2   ", " Tab3  │", "       │"]);
+        expected.set_style(Rect::new(1, 1, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 8, 4), &expected);
+    }
+
+    #[test]
+    fn render_vertical_right() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3"])
+            .orientation(TabsOrientation::VerticalRight)
+            .select(0);
+        let mut expected = Buffer::with_lines(["  Tab1  ", "│ Tab2  ", "│ Tab3  ", "│       "]);
+        expected.set_style(Rect::new(2, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 8, 4), &expected);
+    }
+
+    #[test]
+    fn render_vertical_scrolls_to_selected() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"])
+            .orientation(TabsOrientation::VerticalLeft);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 2));
+        let mut state = TabsState::new(Some(2));
+        StatefulWidget::render(&tabs, buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines([" Tab2 │", " Tab3  "]);
+        expected.set_style(Rect::new(1, 1, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(buffer, expected);
+        assert_eq!(state.offset(), 1);
+    }
+
+    #[test]
+    fn render_close_symbol() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3"]).close_symbol("×");
+        let mut expected = Buffer::with_lines([" Tab1× │ Tab2× │ Tab3×        "]);
+        expected.set_style(Rect::new(1, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 30, 1), &expected);
+    }
+
+    #[rstest]
+    #[case::left_padding(Position::new(0, 0), Some(TabHit::Tab(0)))]
+    #[case::title(Position::new(3, 0), Some(TabHit::Tab(0)))]
+    #[case::close(Position::new(5, 0), Some(TabHit::Close(0)))]
+    #[case::right_padding(Position::new(6, 0), Some(TabHit::Tab(0)))]
+    #[case::divider(Position::new(7, 0), None)]
+    #[case::second_close(Position::new(13, 0), Some(TabHit::Close(1)))]
+    #[case::after_tabs(Position::new(25, 0), None)]
+    #[case::outside(Position::new(3, 1), None)]
+    fn tab_at(#[case] position: Position, #[case] expected: Option<TabHit>) {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3"]).close_symbol("×");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 1));
+        let mut state = TabsState::default();
+        StatefulWidget::render(&tabs, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.tab_at(position), expected);
+    }
+
+    #[test]
+    fn tab_at_vertical() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3"])
+            .orientation(TabsOrientation::VerticalLeft)
+            .close_symbol("×");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 4));
+        let mut state = TabsState::default();
+        StatefulWidget::render(&tabs, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.tab_at(Position::new(2, 1)), Some(TabHit::Tab(1)));
+        assert_eq!(state.tab_at(Position::new(5, 2)), Some(TabHit::Close(2)));
+        assert_eq!(state.tab_at(Position::new(7, 1)), None);
+        assert_eq!(state.tab_at(Position::new(2, 3)), None);
+    }
+
+    #[test]
+    fn title_ranges() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab22", "Tab3"]).divider("--");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+        let mut state = TabsState::default();
+        StatefulWidget::render(&tabs, buffer.area, &mut buffer, &mut state);
+        assert_eq!(
+            state.title_ranges().collect_vec(),
+            [(0, 1..5), (1, 9..14), (2, 18..20)]
+        );
+    }
+
+    #[test]
+    fn title_ranges_scrolled() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"]).scroll_indicators("‹", "›");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+        let mut state = TabsState::new(Some(3));
+        StatefulWidget::render(&tabs, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.title_ranges().collect_vec(), [(2, 2..6), (3, 9..13)]);
+    }
+
+    #[test]
+    fn render_tab_style_and_badges() {
+        let tabs = Tabs::new(vec![
+            Tab::new("Tab1").badge(TabBadge::count(3).yellow()),
+            Tab::new("Tab2").red(),
+            Tab::new("Tab3").badge(TabBadge::dot().red()),
+        ])
+        .highlight_style(Style::new().bold());
+        let expected = Buffer::with_lines([Line::from(vec![
+            " ".into(),
+            "Tab1".bold(),
+            " ".into(),
+            "3".yellow(),
+            " │".into(),
+            " Tab2 ".red(),
+            "│ Tab3 ".into(),
+            "●".red(),
+            "  ".into(),
+        ])]);
+        test_case(tabs, Rect::new(0, 0, 25, 1), &expected);
+    }
+
+    #[test]
+    fn tab_badge_is_not_part_of_title_range() {
+        let tabs = Tabs::new(vec![
+            Tab::new("Tab1").badge(TabBadge::count(12)),
+            "Tab2".into(),
+        ]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+        let mut state = TabsState::default();
+        StatefulWidget::render(&tabs, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.title_ranges().collect_vec(), [(0, 1..5), (1, 11..15)]);
+        assert_eq!(buffer, Buffer::with_lines([" Tab1 12 │ Tab2     "]));
+    }
+
     #[test]
     fn can_be_stylized() {
         assert_eq!(
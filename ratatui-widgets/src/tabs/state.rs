@@ -0,0 +1,539 @@
+use std::{
+    hash::{Hash, Hasher},
+    ops::Range,
+};
+
+use ratatui_core::layout::{Position, Rect};
+
+/// State of the [`Tabs`] widget
+///
+/// This state can be used to switch between tabs. When the tabs are rendered as a stateful widget,
+/// the selected tab will be highlighted and the tabs will be shifted to ensure that the selected
+/// tab is visible. This will modify the [`TabsState`] object passed to the
+/// `Frame::render_stateful_widget` method.
+///
+/// The state consists of two fields:
+/// - [`offset`]: the index of the first tab to be displayed
+/// - [`selected`]: the index of the selected tab, which can be `None` if no tab is selected
+///
+/// When rendered as a stateful widget, the selection of the state is used instead of the one set
+/// with [`Tabs::select`].
+///
+/// [`offset`]: TabsState::offset()
+/// [`selected`]: TabsState::selected()
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Rect,
+///     widgets::{Tabs, TabsState},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let tabs = Tabs::new(["Tab 1", "Tab 2", "Tab 3"]);
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TabsState::default().with_selected(Some(0));
+///
+/// state.select_next(); // select the second tab
+///
+/// frame.render_stateful_widget(&tabs, area, &mut state);
+/// # }
+/// ```
+///
+/// [`Tabs`]: super::Tabs
+/// [`Tabs::select`]: super::Tabs::select
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TabsState {
+    pub(crate) offset: usize,
+    pub(crate) selected: Option<usize>,
+    /// The areas of the tabs from the last render, used for hit-testing
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) tabs: Vec<TabArea>,
+    /// The number of tabs from the last render, used for wrapping the selection
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) len: usize,
+}
+
+/// Compares the offset and selection of the states, ignoring the areas and number of the tabs that
+/// are only kept between renders for the interaction with the tabs.
+impl PartialEq for TabsState {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset && self.selected == other.selected
+    }
+}
+
+impl Eq for TabsState {}
+
+impl Hash for TabsState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.offset.hash(state);
+        self.selected.hash(state);
+    }
+}
+
+/// The area occupied by a rendered tab.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub(crate) struct TabArea {
+    pub(crate) index: usize,
+    pub(crate) area: Rect,
+    pub(crate) title: Rect,
+    pub(crate) close: Option<Rect>,
+}
+
+/// The part of [`Tabs`] at a position, as returned by [`TabsState::tab_at`].
+///
+/// [`Tabs`]: super::Tabs
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TabHit {
+    /// The tab with the given index, including its padding
+    Tab(usize),
+    /// The close symbol of the tab with the given index
+    Close(usize),
+}
+
+impl TabHit {
+    /// The index of the tab that was hit
+    pub const fn index(self) -> usize {
+        match self {
+            Self::Tab(index) | Self::Close(index) => index,
+        }
+    }
+}
+
+impl TabsState {
+    /// Creates a new `TabsState` with the given tab selected
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let state = TabsState::new(Some(1));
+    /// ```
+    pub const fn new(selected: Option<usize>) -> Self {
+        Self {
+            offset: 0,
+            selected,
+            tabs: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Sets the index of the first tab to be displayed
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let state = TabsState::default().with_offset(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the index of the selected tab
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let state = TabsState::default().with_selected(Some(1));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Index of the first tab to be displayed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let state = TabsState::default();
+    /// assert_eq!(state.offset(), 0);
+    /// ```
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Mutable reference to the index of the first tab to be displayed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let mut state = TabsState::default();
+    /// *state.offset_mut() = 1;
+    /// ```
+    pub fn offset_mut(&mut self) -> &mut usize {
+        &mut self.offset
+    }
+
+    /// Index of the selected tab
+    ///
+    /// Returns `None` if no tab is selected
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let state = TabsState::default();
+    /// assert_eq!(state.selected(), None);
+    /// ```
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Mutable reference to the index of the selected tab
+    ///
+    /// Returns `None` if no tab is selected
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let mut state = TabsState::default();
+    /// *state.selected_mut() = Some(1);
+    /// ```
+    pub fn selected_mut(&mut self) -> &mut Option<usize> {
+        &mut self.selected
+    }
+
+    /// Sets the index of the selected tab
+    ///
+    /// Set to `None` if no tab is selected. This will also reset the offset to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let mut state = TabsState::default();
+    /// state.select(Some(1));
+    /// ```
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+        if index.is_none() {
+            self.offset = 0;
+        }
+    }
+
+    /// Selects the next tab or the first one if no tab is selected
+    ///
+    /// Note: until the tabs are rendered, the number of tabs is not known, so the index is set to
+    /// `0` and will be corrected when the tabs are rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let mut state = TabsState::default();
+    /// state.select_next();
+    /// ```
+    pub fn select_next(&mut self) {
+        let next = self.selected.map_or(0, |i| i.saturating_add(1));
+        self.select(Some(next));
+    }
+
+    /// Selects the previous tab or the last one if no tab is selected
+    ///
+    /// Note: until the tabs are rendered, the number of tabs is not known, so the index is set to
+    /// `usize::MAX` and will be corrected when the tabs are rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let mut state = TabsState::default();
+    /// state.select_previous();
+    /// ```
+    pub fn select_previous(&mut self) {
+        let previous = self.selected.map_or(usize::MAX, |i| i.saturating_sub(1));
+        self.select(Some(previous));
+    }
+
+    /// Selects the first tab
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let mut state = TabsState::default();
+    /// state.select_first();
+    /// ```
+    pub fn select_first(&mut self) {
+        self.select(Some(0));
+    }
+
+    /// Selects the last tab
+    ///
+    /// Note: until the tabs are rendered, the number of tabs is not known, so the index is set to
+    /// `usize::MAX` and will be corrected when the tabs are rendered
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let mut state = TabsState::default();
+    /// state.select_last();
+    /// ```
+    pub fn select_last(&mut self) {
+        self.select(Some(usize::MAX));
+    }
+
+    /// Selects the next tab, wrapping around to the first tab after the last one
+    ///
+    /// This uses the number of tabs from the last time the tabs were rendered with this state.
+    /// Before the first render, this behaves like [`TabsState::select_next`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let mut state = TabsState::default();
+    /// state.cycle_next();
+    /// ```
+    pub fn cycle_next(&mut self) {
+        match self.selected {
+            Some(selected) if self.len > 0 && selected.saturating_add(1) >= self.len => {
+                self.select(Some(0));
+            }
+            _ => self.select_next(),
+        }
+    }
+
+    /// Selects the previous tab, wrapping around to the last tab before the first one
+    ///
+    /// This uses the number of tabs from the last time the tabs were rendered with this state.
+    /// Before the first render, this behaves like [`TabsState::select_previous`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let mut state = TabsState::default();
+    /// state.cycle_previous();
+    /// ```
+    pub fn cycle_previous(&mut self) {
+        match self.selected {
+            Some(0) if self.len > 0 => self.select(Some(self.len - 1)),
+            _ => self.select_previous(),
+        }
+    }
+
+    /// Selects the tab at the given position, typically the position of a mouse click
+    ///
+    /// Returns what was hit, as returned by [`TabsState::tab_at`]. The selection is only changed
+    /// when a tab is hit outside of its close symbol, so that closing the tab can be handled by
+    /// the application.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{layout::Position, widgets::TabsState};
+    ///
+    /// let mut state = TabsState::default();
+    /// state.handle_click(Position::new(3, 0));
+    /// ```
+    pub fn handle_click(&mut self, position: Position) -> Option<TabHit> {
+        let hit = self.tab_at(position);
+        if let Some(TabHit::Tab(index)) = hit {
+            self.select(Some(index));
+        }
+        hit
+    }
+
+    /// Returns the horizontal ranges of the rendered tab titles along with the index of each tab.
+    ///
+    /// The ranges are the columns of the titles, excluding padding, dividers and close symbols,
+    /// from the last time the [`Tabs`] were rendered with this state. Tabs that were scrolled out
+    /// of view or clipped entirely are not included, and titles that were partially clipped only
+    /// include the visible columns. For vertical orientations, the tabs are in consecutive rows
+    /// and so all titles start at the same column.
+    ///
+    /// This makes it possible to map a click to a tab without re-deriving the width of the titles
+    /// and dividers. See [`TabsState::tab_at`] to map a position to a tab including its padding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     buffer::Buffer,
+    ///     layout::Rect,
+    ///     widgets::{StatefulWidget, Tabs, TabsState},
+    /// };
+    ///
+    /// let tabs = Tabs::new(["Tab1", "Tab2"]);
+    /// let mut state = TabsState::new(Some(0));
+    /// let area = Rect::new(0, 0, 20, 1);
+    /// tabs.render(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// let click_x = 10;
+    /// let clicked = state
+    ///     .title_ranges()
+    ///     .find(|(_, range)| range.contains(&click_x))
+    ///     .map(|(index, _)| index);
+    /// assert_eq!(clicked, Some(1));
+    /// ```
+    ///
+    /// [`Tabs`]: super::Tabs
+    pub fn title_ranges(&self) -> impl Iterator<Item = (usize, Range<u16>)> + '_ {
+        self.tabs
+            .iter()
+            .map(|tab| (tab.index, tab.title.left()..tab.title.right()))
+    }
+
+    /// Returns the tab at the given position, and whether the position is on its close symbol.
+    ///
+    /// This uses the areas of the tabs from the last time the [`Tabs`] were rendered with this
+    /// state, so it returns `None` before the first render. This is typically used to map mouse
+    /// clicks to tabs. See [`Tabs::close_symbol`] for rendering a close symbol.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     buffer::Buffer,
+    ///     layout::{Position, Rect},
+    ///     widgets::{StatefulWidget, TabHit, Tabs, TabsState},
+    /// };
+    ///
+    /// let tabs = Tabs::new(["Tab1", "Tab2"]).close_symbol("×");
+    /// let mut state = TabsState::new(Some(0));
+    /// let area = Rect::new(0, 0, 20, 1);
+    /// tabs.render(area, &mut Buffer::empty(area), &mut state);
+    ///
+    /// assert_eq!(state.tab_at(Position::new(9, 0)), Some(TabHit::Tab(1)));
+    /// assert_eq!(state.tab_at(Position::new(13, 0)), Some(TabHit::Close(1)));
+    /// ```
+    ///
+    /// [`Tabs`]: super::Tabs
+    /// [`Tabs::close_symbol`]: super::Tabs::close_symbol
+    pub fn tab_at(&self, position: Position) -> Option<TabHit> {
+        self.tabs.iter().find_map(|tab| {
+            if tab.close.is_some_and(|close| close.contains(position)) {
+                Some(TabHit::Close(tab.index))
+            } else if tab.area.contains(position) {
+                Some(TabHit::Tab(tab.index))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let state = TabsState::new(Some(2));
+        assert_eq!(state.selected, Some(2));
+        assert_eq!(state.offset, 0);
+    }
+
+    #[test]
+    fn select() {
+        let mut state = TabsState::default().with_offset(3);
+        state.select(Some(4));
+        assert_eq!(state.selected, Some(4));
+        assert_eq!(state.offset, 3);
+
+        state.select(None);
+        assert_eq!(state.selected, None);
+        assert_eq!(state.offset, 0);
+    }
+
+    #[test]
+    fn cycle() {
+        let mut state = TabsState::new(Some(1));
+        state.len = 3;
+        state.cycle_next();
+        assert_eq!(state.selected, Some(2));
+        state.cycle_next();
+        assert_eq!(state.selected, Some(0));
+        state.cycle_previous();
+        assert_eq!(state.selected, Some(2));
+
+        let mut state = TabsState::default();
+        state.cycle_next();
+        assert_eq!(state.selected, Some(0));
+    }
+
+    #[test]
+    fn cycle_next_after_select_last() {
+        let mut state = TabsState {
+            len: 3,
+            ..TabsState::default()
+        };
+        state.select_last();
+        state.cycle_next();
+        assert_eq!(state.selected, Some(0));
+    }
+
+    #[test]
+    fn equality_ignores_rendered_tabs() {
+        let state = TabsState::new(Some(1)).with_offset(1);
+        let rendered = TabsState {
+            tabs: vec![TabArea::default()],
+            len: 3,
+            ..state.clone()
+        };
+        assert_eq!(state, rendered);
+        assert_ne!(state, TabsState::new(Some(2)).with_offset(1));
+    }
+
+    #[test]
+    fn state_navigation() {
+        let mut state = TabsState::default();
+        state.select_next();
+        assert_eq!(state.selected, Some(0));
+
+        state.select_previous(); // should not go below 0
+        assert_eq!(state.selected, Some(0));
+
+        state.select_next();
+        assert_eq!(state.selected, Some(1));
+
+        state.select_last();
+        assert_eq!(state.selected, Some(usize::MAX));
+
+        state.select_next(); // should not go above usize::MAX
+        assert_eq!(state.selected, Some(usize::MAX));
+
+        state.select_first();
+        assert_eq!(state.selected, Some(0));
+
+        let mut state = TabsState::default();
+        state.select_previous();
+        assert_eq!(state.selected, Some(usize::MAX));
+    }
+}
@@ -0,0 +1,285 @@
+use ratatui_core::{
+    style::{Style, Styled},
+    text::{Line, Span},
+};
+
+/// A single tab in [`Tabs`]
+///
+/// A tab consists of a title, a style that is applied to the whole tab (including its padding),
+/// and an optional [`TabBadge`] that is rendered after the title. The style of the title [`Line`]
+/// is added to the style of the tab.
+///
+/// Anything that can be converted to [`Line`] can be a [`Tab`], so in most cases titles can be
+/// passed to [`Tabs::new`] directly.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::{
+///     style::Stylize,
+///     widgets::{Tab, TabBadge, Tabs},
+/// };
+///
+/// let tabs = Tabs::new([
+///     Tab::new("Inbox").badge(TabBadge::count(3).yellow()),
+///     Tab::new("Build").badge(TabBadge::dot().red()),
+///     Tab::new("Archive").dark_gray(),
+/// ]);
+/// ```
+///
+/// [`Tabs`]: super::Tabs
+/// [`Tabs::new`]: super::Tabs::new
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Tab<'a> {
+    pub(crate) title: Line<'a>,
+    pub(crate) style: Style,
+    pub(crate) badge: Option<TabBadge<'a>>,
+}
+
+impl<'a> Tab<'a> {
+    /// Creates a new [`Tab`] with the given title
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{style::Stylize, widgets::Tab};
+    ///
+    /// let tab = Tab::new("Tab 1");
+    /// let tab = Tab::new("Tab 1".bold());
+    /// ```
+    pub fn new<T>(title: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        Self {
+            title: title.into(),
+            style: Style::default(),
+            badge: None,
+        }
+    }
+
+    /// Sets the style of the whole tab
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This [`Style`] can be overridden by the [`Style`] of the title and by the highlight style of
+    /// the [`Tabs`] for the selected tab.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::Tab,
+    /// };
+    ///
+    /// let tab = Tab::new("Tab 1").style(Style::new().italic());
+    /// ```
+    ///
+    /// [`Tabs`]: super::Tabs
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets a badge that is rendered after the title
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Tab, TabBadge};
+    ///
+    /// let tab = Tab::new("Inbox").badge(TabBadge::count(12));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn badge<T: Into<Option<TabBadge<'a>>>>(mut self, badge: T) -> Self {
+        self.badge = badge.into();
+        self
+    }
+
+    /// Returns the width of the title and the badge
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Tab, TabBadge};
+    ///
+    /// let tab = Tab::new("Inbox").badge(TabBadge::count(12));
+    /// assert_eq!(tab.width(), 8);
+    /// ```
+    pub fn width(&self) -> usize {
+        self.title.width()
+            + self
+                .badge
+                .as_ref()
+                .map_or(0, |badge| 1 + badge.content.width())
+    }
+}
+
+impl<'a, T> From<T> for Tab<'a>
+where
+    T: Into<Line<'a>>,
+{
+    fn from(title: T) -> Self {
+        Self::new(title)
+    }
+}
+
+impl Styled for Tab<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// A badge rendered after the title of a [`Tab`]
+///
+/// Badges are typically used to show a count of unread items or to indicate an error with a dot.
+/// The badge is separated from the title by a space and is not highlighted with the title of the
+/// selected tab. It can be styled with [`TabBadge::style`] or the [`Stylize`] shorthands.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::{style::Stylize, widgets::TabBadge};
+///
+/// let unread = TabBadge::count(3).yellow().bold();
+/// let error = TabBadge::dot().red();
+/// let custom = TabBadge::new("new").green();
+/// ```
+///
+/// [`Stylize`]: ratatui_core::style::Stylize
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TabBadge<'a> {
+    pub(crate) content: Span<'a>,
+}
+
+impl<'a> TabBadge<'a> {
+    /// The symbol used by [`TabBadge::dot`]
+    pub const DOT: &'static str = "●";
+
+    /// Creates a new badge with the given content
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabBadge;
+    ///
+    /// let badge = TabBadge::new("!");
+    /// ```
+    pub fn new<T>(content: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        Self {
+            content: content.into(),
+        }
+    }
+
+    /// Creates a badge showing a count, e.g. of unread items
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabBadge;
+    ///
+    /// let badge = TabBadge::count(42);
+    /// ```
+    pub fn count(count: usize) -> Self {
+        Self::new(count.to_string())
+    }
+
+    /// Creates a badge showing a dot, e.g. to indicate an error
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabBadge;
+    ///
+    /// let badge = TabBadge::dot();
+    /// ```
+    pub fn dot() -> Self {
+        Self::new(Self::DOT)
+    }
+
+    /// Sets the style of the badge
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::TabBadge,
+    /// };
+    ///
+    /// let badge = TabBadge::dot().style(Style::new().red());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.content.style = style.into();
+        self
+    }
+}
+
+impl Styled for TabBadge<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.content.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let tab = Tab::new("Tab1");
+        assert_eq!(tab.title, Line::from("Tab1"));
+        assert_eq!(tab.style, Style::default());
+        assert_eq!(tab.badge, None);
+    }
+
+    #[test]
+    fn from_line() {
+        let tab: Tab = Line::from("Tab1".red()).into();
+        assert_eq!(tab, Tab::new(Line::from("Tab1".red())));
+    }
+
+    #[test]
+    fn stylize() {
+        let tab = Tab::new("Tab1").red().on_white();
+        assert_eq!(tab.style, Style::new().red().on_white());
+        let badge = TabBadge::count(3).yellow();
+        assert_eq!(badge.content, Span::styled("3", Style::new().yellow()));
+    }
+
+    #[test]
+    fn width() {
+        assert_eq!(Tab::new("Tab1").width(), 4);
+        assert_eq!(Tab::new("Tab1").badge(TabBadge::dot()).width(), 6);
+        assert_eq!(Tab::new("Tab1").badge(TabBadge::count(100)).width(), 8);
+    }
+}
@@ -0,0 +1,306 @@
+use std::fmt;
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    widgets::{StatefulWidget, Widget},
+};
+
+use super::{Tab, Tabs, TabsOrientation, TabsState};
+use crate::block::BlockExt;
+
+/// The content of a tab, rendered lazily when the tab is selected.
+type Content<'a> = Box<dyn FnOnce(Rect, &mut Buffer) + 'a>;
+
+/// A widget that pairs [`Tabs`] with a content area showing the selected tab.
+///
+/// Each tab is added with [`TabView::tab`] from a title and a widget for its content. When
+/// rendered, the area is split into the tab bar and the content area, and only the content of the
+/// selected tab is rendered. The tab bar is rendered at the top, or on the left or right depending
+/// on the [`TabsOrientation`] of the [`Tabs`] set with [`TabView::tab_bar`].
+///
+/// `TabView` is usually rendered as a [`StatefulWidget`] with a [`TabsState`] so that the selection
+/// can be changed with the keyboard ([`TabsState::cycle_next`], [`TabsState::cycle_previous`]) and
+/// the mouse ([`TabsState::handle_click`]). When rendered as a [`Widget`], the tab selected with
+/// [`Tabs::select`] on the tab bar is shown, or the first tab if none is selected.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     layout::{Position, Rect},
+///     style::{Style, Stylize},
+///     widgets::{Paragraph, TabView, Tabs, TabsState},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TabsState::new(Some(0));
+///
+/// let view = TabView::new()
+///     .tab_bar(Tabs::default().highlight_style(Style::new().yellow()))
+///     .tab("Info", Paragraph::new("Some information"))
+///     .tab("Help", Paragraph::new("Press tab to switch"));
+/// frame.render_stateful_widget(view, area, &mut state);
+///
+/// // in the event handler
+/// state.cycle_next();
+/// state.handle_click(Position::new(10, 0));
+/// # }
+/// ```
+pub struct TabView<'a> {
+    /// The tab bar, whose titles are set from the added tabs
+    tabs: Tabs<'a>,
+    /// The content of each tab
+    contents: Vec<Content<'a>>,
+}
+
+impl fmt::Debug for TabView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TabView")
+            .field("tabs", &self.tabs)
+            .field("contents", &self.contents.len())
+            .finish()
+    }
+}
+
+impl Default for TabView<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> TabView<'a> {
+    /// Creates a new `TabView` without any tabs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::TabView;
+    ///
+    /// let view = TabView::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            tabs: Tabs::default(),
+            contents: Vec::new(),
+        }
+    }
+
+    /// Sets the [`Tabs`] used to render the tab bar.
+    ///
+    /// This can be used to configure the style, divider, orientation and other options of the tab
+    /// bar. The titles of `tabs` are replaced with the titles of the tabs added with
+    /// [`TabView::tab`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{TabView, Tabs, TabsOrientation};
+    ///
+    /// let tabs = Tabs::default().orientation(TabsOrientation::VerticalLeft);
+    /// let view = TabView::new().tab_bar(tabs);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn tab_bar(mut self, tabs: Tabs<'a>) -> Self {
+        let titles = std::mem::take(&mut self.tabs.titles);
+        self.tabs = Tabs { titles, ..tabs };
+        self
+    }
+
+    /// Adds a tab with the given title and content.
+    ///
+    /// The title can be anything that can be converted into a [`Tab`], including a [`Line`], so
+    /// that tabs can have their own style and badge. The content is only rendered when the tab is
+    /// selected.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{Paragraph, TabView};
+    ///
+    /// let view = TabView::new().tab("Tab 1", Paragraph::new("Content"));
+    /// ```
+    ///
+    /// [`Line`]: ratatui_core::text::Line
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn tab<T, W>(mut self, title: T, content: W) -> Self
+    where
+        T: Into<Tab<'a>>,
+        W: Widget + 'a,
+    {
+        self.tabs.titles.push(title.into());
+        self.contents
+            .push(Box::new(move |area, buf| content.render(area, buf)));
+        self
+    }
+
+    /// Returns the areas of the tab bar and the content.
+    fn layout(&self, area: Rect) -> (Rect, Rect) {
+        let inner = self.tabs.block.inner_if_some(area);
+        match self.tabs.orientation {
+            TabsOrientation::Horizontal => {
+                let height = (area.height - inner.height).saturating_add(1);
+                let [tabs_area, content_area] =
+                    Layout::vertical([Constraint::Length(height), Constraint::Fill(1)]).areas(area);
+                (tabs_area, content_area)
+            }
+            TabsOrientation::VerticalLeft | TabsOrientation::VerticalRight => {
+                let titles_width = (0..self.tabs.titles.len())
+                    .map(|i| self.tabs.tabs_width(i..i + 1))
+                    .max()
+                    .unwrap_or_default();
+                // the titles and the border between the tabs and the content
+                let width = u16::try_from(titles_width)
+                    .unwrap_or(u16::MAX)
+                    .saturating_add(1)
+                    .saturating_add(area.width.saturating_sub(inner.width))
+                    .min(area.width);
+                if self.tabs.orientation == TabsOrientation::VerticalLeft {
+                    let [tabs_area, content_area] =
+                        Layout::horizontal([Constraint::Length(width), Constraint::Fill(1)])
+                            .areas(area);
+                    (tabs_area, content_area)
+                } else {
+                    let [content_area, tabs_area] =
+                        Layout::horizontal([Constraint::Fill(1), Constraint::Length(width)])
+                            .areas(area);
+                    (tabs_area, content_area)
+                }
+            }
+        }
+    }
+}
+
+impl Widget for TabView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = TabsState::new(self.tabs.selected.or(Some(0)));
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for TabView<'_> {
+    type State = TabsState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let (tabs_area, content_area) = self.layout(area);
+        StatefulWidget::render(&self.tabs, tabs_area, buf, state);
+        let content = state
+            .selected
+            .and_then(|selected| self.contents.into_iter().nth(selected));
+        if let Some(content) = content {
+            content(content_area, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::{layout::Position, style::Style};
+
+    use super::*;
+    use crate::{block::Block, paragraph::Paragraph, tabs::TabHit};
+
+    fn view() -> TabView<'static> {
+        TabView::new()
+            .tab_bar(Tabs::default().highlight_style(Style::new()))
+            .tab("Tab1", Paragraph::new("one"))
+            .tab("Tab2", Paragraph::new("two"))
+    }
+
+    #[test]
+    fn render_selected_content() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 15, 3));
+        let mut state = TabsState::new(Some(1));
+        StatefulWidget::render(view(), buffer.area, &mut buffer, &mut state);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([" Tab1 │ Tab2   ", "two            ", "               "])
+        );
+    }
+
+    #[test]
+    fn render_without_state() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 15, 2));
+        Widget::render(view(), buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([" Tab1 │ Tab2   ", "one            "])
+        );
+    }
+
+    #[test]
+    fn render_tab_bar_with_block() {
+        let view = view().tab_bar(
+            Tabs::default()
+                .block(Block::bordered())
+                .highlight_style(Style::new()),
+        );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 15, 4));
+        let mut state = TabsState::new(Some(0));
+        StatefulWidget::render(view, buffer.area, &mut buffer, &mut state);
+        let expected = Buffer::with_lines([
+            "┌─────────────┐",
+            "│ Tab1 │ Tab2 │",
+            "└─────────────┘",
+            "one            ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_vertical() {
+        let view = view().tab_bar(
+            Tabs::default()
+                .orientation(TabsOrientation::VerticalLeft)
+                .highlight_style(Style::new()),
+        );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 3));
+        let mut state = TabsState::new(Some(0));
+        StatefulWidget::render(view, buffer.area, &mut buffer, &mut state);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([" Tab1  one  ", " Tab2 │     ", "      │     "])
+        );
+    }
+
+    #[test]
+    fn layout_vertical_with_long_title() {
+        let title = "x".repeat(usize::from(u16::MAX));
+        let view = TabView::new()
+            .tab_bar(Tabs::default().orientation(TabsOrientation::VerticalRight))
+            .tab(title, Paragraph::new("one"));
+        let area = Rect::new(0, 0, 12, 3);
+        assert_eq!(view.layout(area), (area, Rect::new(0, 0, 0, 3)));
+    }
+
+    #[test]
+    fn switch_tabs() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 15, 2));
+        let mut state = TabsState::new(Some(0));
+        StatefulWidget::render(view(), buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(
+            state.handle_click(Position::new(9, 0)),
+            Some(TabHit::Tab(1))
+        );
+        assert_eq!(state.selected(), Some(1));
+        StatefulWidget::render(view(), buffer.area, &mut buffer, &mut state);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([" Tab1 │ Tab2   ", "two            "])
+        );
+
+        state.cycle_next();
+        assert_eq!(state.selected(), Some(0));
+        state.cycle_previous();
+        assert_eq!(state.selected(), Some(1));
+    }
+}
@@ -0,0 +1,204 @@
+//! The [`Tilemap`] widget renders a grid of [`Sprite`] tiles.
+use ratatui_core::{
+    buffer::{Buffer, Sprite},
+    layout::{Position, Rect, Size},
+    widgets::Widget,
+};
+
+use crate::block::{Block, BlockExt};
+
+/// A widget that renders a grid of tiles from a tileset of [`Sprite`]s
+///
+/// A tilemap is a common building block for roguelikes and other grid based games. The map is made
+/// of rows of tile indices into the tileset, and every tile is drawn by blitting the matching
+/// sprite, which bypasses any text layout. All tiles are assumed to share the same size, which is
+/// taken from the first sprite of the tileset unless set with [`Tilemap::tile_size`].
+///
+/// Indices that are out of range of the tileset (e.g. [`Tilemap::EMPTY`]) leave the area
+/// untouched, so a tilemap can be layered on top of another one. The map can be scrolled with
+/// [`Tilemap::offset`], which sets the tile that is displayed at the top left corner of the area.
+/// Tiles that only partially fit in the area are clipped.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::{
+///     buffer::Sprite,
+///     style::Stylize,
+///     text::Line,
+///     widgets::{Block, Tilemap},
+/// };
+///
+/// let tileset = [
+///     Sprite::from_lines([Line::from(".".dark_gray())], '\0'),
+///     Sprite::from_lines([Line::from("#".white())], '\0'),
+/// ];
+/// let map = [[1, 1, 1, 1], [1, 0, 0, 1], [1, 1, 1, 1]];
+/// let tilemap = Tilemap::new(&tileset, map).block(Block::bordered().title("Dungeon"));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Tilemap<'a> {
+    tileset: &'a [Sprite],
+    tiles: Vec<Vec<usize>>,
+    tile_size: Option<Size>,
+    offset: Position,
+    block: Option<Block<'a>>,
+}
+
+impl<'a> Tilemap<'a> {
+    /// A tile index that is never drawn
+    pub const EMPTY: usize = usize::MAX;
+
+    /// Creates a new tilemap from a tileset and rows of tile indices
+    ///
+    /// `tiles` accepts any iterator of rows, where each row is an iterator of indices into the
+    /// tileset.
+    pub fn new<Rows, Row>(tileset: &'a [Sprite], tiles: Rows) -> Self
+    where
+        Rows: IntoIterator<Item = Row>,
+        Row: IntoIterator<Item = usize>,
+    {
+        Self {
+            tileset,
+            tiles: tiles
+                .into_iter()
+                .map(|row| row.into_iter().collect())
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the size of a single tile
+    ///
+    /// By default the size of the first sprite of the tileset is used.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn tile_size<S: Into<Size>>(mut self, size: S) -> Self {
+        self.tile_size = Some(size.into());
+        self
+    }
+
+    /// Sets the tile (column and row) that is displayed at the top left corner of the area
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn offset<P: Into<Position>>(mut self, offset: P) -> Self {
+        self.offset = offset.into();
+        self
+    }
+
+    /// Wraps the tilemap with a custom [`Block`] widget.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    fn resolved_tile_size(&self) -> Size {
+        self.tile_size
+            .or_else(|| self.tileset.first().map(Sprite::size))
+            .unwrap_or(Size::new(1, 1))
+    }
+}
+
+impl Widget for Tilemap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Tilemap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        let tile_size = self.resolved_tile_size();
+        if inner.is_empty() || tile_size.width == 0 || tile_size.height == 0 {
+            return;
+        }
+        let visible_rows = inner.height.div_ceil(tile_size.height) as usize;
+        let visible_columns = inner.width.div_ceil(tile_size.width) as usize;
+        let rows = self
+            .tiles
+            .iter()
+            .skip(self.offset.y as usize)
+            .take(visible_rows);
+        for (row_index, row) in rows.enumerate() {
+            let columns = row
+                .iter()
+                .skip(self.offset.x as usize)
+                .take(visible_columns);
+            for (column_index, tile) in columns.enumerate() {
+                let Some(sprite) = self.tileset.get(*tile) else {
+                    continue;
+                };
+                let tile_area = Rect {
+                    x: inner.x + column_index as u16 * tile_size.width,
+                    y: inner.y + row_index as u16 * tile_size.height,
+                    width: tile_size.width,
+                    height: tile_size.height,
+                }
+                .intersection(inner);
+                sprite.render(tile_area, buf);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn tileset() -> Vec<Sprite> {
+        vec![
+            Sprite::from_lines(["..", ".."], '\0'),
+            Sprite::from_lines(["##", "##"], '\0'),
+            Sprite::from_lines(["@ ", "  "], ' '),
+        ]
+    }
+
+    #[test]
+    fn render() {
+        let tileset = tileset();
+        let tilemap = Tilemap::new(&tileset, [[1, 0, 1], [0, 2, 0]]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+        tilemap.render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["##..##", "##..##", "..@ ..", "..  .."])
+        );
+    }
+
+    #[test]
+    fn render_clips_partial_tiles() {
+        let tileset = tileset();
+        let tilemap = Tilemap::new(&tileset, [[1, 0], [0, 1]]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+        tilemap.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["##.", "##.", "..#"]));
+    }
+
+    #[test]
+    fn render_with_offset_and_missing_tiles() {
+        let tileset = tileset();
+        let tilemap = Tilemap::new(&tileset, [[1, Tilemap::EMPTY, 7], [Tilemap::EMPTY, 0, 7]])
+            .offset((1, 1))
+            .tile_size((1, 1));
+        let mut buf = Buffer::with_lines(["xx", "xx"]);
+        tilemap.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([".x", "xx"]));
+    }
+
+    #[test]
+    fn render_with_block() {
+        let tileset = tileset();
+        let tilemap = Tilemap::new(&tileset, [[1]]).block(Block::bordered());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+        tilemap.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["┌──┐", "│##│", "│##│", "└──┘"]));
+    }
+}
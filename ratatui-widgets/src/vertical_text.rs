@@ -0,0 +1,266 @@
+//! The [`VerticalText`] widget displays text vertically, one character per row.
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Rect, VerticalAlignment},
+    style::{Style, Styled},
+    text::{DisplayWidth, StyledGrapheme, Text},
+    widgets::Widget,
+};
+
+use crate::block::{Block, BlockExt};
+
+/// A widget to display text vertically, one character per row from top to bottom
+///
+/// Each line of the text is displayed as a column, and the columns are placed next to each other
+/// from left to right, or from right to left for traditional CJK vertical writing (see
+/// [`VerticalText::right_to_left`]). A column is as wide as its widest character, so columns of
+/// wide characters (e.g. CJK characters) take two cells. Characters that don't fit in the height
+/// of the area are truncated, as well as the columns that don't fit in its width.
+///
+/// This is useful to label the Y side of charts or for CJK vertical layouts. The styles of the
+/// text, its lines and their spans are applied to each character.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::{
+///     layout::VerticalAlignment,
+///     style::Stylize,
+///     text::Line,
+///     widgets::VerticalText,
+/// };
+///
+/// let label = VerticalText::new(Line::from("Latency".bold()))
+///     .vertical_alignment(VerticalAlignment::Center);
+///
+/// let poem = VerticalText::new("春眠不覚暁\n処処聞啼鳥").right_to_left(true);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct VerticalText<'a> {
+    /// The text to display, one column per line
+    text: Text<'a>,
+    /// A block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Widget style
+    style: Style,
+    /// Alignment of the characters in each column
+    vertical_alignment: VerticalAlignment,
+    /// Whether the columns are placed from right to left
+    right_to_left: bool,
+}
+
+impl<'a> VerticalText<'a> {
+    /// Creates a new `VerticalText` widget with the given text
+    ///
+    /// `text` accepts any type that is convertible to [`Text`] (e.g. [`&str`], [`String`],
+    /// [`Line`] or [`Span`]). Each line of the text is displayed as a column.
+    ///
+    /// [`Line`]: ratatui_core::text::Line
+    /// [`Span`]: ratatui_core::text::Span
+    pub fn new<T>(text: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        Self {
+            text: text.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Surrounds the widget with a [`Block`]
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the entire widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The styles of the text are applied on top of this style.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the alignment of the characters in each column
+    ///
+    /// The default is [`VerticalAlignment::Top`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets whether the columns are placed from right to left
+    ///
+    /// When `true`, the first line of the text is displayed in the rightmost column, as in
+    /// traditional Chinese, Japanese and Korean vertical writing. The default is `false`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn right_to_left(mut self, right_to_left: bool) -> Self {
+        self.right_to_left = right_to_left;
+        self
+    }
+
+    /// Returns the width of the widget, i.e. the sum of the widths of the columns
+    ///
+    /// This does not account for the block.
+    pub fn width(&self) -> usize {
+        self.columns().map(|(width, _)| width).sum()
+    }
+
+    /// Returns the height of the widget, i.e. the number of characters of the longest line
+    ///
+    /// This does not account for the block.
+    pub fn height(&self) -> usize {
+        self.columns()
+            .map(|(_, graphemes)| graphemes.len())
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the width and the visible graphemes of each column
+    fn columns(&self) -> impl Iterator<Item = (usize, Vec<StyledGrapheme<'_>>)> {
+        self.text.iter().map(|line| {
+            let graphemes = line
+                .styled_graphemes(self.text.style)
+                .filter(|grapheme| grapheme.symbol.display_width() > 0)
+                .collect::<Vec<_>>();
+            let width = graphemes
+                .iter()
+                .map(|grapheme| grapheme.symbol.display_width())
+                .max()
+                .unwrap_or(1);
+            (width, graphemes)
+        })
+    }
+}
+
+impl Widget for VerticalText<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &VerticalText<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area);
+        if area.is_empty() {
+            return;
+        }
+        let mut used_width = 0;
+        for (width, graphemes) in self.columns() {
+            let width = width as u16;
+            if used_width + width > area.width {
+                break;
+            }
+            let x = if self.right_to_left {
+                area.right() - used_width - width
+            } else {
+                area.left() + used_width
+            };
+            used_width += width;
+
+            let height = graphemes.len().min(area.height as usize) as u16;
+            let y = match self.vertical_alignment {
+                VerticalAlignment::Top => area.top(),
+                VerticalAlignment::Center => area.top() + (area.height - height) / 2,
+                VerticalAlignment::Bottom => area.bottom() - height,
+            };
+            for (row, grapheme) in (y..).zip(graphemes.iter().take(height as usize)) {
+                buf[(x, row)]
+                    .set_symbol(grapheme.symbol)
+                    .set_style(grapheme.style)
+                    .set_hyperlink(grapheme.hyperlink);
+            }
+        }
+    }
+}
+
+impl Styled for VerticalText<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::{style::Stylize, text::Line};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn render() {
+        let widget = VerticalText::new("abc\nde");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 4));
+        widget.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["ad ", "be ", "c  ", "   "]));
+    }
+
+    #[test]
+    fn render_styled() {
+        let widget =
+            VerticalText::new(Line::from(vec!["a".red(), "b".into()])).style(Style::new().bold());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 2));
+        widget.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["a", "b"]);
+        expected.set_style(buf.area, Style::new().bold());
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case::top(VerticalAlignment::Top, ["a", "b", " ", " ", " "])]
+    #[case::center(VerticalAlignment::Center, [" ", "a", "b", " ", " "])]
+    #[case::bottom(VerticalAlignment::Bottom, [" ", " ", " ", "a", "b"])]
+    fn vertical_alignment(#[case] alignment: VerticalAlignment, #[case] expected: [&str; 5]) {
+        let widget = VerticalText::new("ab").vertical_alignment(alignment);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 1, 5));
+        widget.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn right_to_left_wide_characters() {
+        let widget = VerticalText::new("春眠\nab").right_to_left(true);
+        assert_eq!(widget.width(), 3);
+        assert_eq!(widget.height(), 2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        widget.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([" a春", " b眠"]));
+    }
+
+    #[test]
+    fn truncated() {
+        let widget = VerticalText::new("abc\n\nde\nfg").block(Block::bordered());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 4));
+        widget.render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["┌───┐", "│a d│", "│b e│", "└───┘"])
+        );
+    }
+}
@@ -1,11 +1,22 @@
+use compact::CompactBuffer;
 use criterion::{black_box, BenchmarkId, Criterion};
 use ratatui::{
     buffer::{Buffer, Cell},
     layout::Rect,
+    style::{Color, Style},
     text::Line,
 };
 
-criterion::criterion_group!(benches, empty, filled, with_lines);
+mod compact;
+
+criterion::criterion_group!(
+    benches,
+    empty,
+    filled,
+    with_lines,
+    compact_from_buffer,
+    diff
+);
 
 const fn rect(size: u16) -> Rect {
     Rect::new(0, 0, size, size)
@@ -58,3 +69,60 @@ fn with_lines(c: &mut Criterion) {
     }
     group.finish();
 }
+
+/// A mostly uniform frame: a background with a few lines of styled text
+fn uniform_frame(area: Rect, text: &str) -> Buffer {
+    let mut buffer = Buffer::empty(area);
+    buffer.set_style(area, Style::new().bg(Color::Blue));
+    for y in (area.top()..area.bottom()).step_by(4) {
+        buffer.set_string(0, y, text, Style::new().fg(Color::Yellow));
+    }
+    buffer
+}
+
+/// Compares converting a `Vec<Cell>` based [`Buffer`] to a prototype with run-length encoded
+/// styles ([`CompactBuffer`]) with cloning the buffer
+fn compact_from_buffer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("buffer/compact_from_buffer");
+    for size in [16, 64, 255] {
+        let buffer = uniform_frame(rect(size), "Hello, World!");
+        group.bench_with_input(BenchmarkId::new("clone", size), &buffer, |b, buffer| {
+            b.iter(|| black_box(buffer).clone());
+        });
+        group.bench_with_input(BenchmarkId::new("compact", size), &buffer, |b, buffer| {
+            b.iter(|| CompactBuffer::from(black_box(buffer)));
+        });
+    }
+    group.finish();
+}
+
+/// Compares diffing two mostly uniform frames stored as `Vec<Cell>` ([`Buffer`]) and as run-length
+/// encoded styles ([`CompactBuffer`])
+fn diff(c: &mut Criterion) {
+    let mut group = c.benchmark_group("buffer/diff");
+    for size in [16, 64, 255] {
+        let previous = uniform_frame(rect(size), "Hello, World!");
+        let next = uniform_frame(rect(size), "Hello, Ratatui!");
+        group.bench_with_input(
+            BenchmarkId::new("vec_cell", size),
+            &(&previous, &next),
+            |b, (previous, next)| {
+                b.iter(|| black_box(previous).diff(black_box(next)).len());
+            },
+        );
+        let compact = (CompactBuffer::from(&previous), CompactBuffer::from(&next));
+        assert_eq!(
+            compact.0.diff(&compact.1).len(),
+            previous.diff(&next).len(),
+            "the prototype must find the same updates as Buffer::diff"
+        );
+        group.bench_with_input(
+            BenchmarkId::new("compact", size),
+            &compact,
+            |b, (previous, next)| {
+                b.iter(|| black_box(previous).diff(black_box(next)).len());
+            },
+        );
+    }
+    group.finish();
+}
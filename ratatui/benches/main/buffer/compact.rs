@@ -0,0 +1,171 @@
+//! A prototype of a buffer with run-length encoded styles, used to measure whether storing the
+//! styles of a [`Buffer`] as runs would make mostly uniform frames cheaper to diff.
+//!
+//! `Buffer` can't use this storage without breaking its API: `Buffer::content` is a public
+//! `Vec<Cell>`, and `Index`, `IndexMut` and `cell_mut` hand out references to the cells, which
+//! widgets use to patch them in place.
+
+use std::cmp::max;
+
+use ratatui::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    style::{Color, Modifier},
+    text::DisplayWidth,
+};
+
+/// A buffer that stores the symbols of the cells in a grid, and their styles as runs of
+/// consecutive cells that share the same style
+pub struct CompactBuffer {
+    area: Rect,
+    symbols: Vec<String>,
+    /// The style runs, which cover all the symbols in order
+    runs: Vec<StyleRun>,
+}
+
+/// A run of consecutive cells that share the same style
+struct StyleRun {
+    /// The index one past the last cell of the run
+    end: usize,
+    style: CellStyle,
+}
+
+/// Everything a [`Cell`] stores besides its symbol
+#[derive(Eq, PartialEq)]
+struct CellStyle {
+    fg: Color,
+    bg: Color,
+    #[cfg(feature = "underline-color")]
+    underline_color: Color,
+    modifier: Modifier,
+    skip: bool,
+    hyperlink: Option<String>,
+}
+
+impl CellStyle {
+    fn matches(&self, cell: &Cell) -> bool {
+        #[cfg(feature = "underline-color")]
+        if self.underline_color != cell.underline_color {
+            return false;
+        }
+        self.fg == cell.fg
+            && self.bg == cell.bg
+            && self.modifier == cell.modifier
+            && self.skip == cell.skip
+            && self.hyperlink.as_deref() == cell.hyperlink()
+    }
+
+    fn cell(&self, symbol: &str) -> Cell {
+        let mut cell = Cell::default();
+        cell.set_symbol(symbol)
+            .set_fg(self.fg)
+            .set_bg(self.bg)
+            .set_skip(self.skip)
+            .set_hyperlink(self.hyperlink.as_deref());
+        #[cfg(feature = "underline-color")]
+        {
+            cell.underline_color = self.underline_color;
+        }
+        cell.modifier = self.modifier;
+        cell
+    }
+}
+
+impl From<&Cell> for CellStyle {
+    fn from(cell: &Cell) -> Self {
+        Self {
+            fg: cell.fg,
+            bg: cell.bg,
+            #[cfg(feature = "underline-color")]
+            underline_color: cell.underline_color,
+            modifier: cell.modifier,
+            skip: cell.skip,
+            hyperlink: cell.hyperlink().map(String::from),
+        }
+    }
+}
+
+impl CompactBuffer {
+    /// Builds a minimal list of updated cells needed to draw `other` over `self`
+    ///
+    /// This behaves like [`Buffer::diff`], but compares the styles once per pair of overlapping
+    /// runs rather than for each cell.
+    pub fn diff(&self, other: &Self) -> Vec<(u16, u16, Cell)> {
+        let len = self.symbols.len().min(other.symbols.len());
+        let mut updates = vec![];
+        if len == 0 {
+            return updates;
+        }
+        let mut previous_run = 0;
+        let mut next_run = 0;
+        let mut same_style = self.runs[0].style == other.runs[0].style;
+        // Cells invalidated by drawing/replacing preceding multi-width characters:
+        let mut invalidated: usize = 0;
+        // Cells from the current buffer to skip due to preceding multi-width characters taking
+        // their place (the skipped cells should be blank anyway), or due to per-cell-skipping:
+        let mut to_skip: usize = 0;
+        for (i, (current, previous)) in other.symbols.iter().zip(&self.symbols).enumerate() {
+            let mut run_changed = false;
+            while self.runs[previous_run].end <= i {
+                previous_run += 1;
+                run_changed = true;
+            }
+            while other.runs[next_run].end <= i {
+                next_run += 1;
+                run_changed = true;
+            }
+            if run_changed {
+                same_style = self.runs[previous_run].style == other.runs[next_run].style;
+            }
+
+            let style = &other.runs[next_run].style;
+            if !style.skip
+                && (!same_style || current != previous || invalidated > 0)
+                && to_skip == 0
+            {
+                let (x, y) = self.pos_of(i);
+                updates.push((x, y, style.cell(current)));
+            }
+
+            to_skip = current.display_width().saturating_sub(1);
+
+            let affected_width = max(current.display_width(), previous.display_width());
+            invalidated = max(affected_width, invalidated).saturating_sub(1);
+        }
+        updates
+    }
+
+    fn pos_of(&self, index: usize) -> (u16, u16) {
+        let width = self.area.width as usize;
+        let x = index % width + self.area.x as usize;
+        let y = index / width + self.area.y as usize;
+        (
+            u16::try_from(x).expect("x overflow. This should never happen as area.width is u16"),
+            u16::try_from(y).expect("y overflow. This should never happen as area.height is u16"),
+        )
+    }
+}
+
+impl From<&Buffer> for CompactBuffer {
+    fn from(buffer: &Buffer) -> Self {
+        let mut runs: Vec<StyleRun> = Vec::new();
+        for (i, cell) in buffer.content.iter().enumerate() {
+            match runs.last_mut() {
+                Some(run) if run.style.matches(cell) => run.end = i + 1,
+                _ => runs.push(StyleRun {
+                    end: i + 1,
+                    style: CellStyle::from(cell),
+                }),
+            }
+        }
+        Self {
+            area: buffer.area,
+            symbols: buffer
+                .content
+                .iter()
+                .map(|cell| cell.symbol().to_string())
+                .collect(),
+            runs,
+        }
+    }
+}
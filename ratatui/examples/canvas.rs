@@ -137,7 +137,8 @@ impl App {
                 Marker::Dot => Marker::Braille,
                 Marker::Braille => Marker::Block,
                 Marker::Block => Marker::HalfBlock,
-                Marker::HalfBlock => Marker::Bar,
+                Marker::HalfBlock => Marker::Octant,
+                Marker::Octant => Marker::Bar,
                 Marker::Bar => Marker::Dot,
             };
         }
@@ -208,6 +209,7 @@ impl App {
                 ctx.draw(&Points {
                     coords: &points,
                     color: Color::White,
+                    colors: &[],
                 });
             })
     }
@@ -235,20 +237,20 @@ impl App {
             .y_bounds([bottom, top])
             .paint(|ctx| {
                 for i in 0..=11 {
-                    ctx.draw(&Rectangle {
-                        x: f64::from(i * i + 3 * i) / 2.0 + 2.0,
-                        y: 2.0,
-                        width: f64::from(i),
-                        height: f64::from(i),
-                        color: Color::Red,
-                    });
-                    ctx.draw(&Rectangle {
-                        x: f64::from(i * i + 3 * i) / 2.0 + 2.0,
-                        y: 21.0,
-                        width: f64::from(i),
-                        height: f64::from(i),
-                        color: Color::Blue,
-                    });
+                    ctx.draw(&Rectangle::new(
+                        f64::from(i * i + 3 * i) / 2.0 + 2.0,
+                        2.0,
+                        f64::from(i),
+                        f64::from(i),
+                        Color::Red,
+                    ));
+                    ctx.draw(&Rectangle::new(
+                        f64::from(i * i + 3 * i) / 2.0 + 2.0,
+                        21.0,
+                        f64::from(i),
+                        f64::from(i),
+                        Color::Blue,
+                    ));
                 }
                 for i in 0..100 {
                     if i % 10 != 0 {
@@ -0,0 +1,96 @@
+//! # [Ratatui] Gallery example
+//!
+//! The latest version of this example is available in the [examples] folder in the repository.
+//!
+//! Please note that the examples are designed to be run against the `main` branch of the Github
+//! repository. This means that you may not be able to compile with the latest release version on
+//! crates.io, or the one that you have installed locally.
+//!
+//! See the [examples readme] for more information on finding examples that match the version of the
+//! library you are using.
+//!
+//! [Ratatui]: https://github.com/ratatui/ratatui
+//! [examples]: https://github.com/ratatui/ratatui/blob/main/examples
+//! [examples readme]: https://github.com/ratatui/ratatui/blob/main/examples/README.md
+
+use color_eyre::Result;
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{gallery, List, ListState, StatefulWidget, Widget},
+    DefaultTerminal,
+};
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+    let terminal = ratatui::init();
+    let app_result = App::default().run(terminal);
+    ratatui::restore();
+    app_result
+}
+
+#[derive(Default)]
+struct App {
+    should_exit: bool,
+    state: ListState,
+}
+
+impl App {
+    fn run(mut self, mut terminal: DefaultTerminal) -> Result<()> {
+        self.state.select_first();
+        while !self.should_exit {
+            terminal.draw(|frame| frame.render_widget(&mut self, frame.area()))?;
+            self.handle_events()?;
+        }
+        Ok(())
+    }
+
+    fn handle_events(&mut self) -> Result<()> {
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press {
+                match key.code {
+                    KeyCode::Char('j') | KeyCode::Down => self.state.select_next(),
+                    KeyCode::Char('k') | KeyCode::Up => self.state.select_previous(),
+                    KeyCode::Char('q') | KeyCode::Esc => self.should_exit = true,
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Widget for &mut App {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let demos = gallery::demos();
+        let [header_area, main_area, footer_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+        let [list_area, demo_area] =
+            Layout::horizontal([Constraint::Length(14), Constraint::Fill(1)]).areas(main_area);
+
+        Line::from("Ratatui widget gallery")
+            .bold()
+            .centered()
+            .render(header_area, buf);
+        let list = List::new(demos.iter().map(gallery::Demo::name))
+            .highlight_symbol("> ")
+            .highlight_style(Style::new().reversed());
+        StatefulWidget::render(list, list_area, buf, &mut self.state);
+
+        let selected = self.state.selected().and_then(|index| demos.get(index));
+        if let Some(demo) = selected {
+            demo.render(demo_area, buf);
+            Line::from(demo.description())
+                .italic()
+                .centered()
+                .render(footer_area, buf);
+        }
+    }
+}
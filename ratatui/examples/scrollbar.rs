@@ -58,24 +58,30 @@ impl App {
                         KeyCode::Char('q') => return Ok(()),
                         KeyCode::Char('j') | KeyCode::Down => {
                             self.vertical_scroll = self.vertical_scroll.saturating_add(1);
-                            self.vertical_scroll_state =
-                                self.vertical_scroll_state.position(self.vertical_scroll);
+                            self.vertical_scroll_state = self
+                                .vertical_scroll_state
+                                .clone()
+                                .position(self.vertical_scroll);
                         }
                         KeyCode::Char('k') | KeyCode::Up => {
                             self.vertical_scroll = self.vertical_scroll.saturating_sub(1);
-                            self.vertical_scroll_state =
-                                self.vertical_scroll_state.position(self.vertical_scroll);
+                            self.vertical_scroll_state = self
+                                .vertical_scroll_state
+                                .clone()
+                                .position(self.vertical_scroll);
                         }
                         KeyCode::Char('h') | KeyCode::Left => {
                             self.horizontal_scroll = self.horizontal_scroll.saturating_sub(1);
                             self.horizontal_scroll_state = self
                                 .horizontal_scroll_state
+                                .clone()
                                 .position(self.horizontal_scroll);
                         }
                         KeyCode::Char('l') | KeyCode::Right => {
                             self.horizontal_scroll = self.horizontal_scroll.saturating_add(1);
                             self.horizontal_scroll_state = self
                                 .horizontal_scroll_state
+                                .clone()
                                 .position(self.horizontal_scroll);
                         }
                         _ => {}
@@ -129,8 +135,14 @@ impl App {
                 Span::styled(Masked::new("password", '*'), Style::new().fg(Color::Red)),
             ]),
         ];
-        self.vertical_scroll_state = self.vertical_scroll_state.content_length(text.len());
-        self.horizontal_scroll_state = self.horizontal_scroll_state.content_length(long_line.len());
+        self.vertical_scroll_state = self
+            .vertical_scroll_state
+            .clone()
+            .content_length(text.len());
+        self.horizontal_scroll_state = self
+            .horizontal_scroll_state
+            .clone()
+            .content_length(long_line.len());
 
         let create_block = |title: &'static str| Block::bordered().gray().title(title.bold());
 
@@ -41,8 +41,6 @@ const INFO_TEXT: [&str; 2] = [
     "(Shift + →) next color | (Shift + ←) previous color",
 ];
 
-const ITEM_HEIGHT: usize = 4;
-
 fn main() -> Result<()> {
     color_eyre::install()?;
     let terminal = ratatui::init();
@@ -119,7 +117,7 @@ impl App {
         Self {
             state: TableState::default().with_selected(0),
             longest_item_lens: constraint_len_calculator(&data_vec),
-            scroll_state: ScrollbarState::new((data_vec.len() - 1) * ITEM_HEIGHT),
+            scroll_state: ScrollbarState::default(),
             colors: TableColors::new(&PALETTES[0]),
             color_index: 0,
             items: data_vec,
@@ -137,7 +135,6 @@ impl App {
             None => 0,
         };
         self.state.select(Some(i));
-        self.scroll_state = self.scroll_state.position(i * ITEM_HEIGHT);
     }
 
     pub fn previous_row(&mut self) {
@@ -152,7 +149,6 @@ impl App {
             None => 0,
         };
         self.state.select(Some(i));
-        self.scroll_state = self.scroll_state.position(i * ITEM_HEIGHT);
     }
 
     pub fn next_column(&mut self) {
@@ -263,7 +259,8 @@ impl App {
         ]))
         .bg(self.colors.buffer_bg)
         .highlight_spacing(HighlightSpacing::Always);
-        frame.render_stateful_widget(t, area, &mut self.state);
+        frame.render_stateful_widget(&t, area, &mut self.state);
+        self.scroll_state.sync_with_table(&t, &self.state, area);
     }
 
     fn render_scrollbar(&mut self, frame: &mut Frame, area: Rect) {
@@ -358,8 +358,12 @@ pub mod backend {
     pub use ratatui_termwiz::{FromTermwiz, IntoTermwiz, TermwizBackend};
 }
 
+#[cfg(feature = "persist")]
+pub mod persist;
 pub mod prelude;
-pub use ratatui_core::{style, symbols, text};
+pub use ratatui_core::{markup, style, symbols, text};
+pub mod timestep;
+pub mod touch;
 pub mod widgets;
 pub use ratatui_widgets::border;
 #[cfg(feature = "crossterm")]
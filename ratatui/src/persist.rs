@@ -0,0 +1,236 @@
+//! Persistence of application state between sessions.
+//!
+//! Applications often want to remember where the user left off: the scroll position of a list,
+//! the selected tab, the size of resizable panes, etc. [`Session`] is a registry of named values
+//! that is restored from a file on startup and saved back to it on exit. Any type that implements
+//! [`serde::Serialize`] and [`serde::Deserialize`] can be stored, including the states of the
+//! widgets that support serde (e.g. [`ListState`], [`TableState`] and [`ScrollbarState`]).
+//!
+//! The session is stored as JSON in `session.json` in the configuration directory of the
+//! application (see [`Session::load_app`] and [`config_dir`]), or at any other path with
+//! [`Session::load`].
+//!
+//! This module is only available when the `persist` feature is enabled.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use ratatui::{persist::Session, widgets::ListState};
+//!
+//! # fn main() -> std::io::Result<()> {
+//! let mut session = Session::load_app("my-app")?;
+//! let mut list_state = ListState::default();
+//! let tab: usize = session.get("tab").unwrap_or_default();
+//! session.restore("files", &mut list_state);
+//!
+//! // run the application...
+//!
+//! session.set("tab", &tab)?;
+//! session.set("files", &list_state)?;
+//! session.save()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`ListState`]: crate::widgets::ListState
+//! [`TableState`]: crate::widgets::TableState
+//! [`ScrollbarState`]: crate::widgets::ScrollbarState
+use std::{
+    collections::BTreeMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+/// The name of the file in which the session of an application is stored.
+const SESSION_FILE: &str = "session.json";
+
+/// A registry of named values that is persisted between the sessions of an application.
+///
+/// The values are stored as serde values, so a value can be restored as any type that has the same
+/// serialized representation. Values that can't be deserialized as the requested type (e.g. after
+/// the type changed in a new version of the application) are ignored, so that a stale session
+/// never prevents an application from starting.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Session {
+    path: PathBuf,
+    values: BTreeMap<String, Value>,
+}
+
+impl Session {
+    /// Creates an empty session that is saved at the given path.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            values: BTreeMap::new(),
+        }
+    }
+
+    /// Loads the session stored at the given path.
+    ///
+    /// Returns an empty session if the file doesn't exist yet, and an error if it can't be read
+    /// or if it is not a valid session file.
+    pub fn load<P: Into<PathBuf>>(path: P) -> io::Result<Self> {
+        let mut session = Self::new(path);
+        match fs::read(&session.path) {
+            Ok(content) => session.values = serde_json::from_slice(&content)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+        Ok(session)
+    }
+
+    /// Loads the session of the application with the given name from its configuration directory.
+    ///
+    /// The session is stored in `<config dir>/<app_name>/session.json`, where the configuration
+    /// directory is the one returned by [`config_dir`]. Returns an error if the configuration
+    /// directory can't be determined, and otherwise behaves like [`Session::load`].
+    pub fn load_app(app_name: &str) -> io::Result<Self> {
+        let dir = config_dir().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "could not determine the configuration directory",
+            )
+        })?;
+        Self::load(dir.join(app_name).join(SESSION_FILE))
+    }
+
+    /// Returns the path where the session is saved.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the value with the given name.
+    ///
+    /// Returns `None` if there is no such value, or if it can't be deserialized as `T`.
+    pub fn get<T: DeserializeOwned>(&self, name: &str) -> Option<T> {
+        let value = self.values.get(name)?;
+        T::deserialize(value).ok()
+    }
+
+    /// Overwrites `value` with the value with the given name, if there is one.
+    ///
+    /// Returns whether the value was restored. This is convenient for widget states that are
+    /// created with their own defaults before being restored.
+    pub fn restore<T: DeserializeOwned>(&self, name: &str, value: &mut T) -> bool {
+        let Some(restored) = self.get(name) else {
+            return false;
+        };
+        *value = restored;
+        true
+    }
+
+    /// Sets the value with the given name, replacing the previous value.
+    ///
+    /// Returns an error if the value can't be serialized (e.g. a map with non-string keys).
+    pub fn set<T: Serialize + ?Sized>(&mut self, name: &str, value: &T) -> io::Result<()> {
+        let value = serde_json::to_value(value)?;
+        self.values.insert(name.to_string(), value);
+        Ok(())
+    }
+
+    /// Removes the value with the given name.
+    pub fn remove(&mut self, name: &str) {
+        self.values.remove(name);
+    }
+
+    /// Saves the session to its path, creating the parent directories if needed.
+    ///
+    /// The session is first written to a temporary file which then replaces the session file, so
+    /// that the previous session is kept if the application is interrupted while saving.
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_vec_pretty(&self.values)?;
+        let temp_path = self.path.with_extension("json.tmp");
+        fs::write(&temp_path, content)?;
+        fs::rename(temp_path, &self.path)
+    }
+}
+
+/// Returns the platform's directory for the configuration of applications.
+///
+/// This is:
+///
+/// - `%APPDATA%` on Windows
+/// - `$HOME/Library/Application Support` on macOS
+/// - `$XDG_CONFIG_HOME` or `$HOME/.config` on other platforms
+///
+/// Returns `None` if the required environment variables are not set.
+pub fn config_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        return env::var_os("APPDATA").map(PathBuf::from);
+    }
+    let home = env::var_os("HOME").map(PathBuf::from);
+    if cfg!(target_os = "macos") {
+        return home.map(|home| home.join("Library").join("Application Support"));
+    }
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| home.map(|home| home.join(".config")))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::widgets::ListState;
+
+    /// Returns a path in a new temporary directory.
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("ratatui-persist-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("nested").join(SESSION_FILE)
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = temp_path("save_and_load");
+        let mut session = Session::load(&path).unwrap();
+        assert_eq!(session, Session::new(&path));
+
+        let state = ListState::default().with_selected(Some(3)).with_offset(2);
+        session.set("list", &state).unwrap();
+        session.set("tab", &1).unwrap();
+        session.save().unwrap();
+
+        let session = Session::load(&path).unwrap();
+        let mut restored = ListState::default();
+        assert!(session.restore("list", &mut restored));
+        assert_eq!(restored, state);
+        assert_eq!(session.get::<usize>("tab"), Some(1));
+        assert!(!path.with_extension("json.tmp").exists());
+        fs::remove_dir_all(path.parent().unwrap().parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn mismatched_types_are_ignored() {
+        let mut session = Session::new("session.json");
+        session.set("tab", "settings").unwrap();
+        let mut tab = 2_usize;
+        assert!(!session.restore("tab", &mut tab));
+        assert_eq!(tab, 2);
+        assert_eq!(session.get::<String>("tab"), Some("settings".to_string()));
+        assert_eq!(session.get::<String>("missing"), None);
+
+        session.remove("tab");
+        assert_eq!(session.get::<String>("tab"), None);
+    }
+
+    #[test]
+    fn invalid_file() {
+        let path = temp_path("invalid_file");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "not json").unwrap();
+        let err = Session::load(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        fs::remove_dir_all(path.parent().unwrap().parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,261 @@
+//! Utilities for running game-loop style applications with a fixed timestep.
+//!
+//! Terminal games and animations often want their simulation (physics, movement, animations) to
+//! advance in constant increments, independently of how often the screen is drawn or how long it
+//! takes to process events. [`FixedTimestep`] implements the classic accumulator approach: the
+//! time that elapsed between frames is accumulated and consumed in fixed sized steps, and the
+//! remainder is exposed as an interpolation factor that can be used to smooth rendering between
+//! two simulation states.
+//!
+//! See [Fix Your Timestep!](https://gafferongames.com/post/fix_your_timestep/) for more details
+//! about the technique.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use ratatui::timestep::FixedTimestep;
+//!
+//! # fn update(_dt: Duration) {}
+//! # fn draw(_alpha: f64) {}
+//! let mut timestep = FixedTimestep::from_hz(60.0);
+//! loop {
+//!     for _ in 0..timestep.tick() {
+//!         update(timestep.step());
+//!     }
+//!     draw(timestep.alpha());
+//! #   break;
+//! }
+//! ```
+use std::time::{Duration, Instant};
+
+/// The default maximum amount of time that is accumulated in a single frame.
+const DEFAULT_MAX_FRAME_TIME: Duration = Duration::from_millis(250);
+
+/// A fixed timestep accumulator for game loops.
+///
+/// Each frame, call [`FixedTimestep::tick`] (which measures the elapsed time itself) or
+/// [`FixedTimestep::advance`] (which takes an explicit delta time) to find out how many fixed
+/// steps the simulation should be advanced. After running the updates, [`FixedTimestep::alpha`]
+/// returns how far the current time is between the last and the next step, which can be used to
+/// interpolate the rendered state.
+///
+/// To avoid a "spiral of death" where a slow frame causes more updates which cause an even
+/// slower frame, the elapsed time of a single frame is capped at
+/// [`FixedTimestep::max_frame_time`] (250ms by default).
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::timestep::FixedTimestep;
+///
+/// let mut timestep = FixedTimestep::new(Duration::from_millis(10));
+/// assert_eq!(timestep.advance(Duration::from_millis(25)), 2);
+/// assert_eq!(timestep.alpha(), 0.5);
+/// assert_eq!(timestep.advance(Duration::from_millis(5)), 1);
+/// assert_eq!(timestep.alpha(), 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct FixedTimestep {
+    step: Duration,
+    max_frame_time: Duration,
+    accumulator: Duration,
+    delta: Duration,
+    last_tick: Option<Instant>,
+}
+
+impl FixedTimestep {
+    /// Creates a new fixed timestep with the given step duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    pub const fn new(step: Duration) -> Self {
+        assert!(!step.is_zero(), "step must be greater than zero");
+        Self {
+            step,
+            max_frame_time: DEFAULT_MAX_FRAME_TIME,
+            accumulator: Duration::ZERO,
+            delta: Duration::ZERO,
+            last_tick: None,
+        }
+    }
+
+    /// Creates a new fixed timestep that runs the given number of steps per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hz` is not a positive finite number, or if it is so large that a step is shorter
+    /// than a nanosecond, or so small that a step doesn't fit in a [`Duration`].
+    pub fn from_hz(hz: f64) -> Self {
+        let step = Duration::try_from_secs_f64(1.0 / hz)
+            .ok()
+            .filter(|step| !step.is_zero() && hz.is_finite());
+        match step {
+            Some(step) => Self::new(step),
+            None => panic!("hz must be a positive number that gives a step of at least 1ns"),
+        }
+    }
+
+    /// Sets the maximum amount of time that is accumulated in a single frame.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn max_frame_time(mut self, max_frame_time: Duration) -> Self {
+        self.max_frame_time = max_frame_time;
+        self
+    }
+
+    /// Returns the duration of a single step.
+    pub const fn step(&self) -> Duration {
+        self.step
+    }
+
+    /// Returns the time that elapsed in the last frame, after capping it to the maximum frame
+    /// time.
+    ///
+    /// This is useful for the parts of an application that should be updated once per frame with
+    /// a variable delta time rather than in fixed steps.
+    pub const fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    /// Measures the time since the previous call and returns the number of steps to run.
+    ///
+    /// The first call only starts the clock and returns `0`.
+    pub fn tick(&mut self) -> u32 {
+        let now = Instant::now();
+        let delta = self
+            .last_tick
+            .map_or(Duration::ZERO, |last| now.duration_since(last));
+        self.last_tick = Some(now);
+        self.advance(delta)
+    }
+
+    /// Adds `delta` to the accumulated time and returns the number of steps to run.
+    ///
+    /// `delta` is capped to the maximum frame time. The steps are consumed from the accumulator,
+    /// leaving only the remainder that is smaller than a single step. The number of steps saturates
+    /// at [`u32::MAX`], e.g. with a step of a nanosecond and a long maximum frame time.
+    pub fn advance(&mut self, delta: Duration) -> u32 {
+        self.delta = delta.min(self.max_frame_time);
+        let accumulator = self.accumulator.saturating_add(self.delta).as_nanos();
+        let step = self.step.as_nanos();
+        // the remainder is smaller than the step, so it fits in a duration
+        let remainder = accumulator % step;
+        self.accumulator = Duration::new(
+            (remainder / 1_000_000_000) as u64,
+            (remainder % 1_000_000_000) as u32,
+        );
+        u32::try_from(accumulator / step).unwrap_or(u32::MAX)
+    }
+
+    /// Returns the interpolation factor between the previous and the next step.
+    ///
+    /// The value is in the range `0.0..1.0`, where `0.0` means the current time is exactly at the
+    /// last step. Render `previous * (1.0 - alpha) + current * alpha` to smooth out the movement
+    /// when the frame rate is not a multiple of the step rate.
+    pub fn alpha(&self) -> f64 {
+        self.accumulator.as_secs_f64() / self.step.as_secs_f64()
+    }
+
+    /// Clears the accumulated time and restarts the clock used by [`FixedTimestep::tick`].
+    ///
+    /// Call this after the application was paused to avoid catching up with the elapsed time.
+    pub fn reset(&mut self) {
+        self.accumulator = Duration::ZERO;
+        self.delta = Duration::ZERO;
+        self.last_tick = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    const STEP: Duration = Duration::from_millis(10);
+
+    #[test]
+    fn accumulates_partial_steps() {
+        let mut timestep = FixedTimestep::new(STEP);
+        assert_eq!(timestep.advance(Duration::from_millis(4)), 0);
+        assert_eq!(timestep.advance(Duration::from_millis(4)), 0);
+        assert_eq!(timestep.advance(Duration::from_millis(4)), 1);
+        assert_eq!(timestep.advance(Duration::from_millis(18)), 2);
+        assert_eq!(timestep.advance(Duration::ZERO), 0);
+        assert_eq!(timestep.delta(), Duration::ZERO);
+    }
+
+    #[test]
+    fn caps_the_frame_time() {
+        let mut timestep = FixedTimestep::new(STEP);
+        assert_eq!(timestep.advance(Duration::from_secs(10)), 25);
+        assert_eq!(timestep.delta(), DEFAULT_MAX_FRAME_TIME);
+        assert!(timestep.alpha().abs() < 1e-9);
+
+        let mut timestep = timestep.max_frame_time(Duration::from_millis(35));
+        assert_eq!(timestep.advance(Duration::from_secs(10)), 3);
+        assert_eq!(timestep.delta(), Duration::from_millis(35));
+    }
+
+    #[rstest]
+    #[case::start(Duration::ZERO, 0.0)]
+    #[case::quarter(Duration::from_micros(2500), 0.25)]
+    #[case::half(Duration::from_millis(5), 0.5)]
+    #[case::after_steps(Duration::from_micros(27_500), 0.75)]
+    fn alpha(#[case] delta: Duration, #[case] expected: f64) {
+        let mut timestep = FixedTimestep::new(STEP);
+        timestep.advance(delta);
+        assert!((timestep.alpha() - expected).abs() < 1e-9);
+        assert!((0.0..1.0).contains(&timestep.alpha()));
+    }
+
+    #[test]
+    fn reset() {
+        let mut timestep = FixedTimestep::new(STEP);
+        timestep.tick();
+        timestep.advance(Duration::from_millis(15));
+        timestep.reset();
+        assert_eq!(timestep, FixedTimestep::new(STEP));
+        assert_eq!(timestep.tick(), 0);
+    }
+
+    #[test]
+    fn from_hz() {
+        let timestep = FixedTimestep::from_hz(100.0);
+        assert_eq!(timestep.step(), STEP);
+    }
+
+    #[test]
+    fn many_steps() {
+        let mut timestep =
+            FixedTimestep::new(Duration::from_nanos(1)).max_frame_time(Duration::from_secs(10));
+        assert_eq!(timestep.advance(Duration::from_secs(1)), 1_000_000_000);
+        assert_eq!(timestep.advance(Duration::from_secs(10)), u32::MAX);
+        assert!(timestep.alpha().abs() < 1e-9);
+    }
+
+    #[rstest]
+    #[case::zero(0.0)]
+    #[case::negative(-1.0)]
+    #[case::infinite(f64::INFINITY)]
+    #[case::nan(f64::NAN)]
+    #[case::step_below_a_nanosecond(1e12)]
+    #[case::step_too_long(1e-30)]
+    #[should_panic = "hz must be a positive number"]
+    fn invalid_hz(#[case] hz: f64) {
+        let _ = FixedTimestep::from_hz(hz);
+    }
+
+    #[test]
+    #[should_panic = "step must be greater than zero"]
+    fn zero_step() {
+        let _ = FixedTimestep::new(Duration::ZERO);
+    }
+}
@@ -0,0 +1,365 @@
+//! Utilities for handling touch input.
+//!
+//! Terminals running on tablets and phones usually report touches as mouse events: a touch is a
+//! mouse button press, moving the finger is a drag and lifting it is a button release. These
+//! events have different dynamics than the ones of a real mouse: fingers are less precise, they
+//! move a little while tapping, and users expect scrollable content to follow the finger and keep
+//! scrolling with momentum after a flick.
+//!
+//! Ratatui does not handle input, so this module only provides backend independent building
+//! blocks that applications feed with the events of their backend:
+//!
+//! - [`TouchScroll`] turns press, drag and release events into a number of lines to scroll (e.g.
+//!   the offset of a [`ListState`] or [`TableState`]), with momentum after the finger is lifted,
+//!   and distinguishes taps from drags
+//! - [`Rect::outer`] enlarges small hit targets (e.g. buttons or scrollbar thumbs) so that they
+//!   are easier to hit with a finger
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::{Duration, Instant};
+//!
+//! use ratatui::{layout::Position, touch::TouchScroll, widgets::ListState};
+//!
+//! let mut touch = TouchScroll::new();
+//! let mut state = ListState::default();
+//! let start = Instant::now();
+//!
+//! // the finger moves up by 4 rows in 40ms, which scrolls the list down by 4 items
+//! touch.press(Position::new(5, 10), start);
+//! let lines = touch.drag(Position::new(5, 6), start + Duration::from_millis(40));
+//! *state.offset_mut() = state.offset().saturating_add_signed(lines);
+//! assert_eq!(state.offset(), 4);
+//!
+//! // after the finger is lifted, the list keeps scrolling with momentum
+//! assert_eq!(touch.release(start + Duration::from_millis(40)), None);
+//! let lines = touch.tick(start + Duration::from_millis(65));
+//! *state.offset_mut() = state.offset().saturating_add_signed(lines);
+//! assert_eq!(state.offset(), 6);
+//! ```
+//!
+//! [`ListState`]: crate::widgets::ListState
+//! [`TableState`]: crate::widgets::TableState
+//! [`Rect::outer`]: crate::layout::Rect::outer
+use std::time::{Duration, Instant};
+
+use crate::layout::Position;
+
+/// The default distance (in cells) that a touch can move before it is considered a drag.
+const DEFAULT_SLOP: u16 = 1;
+
+/// The default fraction of the momentum velocity that is kept after one second.
+const DEFAULT_FRICTION: f64 = 0.05;
+
+/// The velocity (in lines per second) below which the momentum stops.
+const MIN_VELOCITY: f64 = 1.0;
+
+/// The time after which a finger that doesn't move is considered to be held still, which cancels
+/// the momentum when it is lifted.
+const HOLD_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Tracks press-drag scroll gestures with momentum.
+///
+/// Feed the mouse events of the backend to [`TouchScroll::press`], [`TouchScroll::drag`] and
+/// [`TouchScroll::release`], and call [`TouchScroll::tick`] on every frame to apply the momentum
+/// of a flick. Each of these methods returns the number of lines to scroll, where positive values
+/// scroll the content down (the finger moves up) and negative values scroll it up.
+///
+/// A touch that moves less than the slop ([`TouchScroll::slop`], 1 cell by default) is a tap
+/// rather than a drag: it doesn't scroll, and its position is returned when the finger is lifted,
+/// so that the application can e.g. select the item under the finger.
+///
+/// The velocity of the momentum decays exponentially according to the friction
+/// ([`TouchScroll::friction`]), which is the fraction of the velocity that is kept after one
+/// second.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TouchScroll {
+    slop: u16,
+    friction: f64,
+    gesture: Option<Gesture>,
+    /// The scroll velocity in lines per second
+    velocity: f64,
+    /// The fraction of a line that was scrolled by the momentum but not returned yet
+    remainder: f64,
+    last_tick: Option<Instant>,
+}
+
+/// A touch that is in progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Gesture {
+    start: Position,
+    last: Position,
+    last_time: Instant,
+    dragging: bool,
+}
+
+impl Default for TouchScroll {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TouchScroll {
+    /// Creates a new touch scroll tracker with the default slop and friction.
+    pub const fn new() -> Self {
+        Self {
+            slop: DEFAULT_SLOP,
+            friction: DEFAULT_FRICTION,
+            gesture: None,
+            velocity: 0.0,
+            remainder: 0.0,
+            last_tick: None,
+        }
+    }
+
+    /// Sets the distance (in cells) that a touch can move before it is considered a drag.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn slop(mut self, slop: u16) -> Self {
+        self.slop = slop;
+        self
+    }
+
+    /// Sets the fraction of the momentum velocity that is kept after one second.
+    ///
+    /// The velocity decays exponentially: after `t` seconds, it is multiplied by `friction^t`. The
+    /// distance is integrated over the time between two [ticks](TouchScroll::tick), so the
+    /// momentum doesn't depend on the frame rate. Lower values stop the momentum sooner, values
+    /// closer to `1.0` make it last longer, and `0.0` disables it. The value is clamped to
+    /// `0.0..=0.999`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn friction(mut self, friction: f64) -> Self {
+        self.friction = friction.clamp(0.0, 0.999);
+        self
+    }
+
+    /// Returns whether the content is still scrolling with momentum.
+    pub fn is_scrolling(&self) -> bool {
+        self.gesture.is_none() && self.velocity != 0.0
+    }
+
+    /// Starts a touch at the given position, which stops any momentum.
+    pub fn press(&mut self, position: Position, now: Instant) {
+        self.stop();
+        self.gesture = Some(Gesture {
+            start: position,
+            last: position,
+            last_time: now,
+            dragging: false,
+        });
+    }
+
+    /// Moves the touch to the given position and returns the number of lines to scroll.
+    ///
+    /// This returns `0` while the touch is within the slop of its start position, or when no
+    /// touch is in progress.
+    pub fn drag(&mut self, position: Position, now: Instant) -> isize {
+        let slop = self.slop;
+        let Some(gesture) = self.gesture.as_mut() else {
+            return 0;
+        };
+        if !gesture.dragging {
+            let moved_x = position.x.abs_diff(gesture.start.x);
+            let moved_y = position.y.abs_diff(gesture.start.y);
+            if moved_x <= slop && moved_y <= slop {
+                return 0;
+            }
+            gesture.dragging = true;
+        }
+        let lines = (i32::from(gesture.last.y) - i32::from(position.y)) as isize;
+        let elapsed = now
+            .saturating_duration_since(gesture.last_time)
+            .as_secs_f64();
+        gesture.last = position;
+        gesture.last_time = now;
+        if elapsed > 0.0 {
+            let velocity = lines as f64 / elapsed;
+            // smooth out the velocity, as the events of a drag are not evenly spaced
+            self.velocity = if self.velocity == 0.0 {
+                velocity
+            } else {
+                0.6f64.mul_add(velocity, 0.4 * self.velocity)
+            };
+        }
+        lines
+    }
+
+    /// Ends the touch and starts the momentum if the touch was a flick.
+    ///
+    /// Returns the start position of the touch if it was a tap (i.e. it stayed within the slop),
+    /// and `None` if it was a drag or if no touch was in progress.
+    pub fn release(&mut self, now: Instant) -> Option<Position> {
+        let gesture = self.gesture.take()?;
+        if !gesture.dragging {
+            self.stop();
+            return Some(gesture.start);
+        }
+        if now.saturating_duration_since(gesture.last_time) > HOLD_TIMEOUT {
+            self.stop();
+        }
+        self.last_tick = Some(now);
+        None
+    }
+
+    /// Advances the momentum to the given time and returns the number of lines to scroll.
+    ///
+    /// Call this on every frame. This returns `0` while a touch is in progress or once the
+    /// momentum has stopped.
+    pub fn tick(&mut self, now: Instant) -> isize {
+        if self.gesture.is_some() {
+            return 0;
+        }
+        let Some(last_tick) = self.last_tick.replace(now) else {
+            return 0;
+        };
+        if self.velocity.abs() < MIN_VELOCITY {
+            self.stop();
+            return 0;
+        }
+        let elapsed = now.saturating_duration_since(last_tick).as_secs_f64();
+        // the integral of `velocity * friction^t` over the elapsed time
+        let decay = self.friction.powf(elapsed);
+        let distance = self.velocity * (decay - 1.0) / self.friction.ln() + self.remainder;
+        let lines = distance.trunc();
+        self.remainder = distance - lines;
+        self.velocity *= decay;
+        lines as isize
+    }
+
+    /// Stops the momentum.
+    pub fn stop(&mut self) {
+        self.velocity = 0.0;
+        self.remainder = 0.0;
+        self.last_tick = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    /// Returns a tracker after a flick of 4 lines up in 40ms (100 lines per second)
+    fn flicked(start: Instant) -> TouchScroll {
+        let mut touch = TouchScroll::new();
+        touch.press(Position::new(5, 10), start);
+        assert_eq!(touch.drag(Position::new(5, 6), start + ms(40)), 4);
+        assert_eq!(touch.release(start + ms(40)), None);
+        touch
+    }
+
+    #[test]
+    fn tap() {
+        let start = Instant::now();
+        let mut touch = TouchScroll::new();
+        touch.press(Position::new(5, 10), start);
+        // moving within the slop doesn't scroll
+        assert_eq!(touch.drag(Position::new(6, 11), start + ms(20)), 0);
+        assert_eq!(touch.release(start + ms(40)), Some(Position::new(5, 10)));
+        assert!(!touch.is_scrolling());
+        assert_eq!(touch.tick(start + ms(60)), 0);
+    }
+
+    #[test]
+    fn drag() {
+        let start = Instant::now();
+        let mut touch = TouchScroll::new().slop(2);
+        touch.press(Position::new(5, 10), start);
+        assert_eq!(touch.drag(Position::new(5, 8), start + ms(20)), 0);
+        assert_eq!(touch.drag(Position::new(5, 7), start + ms(40)), 3);
+        assert_eq!(touch.drag(Position::new(5, 9), start + ms(60)), -2);
+        // no momentum while the finger is down
+        assert_eq!(touch.tick(start + ms(80)), 0);
+        assert_eq!(touch.drag(Position::new(5, 9), start + ms(100)), 0);
+    }
+
+    #[test]
+    fn drag_without_press() {
+        let mut touch = TouchScroll::new();
+        assert_eq!(touch.drag(Position::new(5, 10), Instant::now()), 0);
+        assert_eq!(touch.release(Instant::now()), None);
+    }
+
+    #[test]
+    fn fling() {
+        let start = Instant::now();
+        let mut touch = flicked(start);
+        assert!(touch.is_scrolling());
+        assert_eq!(touch.tick(start + ms(65)), 2);
+        assert!(touch.tick(start + ms(1000)) > 0);
+    }
+
+    #[test]
+    fn hold_before_release() {
+        let start = Instant::now();
+        let mut touch = TouchScroll::new();
+        touch.press(Position::new(5, 10), start);
+        touch.drag(Position::new(5, 6), start + ms(40));
+        assert_eq!(touch.release(start + ms(40) + HOLD_TIMEOUT + ms(1)), None);
+        assert!(!touch.is_scrolling());
+        assert_eq!(touch.tick(start + ms(500)), 0);
+    }
+
+    #[test]
+    fn press_stops_momentum() {
+        let start = Instant::now();
+        let mut touch = flicked(start);
+        touch.press(Position::new(5, 10), start + ms(50));
+        assert!(!touch.is_scrolling());
+        assert_eq!(touch.tick(start + ms(100)), 0);
+    }
+
+    #[test]
+    fn decay_keeps_friction_after_one_second() {
+        let start = Instant::now();
+        let mut touch = flicked(start);
+        touch.tick(start + ms(1040));
+        assert!((touch.velocity - 100.0 * DEFAULT_FRICTION).abs() < 1e-6);
+    }
+
+    #[test]
+    fn decay_does_not_depend_on_the_frame_rate() {
+        let start = Instant::now();
+        let mut once = flicked(start);
+        let mut often = flicked(start);
+        let lines = once.tick(start + ms(540));
+        let often_lines = (1..=50)
+            .map(|frame| often.tick(start + ms(40 + 10 * frame)))
+            .sum::<isize>();
+        assert_eq!(lines, often_lines);
+        assert!((once.velocity - often.velocity).abs() < 1e-6);
+        assert_eq!(lines, 25);
+    }
+
+    #[test]
+    fn momentum_stops_below_min_velocity() {
+        let start = Instant::now();
+        let mut touch = flicked(start);
+        let mut frame = 40;
+        while touch.is_scrolling() {
+            frame += 100;
+            touch.tick(start + ms(frame));
+        }
+        assert!(touch.velocity.abs() < f64::EPSILON);
+        assert!(frame < 3000);
+    }
+
+    #[test]
+    fn zero_friction_disables_momentum() {
+        let start = Instant::now();
+        let mut touch = flicked(start).friction(-1.0);
+        assert_eq!(touch.tick(start + ms(100)), 0);
+        assert!(!touch.is_scrolling());
+    }
+}
@@ -21,10 +21,14 @@
 //! - [`LineGauge`]: display progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
+//! - [`Remote`]: displays a widget whose data is loaded asynchronously, with loading and error
+//!   states.
+//! - [`HRuler`] and [`VRuler`]: display cell coordinates to help debugging layouts.
 //! - [`Scrollbar`]: displays a scrollbar.
 //! - [`Sparkline`]: display a single data set as a sparkline.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`VerticalText`]: displays text vertically, one character per row.
 //!
 //! [`Canvas`]: crate::widgets::canvas::Canvas
 
@@ -33,21 +37,34 @@ pub use ratatui_core::widgets::{StatefulWidget, Widget};
 pub use ratatui_widgets::block;
 #[cfg(feature = "widget-calendar")]
 pub use ratatui_widgets::calendar;
+#[cfg(feature = "widget-gallery")]
+pub use ratatui_widgets::gallery;
 pub use ratatui_widgets::{
-    barchart::{Bar, BarChart, BarGroup},
+    barchart::{Bar, BarChart, BarChartState, BarGroup, BarValuePosition, GroupLabelPosition},
     block::{Block, Padding},
     borders::{BorderType, Borders},
+    cached::{Cached, CachedState},
     canvas,
-    chart::{Axis, Chart, Dataset, GraphType, LegendPosition},
+    chart::{
+        Axis, Candle, Chart, ChartState, Dataset, GraphType, LegendPosition, ReferenceBand,
+        ReferenceLine, Scale, StepPosition,
+    },
     clear::Clear,
-    gauge::{Gauge, LineGauge},
+    gauge::{Gauge, GaugeLabelPosition, GaugeSegment, LineGauge},
     list::{List, ListDirection, ListItem, ListState},
     logo::{RatatuiLogo, Size as RatatuiLogoSize},
-    paragraph::{Paragraph, Wrap},
-    scrollbar::{ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState},
-    sparkline::{RenderDirection, Sparkline, SparklineBar},
+    paragraph::{measure_text, Paragraph, ParagraphScrollState, ParagraphState, TextMetrics, Wrap},
+    remote::Remote,
+    ruler::{HRuler, VRuler},
+    scrollbar::{
+        ScrollDirection, Scrollbar, ScrollbarAutoHide, ScrollbarMarker, ScrollbarOrientation,
+        ScrollbarPart, ScrollbarState,
+    },
+    sparkline::{AbsentValueFill, RenderDirection, Sparkline, SparklineBar, SparklineState},
     table::{Cell, HighlightSpacing, Row, Table, TableState},
-    tabs::Tabs,
+    tabs::{Tab, TabBadge, TabHit, TabView, Tabs, TabsOrientation, TabsState},
+    tilemap::Tilemap,
+    vertical_text::VerticalText,
 };
 #[instability::unstable(feature = "widget-ref")]
 pub use {stateful_widget_ref::StatefulWidgetRef, widget_ref::WidgetRef};
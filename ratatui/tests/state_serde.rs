@@ -45,7 +45,7 @@ impl AppState {
     fn select(&mut self, index: usize) {
         self.list.select(Some(index));
         self.table.select_cell(Some((index, index)));
-        self.scrollbar = self.scrollbar.position(index);
+        self.scrollbar = self.scrollbar.clone().position(index);
     }
 }
 
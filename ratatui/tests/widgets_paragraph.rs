@@ -98,7 +98,7 @@ fn widgets_paragraph_can_scroll_horizontally() {
         paragraph.clone().alignment(Alignment::Left).scroll((0, 7)),
         &Buffer::with_lines([
             "┌──────────────────┐",
-            "│在可以水平滚动了！│",
+            "│ 可以水平滚动了！ │",
             "│ph can scroll hori│",
             "│line              │",
             "│                  │",